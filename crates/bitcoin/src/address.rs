@@ -1,4 +1,4 @@
-use crate::{types::*, Error, Script};
+use crate::{base58, bech32, types::*, Error, Network, Script};
 use bitcoin_hashes::{hash160::Hash as Hash160, Hash};
 use codec::{Decode, Encode, MaxEncodedLen};
 use primitive_types::{H160, H256};
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use secp256k1::{constants::PUBLIC_KEY_SIZE, Error as Secp256k1Error, PublicKey as Secp256k1PublicKey};
 
@@ -35,6 +35,10 @@ pub enum Address {
     // output: OP_0 {sha256(redeem_script)}
     // witness: [redeem_script_sig ...] {redeem_script}
     P2WSHv0(H256),
+    // input: <>
+    // output: OP_1 {tweaked_output_key}
+    // witness: {signature} | [script_input ...] {script} {control_block}
+    P2TRv1(H256),
 }
 
 impl Address {
@@ -45,7 +49,8 @@ impl Address {
         const OP_CHECK_SIG: u8 = OpCode::OpCheckSig as u8;
         const OP_EQUAL: u8 = OpCode::OpEqual as u8;
         const OP_0: u8 = OpCode::Op0 as u8;
-        const MAX_ADDRESS_BYTES: usize = HASH256_SIZE_HEX as usize + 2; // max length is for P2WSHv0; see the match below
+        const OP_1: u8 = OpCode::Op1 as u8;
+        const MAX_ADDRESS_BYTES: usize = HASH256_SIZE_HEX as usize + 2; // max length is for P2WSHv0/P2TRv1; see the match below
 
         let bytes = script.as_bytes();
 
@@ -70,6 +75,9 @@ impl Address {
             &[OP_0, HASH160_SIZE_HEX, ref addr @ ..] if addr.len() == HASH160_SIZE_HEX as usize => {
                 Ok(Self::P2WPKHv0(H160::from_slice(addr)))
             }
+            &[OP_1, HASH256_SIZE_HEX, ref addr @ ..] if addr.len() == HASH256_SIZE_HEX as usize => {
+                Ok(Self::P2TRv1(H256::from_slice(addr)))
+            }
             _ => Err(Error::InvalidBtcAddress),
         }
     }
@@ -108,9 +116,82 @@ impl Address {
                 script.append(script_hash);
                 script
             }
+            Self::P2TRv1(output_key) => {
+                let mut script = Script::new();
+                script.append(OpCode::Op1);
+                script.append(HASH256_SIZE_HEX);
+                script.append(output_key);
+                script
+            }
+        }
+    }
+
+    /// Encodes the address as a human-readable string for the given network. Legacy
+    /// addresses use Base58Check, witness programs use Bech32 (v0) or Bech32m (v1).
+    pub fn to_string(&self, network: Network) -> String {
+        let base58_check = |prefix: u8, hash: &H160| {
+            let mut payload = Vec::with_capacity(1 + HASH160_SIZE_HEX as usize);
+            payload.push(prefix);
+            payload.extend_from_slice(hash.as_bytes());
+            base58::encode_check(&payload)
+        };
+        match self {
+            Self::P2PKH(hash) => base58_check(network.p2pkh_prefix(), hash),
+            Self::P2SH(hash) => base58_check(network.p2sh_prefix(), hash),
+            Self::P2WPKHv0(hash) => bech32::encode_segwit(network.bech32_hrp(), 0, hash.as_bytes()),
+            Self::P2WSHv0(hash) => bech32::encode_segwit(network.bech32_hrp(), 0, hash.as_bytes()),
+            Self::P2TRv1(hash) => bech32::encode_segwit(network.bech32_hrp(), 1, hash.as_bytes()),
+        }
+    }
+
+    fn from_segwit_str(encoded: &str) -> Result<Self, Error> {
+        let (hrp, version, program) = bech32::decode_segwit(encoded)?;
+        if !Network::is_bech32_hrp(&hrp) {
+            return Err(Error::InvalidBtcAddress);
+        }
+        match (version, program.len()) {
+            (0, len) if len == HASH160_SIZE_HEX as usize => Ok(Self::P2WPKHv0(H160::from_slice(&program))),
+            (0, len) if len == HASH256_SIZE_HEX as usize => Ok(Self::P2WSHv0(H256::from_slice(&program))),
+            (1, len) if len == HASH256_SIZE_HEX as usize => Ok(Self::P2TRv1(H256::from_slice(&program))),
+            (0, _) | (1, _) => Err(Error::InvalidBtcAddress),
+            _ => Err(Error::UnsupportedWitnessVersion),
+        }
+    }
+
+    fn from_base58_str(encoded: &str) -> Result<Self, Error> {
+        let payload = base58::decode_check(encoded)?;
+        match payload.as_slice() {
+            [prefix, hash @ ..] if hash.len() == HASH160_SIZE_HEX as usize => {
+                if [Network::Mainnet, Network::Testnet]
+                    .iter()
+                    .any(|n| n.p2pkh_prefix() == *prefix)
+                {
+                    Ok(Self::P2PKH(H160::from_slice(hash)))
+                } else if [Network::Mainnet, Network::Testnet]
+                    .iter()
+                    .any(|n| n.p2sh_prefix() == *prefix)
+                {
+                    Ok(Self::P2SH(H160::from_slice(hash)))
+                } else {
+                    Err(Error::InvalidBtcAddress)
+                }
+            }
+            _ => Err(Error::InvalidBtcAddress),
         }
     }
 
+    /// The P2SH address committing to the given redeem script
+    pub fn from_redeem_script(redeem_script: &[u8]) -> Self {
+        Self::P2SH(H160::from(Hash160::hash(redeem_script).into_inner()))
+    }
+
+    /// The P2WSHv0 address committing to the given witness script
+    pub fn from_witness_script(witness_script: &[u8]) -> Self {
+        let mut hasher = Sha256::default();
+        hasher.input(witness_script);
+        Self::P2WSHv0(H256::from_slice(&hasher.result()[..]))
+    }
+
     #[cfg(feature = "std")]
     pub fn random() -> Self {
         Address::P2PKH(H160::random())
@@ -126,7 +207,7 @@ impl Address {
     pub fn is_zero(&self) -> bool {
         match self {
             Self::P2PKH(hash) | Self::P2SH(hash) | Self::P2WPKHv0(hash) => hash.is_zero(),
-            Self::P2WSHv0(hash) => hash.is_zero(),
+            Self::P2WSHv0(hash) | Self::P2TRv1(hash) => hash.is_zero(),
         }
     }
 }
@@ -137,6 +218,24 @@ impl Default for Address {
     }
 }
 
+/// Parses a human-readable address of any supported network. Since the Parachain
+/// is network agnostic, the network encoded in the string is not returned.
+impl core::str::FromStr for Address {
+    type Err = Error;
+
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        let is_segwit = encoded
+            .rfind('1')
+            .map(|separator| Network::is_bech32_hrp(&encoded[..separator].to_ascii_lowercase()))
+            .unwrap_or(false);
+        if is_segwit {
+            Self::from_segwit_str(encoded)
+        } else {
+            Self::from_base58_str(encoded)
+        }
+    }
+}
+
 /// Compressed ECDSA (secp256k1 curve) Public Key
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct PublicKey(pub [u8; PUBLIC_KEY_SIZE]);
@@ -281,9 +380,34 @@ impl PublicKey {
     /// Construct the redeemScript for a one-signature-required
     /// p2sh transaction.
     pub(crate) fn to_redeem_script(&self) -> Vec<u8> {
-        let mut redeem_script = self.0.to_vec();
-        redeem_script.push(OpCode::OpCheckSig as u8);
-        redeem_script
+        let mut redeem_script = Script::new();
+        redeem_script.append(self.0.to_vec());
+        redeem_script.append(OpCode::OpCheckSig);
+        redeem_script.bytes
+    }
+
+    /// Parses a one-signature-required script, i.e. `{pubkey} OP_CHECKSIG`,
+    /// as used in single-key p2sh and p2wsh spends.
+    pub(crate) fn from_redeem_script(redeem_script: &[u8]) -> Option<Self> {
+        const OP_CHECK_SIG: u8 = OpCode::OpCheckSig as u8;
+        match redeem_script {
+            [len, public_key @ .., OP_CHECK_SIG]
+                if *len as usize == PUBLIC_KEY_SIZE && public_key.len() == PUBLIC_KEY_SIZE =>
+            {
+                Self::from_slice(public_key)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a compressed public key, returns `None` if the length is invalid
+    pub(crate) fn from_slice(bytes: &[u8]) -> Option<Self> {
+        let mut public_key = [0; PUBLIC_KEY_SIZE];
+        if bytes.len() != PUBLIC_KEY_SIZE {
+            return None;
+        }
+        public_key.copy_from_slice(bytes);
+        Some(Self(public_key))
     }
 
     /// Construct the scriptSig for a one-signature-required
@@ -312,6 +436,7 @@ mod tests {
     use super::*;
     use frame_support::assert_err;
     use secp256k1::{rand::rngs::OsRng, Secp256k1, SecretKey as Secp256k1SecretKey};
+    use std::str::FromStr;
 
     #[test]
     fn test_public_key_to_hash() {
@@ -329,6 +454,106 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_p2tr_script_pub_key_roundtrip() {
+        // bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr (BIP-86 test vector)
+        let script =
+            Script::from(hex::decode("5120a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c").unwrap());
+        let address = Address::from_script_pub_key(&script).unwrap();
+
+        assert_eq!(
+            address,
+            Address::P2TRv1(H256::from_slice(
+                &hex::decode("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c").unwrap()
+            ))
+        );
+        assert_eq!(address.to_script_pub_key(), script);
+    }
+
+    #[test]
+    fn test_unsupported_witness_program_fails() {
+        // witness v1 programs must be exactly 32 bytes
+        let script = Script::from(hex::decode("5114751e76e8199196d454941c45d1b3a323f1433bd6").unwrap());
+        assert_err!(Address::from_script_pub_key(&script), Error::InvalidBtcAddress);
+
+        // witness v2 is not yet defined
+        let script =
+            Script::from(hex::decode("5220a37c3903c8d0db6512e2b40b0dffa05e5a3ab73603ce8c9c4b771e5412328f90").unwrap());
+        assert_err!(Address::from_script_pub_key(&script), Error::InvalidBtcAddress);
+    }
+
+    #[test]
+    fn test_address_string_roundtrip() {
+        let h160 = |s: &str| H160::from_slice(&hex::decode(s).unwrap());
+        let h256 = |s: &str| H256::from_slice(&hex::decode(s).unwrap());
+
+        let cases = vec![
+            (
+                Network::Mainnet,
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+                Address::P2PKH(h160("77bff20c60e522dfaa3350c39b030a5d004e839a")),
+            ),
+            (
+                Network::Mainnet,
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                Address::P2SH(h160("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb")),
+            ),
+            (
+                Network::Testnet,
+                "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                Address::P2PKH(h160("243f1394f44554f4ce3fd68649c19adc483ce924")),
+            ),
+            (
+                Network::Mainnet,
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                Address::P2WPKHv0(h160("751e76e8199196d454941c45d1b3a323f1433bd6")),
+            ),
+            (
+                Network::Regtest,
+                "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080",
+                Address::P2WPKHv0(h160("751e76e8199196d454941c45d1b3a323f1433bd6")),
+            ),
+            (
+                Network::Testnet,
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+                Address::P2WSHv0(h256("1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262")),
+            ),
+            (
+                Network::Mainnet,
+                "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+                Address::P2TRv1(h256("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c")),
+            ),
+            (
+                Network::Testnet,
+                "tb1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqp3mvzv",
+                Address::P2TRv1(h256("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c")),
+            ),
+        ];
+
+        for (network, encoded, address) in cases {
+            assert_eq!(Address::from_str(encoded).unwrap(), address);
+            assert_eq!(address.to_string(network), encoded);
+        }
+    }
+
+    #[test]
+    fn test_address_from_str_invalid_checksum_fails() {
+        assert_err!(
+            Address::from_str("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"),
+            Error::InvalidBase58Checksum
+        );
+        assert_err!(
+            Address::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"),
+            Error::InvalidBech32Checksum
+        );
+    }
+
+    #[test]
+    fn test_address_from_str_unknown_hrp_fails() {
+        // litecoin segwit address
+        assert!(Address::from_str("ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9").is_err());
+    }
+
     #[test]
     fn test_check_secret_key_constraints() {
         assert_err!(
@@ -0,0 +1,130 @@
+//! Base58Check encoding as used by legacy (P2PKH & P2SH) addresses.
+//! <https://en.bitcoin.it/wiki/Base58Check_encoding>
+
+use crate::{utils::sha256d, Error};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const CHECKSUM_SIZE: usize = 4;
+
+/// Upper bound on the length of strings we try to decode, no address is longer than this
+const MAX_ENCODED_LEN: usize = 128;
+
+fn checksum(payload: &[u8]) -> [u8; CHECKSUM_SIZE] {
+    let mut ret = [0; CHECKSUM_SIZE];
+    ret.copy_from_slice(&sha256d(payload)[..CHECKSUM_SIZE]);
+    ret
+}
+
+/// Encodes the payload using Base58 without a checksum
+pub(crate) fn encode(payload: &[u8]) -> String {
+    let leading_zeros = payload.iter().take_while(|&&byte| byte == 0).count();
+
+    // base58 digits of the payload in little-endian order; log(256) / log(58) ~ 1.37
+    let mut digits: Vec<u8> = Vec::with_capacity(payload.len() * 138 / 100 + 1);
+    for &byte in &payload[leading_zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut ret = String::with_capacity(leading_zeros + digits.len());
+    ret.extend(vec!['1'; leading_zeros]);
+    ret.extend(digits.iter().rev().map(|&digit| ALPHABET[digit as usize] as char));
+    ret
+}
+
+/// Decodes a Base58 string without verifying any checksum
+pub(crate) fn decode(encoded: &str) -> Result<Vec<u8>, Error> {
+    if encoded.len() > MAX_ENCODED_LEN {
+        return Err(Error::InvalidBase58);
+    }
+
+    let leading_ones = encoded.bytes().take_while(|&c| c == b'1').count();
+
+    // bytes of the payload in little-endian order
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    for c in encoded.bytes().skip(leading_ones) {
+        let mut carry = ALPHABET.iter().position(|&x| x == c).ok_or(Error::InvalidBase58)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut ret = vec![0; leading_ones];
+    ret.extend(bytes.iter().rev());
+    Ok(ret)
+}
+
+/// Appends the 4-byte checksum to the payload and encodes the result
+pub(crate) fn encode_check(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&checksum(payload));
+    encode(&bytes)
+}
+
+/// Decodes the string and verifies the 4-byte checksum, returning only the payload
+pub(crate) fn decode_check(encoded: &str) -> Result<Vec<u8>, Error> {
+    let mut bytes = decode(encoded)?;
+    if bytes.len() < CHECKSUM_SIZE {
+        return Err(Error::InvalidBase58);
+    }
+    let expected = bytes.split_off(bytes.len() - CHECKSUM_SIZE);
+    if expected[..] != checksum(&bytes)[..] {
+        return Err(Error::InvalidBase58Checksum);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::assert_err;
+
+    #[test]
+    fn test_base58_roundtrip() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(encode(&[0, 0, 1]), "112");
+        assert_eq!(encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(decode("StV1DL6CwTryKyV").unwrap(), b"hello world".to_vec());
+        assert_eq!(decode("112").unwrap(), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_base58_invalid_character_fails() {
+        // 0, O, I and l are excluded from the alphabet
+        assert_err!(decode("1BvBMSEYst0etqTFn5Au4m4GFg7xJaNVN2"), Error::InvalidBase58);
+    }
+
+    #[test]
+    fn test_base58check_roundtrip() {
+        let payload = hex::decode("0062e907b15cbf27d5425399ebf6f0fb50ebb88f18").unwrap();
+        let encoded = encode_check(&payload);
+        assert_eq!(encoded, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+        assert_eq!(decode_check(&encoded).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_base58check_invalid_checksum_fails() {
+        assert_err!(
+            decode_check("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Error::InvalidBase58Checksum
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Bech32 and Bech32m encoding of segregated witness addresses.
+//! <https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki>
+//! <https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki>
+
+use crate::Error;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Overall length limit of a Bech32 string
+const MAX_ENCODED_LEN: usize = 90;
+
+/// Witness programs must be between 2 and 40 bytes
+const MIN_WITNESS_PROGRAM_LEN: usize = 2;
+const MAX_WITNESS_PROGRAM_LEN: usize = 40;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Variant {
+    /// Used for witness version 0
+    Bech32,
+    /// Used for witness version 1 and higher
+    Bech32m,
+}
+
+impl Variant {
+    const fn constant(self) -> u32 {
+        match self {
+            Self::Bech32 => 1,
+            Self::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    fn for_witness_version(version: u8) -> Self {
+        if version == 0 {
+            Self::Bech32
+        } else {
+            Self::Bech32m
+        }
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes()
+        .map(|c| c >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 0x1f))
+}
+
+fn create_checksum(hrp: &str, data: &[u8], variant: Variant) -> [u8; CHECKSUM_LENGTH] {
+    let values = hrp_expand(hrp)
+        .chain(data.iter().copied())
+        .chain([0; CHECKSUM_LENGTH].into_iter());
+    let modulus = polymod(values) ^ variant.constant();
+    let mut ret = [0; CHECKSUM_LENGTH];
+    for (i, item) in ret.iter_mut().enumerate() {
+        *item = ((modulus >> (5 * (5 - i))) & 0x1f) as u8;
+    }
+    ret
+}
+
+/// Regroups `data` from `from`-bit to `to`-bit words
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Error> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max_value: u32 = (1 << to) - 1;
+    let mut ret = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for &value in data {
+        if (value as u32) >> from != 0 {
+            return Err(Error::InvalidBech32);
+        }
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            ret.push(((acc >> bits) & max_value) as u8);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if pad {
+        if bits > 0 {
+            ret.push(((acc << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max_value) != 0 {
+        return Err(Error::InvalidBech32);
+    }
+    Ok(ret)
+}
+
+/// Encodes a witness program as a segwit address for the given human-readable part
+pub(crate) fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + program.len() * 8 / 5 + 1);
+    data.push(version);
+    data.extend(convert_bits(program, 8, 5, true).expect("8-bit words always fit"));
+    let checksum = create_checksum(hrp, &data, Variant::for_witness_version(version));
+
+    let mut ret = String::with_capacity(hrp.len() + 1 + data.len() + CHECKSUM_LENGTH);
+    ret.push_str(hrp);
+    ret.push('1');
+    ret.extend(
+        data.iter()
+            .chain(checksum.iter())
+            .map(|&value| CHARSET[value as usize] as char),
+    );
+    ret
+}
+
+/// Decodes a segwit address, returning the human-readable part (always lowercase),
+/// the witness version and the witness program
+pub(crate) fn decode_segwit(encoded: &str) -> Result<(String, u8, Vec<u8>), Error> {
+    if encoded.len() > MAX_ENCODED_LEN {
+        return Err(Error::InvalidBech32);
+    }
+
+    // mixed case strings are not allowed
+    let has_lower = encoded.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = encoded.bytes().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(Error::InvalidBech32);
+    }
+    let encoded = encoded.to_ascii_lowercase();
+
+    let separator = encoded.rfind('1').ok_or(Error::InvalidBech32)?;
+    let (hrp, data) = (&encoded[..separator], &encoded[separator + 1..]);
+    if hrp.is_empty() || data.len() <= CHECKSUM_LENGTH || hrp.bytes().any(|c| !(33..=126).contains(&c)) {
+        return Err(Error::InvalidBech32);
+    }
+
+    let data = data
+        .bytes()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|&x| x == c)
+                .map(|position| position as u8)
+                .ok_or(Error::InvalidBech32)
+        })
+        .collect::<Result<Vec<u8>, Error>>()?;
+
+    let version = data[0];
+    if version > 16 {
+        return Err(Error::UnsupportedWitnessVersion);
+    }
+
+    let modulus = polymod(hrp_expand(hrp).chain(data.iter().copied()));
+    if modulus != Variant::for_witness_version(version).constant() {
+        return Err(Error::InvalidBech32Checksum);
+    }
+
+    let program = convert_bits(&data[1..data.len() - CHECKSUM_LENGTH], 5, 8, false)?;
+    if program.len() < MIN_WITNESS_PROGRAM_LEN || program.len() > MAX_WITNESS_PROGRAM_LEN {
+        return Err(Error::InvalidBech32);
+    }
+
+    Ok((String::from(hrp), version, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::assert_err;
+
+    #[test]
+    fn test_encode_segwit_v0() {
+        let program = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(
+            encode_segwit("bc", 0, &program),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+    }
+
+    #[test]
+    fn test_decode_segwit_uppercase() {
+        let (hrp, version, program) = decode_segwit("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap();
+        assert_eq!(hrp, "bc");
+        assert_eq!(version, 0);
+        assert_eq!(
+            program,
+            hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_segwit_v1_roundtrip() {
+        let encoded = "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr";
+        let (hrp, version, program) = decode_segwit(encoded).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(encode_segwit(&hrp, version, &program), encoded);
+    }
+
+    #[test]
+    fn test_decode_mixed_case_fails() {
+        assert_err!(
+            decode_segwit("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kV8F3T4"),
+            Error::InvalidBech32
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_checksum_fails() {
+        assert_err!(
+            decode_segwit("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"),
+            Error::InvalidBech32Checksum
+        );
+    }
+
+    #[test]
+    fn test_decode_wrong_variant_fails() {
+        // witness v1 encoded with the (bech32) v0 checksum, see BIP-350
+        assert_err!(
+            decode_segwit("bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx"),
+            Error::InvalidBech32Checksum
+        );
+    }
+}
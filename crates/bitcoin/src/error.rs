@@ -21,4 +21,9 @@ pub enum Error {
     InvalidCompact,
     BoundExceeded,
     InvalidTxid,
+    InvalidBase58,
+    InvalidBase58Checksum,
+    InvalidBech32,
+    InvalidBech32Checksum,
+    UnsupportedWitnessVersion,
 }
@@ -12,6 +12,7 @@
 //!
 //! - (De)serialization of block headers, transactions and merkle proofs.
 //! - Script (address) construction and parsing.
+//! - Human-readable address encoding (Base58Check and Bech32/Bech32m).
 //! - Merkle proof construction and verification.
 //! - Elliptic curve multiplication over Secp256k1.
 
@@ -35,6 +36,13 @@ pub mod merkle;
 mod address;
 pub use address::*;
 
+mod base58;
+
+mod bech32;
+
+mod network;
+pub use network::Network;
+
 mod script;
 pub use script::Script;
 
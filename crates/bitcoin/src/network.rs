@@ -0,0 +1,82 @@
+use crate::pow::{MIN_DIFFICULTY_TARGET, REGTEST_MIN_DIFFICULTY_TARGET, REGTEST_POW_LIMIT, UNROUNDED_MAX_TARGET};
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitive_types::U256;
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+
+/// The Bitcoin network a value is intended for. This determines the human-readable
+/// encodings and the proof of work rules that block headers must follow.
+///
+/// Signet is not supported: its blocks are only valid with a signature in the coinbase
+/// transaction, which cannot be checked from the block headers alone. Signet addresses
+/// use the same encodings as `Testnet`.
+#[derive(
+    Serialize, Deserialize, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen,
+)]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Testnet,
+    Regtest,
+}
+
+impl Network {
+    /// Human-readable part used for Bech32/Bech32m (segwit) addresses
+    // https://github.com/satoshilabs/slips/blob/master/slip-0173.md
+    pub fn bech32_hrp(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "bc",
+            Self::Testnet => "tb",
+            Self::Regtest => "bcrt",
+        }
+    }
+
+    pub(crate) fn is_bech32_hrp(hrp: &str) -> bool {
+        matches!(hrp, "bc" | "tb" | "bcrt")
+    }
+
+    /// Base58Check version byte of P2PKH addresses
+    pub fn p2pkh_prefix(&self) -> u8 {
+        match self {
+            Self::Mainnet => 0x00,
+            Self::Testnet | Self::Regtest => 0x6f,
+        }
+    }
+
+    /// Base58Check version byte of P2SH addresses
+    pub fn p2sh_prefix(&self) -> u8 {
+        match self {
+            Self::Mainnet => 0x05,
+            Self::Testnet | Self::Regtest => 0xc4,
+        }
+    }
+
+    /// Highest target allowed by the proof of work, i.e. the lowest difficulty
+    // https://github.com/bitcoin/bitcoin/blob/5ba5becbb5d8c794efe579caeea7eea64f895a13/src/chainparams.cpp
+    pub fn pow_limit(&self) -> U256 {
+        match self {
+            Self::Mainnet | Self::Testnet => UNROUNDED_MAX_TARGET,
+            Self::Regtest => REGTEST_POW_LIMIT,
+        }
+    }
+
+    /// Target of blocks mined at the lowest difficulty, this is the
+    /// `pow_limit` after a round trip through the compact encoding
+    pub fn min_difficulty_target(&self) -> U256 {
+        match self {
+            Self::Mainnet | Self::Testnet => MIN_DIFFICULTY_TARGET,
+            Self::Regtest => REGTEST_MIN_DIFFICULTY_TARGET,
+        }
+    }
+
+    /// Whether a block may be mined at the lowest difficulty if its timestamp
+    /// is more than twice the target spacing after that of its parent
+    pub fn allows_min_difficulty_blocks(&self) -> bool {
+        matches!(self, Self::Testnet | Self::Regtest)
+    }
+
+    /// Whether the difficulty is kept the same at the end of each difficulty period
+    pub fn no_retargeting(&self) -> bool {
+        matches!(self, Self::Regtest)
+    }
+}
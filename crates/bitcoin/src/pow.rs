@@ -1,4 +1,4 @@
-use crate::{Error, GetCompact};
+use crate::{Error, GetCompact, Network};
 use primitive_types::U256;
 
 /// Target Timespan: 2 weeks (1209600 seconds)
@@ -12,12 +12,29 @@ pub const TARGET_TIMESPAN_DIVISOR: u64 = 4;
 /// 0x00000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
 pub const UNROUNDED_MAX_TARGET: U256 = U256([<u64>::MAX, <u64>::MAX, <u64>::MAX, 0x0000_0000_ffff_ffffu64]);
 
+/// Maximum Target as encoded in block headers (0x1d00ffff)
+/// 0x00000000FFFF0000000000000000000000000000000000000000000000000000
+pub const MIN_DIFFICULTY_TARGET: U256 = U256([0, 0, 0, 0x0000_0000_ffff_0000u64]);
+
+/// Unrounded Maximum Target of regtest
+/// 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+pub const REGTEST_POW_LIMIT: U256 = U256([<u64>::MAX, <u64>::MAX, <u64>::MAX, 0x7fff_ffff_ffff_ffffu64]);
+
+/// Maximum Target of regtest as encoded in block headers (0x207fffff)
+/// 0x7FFFFF0000000000000000000000000000000000000000000000000000000000
+pub const REGTEST_MIN_DIFFICULTY_TARGET: U256 = U256([0, 0, 0, 0x7fff_ff00_0000_0000u64]);
+
 // https://github.com/bitcoin/bitcoin/blob/89b910711c004c21b7d67baa888073742f7f94f0/src/pow.cpp#L49-L72
 pub fn calculate_next_work_required(
+    network: Network,
     previous_target: U256,
     first_block_time: u64,
     last_block_time: u64,
 ) -> Result<u32, Error> {
+    if network.no_retargeting() {
+        return previous_target.get_compact().ok_or(Error::InvalidCompact);
+    }
+
     let mut actual_timespan = last_block_time.saturating_sub(first_block_time);
 
     if actual_timespan < TARGET_TIMESPAN / TARGET_TIMESPAN_DIVISOR {
@@ -32,13 +49,20 @@ pub fn calculate_next_work_required(
     let target = target / TARGET_TIMESPAN;
 
     // ensure target does not exceed max
-    if target > UNROUNDED_MAX_TARGET {
-        UNROUNDED_MAX_TARGET
-    } else {
-        target
-    }
-    .get_compact()
-    .ok_or(Error::InvalidCompact)
+    let pow_limit = network.pow_limit();
+    if target > pow_limit { pow_limit } else { target }
+        .get_compact()
+        .ok_or(Error::InvalidCompact)
+}
+
+/// Expected number of hashes required to produce a block with the given target, i.e. `2**256 / (target + 1)`
+// https://github.com/bitcoin/bitcoin/blob/89b910711c004c21b7d67baa888073742f7f94f0/src/chain.cpp
+pub fn get_block_proof(target: U256) -> U256 {
+    // we need to compute 2**256 / (target + 1), but we can't represent 2**256
+    // as it's too large for a U256. However, as 2**256 is at least as large
+    // as target + 1, it is equal to ((2**256 - target - 1) / (target + 1)) + 1,
+    // or ~target / (target + 1) + 1.
+    (!target / target.saturating_add(U256::one())).saturating_add(U256::one())
 }
 
 // https://github.com/bitcoin/bitcoin/blob/7fcf53f7b4524572d1d0c9a5fdc388e87eb02416/src/test/pow_tests.cpp
@@ -58,7 +82,7 @@ mod tests {
         let first_block_time = 1261130161; // Block #30240
         let last_block_time = 1262152739; // Block #32255
         assert_ok!(
-            calculate_next_work_required(previous_target, first_block_time, last_block_time),
+            calculate_next_work_required(Network::Mainnet, previous_target, first_block_time, last_block_time),
             0x1d00d86a
         );
     }
@@ -69,7 +93,7 @@ mod tests {
         let first_block_time = 1231006505; // Block #0
         let last_block_time = 1233061996; // Block #2015
         assert_ok!(
-            calculate_next_work_required(previous_target, first_block_time, last_block_time),
+            calculate_next_work_required(Network::Mainnet, previous_target, first_block_time, last_block_time),
             0x1d00ffff
         );
     }
@@ -80,7 +104,7 @@ mod tests {
         let first_block_time = 1279008237; // Block #66528
         let last_block_time = 1279297671; // Block #68543
         assert_ok!(
-            calculate_next_work_required(previous_target, first_block_time, last_block_time),
+            calculate_next_work_required(Network::Mainnet, previous_target, first_block_time, last_block_time),
             0x1c0168fd
         );
     }
@@ -91,7 +115,7 @@ mod tests {
         let first_block_time = 1263163443; // NOTE: Not an actual block time
         let last_block_time = 1269211443; // Block #46367
         assert_ok!(
-            calculate_next_work_required(previous_target, first_block_time, last_block_time),
+            calculate_next_work_required(Network::Mainnet, previous_target, first_block_time, last_block_time),
             0x1d00e1fd
         );
     }
@@ -103,8 +127,57 @@ mod tests {
         let first_block_time = 1632234876; // Block #701568
         let last_block_time = 1633390031; // Block #703583
         assert_ok!(
-            calculate_next_work_required(previous_target, first_block_time, last_block_time),
+            calculate_next_work_required(Network::Mainnet, previous_target, first_block_time, last_block_time),
             0x170e2632 // Block #703584
         );
     }
+
+    #[test]
+    fn get_next_work_testnet() {
+        // testnet3 follows the same retarget rules as mainnet
+        let previous_target = target_set_compact(0x1d00ffff);
+        let first_block_time = 1261130161;
+        let last_block_time = 1262152739;
+        assert_ok!(
+            calculate_next_work_required(Network::Testnet, previous_target, first_block_time, last_block_time),
+            0x1d00d86a
+        );
+    }
+
+    #[test]
+    fn get_next_work_regtest_no_retargeting() {
+        let previous_target = target_set_compact(0x207fffff);
+        let first_block_time = 1296688602;
+        let last_block_time = 1296688602 + TARGET_TIMESPAN * 2;
+        assert_ok!(
+            calculate_next_work_required(Network::Regtest, previous_target, first_block_time, last_block_time),
+            0x207fffff
+        );
+    }
+
+    #[test]
+    fn min_difficulty_targets_match_compact_pow_limits() {
+        for network in [Network::Mainnet, Network::Testnet, Network::Regtest] {
+            let bits = network.pow_limit().get_compact().unwrap();
+            assert_eq!(target_set_compact(bits), network.min_difficulty_target());
+        }
+        assert_eq!(Network::Mainnet.min_difficulty_target(), target_set_compact(0x1d00ffff));
+        assert_eq!(Network::Regtest.min_difficulty_target(), target_set_compact(0x207fffff));
+    }
+
+    #[test]
+    fn get_block_proof_genesis() {
+        // chainwork of the genesis block
+        assert_eq!(
+            get_block_proof(target_set_compact(0x1d00ffff)),
+            U256::from(0x100010001u64)
+        );
+    }
+
+    #[test]
+    fn get_block_proof_bounds() {
+        assert_eq!(get_block_proof(U256::max_value()), U256::one());
+        assert_eq!(get_block_proof(U256::zero()), U256::max_value());
+        assert_eq!(get_block_proof(U256::from(2).pow(254.into())), U256::from(3));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{formatter::TryFormat, types::*, Error};
+use crate::{formatter::TryFormat, types::*, Error, PublicKey};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
@@ -65,6 +65,31 @@ impl Script {
         script
     }
 
+    /// Constructs the m-of-n multisig script `OP_m {pubkey}... OP_n OP_CHECKMULTISIG`. The
+    /// keys are sorted lexicographically (BIP67) so that the script, and hence its address,
+    /// does not depend on the order in which they are given.
+    pub fn multisig(threshold: u8, public_keys: &[PublicKey]) -> Result<Script, Error> {
+        let count = public_keys.len();
+        if threshold == 0 || threshold as usize > count || count > MAX_MULTISIG_PUBLIC_KEYS as usize {
+            return Err(Error::InvalidScript);
+        }
+
+        let mut public_keys = public_keys.to_vec();
+        public_keys.sort_by(|a, b| a.0.cmp(&b.0));
+        if public_keys.windows(2).any(|keys| keys[0] == keys[1]) {
+            return Err(Error::InvalidScript);
+        }
+
+        let mut script = Script::new();
+        script.append(OpCode::Op1 as u8 + threshold - 1);
+        for public_key in public_keys {
+            script.append(public_key.0.to_vec());
+        }
+        script.append(OpCode::Op1 as u8 + count as u8 - 1);
+        script.append(OpCode::OpCheckMultisig);
+        Ok(script)
+    }
+
     pub fn is_p2wpkh_v0(&self) -> bool {
         // first byte is version
         self.len() == P2WPKH_V0_SCRIPT_SIZE as usize
@@ -79,6 +104,13 @@ impl Script {
             && self.bytes[1] == HASH256_SIZE_HEX
     }
 
+    pub fn is_p2tr_v1(&self) -> bool {
+        // first byte is version
+        self.len() == P2TR_V1_SCRIPT_SIZE as usize
+            && self.bytes[0] == OpCode::Op1 as u8
+            && self.bytes[1] == HASH256_SIZE_HEX
+    }
+
     pub fn is_p2pkh(&self) -> bool {
         self.len() == P2PKH_SCRIPT_SIZE as usize
             && self.bytes[0] == OpCode::OpDup as u8
@@ -9,6 +9,7 @@ use crate::{
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use secp256k1::constants::PUBLIC_KEY_SIZE;
 
 #[cfg(any(feature = "parser", test))]
 use crate::parser::parse_block_header;
@@ -172,10 +173,13 @@ pub const P2PKH_SCRIPT_SIZE: u32 = 25;
 pub const P2SH_SCRIPT_SIZE: u32 = 23;
 pub const P2WPKH_V0_SCRIPT_SIZE: u32 = 22;
 pub const P2WSH_V0_SCRIPT_SIZE: u32 = 34;
+pub const P2TR_V1_SCRIPT_SIZE: u32 = 34;
 pub const HASH160_SIZE_HEX: u8 = 0x14;
 pub const HASH256_SIZE_HEX: u8 = 0x20;
 // TODO: reduce to H256 size + op code
 pub const MAX_OPRETURN_SIZE: usize = 83;
+// the key count of a multisig script is pushed with a single `OP_1`..`OP_16` opcode
+pub const MAX_MULTISIG_PUBLIC_KEYS: u8 = 16;
 
 /// Structs
 
@@ -271,6 +275,69 @@ impl TransactionInput {
         self.witness = witness;
     }
 
+    /// Extracts the public key that signed this input. Only inputs spending from a single
+    /// compressed public key are supported, i.e. p2pkh, p2wpkh (native or nested in p2sh),
+    /// and p2sh or p2wsh spends of a `{pubkey} OP_CHECKSIG` script.
+    pub fn extract_public_key(&self) -> Result<PublicKey, Error> {
+        self.extract_spent_output()?.0.ok_or(Error::UnsupportedInputFormat)
+    }
+
+    /// Extracts the address of the output that is spent by this input. In addition to the
+    /// formats supported by `extract_public_key`, this includes p2sh and p2wsh spends of
+    /// arbitrary scripts, e.g. `OP_CHECKMULTISIG`.
+    pub fn extract_address(&self) -> Result<Address, Error> {
+        Ok(self.extract_spent_output()?.1)
+    }
+
+    fn extract_spent_output(&self) -> Result<(Option<PublicKey>, Address), Error> {
+        let script_sig = parse_script_pushes(&self.script)?;
+        match (self.witness.as_slice(), script_sig.as_slice()) {
+            // witness: {signature} {pubkey}
+            ([_, public_key], []) if public_key.len() == PUBLIC_KEY_SIZE => {
+                let public_key = PublicKey::from_slice(public_key).ok_or(Error::UnsupportedInputFormat)?;
+                let address = Address::P2WPKHv0(public_key.to_hash());
+                Ok((Some(public_key), address))
+            }
+            // input: {OP_0 {hash160(pubkey)}}
+            // witness: {signature} {pubkey}
+            ([_, public_key], [redeem_script]) if public_key.len() == PUBLIC_KEY_SIZE => {
+                let public_key = PublicKey::from_slice(public_key).ok_or(Error::UnsupportedInputFormat)?;
+                let witness_program = Address::P2WPKHv0(public_key.to_hash()).to_script_pub_key();
+                if witness_program.as_bytes() != *redeem_script {
+                    return Err(Error::UnsupportedInputFormat);
+                }
+                Ok((Some(public_key), Address::from_redeem_script(redeem_script)))
+            }
+            // witness: [witness_script_sig ...] {witness_script}
+            ([_, .., witness_script], []) => {
+                let public_key = PublicKey::from_redeem_script(witness_script);
+                Ok((public_key, Address::from_witness_script(witness_script)))
+            }
+            // input: {OP_0 {sha256(witness_script)}}
+            // witness: [witness_script_sig ...] {witness_script}
+            ([_, .., witness_script], [redeem_script]) => {
+                let public_key = PublicKey::from_redeem_script(witness_script);
+                let witness_program = Address::from_witness_script(witness_script).to_script_pub_key();
+                if witness_program.as_bytes() != *redeem_script {
+                    return Err(Error::UnsupportedInputFormat);
+                }
+                Ok((public_key, Address::from_redeem_script(redeem_script)))
+            }
+            // input: {signature} {pubkey}
+            ([], [_, public_key]) if public_key.len() == PUBLIC_KEY_SIZE => {
+                let public_key = PublicKey::from_slice(public_key).ok_or(Error::UnsupportedInputFormat)?;
+                let address = Address::P2PKH(public_key.to_hash());
+                Ok((Some(public_key), address))
+            }
+            // input: [redeem_script_sig ...] {redeem_script}
+            ([], [_, .., redeem_script]) => {
+                let public_key = PublicKey::from_redeem_script(redeem_script);
+                Ok((public_key, Address::from_redeem_script(redeem_script)))
+            }
+            _ => Err(Error::UnsupportedInputFormat),
+        }
+    }
+
     // used by the benchmarks to make the
     // transaction be an expected length
     #[cfg(feature = "runtime-benchmarks")]
@@ -286,6 +353,33 @@ impl TransactionInput {
     }
 }
 
+/// Splits a scriptSig into the data that it pushes onto the stack. Since the
+/// scriptSig of standard inputs may only contain push operations, any other
+/// opcode is rejected.
+fn parse_script_pushes(mut script: &[u8]) -> Result<Vec<&[u8]>, Error> {
+    const OP_PUSH_DATA_1: u8 = OpCode::OpPushData1 as u8;
+    const OP_PUSH_DATA_2: u8 = OpCode::OpPushData2 as u8;
+
+    let mut pushes = Vec::new();
+    while let Some((&opcode, rest)) = script.split_first() {
+        let (len, rest) = match (opcode, rest) {
+            // OP_0 pushes the empty array, opcodes up to 0x4b push that number of bytes
+            (0x00..=0x4b, rest) => (opcode as usize, rest),
+            (OP_PUSH_DATA_1, [len, rest @ ..]) => (*len as usize, rest),
+            (OP_PUSH_DATA_2, [lo, hi, rest @ ..]) => (u16::from_le_bytes([*lo, *hi]) as usize, rest),
+            (OP_PUSH_DATA_1, _) | (OP_PUSH_DATA_2, _) => return Err(Error::EndOfFile),
+            _ => return Err(Error::UnsupportedInputFormat),
+        };
+        if rest.len() < len {
+            return Err(Error::EndOfFile);
+        }
+        let (data, rest) = rest.split_at(len);
+        pushes.push(data);
+        script = rest;
+    }
+    Ok(pushes)
+}
+
 pub type Value = i64;
 
 /// Bitcoin transaction output
@@ -521,7 +615,7 @@ impl BlockBuilder {
             .with_previous_hash(previous_hash)
             .with_version(4)
             .with_coinbase(&Address::default(), 50, 3)
-            .with_timestamp(u32::MAX);
+            .with_timestamp(1588814835);
 
         // we expect at least two hashes for payment + merkle root
         let tree_height = hashes - 1; // remove the merkle root to get height
@@ -587,6 +681,8 @@ pub struct BlockChain {
     pub chain_id: u32,
     pub start_height: u32,
     pub max_height: u32,
+    /// cumulative work of the block at `max_height`
+    pub chain_work: U256,
 }
 
 /// Represents a bitcoin 32 bytes hash digest encoded in little-endian
@@ -993,6 +1089,120 @@ mod tests {
         assert_eq!(input.source, source);
     }
 
+    #[test]
+    fn test_extract_input_address_p2sh_p2wpkh() {
+        let raw_tx = hex::decode(&sample_example_real_rawtx()).unwrap();
+        let transaction = parse_transaction(&raw_tx).unwrap();
+        let input = &transaction.inputs[0];
+
+        let public_key = PublicKey::from_slice(
+            &hex::decode("039d25ab79f41f75ceaf882411fd41fa670a4c672c23ffaf0e361a969cde0692e8").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(input.extract_public_key().unwrap(), public_key);
+        assert_eq!(
+            input.extract_address().unwrap(),
+            Address::P2SH(H160::from_str("2928f43af18d2d60e8a843540d8086b305341339").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_extract_input_address_from_builder() {
+        let public_key = PublicKey::dummy();
+        let sig = vec![1; 72];
+
+        let input = TransactionInputBuilder::new()
+            .with_p2pkh(&public_key, sig.clone())
+            .build();
+        assert_eq!(input.extract_public_key().unwrap(), public_key);
+        assert_eq!(input.extract_address().unwrap(), Address::P2PKH(public_key.to_hash()));
+
+        let input = TransactionInputBuilder::new()
+            .with_p2wpkh(&public_key, sig.clone())
+            .build();
+        assert_eq!(input.extract_public_key().unwrap(), public_key);
+        assert_eq!(
+            input.extract_address().unwrap(),
+            Address::P2WPKHv0(public_key.to_hash())
+        );
+
+        let input = TransactionInputBuilder::new()
+            .with_p2sh(&public_key, sig.clone())
+            .build();
+        assert_eq!(input.extract_public_key().unwrap(), public_key);
+        assert_eq!(
+            input.extract_address().unwrap(),
+            Address::from_redeem_script(&public_key.to_redeem_script())
+        );
+
+        let input = TransactionInputBuilder::new().with_p2wsh(&public_key, sig).build();
+        assert_eq!(input.extract_public_key().unwrap(), public_key);
+        assert_eq!(
+            input.extract_address().unwrap(),
+            Address::from_witness_script(&public_key.to_redeem_script())
+        );
+    }
+
+    #[test]
+    fn test_extract_input_address_p2wsh_multisig() {
+        let public_keys = [
+            PublicKey::dummy(),
+            PublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap(),
+        ];
+        let witness_script = Script::multisig(2, &public_keys).unwrap();
+
+        // OP_0 {signature} {signature} {witness_script}
+        let input = TransactionInputBuilder::new()
+            .add_witness(&[])
+            .add_witness(&[1; 72])
+            .add_witness(&[2; 72])
+            .add_witness(witness_script.as_bytes())
+            .build();
+        assert_err!(input.extract_public_key(), Error::UnsupportedInputFormat);
+        assert_eq!(
+            input.extract_address().unwrap(),
+            Address::from_witness_script(witness_script.as_bytes())
+        );
+    }
+
+    #[test]
+    fn test_multisig_script() {
+        let public_key = PublicKey::dummy();
+        let other_public_key = PublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+
+        let script = Script::multisig(1, &[public_key.clone(), other_public_key.clone()]).unwrap();
+        let bytes = script.as_bytes();
+        assert_eq!(bytes.len(), 3 + 2 * (PUBLIC_KEY_SIZE + 1));
+        assert_eq!(bytes[0], OpCode::Op1 as u8);
+        assert_eq!(bytes[bytes.len() - 2], OpCode::Op2 as u8);
+        assert_eq!(bytes[bytes.len() - 1], OpCode::OpCheckMultisig as u8);
+
+        // the script does not depend on the order of the keys
+        assert_eq!(
+            Script::multisig(1, &[other_public_key.clone(), public_key.clone()]).unwrap(),
+            script
+        );
+
+        assert_err!(Script::multisig(0, &[public_key.clone()]), Error::InvalidScript);
+        assert_err!(
+            Script::multisig(3, &[public_key.clone(), other_public_key]),
+            Error::InvalidScript
+        );
+        assert_err!(
+            Script::multisig(1, &[public_key.clone(), public_key]),
+            Error::InvalidScript
+        );
+    }
+
+    #[test]
+    fn test_extract_input_address_p2pk_fails() {
+        // source: https://blockstream.info/tx/f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16?expand
+        let raw_tx = "0100000001c997a5e56e104102fa209c6a852dd90660a20b2d9c352423edce25857fcd3704000000004847304402204e45e16932b8af514961a1d3a1a25fdf3f4f7732e9d624c6c61548ab5fb8cd410220181522ec8eca07de4860a4acdd12909d831cc56cbbac4622082221a8768d1d0901ffffffff0200ca9a3b00000000434104ae1a62fe09c5f51b13905f07f06b99a2f7159b2225f374cd378d71302fa28414e7aab37397f554a7df5f142c21c1b7303b8a0626f1baded5c72a704f7e6cd84cac00286bee0000000043410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac00000000";
+        let transaction = parse_transaction(&hex::decode(&raw_tx).unwrap()).unwrap();
+
+        assert_err!(transaction.inputs[0].extract_address(), Error::UnsupportedInputFormat);
+    }
+
     #[test]
     fn test_transaction_builder() {
         let address = Address::P2PKH(H160::from_str(&"66c7060feb882664ae62ffad0051fe843e318e85").unwrap());
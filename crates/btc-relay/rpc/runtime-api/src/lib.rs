@@ -2,15 +2,98 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchError;
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies a block either by its hash or by its height in the main chain
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(untagged))]
+pub enum BlockId<H256Le> {
+    Hash(H256Le),
+    Height(u32),
+}
+
+/// A block header stored in the relay, together with its position in the relayed chains
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RelayedBlockHeader<H256Le, BlockNumber> {
+    pub hash: H256Le,
+    pub hash_prev_block: H256Le,
+    pub merkle_root: H256Le,
+    pub version: i32,
+    pub timestamp: u32,
+    pub target: U256,
+    pub nonce: u32,
+    /// height of the block in the bitcoin chain
+    pub block_height: u32,
+    /// id of the chain that this block belongs to, the main chain has id 0
+    pub chain_id: u32,
+    /// active block number of the parachain at the time this block was submitted
+    pub para_height: BlockNumber,
+    /// cumulative work of the chain up to and including this block
+    pub chain_work: U256,
+}
+
+/// The main chain or one of the forks tracked by the relay
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RelayedChain {
+    pub chain_id: u32,
+    pub start_height: u32,
+    pub max_height: u32,
+    /// cumulative work of the block at `max_height`
+    pub chain_work: U256,
+}
+
+/// An output of a transaction whose inclusion was verified
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct VerifiedTransactionOutput {
+    /// value in satoshis
+    pub value: i64,
+    #[cfg_attr(feature = "std", serde(with = "impl_serde::serialize"))]
+    pub script: Vec<u8>,
+}
 
 sp_api::decl_runtime_apis! {
-    pub trait BtcRelayApi<H256Le> where
+    #[api_version(2)]
+    pub trait BtcRelayApi<H256Le, BlockNumber, FullTransactionProof> where
         H256Le: Codec,
+        BlockNumber: Codec,
+        FullTransactionProof: Codec,
     {
         /// Verify that the block with the given block hash is relayed, has sufficient
         /// confirmations and is part of the main chain
         fn verify_block_header_inclusion(block_hash: H256Le) -> Result<(), DispatchError>;
+
+        /// Get the best block of the main chain
+        #[api_version(2)]
+        fn get_best_block() -> Result<RelayedBlockHeader<H256Le, BlockNumber>, DispatchError>;
+
+        /// Get a block by its hash, or by its height in the main chain
+        #[api_version(2)]
+        fn get_block_header(block_id: BlockId<H256Le>) -> Result<RelayedBlockHeader<H256Le, BlockNumber>, DispatchError>;
+
+        /// Get the main chain followed by all forks, ordered by descending chain work
+        #[api_version(2)]
+        fn get_chains() -> Vec<RelayedChain>;
+
+        /// Get the number of confirmations of a block, this is zero for blocks that are not in the main chain
+        #[api_version(2)]
+        fn get_confirmations(block_hash: H256Le) -> Result<u32, DispatchError>;
+
+        /// Dry-run the verification of a transaction inclusion proof with the default number of
+        /// confirmations, returning the outputs of the transaction
+        #[api_version(2)]
+        fn verify_transaction_inclusion(proof: FullTransactionProof) -> Result<Vec<VerifiedTransactionOutput>, DispatchError>;
     }
 }
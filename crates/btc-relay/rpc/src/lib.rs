@@ -1,26 +1,53 @@
 //! RPC interface for the BtcRelay Module.
 
-use codec::Codec;
+use codec::{Codec, Decode};
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpseeError, RpcResult},
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
 use sp_runtime::{traits::Block as BlockT, DispatchError};
 use std::sync::Arc;
 
-pub use btc_relay_rpc_runtime_api::BtcRelayApi as BtcRelayRuntimeApi;
+pub use btc_relay_rpc_runtime_api::{
+    BlockId, BtcRelayApi as BtcRelayRuntimeApi, RelayedBlockHeader, RelayedChain, VerifiedTransactionOutput,
+};
 
 #[rpc(client, server)]
-pub trait BtcRelayApi<BlockHash, H256Le> {
+pub trait BtcRelayApi<BlockHash, H256Le, BlockNumber> {
     #[method(name = "btcRelay_verifyBlockHeaderInclusion")]
     fn verify_block_header_inclusion(
         &self,
         block_hash: H256Le,
         at: Option<BlockHash>,
     ) -> RpcResult<Result<(), DispatchError>>;
+
+    #[method(name = "btcRelay_getBestBlock")]
+    fn get_best_block(&self, at: Option<BlockHash>) -> RpcResult<RelayedBlockHeader<H256Le, BlockNumber>>;
+
+    #[method(name = "btcRelay_getBlockHeader")]
+    fn get_block_header(
+        &self,
+        block_id: BlockId<H256Le>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RelayedBlockHeader<H256Le, BlockNumber>>;
+
+    #[method(name = "btcRelay_getChains")]
+    fn get_chains(&self, at: Option<BlockHash>) -> RpcResult<Vec<RelayedChain>>;
+
+    #[method(name = "btcRelay_getConfirmations")]
+    fn get_confirmations(&self, block_hash: H256Le, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// Takes the SCALE encoded `FullTransactionProof`
+    #[method(name = "btcRelay_verifyTransactionInclusion")]
+    fn verify_transaction_inclusion(
+        &self,
+        proof: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VerifiedTransactionOutput>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -31,13 +58,50 @@ fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
     )))
 }
 
+fn invalid_params_err<T: ToString>(message: T) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        ErrorCode::InvalidParams.code(),
+        message.to_string(),
+        None::<()>,
+    )))
+}
+
+fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError>, E>, msg: String) -> RpcResult<T> {
+    result
+        .map_err(|err| internal_err(format!("Runtime error: {:?}: {:?}", msg, err)))?
+        .map_err(|err| internal_err(format!("Execution error: {:?}: {:?}", msg, err)))
+}
+
+/// The calls other than `verify_block_header_inclusion` were added in version 2 of the runtime api
+fn ensure_api_version<C, Block, H256Le, BlockNumber, FullTransactionProof>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+) -> RpcResult<()>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: BtcRelayRuntimeApi<Block, H256Le, BlockNumber, FullTransactionProof>,
+    H256Le: Codec,
+    BlockNumber: Codec,
+    FullTransactionProof: Codec,
+{
+    let version = client
+        .runtime_api()
+        .api_version::<dyn BtcRelayRuntimeApi<Block, H256Le, BlockNumber, FullTransactionProof>>(at)
+        .map_err(|e| internal_err(format!("Unable to get the runtime api version: {:?}", e)))?;
+    match version {
+        Some(version) if version >= 2 => Ok(()),
+        _ => Err(internal_err("Not supported by the runtime at this block")),
+    }
+}
+
 /// A struct that implements the [`BtcRelayApi`].
-pub struct BtcRelay<C, B> {
+pub struct BtcRelay<C, B, P> {
     client: Arc<C>,
-    _marker: std::marker::PhantomData<B>,
+    _marker: std::marker::PhantomData<(B, P)>,
 }
 
-impl<C, B> BtcRelay<C, B> {
+impl<C, B, P> BtcRelay<C, B, P> {
     /// Create new `BtcRelay` with the given reference to the client.
     pub fn new(client: Arc<C>) -> Self {
         BtcRelay {
@@ -48,12 +112,15 @@ impl<C, B> BtcRelay<C, B> {
 }
 
 #[async_trait]
-impl<C, Block, H256Le> BtcRelayApiServer<<Block as BlockT>::Hash, H256Le> for BtcRelay<C, Block>
+impl<C, Block, H256Le, BlockNumber, FullTransactionProof>
+    BtcRelayApiServer<<Block as BlockT>::Hash, H256Le, BlockNumber> for BtcRelay<C, Block, FullTransactionProof>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: BtcRelayRuntimeApi<Block, H256Le>,
+    C::Api: BtcRelayRuntimeApi<Block, H256Le, BlockNumber, FullTransactionProof>,
     H256Le: Codec,
+    BlockNumber: Codec,
+    FullTransactionProof: Codec + Send + Sync + 'static,
 {
     fn verify_block_header_inclusion(
         &self,
@@ -66,4 +133,68 @@ where
         api.verify_block_header_inclusion(at, block_hash)
             .map_err(|e| internal_err(format!("execution error: Unable to dry run extrinsic {:?}", e)))
     }
+
+    fn get_best_block(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RelayedBlockHeader<H256Le, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, H256Le, BlockNumber, FullTransactionProof>(&*self.client, at)?;
+
+        handle_response(api.get_best_block(at), "Unable to get the best block".into())
+    }
+
+    fn get_block_header(
+        &self,
+        block_id: BlockId<H256Le>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RelayedBlockHeader<H256Le, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, H256Le, BlockNumber, FullTransactionProof>(&*self.client, at)?;
+
+        handle_response(
+            api.get_block_header(at, block_id),
+            "Unable to get the block header".into(),
+        )
+    }
+
+    fn get_chains(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<RelayedChain>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, H256Le, BlockNumber, FullTransactionProof>(&*self.client, at)?;
+
+        api.get_chains(at)
+            .map_err(|e| internal_err(format!("Unable to fetch chains: {:?}", e)))
+    }
+
+    fn get_confirmations(&self, block_hash: H256Le, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, H256Le, BlockNumber, FullTransactionProof>(&*self.client, at)?;
+
+        handle_response(
+            api.get_confirmations(at, block_hash),
+            "Unable to get the block confirmations".into(),
+        )
+    }
+
+    fn verify_transaction_inclusion(
+        &self,
+        proof: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VerifiedTransactionOutput>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, H256Le, BlockNumber, FullTransactionProof>(&*self.client, at)?;
+
+        let proof = FullTransactionProof::decode(&mut &proof[..])
+            .map_err(|e| invalid_params_err(format!("Unable to decode transaction proof: {:?}", e)))?;
+
+        handle_response(
+            api.verify_transaction_inclusion(at, proof),
+            "Unable to verify transaction inclusion".into(),
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use super::*;
 use crate::Pallet as BtcRelay;
-use bitcoin::types::{Block, BlockBuilder, H256Le, TransactionBuilder, TransactionInputBuilder};
+use bitcoin::types::{Block, BlockBuilder, BlockHeader, H256Le, TransactionBuilder, TransactionInputBuilder};
 use frame_benchmarking::v2::*;
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
@@ -9,20 +9,28 @@ use sp_std::prelude::*;
 
 const SECURE_BITCOIN_CONFIRMATIONS: u32 = 6;
 
+const INIT_TIMESTAMP: u32 = 1588813835;
+
 fn initialize_relay<T: Config>(caller: T::AccountId) -> Block {
     let init_block = BlockBuilder::new()
         .with_version(4)
         .with_coinbase(&BtcAddress::P2PKH(H160::from([0; 20])), 50, 3)
-        .with_timestamp(u32::MAX)
+        .with_timestamp(INIT_TIMESTAMP)
         .mine(U256::from(2).pow(254.into()))
         .unwrap();
     assert_ok!(BtcRelay::<T>::_initialize(caller, init_block.header, 0));
     init_block
 }
 
-fn new_block<T: Config>(parent_hash: H256Le, seed: usize) -> Block {
+fn get_header<T: Config>(block_hash: H256Le) -> BlockHeader {
+    BtcRelay::<T>::get_block_header_from_hash(block_hash)
+        .unwrap()
+        .block_header
+}
+
+fn new_block<T: Config>(parent: &BlockHeader, seed: usize) -> Block {
     BlockBuilder::new()
-        .with_previous_hash(parent_hash)
+        .with_previous_hash(parent.hash)
         .with_version(4)
         .with_coinbase(&BtcAddress::P2PKH(H160::from([0; 20])), 50, 3)
         .add_transaction(
@@ -31,13 +39,13 @@ fn new_block<T: Config>(parent_hash: H256Le, seed: usize) -> Block {
                 .add_input(TransactionInputBuilder::new().with_script(&vec![0; seed]).build())
                 .build(),
         )
-        .with_timestamp(u32::MAX)
+        .with_timestamp(parent.timestamp + TARGET_SPACING)
         .mine(U256::from(2).pow(254.into()))
         .unwrap()
 }
 
 fn add_new_block_to_relay<T: Config>(caller: T::AccountId, parent_hash: H256Le, seed: usize) -> Block {
-    let block = new_block::<T>(parent_hash, seed);
+    let block = new_block::<T>(&get_header::<T>(parent_hash), seed);
     assert_ok!(BtcRelay::<T>::_store_block_header(&caller, block.header));
     block
 }
@@ -54,7 +62,7 @@ pub mod benchmarks {
         let block = BlockBuilder::new()
             .with_version(4)
             .with_coinbase(&BtcAddress::P2PKH(H160::from([0; 20])), 50, 3)
-            .with_timestamp(u32::MAX)
+            .with_timestamp(INIT_TIMESTAMP)
             .mine(U256::from(2).pow(254.into()))
             .unwrap();
 
@@ -67,8 +75,13 @@ pub mod benchmarks {
         let caller: T::AccountId = whitelisted_caller();
 
         let init_block = initialize_relay::<T>(caller.clone());
-        let init_block_hash = init_block.header.hash;
-        let block = new_block::<T>(init_block_hash, 0);
+
+        // store enough ancestors for the median time past to be checked
+        let mut block_hash = init_block.header.hash;
+        for _ in 1..MEDIAN_TIME_SPAN {
+            block_hash = add_new_block_to_relay::<T>(caller.clone(), block_hash, 0).header.hash;
+        }
+        let block = new_block::<T>(&get_header::<T>(block_hash), 0);
 
         #[extrinsic_call]
         _(RawOrigin::Signed(caller), block.header, u32::MAX);
@@ -90,7 +103,7 @@ pub mod benchmarks {
             add_new_block_to_relay::<T>(caller.clone(), init_block_hash, i as usize);
         }
 
-        let block = new_block::<T>(init_block_hash, f as usize);
+        let block = new_block::<T>(&init_block.header, f as usize);
 
         #[extrinsic_call]
         store_block_header(RawOrigin::Signed(caller), block.header, u32::MAX);
@@ -113,7 +126,7 @@ pub mod benchmarks {
         }
 
         let _block_2_1 = add_new_block_to_relay::<T>(caller.clone(), block_1.header.hash, (f + 1) as usize);
-        let block_2_2 = new_block::<T>(block_1.header.hash, (f + 2) as usize);
+        let block_2_2 = new_block::<T>(&block_1.header, (f + 2) as usize);
 
         #[extrinsic_call]
         store_block_header(RawOrigin::Signed(caller), block_2_2.header, u32::MAX);
@@ -152,7 +165,7 @@ pub mod benchmarks {
 
         // we can benchmark the worst-case complexity for swapping
         // since we know how many blocks are required
-        let block = new_block::<T>(init_block_hash, f as usize);
+        let block = new_block::<T>(&get_header::<T>(init_block_hash), f as usize);
 
         #[extrinsic_call]
         store_block_header(RawOrigin::Signed(caller), block.header, u32::MAX);
@@ -166,5 +179,111 @@ pub mod benchmarks {
         assert_eq!(rich_header.chain_id, MAIN_CHAIN_ID);
     }
 
+    #[benchmark]
+    pub fn store_block_headers(h: Linear<1, 100>, f: Linear<1, 6>) {
+        let caller: T::AccountId = whitelisted_caller();
+        StableBitcoinConfirmations::<T>::put(SECURE_BITCOIN_CONFIRMATIONS);
+
+        let init_block = initialize_relay::<T>(caller.clone());
+        let init_block_hash = init_block.header.hash;
+
+        // the main chain is long enough that extending a fork by `h` blocks does not cause a reorg
+        let mut block_hash = init_block_hash;
+        for _ in 0..(h + SECURE_BITCOIN_CONFIRMATIONS) {
+            block_hash = add_new_block_to_relay::<T>(caller.clone(), block_hash, 0).header.hash;
+        }
+
+        // forks of a single block, the headers are added to the one in the last position
+        for i in 1..f {
+            block_hash = add_new_block_to_relay::<T>(caller.clone(), init_block_hash, i as usize)
+                .header
+                .hash;
+        }
+
+        let mut parent = get_header::<T>(block_hash);
+        let block_headers = (0..h)
+            .map(|_| {
+                parent = new_block::<T>(&parent, f as usize).header;
+                parent
+            })
+            .collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), block_headers, u32::MAX);
+
+        // make sure all headers are stored
+        assert!(BtcRelay::<T>::block_header_exists(parent.hash));
+    }
+
+    #[benchmark]
+    pub fn set_pruning_config() {
+        StableBitcoinConfirmations::<T>::put(SECURE_BITCOIN_CONFIRMATIONS);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            Some(SECURE_BITCOIN_CONFIRMATIONS),
+            Some(DIFFICULTY_ADJUSTMENT_INTERVAL),
+        );
+
+        assert_eq!(StaleForkDepth::<T>::get(), Some(SECURE_BITCOIN_CONFIRMATIONS));
+    }
+
+    #[benchmark]
+    pub fn prune_stale_fork(f: Linear<2, 6>, h: Linear<1, 100>) {
+        let caller: T::AccountId = whitelisted_caller();
+        StableBitcoinConfirmations::<T>::put(SECURE_BITCOIN_CONFIRMATIONS);
+        StaleForkDepth::<T>::put(SECURE_BITCOIN_CONFIRMATIONS);
+
+        let init_block = initialize_relay::<T>(caller.clone());
+        let init_block_hash = init_block.header.hash;
+
+        // the main chain is long enough for a fork of `h` blocks to be stale
+        let mut main_chain_hashes = vec![init_block_hash];
+        for _ in 0..(h + SECURE_BITCOIN_CONFIRMATIONS + 1) {
+            let block = add_new_block_to_relay::<T>(caller.clone(), *main_chain_hashes.last().unwrap(), 0);
+            main_chain_hashes.push(block.header.hash);
+        }
+
+        let mut block_hash = init_block_hash;
+        for _ in 0..h {
+            let block = add_new_block_to_relay::<T>(caller.clone(), block_hash, 1);
+            block_hash = block.header.hash;
+        }
+        let stale_fork_id = BtcRelay::<T>::get_block_header_from_hash(block_hash).unwrap().chain_id;
+
+        // forks of the main chain tip, which are not stale
+        let parent_hash = main_chain_hashes[main_chain_hashes.len() - 2];
+        for i in 2..f {
+            add_new_block_to_relay::<T>(caller.clone(), parent_hash, i as usize);
+        }
+
+        #[block]
+        {
+            BtcRelay::<T>::prune_storage(Weight::MAX);
+        }
+
+        assert!(!ChainsIndex::<T>::contains_key(stale_fork_id));
+        assert_eq!(Chains::<T>::iter().count(), f as usize - 1);
+    }
+
+    #[benchmark]
+    pub fn prune_main_chain_header() {
+        let caller: T::AccountId = whitelisted_caller();
+        MainChainRetention::<T>::put(1);
+
+        let init_block = initialize_relay::<T>(caller.clone());
+        let block = add_new_block_to_relay::<T>(caller.clone(), init_block.header.hash, 0);
+        add_new_block_to_relay::<T>(caller.clone(), block.header.hash, 0);
+
+        #[block]
+        {
+            BtcRelay::<T>::prune_storage(Weight::MAX);
+        }
+
+        assert!(!BtcRelay::<T>::block_header_exists(init_block.header.hash));
+        assert_eq!(ChainsIndex::<T>::get(MAIN_CHAIN_ID).unwrap().start_height, 1);
+    }
+
     impl_benchmark_test_suite!(BtcRelay, crate::mock::ExtBuilder::build(), crate::mock::Test);
 }
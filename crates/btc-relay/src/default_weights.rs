@@ -42,6 +42,10 @@ pub trait WeightInfo {
 	fn store_block_header_new_fork_sorted(f: u32, ) -> Weight;
 	fn store_block_header_new_fork_unsorted(f: u32, ) -> Weight;
 	fn store_block_header_reorganize_chains(f: u32, ) -> Weight;
+	fn store_block_headers(h: u32, f: u32, ) -> Weight;
+	fn set_pruning_config() -> Weight;
+	fn prune_stale_fork(f: u32, h: u32, ) -> Weight;
+	fn prune_main_chain_header() -> Weight;
 }
 
 /// Weights for btc_relay using the Substrate node and recommended hardware.
@@ -49,8 +53,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: BTCRelay BestBlock (r:1 w:1)
 	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainCounter (r:1 w:1)
 	/// Proof: BTCRelay ChainCounter (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:1 w:1)
@@ -82,8 +86,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:1 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -107,8 +111,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:1)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -137,8 +141,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:6)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -168,8 +172,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:3 w:2)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:13 w:24)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -196,14 +200,121 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(46_u64))
 			.saturating_add(Weight::from_parts(0, 1305).saturating_mul(f.into()))
 	}
+	/// Storage: BTCRelay Chains (r:7 w:6)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:101 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:106 w:100)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:100 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlock (r:1 w:0)
+	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `h` is `[1, 100]`.
+	/// The range of component `f` is `[1, 6]`.
+	fn store_block_headers(h: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1786 + f * (99 ±0) + h * (139 ±0)`
+		//  Estimated: `6340 + f * (2499 ±0) + h * (5222 ±0)`
+		// Minimum execution time: 96_305_000 picoseconds.
+		Weight::from_parts(21_618_305, 6340)
+			// Standard Error: 11_052
+			.saturating_add(Weight::from_parts(58_126_440, 0).saturating_mul(h.into()))
+			// Standard Error: 207_413
+			.saturating_add(Weight::from_parts(6_011_871, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 5222).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StaleForkDepth (r:0 w:1)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:0 w:1)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_pruning_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_036_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:7 w:1)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:5 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:10 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// The range of component `f` is `[2, 6]`.
+	/// The range of component `h` is `[1, 100]`.
+	fn prune_stale_fork(f: u32, h: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1164 + f * (229 ±0) + h * (108 ±0)`
+		//  Estimated: `6330 + f * (7721 ±0) + h * (2547 ±0)`
+		// Minimum execution time: 71_507_000 picoseconds.
+		Weight::from_parts(38_290_518, 6330)
+			// Standard Error: 174_212
+			.saturating_add(Weight::from_parts(16_842_307, 0).saturating_mul(f.into()))
+			// Standard Error: 6_891
+			.saturating_add(Weight::from_parts(2_937_646, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 7721).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:1 w:0)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:0 w:1)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	fn prune_main_chain_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `6004`
+		// Minimum execution time: 43_719_000 picoseconds.
+		Weight::from_parts(45_106_000, 6004)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: BTCRelay BestBlock (r:1 w:1)
 	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainCounter (r:1 w:1)
 	/// Proof: BTCRelay ChainCounter (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:1 w:1)
@@ -235,8 +346,8 @@ impl WeightInfo for () {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:1 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -260,8 +371,8 @@ impl WeightInfo for () {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:1)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -290,8 +401,8 @@ impl WeightInfo for () {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:6)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -321,8 +432,8 @@ impl WeightInfo for () {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:3 w:2)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:13 w:24)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -349,4 +460,111 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(46_u64))
 			.saturating_add(Weight::from_parts(0, 1305).saturating_mul(f.into()))
 	}
+	/// Storage: BTCRelay Chains (r:7 w:6)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:101 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:106 w:100)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:100 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlock (r:1 w:0)
+	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `h` is `[1, 100]`.
+	/// The range of component `f` is `[1, 6]`.
+	fn store_block_headers(h: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1786 + f * (99 ±0) + h * (139 ±0)`
+		//  Estimated: `6340 + f * (2499 ±0) + h * (5222 ±0)`
+		// Minimum execution time: 96_305_000 picoseconds.
+		Weight::from_parts(21_618_305, 6340)
+			// Standard Error: 11_052
+			.saturating_add(Weight::from_parts(58_126_440, 0).saturating_mul(h.into()))
+			// Standard Error: 207_413
+			.saturating_add(Weight::from_parts(6_011_871, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 5222).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StaleForkDepth (r:0 w:1)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:0 w:1)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_pruning_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_036_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:7 w:1)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:5 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:10 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// The range of component `f` is `[2, 6]`.
+	/// The range of component `h` is `[1, 100]`.
+	fn prune_stale_fork(f: u32, h: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1164 + f * (229 ±0) + h * (108 ±0)`
+		//  Estimated: `6330 + f * (7721 ±0) + h * (2547 ±0)`
+		// Minimum execution time: 71_507_000 picoseconds.
+		Weight::from_parts(38_290_518, 6330)
+			// Standard Error: 174_212
+			.saturating_add(Weight::from_parts(16_842_307, 0).saturating_mul(f.into()))
+			// Standard Error: 6_891
+			.saturating_add(Weight::from_parts(2_937_646, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 7721).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:1 w:0)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:0 w:1)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	fn prune_main_chain_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `6004`
+		// Minimum execution time: 43_719_000 picoseconds.
+		Weight::from_parts(45_106_000, 6004)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
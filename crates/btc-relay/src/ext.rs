@@ -22,3 +22,14 @@ pub(crate) mod security {
         <security::Pallet<T>>::parachain_block_expired(opentime, period)
     }
 }
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod timestamp {
+    use sp_runtime::traits::UniqueSaturatedInto;
+
+    /// Returns the parachain time in seconds, or zero if it has not been set yet
+    pub fn now<T: crate::Config>() -> u64 {
+        let now: u64 = <pallet_timestamp::Pallet<T>>::get().unique_saturated_into();
+        now / 1000
+    }
+}
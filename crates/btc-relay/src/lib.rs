@@ -3,7 +3,7 @@
 //! Based on the [specification](https://spec.interlay.io/spec/btc-relay/index.html).
 //!
 //! This pallet implements a Bitcoin light client to store and verify block headers in accordance
-//! with SPV assumptions - i.e. longest chain.
+//! with SPV assumptions - i.e. the chain with the most cumulative work.
 //!
 //! Unless otherwise stated, the primary source of truth for code contained herein is the
 //! [Bitcoin Core repository](https://github.com/bitcoin/bitcoin), though implementation
@@ -31,6 +31,7 @@
 
 mod ext;
 
+pub mod migration;
 pub mod types;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -56,13 +57,15 @@ use bitcoin::types::{BlockBuilder, TransactionBuilder, TransactionOutput};
 use bitcoin::{
     merkle::ProofResult,
     types::{BlockChain, BlockHeader, H256Le, Transaction, Value},
-    Error as BitcoinError, SetCompact,
+    Error as BitcoinError, Network, SetCompact,
 };
 use frame_support::{
-    dispatch::{DispatchError, DispatchResult},
+    dispatch::{DispatchError, DispatchResult, PostDispatchInfo},
     ensure, runtime_print,
+    storage::with_storage_layer,
     traits::Get,
     transactional,
+    weights::Weight,
 };
 use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
 use sp_core::{H256, U256};
@@ -77,7 +80,7 @@ pub use bitcoin::{
     PublicKey as BtcPublicKey,
 };
 pub use pallet::*;
-pub use types::{OpReturnPaymentData, RichBlockHeader};
+pub use types::{BatchedOpReturnPaymentData, OpReturnPaymentData, RichBlockHeader};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -85,11 +88,15 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + security::Config {
+    pub trait Config: frame_system::Config + pallet_timestamp::Config + security::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>>
             + Into<<Self as frame_system::Config>::RuntimeEvent>
@@ -103,7 +110,15 @@ pub mod pallet {
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            if ChainWorkMigration::<T>::exists() {
+                migration::v1::migrate_chain_work::<T>(remaining_weight)
+            } else {
+                Self::prune_storage(remaining_weight)
+            }
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -163,13 +178,10 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let relayer = ensure_signed(origin)?;
 
-            // the worst-case complexity is always dictated by the number of chains
-            // TODO: as the growth of `Chains` is unbounded this extrinsic may become
-            // prohibitively expensive, we should remove old forks from storage
+            // the worst-case complexity is always dictated by the number of chains,
+            // stale forks are removed in `on_idle` so this does not grow unbounded
             ensure!(
-                // ideally we would compare the number of entries in `Chains` here but
-                // since we never delete from that this should be equal to the length
-                Self::get_chain_counter().saturating_add(1) <= fork_bound,
+                Self::get_chain_count().saturating_add(1) <= fork_bound,
                 Error::<T>::WrongForkBound
             );
 
@@ -179,6 +191,114 @@ pub mod pallet {
             // don't take tx fees on success
             Ok(Pays::No.into())
         }
+
+        /// Set the pruning configuration of the relay. Forks that fall more than `stale_fork_depth`
+        /// blocks behind the main chain are removed, as are main chain headers more than
+        /// `main_chain_retention` blocks below the best block. `None` disables the respective pruning.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the dispatch origin of this call (must be _Root_)
+        /// * `stale_fork_depth` - depth after which forks are removed
+        /// * `main_chain_retention` - number of main chain headers to keep
+        ///
+        /// # Weight: `O(1)`
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_pruning_config())]
+        #[transactional]
+        pub fn set_pruning_config(
+            origin: OriginFor<T>,
+            stale_fork_depth: Option<u32>,
+            main_chain_retention: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let bitcoin_confirmations = Self::get_stable_transaction_confirmations();
+            if let Some(depth) = stale_fork_depth {
+                // forks that are closer to the main chain may still cause a reorg
+                ensure!(depth >= bitcoin_confirmations, Error::<T>::InvalidStaleForkDepth);
+            }
+            if let Some(retention) = main_chain_retention {
+                // the difficulty retarget needs the first header of the current period
+                ensure!(
+                    retention >= DIFFICULTY_ADJUSTMENT_INTERVAL.max(bitcoin_confirmations),
+                    Error::<T>::InvalidMainChainRetention
+                );
+            }
+
+            StaleForkDepth::<T>::set(stale_fork_depth);
+            MainChainRetention::<T>::set(main_chain_retention);
+            Self::deposit_event(Event::<T>::PruningConfigUpdated {
+                stale_fork_depth,
+                main_chain_retention,
+            });
+            Ok(().into())
+        }
+
+        /// Stores a contiguous run of block headers, each header must build on the one before it.
+        /// The headers are stored in order until one of them fails, in which case the headers
+        /// before it are kept and the failure is reported in a `StoreBlockHeadersInterrupted` event.
+        /// The call only fails if the headers are malformed or the first header cannot be stored.
+        ///
+        /// # Arguments
+        ///
+        /// * `block_headers` - Bitcoin block headers, ordered by height.
+        /// * `fork_bound` - upper bound on the number of chains.
+        ///
+        /// ## Complexity
+        /// - `O(H * F)` where `H` is the number of headers and `F` is the number of forks
+        ///
+        /// Every header that is added to a fork may reorganize the chains, so the worst case
+        /// is charged for each of them upfront and refunded for headers that extend the main chain.
+        #[pallet::call_index(3)]
+        #[pallet::weight((
+            {
+                let h = block_headers.len() as u32;
+                let f = *fork_bound;
+                Pallet::<T>::store_block_headers_weight(h, h, f)
+            },
+            DispatchClass::Operational
+        ))]
+        #[transactional]
+        pub fn store_block_headers(
+            origin: OriginFor<T>,
+            mut block_headers: Vec<BlockHeader>,
+            fork_bound: u32,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            let header_count = block_headers.len() as u32;
+            ensure!(
+                header_count > 0 && header_count <= MAX_BLOCK_HEADERS_PER_BATCH,
+                Error::<T>::InvalidBlockHeaderCount
+            );
+            // a contiguous run of headers can create at most one new fork
+            ensure!(
+                Self::get_chain_count().saturating_add(1) <= fork_bound,
+                Error::<T>::WrongForkBound
+            );
+
+            for block_header in block_headers.iter_mut() {
+                Self::_validate_block_header(block_header)?;
+            }
+            ensure!(
+                block_headers
+                    .windows(2)
+                    .all(|pair| pair[1].hash_prev_block == pair[0].hash),
+                Error::<T>::NonContiguousBlockHeaders
+            );
+
+            let (stored, stored_on_fork) = Self::_store_block_headers(&relayer, block_headers)?;
+
+            // the header that interrupted the batch is charged as well, including a reorg
+            let processed = stored.saturating_add(1).min(header_count);
+            let reorganized = stored_on_fork.saturating_add(processed.saturating_sub(stored));
+            Ok(PostDispatchInfo {
+                actual_weight: Some(Self::store_block_headers_weight(processed, reorganized, fork_bound)),
+                // don't take tx fees on success
+                pays_fee: Pays::No,
+            })
+        }
     }
 
     #[pallet::event]
@@ -210,6 +330,35 @@ pub mod pallet {
             fork_height: u32,
             fork_id: u32,
         },
+        /// Choosing the main chain by height would have given a different result than
+        /// choosing it by cumulative work, `reorg` is the decision based on work
+        ForkChoiceDisagreement {
+            main_chain_height: u32,
+            main_chain_work: U256,
+            fork_height: u32,
+            fork_work: U256,
+            fork_id: u32,
+            reorg: bool,
+        },
+        PruningConfigUpdated {
+            stale_fork_depth: Option<u32>,
+            main_chain_retention: Option<u32>,
+        },
+        ForkPruned {
+            fork_id: u32,
+            start_height: u32,
+            max_height: u32,
+        },
+        MainChainPruned {
+            start_height: u32,
+        },
+        /// A batch of block headers was only partially stored, `index` is the position
+        /// of the header that could not be stored
+        StoreBlockHeadersInterrupted {
+            index: u32,
+            block_hash: H256Le,
+            error: DispatchError,
+        },
     }
 
     #[pallet::error]
@@ -318,6 +467,20 @@ pub mod pallet {
         BoundExceeded,
         /// Coinbase tx must be the first transaction in the block
         InvalidCoinbasePosition,
+        /// Stale fork depth must be at least the number of stable Bitcoin confirmations
+        InvalidStaleForkDepth,
+        /// Main chain retention must cover the difficulty adjustment interval
+        InvalidMainChainRetention,
+        /// Block timestamp is not greater than the median time of the previous blocks
+        TimestampTooEarly,
+        /// Block timestamp is too far ahead of the parachain time
+        TimestampTooFarInFuture,
+        /// Number of block headers in the batch is zero or exceeds the maximum
+        InvalidBlockHeaderCount,
+        /// Each block header in the batch must build on the previous one
+        NonContiguousBlockHeaders,
+        /// The chain work of the stored block headers is still being migrated
+        ChainWorkMigrationInProgress,
     }
 
     /// Store Bitcoin block headers
@@ -325,8 +488,8 @@ pub mod pallet {
     pub(super) type BlockHeaders<T: Config> =
         StorageMap<_, Blake2_128Concat, H256Le, RichBlockHeader<BlockNumberFor<T>>, ValueQuery>;
 
-    /// Priority queue of BlockChain elements, ordered by the maximum height (descending).
-    /// The first index into this mapping (0) is considered to be the longest chain. The value
+    /// Priority queue of BlockChain elements, ordered by the cumulative work (descending).
+    /// The first index into this mapping (0) is considered to be the main chain. The value
     /// of the entry is the index into `ChainsIndex` to retrieve the `BlockChain`.
     #[pallet::storage]
     // TODO: migrate this to sorted vec
@@ -358,6 +521,10 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type ChainCounter<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Number of entries in `Chains`, i.e. the main chain and all forks
+    #[pallet::storage]
+    pub(super) type ChainCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     /// Global security parameter k for stable Bitcoin transactions
     #[pallet::storage]
     #[pallet::getter(fn bitcoin_confirmations)]
@@ -368,16 +535,37 @@ pub mod pallet {
     #[pallet::getter(fn parachain_confirmations)]
     pub(super) type StableParachainConfirmations<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-    /// Whether the module should perform difficulty checks.
+    /// The Bitcoin network whose difficulty rules block headers must follow.
     #[pallet::storage]
-    #[pallet::getter(fn disable_difficulty_check)]
-    pub(super) type DisableDifficultyCheck<T: Config> = StorageValue<_, bool, ValueQuery>;
+    #[pallet::getter(fn bitcoin_network)]
+    pub(super) type BitcoinNetwork<T: Config> = StorageValue<_, Network, ValueQuery>;
 
     /// Whether the module should perform inclusion checks.
     #[pallet::storage]
     #[pallet::getter(fn disable_inclusion_check)]
     pub(super) type DisableInclusionCheck<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Number of blocks a fork may fall behind the main chain before it is removed.
+    /// Forks are never removed if this is not set.
+    #[pallet::storage]
+    #[pallet::getter(fn stale_fork_depth)]
+    pub(super) type StaleForkDepth<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Number of main chain headers below the best block that are kept in storage.
+    /// Main chain headers are never removed if this is not set.
+    #[pallet::storage]
+    #[pallet::getter(fn main_chain_retention)]
+    pub(super) type MainChainRetention<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Position in `Chains` of the next fork that is checked for staleness in `on_idle`
+    #[pallet::storage]
+    pub(super) type ForkPruningCursor<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Progress of the migration that adds the chain work to the stored block headers. The
+    /// relay cannot be used while this is set.
+    #[pallet::storage]
+    pub(super) type ChainWorkMigration<T: Config> = StorageValue<_, migration::v1::MigrationStage, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -385,10 +573,14 @@ pub mod pallet {
         pub bitcoin_confirmations: u32,
         /// Global security parameter k for stable Parachain transactions
         pub parachain_confirmations: BlockNumberFor<T>,
-        /// Whether the module should perform difficulty checks.
-        pub disable_difficulty_check: bool,
+        /// The Bitcoin network whose difficulty rules block headers must follow.
+        pub bitcoin_network: Network,
         /// Whether the module should perform inclusion checks.
         pub disable_inclusion_check: bool,
+        /// Number of blocks a fork may fall behind the main chain before it is removed.
+        pub stale_fork_depth: Option<u32>,
+        /// Number of main chain headers below the best block that are kept in storage.
+        pub main_chain_retention: Option<u32>,
     }
 
     #[pallet::genesis_build]
@@ -396,8 +588,10 @@ pub mod pallet {
         fn build(&self) {
             StableBitcoinConfirmations::<T>::put(self.bitcoin_confirmations);
             StableParachainConfirmations::<T>::put(self.parachain_confirmations);
-            DisableDifficultyCheck::<T>::put(self.disable_difficulty_check);
+            BitcoinNetwork::<T>::put(self.bitcoin_network);
             DisableInclusionCheck::<T>::put(self.disable_inclusion_check);
+            StaleForkDepth::<T>::set(self.stale_fork_depth);
+            MainChainRetention::<T>::set(self.main_chain_retention);
         }
     }
 }
@@ -413,6 +607,13 @@ pub const TARGET_SPACING: u32 = 10 * 60;
 /// See: <https://spec.interlay.io/intro/accepted-format.html#accepted-bitcoin-transaction-format>
 pub const ACCEPTED_MAX_TRANSACTION_OUTPUTS: usize = 3;
 
+/// Maximum number of requests that can be paid by a single batched transaction
+pub const MAX_BATCHED_PAYMENTS: usize = 16;
+
+/// Accepted maximum number of transaction outputs for validation of batched redeems:
+/// a payment and op_return per request, plus an optional return-to-self output
+pub const ACCEPTED_MAX_BATCHED_TRANSACTION_OUTPUTS: usize = 2 * MAX_BATCHED_PAYMENTS + 1;
+
 /// Unrounded Maximum Target
 /// 0x00000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
 pub const UNROUNDED_MAX_TARGET: U256 = U256([<u64>::MAX, <u64>::MAX, <u64>::MAX, 0x0000_0000_ffff_ffffu64]);
@@ -420,20 +621,42 @@ pub const UNROUNDED_MAX_TARGET: U256 = U256([<u64>::MAX, <u64>::MAX, <u64>::MAX,
 /// Main chain id
 pub const MAIN_CHAIN_ID: u32 = 0;
 
+/// Maximum number of block headers that can be stored in a single call
+pub const MAX_BLOCK_HEADERS_PER_BATCH: u32 = 100;
+
+/// Stale fork depth used when pruning is first enabled, one day of Bitcoin blocks
+pub const DEFAULT_STALE_FORK_DEPTH: u32 = 144;
+
+/// Maximum number of forks that are checked for staleness in a single block
+pub const MAX_FORKS_CHECKED_PER_BLOCK: u32 = 10;
+
+/// Maximum number of main chain headers that are removed in a single block
+pub const MAX_PRUNED_HEADERS_PER_BLOCK: u32 = 100;
+
+/// Number of previous blocks used to compute the median time past
+// https://github.com/bitcoin/bitcoin/blob/5ba5becbb5d8c794efe579caeea7eea64f895a13/src/chain.h#L277
+pub const MEDIAN_TIME_SPAN: usize = 11;
+
+/// Maximum number of seconds a block timestamp may be ahead of the current time: 2 hours
+// https://github.com/bitcoin/bitcoin/blob/5ba5becbb5d8c794efe579caeea7eea64f895a13/src/chain.h#L22
+pub const MAX_FUTURE_BLOCK_TIME: u64 = 2 * 60 * 60;
+
 #[cfg_attr(test, mockable)]
 impl<T: Config> Pallet<T> {
     pub fn _initialize(relayer: T::AccountId, basic_block_header: BlockHeader, block_height: u32) -> DispatchResult {
         // Check if BTC-Relay was already initialized
         ensure!(!Self::best_block_exists(), Error::<T>::AlreadyInitialized);
+        Self::ensure_chain_work_migrated()?;
 
-        // header must be the start of a difficulty period
+        // header must be the start of a difficulty period, unless the difficulty is never retargeted
         ensure!(
-            Self::disable_difficulty_check() || block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0,
+            Self::bitcoin_network().no_retargeting() || block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0,
             Error::<T>::InvalidStartHeight
         );
 
-        // construct the BlockChain struct
-        Self::create_and_store_blockchain(block_height, &basic_block_header)?;
+        // construct the BlockChain struct, the chain work is counted from this block onwards
+        let chain_work = bitcoin::pow::get_block_proof(basic_block_header.target);
+        Self::create_and_store_blockchain(block_height, &basic_block_header, chain_work)?;
 
         // Set BestBlock and BestBlockHeight to the submitted block
         Self::update_chain_head(&basic_block_header, block_height);
@@ -451,6 +674,7 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn _store_block_header(relayer: &T::AccountId, basic_block_header: BlockHeader) -> DispatchResult {
+        Self::ensure_chain_work_migrated()?;
         let prev_header = Self::get_block_header_from_hash(basic_block_header.hash_prev_block)?;
 
         // check if the prev block is the highest block in the chain
@@ -467,6 +691,11 @@ impl<T: Config> Pallet<T> {
         // ensure the block header is valid
         Self::verify_block_header(&basic_block_header, current_block_height, prev_header)?;
 
+        let chain_work = prev_header
+            .chain_work
+            .checked_add(bitcoin::pow::get_block_proof(basic_block_header.target))
+            .ok_or(Error::<T>::ArithmeticOverflow)?;
+
         // Update the blockchain
         // check if we create a new blockchain or extend the existing one
         runtime_print!("Prev max height: {:?}", prev_blockchain.max_height);
@@ -476,10 +705,15 @@ impl<T: Config> Pallet<T> {
 
         let chain_id = if is_new_fork {
             // create new blockchain element
-            Self::create_and_store_blockchain(current_block_height, &basic_block_header)?
+            let blockchain = Self::create_and_store_blockchain(current_block_height, &basic_block_header, chain_work)?;
+
+            // a single block with enough work may already overtake the main chain
+            Self::reorganize_chains(&blockchain)?;
+            blockchain.chain_id
         } else {
             // extend the current chain
-            let blockchain = Self::extend_blockchain(current_block_height, &basic_block_header, prev_blockchain)?;
+            let blockchain =
+                Self::extend_blockchain(current_block_height, &basic_block_header, prev_blockchain, chain_work)?;
 
             if blockchain.chain_id != MAIN_CHAIN_ID {
                 // if we added a block to a fork, we may need to reorder the chains
@@ -513,6 +747,49 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Stores the block headers in order until one of them fails, the headers stored before
+    /// the failing one are kept. Returns the number of stored headers and how many of those
+    /// did not extend the main chain tip, or the error if not even the first header could be stored.
+    pub fn _store_block_headers(
+        relayer: &T::AccountId,
+        block_headers: Vec<BlockHeader>,
+    ) -> Result<(u32, u32), DispatchError> {
+        let mut stored = 0u32;
+        let mut stored_on_fork = 0u32;
+        for block_header in block_headers {
+            // only headers that are added to a fork can reorganize the chains
+            let extends_main_chain = Self::get_best_block() == block_header.hash_prev_block;
+            // revert the changes of a single failing header only
+            match with_storage_layer(|| Self::_store_block_header(relayer, block_header)) {
+                Ok(()) => {
+                    stored = stored.saturating_add(1);
+                    if !extends_main_chain {
+                        stored_on_fork = stored_on_fork.saturating_add(1);
+                    }
+                }
+                Err(err) if stored == 0 => return Err(err),
+                Err(err) => {
+                    Self::deposit_event(Event::<T>::StoreBlockHeadersInterrupted {
+                        index: stored,
+                        block_hash: block_header.hash,
+                        error: err,
+                    });
+                    break;
+                }
+            }
+        }
+        Ok((stored, stored_on_fork))
+    }
+
+    /// Weight of `store_block_headers` for `header_count` headers, `fork_header_count` of which
+    /// are added to a fork and may reorganize the chains.
+    pub fn store_block_headers_weight(header_count: u32, fork_header_count: u32, fork_bound: u32) -> Weight {
+        <T as Config>::WeightInfo::store_block_headers(header_count, fork_bound).saturating_add(
+            <T as Config>::WeightInfo::store_block_header_reorganize_chains(fork_bound)
+                .saturating_mul(fork_header_count.into()),
+        )
+    }
+
     pub fn _validate_block_header(block_header: &mut BlockHeader) -> Result<(), DispatchError> {
         block_header.ensure_version().map_err(Error::<T>::from)?;
         block_header.update_hash().map_err(Error::<T>::from)?;
@@ -584,6 +861,20 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// interface to redeem to check that a transaction paying several requests at once is
+    /// included and is valid
+    pub fn verify_and_validate_batched_op_return_transaction<V: TryInto<Value>>(
+        unchecked_transaction: FullTransactionProof,
+        expected_payments: Vec<(H256, BtcAddress, V)>,
+    ) -> Result<(), DispatchError> {
+        // Verify that the transaction is indeed included in the main chain
+        let transaction = Self::_verify_transaction_inclusion(unchecked_transaction, None)?;
+
+        // Check that the transaction matches the given parameters
+        Self::validate_batched_op_return_transaction(transaction, expected_payments)?;
+        Ok(())
+    }
+
     pub fn _verify_transaction_inclusion(
         unchecked_transaction: FullTransactionProof,
         confirmations: Option<u32>,
@@ -644,8 +935,9 @@ impl<T: Config> Pallet<T> {
         block_hash: H256Le,
         confirmations: Option<u32>,
     ) -> Result<BlockHeader, DispatchError> {
+        Self::ensure_chain_work_migrated()?;
         let best_block_height = Self::get_best_block_height();
-        Self::ensure_no_ongoing_fork(best_block_height)?;
+        Self::ensure_no_ongoing_fork()?;
 
         let rich_header = Self::get_block_header_from_hash(block_hash)?;
 
@@ -663,7 +955,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Checks if transaction is valid. Returns the return-to-self address, if any, for theft checking purposes
-    fn validate_op_return_transaction<V: TryInto<i64>>(
+    pub fn validate_op_return_transaction<V: TryInto<i64>>(
         transaction: Transaction,
         recipient_btc_address: BtcAddress,
         expected_btc: V,
@@ -677,6 +969,26 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Checks if a batched transaction pays exactly the given (op_return, recipient, amount) triples.
+    /// Returns the return-to-self address, if any, for theft checking purposes
+    pub fn validate_batched_op_return_transaction<V: TryInto<Value>>(
+        transaction: Transaction,
+        expected_payments: Vec<(H256, BtcAddress, V)>,
+    ) -> Result<Option<BtcAddress>, DispatchError> {
+        let payment_data = BatchedOpReturnPaymentData::<T>::try_from(transaction)?;
+        let expected_payments = expected_payments
+            .into_iter()
+            .map(|(op_return, recipient, amount)| {
+                Ok((
+                    op_return,
+                    recipient,
+                    amount.try_into().map_err(|_| Error::<T>::InvalidPaymentAmount)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, DispatchError>>()?;
+        payment_data.ensure_valid_payments_to(&expected_payments)
+    }
+
     pub fn is_fully_initialized() -> Result<bool, DispatchError> {
         if !StartBlockHeight::<T>::exists() {
             return Ok(false);
@@ -727,7 +1039,7 @@ impl<T: Config> Pallet<T> {
     // START: Storage getter functions
     // ********************************
 
-    /// Get chain id from position (sorted by max block height)
+    /// Get chain id from position (sorted by chain work)
     fn get_chain_id_from_position(position: u32) -> Result<u32, DispatchError> {
         Chains::<T>::get(position).ok_or(Error::<T>::InvalidChainID.into())
     }
@@ -767,6 +1079,11 @@ impl<T: Config> Pallet<T> {
         ChainCounter::<T>::get()
     }
 
+    /// Get the number of chains (main chain and forks) in storage
+    fn get_chain_count() -> u32 {
+        ChainCount::<T>::get()
+    }
+
     /// Get a block hash from a blockchain
     ///
     /// # Arguments
@@ -781,7 +1098,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Get a block header from its hash
-    fn get_block_header_from_hash(block_hash: H256Le) -> Result<RichBlockHeader<BlockNumberFor<T>>, DispatchError> {
+    pub fn get_block_header_from_hash(block_hash: H256Le) -> Result<RichBlockHeader<BlockNumberFor<T>>, DispatchError> {
         BlockHeaders::<T>::try_get(block_hash).or(Err(Error::<T>::BlockNotFound.into()))
     }
 
@@ -790,6 +1107,34 @@ impl<T: Config> Pallet<T> {
         BlockHeaders::<T>::contains_key(block_hash)
     }
 
+    /// Get a block header of the main chain from its height
+    pub fn get_main_chain_block_header(block_height: u32) -> Result<RichBlockHeader<BlockNumberFor<T>>, DispatchError> {
+        let block_hash = Self::get_block_hash(MAIN_CHAIN_ID, block_height)?;
+        Self::get_block_header_from_hash(block_hash)
+    }
+
+    /// Get the main chain followed by all forks, ordered by their position in the Chains mapping
+    pub fn get_block_chains() -> Vec<BlockChain> {
+        let mut chains = Chains::<T>::iter().collect::<Vec<(u32, u32)>>();
+        chains.sort_by_key(|k| k.0);
+        chains
+            .into_iter()
+            .filter_map(|(_, chain_id)| ChainsIndex::<T>::get(chain_id))
+            .collect()
+    }
+
+    /// Get the number of confirmations of a block, blocks that are not part of the
+    /// main chain have no confirmations
+    pub fn get_block_confirmations(block_hash: H256Le) -> Result<u32, DispatchError> {
+        let rich_header = Self::get_block_header_from_hash(block_hash)?;
+        if rich_header.chain_id != MAIN_CHAIN_ID {
+            return Ok(0);
+        }
+        Ok(Self::get_best_block_height()
+            .saturating_sub(rich_header.block_height)
+            .saturating_add(1))
+    }
+
     /// Get a block header from
     fn get_block_header_from_height(
         blockchain: &BlockChain,
@@ -846,12 +1191,16 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Create a new blockchain element with a new chain id
-    fn create_and_store_blockchain(block_height: u32, basic_block_header: &BlockHeader) -> Result<u32, DispatchError> {
+    fn create_and_store_blockchain(
+        block_height: u32,
+        basic_block_header: &BlockHeader,
+        chain_work: U256,
+    ) -> Result<BlockChain, DispatchError> {
         // get a new chain id
         let chain_id = Self::increment_chain_counter()?;
 
         // generate an empty blockchain
-        let blockchain = Self::generate_blockchain(chain_id, block_height, basic_block_header.hash);
+        let blockchain = Self::generate_blockchain(chain_id, block_height, basic_block_header.hash, chain_work);
 
         // Store a pointer to BlockChain in ChainsIndex
         Self::set_block_chain_from_id(blockchain.chain_id, &blockchain);
@@ -859,13 +1208,18 @@ impl<T: Config> Pallet<T> {
         // Store the reference to the blockchain in Chains
         Self::insert_sorted(&blockchain)?;
 
-        Self::store_rich_header(basic_block_header.clone(), block_height, blockchain.chain_id);
+        Self::store_rich_header(
+            basic_block_header.clone(),
+            block_height,
+            blockchain.chain_id,
+            chain_work,
+        );
 
-        Ok(blockchain.chain_id)
+        Ok(blockchain)
     }
 
     /// Generate the raw blockchain from a chain Id and with a single block
-    fn generate_blockchain(chain_id: u32, block_height: u32, block_hash: H256Le) -> BlockChain {
+    fn generate_blockchain(chain_id: u32, block_height: u32, block_hash: H256Le, chain_work: U256) -> BlockChain {
         // initialize an empty chain
 
         Self::insert_block_hash(chain_id, block_height, block_hash);
@@ -874,6 +1228,7 @@ impl<T: Config> Pallet<T> {
             chain_id,
             start_height: block_height,
             max_height: block_height,
+            chain_work,
         }
     }
 
@@ -890,6 +1245,7 @@ impl<T: Config> Pallet<T> {
         block_height: u32,
         basic_block_header: &BlockHeader,
         prev_blockchain: BlockChain,
+        chain_work: U256,
     ) -> Result<BlockChain, DispatchError> {
         let mut blockchain = prev_blockchain;
 
@@ -899,9 +1255,15 @@ impl<T: Config> Pallet<T> {
         Self::insert_block_hash(blockchain.chain_id, block_height, basic_block_header.hash);
 
         blockchain.max_height = block_height;
+        blockchain.chain_work = chain_work;
         Self::set_block_chain_from_id(blockchain.chain_id, &blockchain);
 
-        Self::store_rich_header(basic_block_header.clone(), block_height, blockchain.chain_id);
+        Self::store_rich_header(
+            basic_block_header.clone(),
+            block_height,
+            blockchain.chain_id,
+            chain_work,
+        );
 
         Ok(blockchain)
     }
@@ -911,6 +1273,15 @@ impl<T: Config> Pallet<T> {
         Self::bitcoin_confirmations()
     }
 
+    /// Get the work of the required confirmations for stable transactions at the
+    /// difficulty of the current best block, i.e. by how much a fork has to exceed
+    /// the main chain before we reorg
+    fn get_stable_confirmations_work() -> Result<U256, DispatchError> {
+        let best_block = Self::get_block_header_from_hash(Self::get_best_block())?;
+        Ok(bitcoin::pow::get_block_proof(best_block.block_header.target)
+            .saturating_mul(Self::get_stable_transaction_confirmations().into()))
+    }
+
     // *********************************
     // END: Storage getter functions
     // *********************************
@@ -947,22 +1318,114 @@ impl<T: Config> Pallet<T> {
         // Check that the PoW hash satisfies the target set in the block header
         ensure!(block_header.hash.as_u256() < block_header.target, Error::<T>::LowDiff);
 
-        if Self::disable_difficulty_check() {
-            return Ok(());
+        // Check that the timestamp is greater than the median time past, this is
+        // skipped until enough ancestors are stored since the window would be incomplete
+        if let Some(median_time_past) = Self::get_median_time_past(&prev_block_header) {
+            ensure!(block_header.timestamp > median_time_past, Error::<T>::TimestampTooEarly);
         }
 
-        let expected_target =
-            if block_height >= DIFFICULTY_ADJUSTMENT_INTERVAL && block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0 {
-                Self::compute_new_target(&prev_block_header, block_height)?
-            } else {
-                prev_block_header.block_header.target
-            };
+        // Check that the timestamp is at most two hours ahead of the parachain time,
+        // which is zero until the first timestamp inherent has been processed
+        let now = ext::timestamp::now::<T>();
+        ensure!(
+            now == 0 || block_header.timestamp as u64 <= now.saturating_add(MAX_FUTURE_BLOCK_TIME),
+            Error::<T>::TimestampTooFarInFuture
+        );
 
+        // Check that the target follows the difficulty rules of the Bitcoin network
+        let expected_target = Self::get_next_work_required(block_header, block_height, &prev_block_header)?;
         ensure!(block_header.target == expected_target, Error::<T>::DiffTargetHeader);
 
         Ok(())
     }
 
+    /// Returns the target required for the given block header by the difficulty rules of the Bitcoin network
+    ///
+    /// # Arguments
+    ///
+    /// * `block_header`: the block header to check
+    /// * `block_height`: the height of the block header
+    /// * `prev_block_header`: previous block header
+    // https://github.com/bitcoin/bitcoin/blob/89b910711c004c21b7d67baa888073742f7f94f0/src/pow.cpp#L13-L47
+    fn get_next_work_required(
+        block_header: &BlockHeader,
+        block_height: u32,
+        prev_block_header: &RichBlockHeader<BlockNumberFor<T>>,
+    ) -> Result<U256, DispatchError> {
+        let network = Self::bitcoin_network();
+        let prev_target = prev_block_header.block_header.target;
+
+        if block_height < DIFFICULTY_ADJUSTMENT_INTERVAL || block_height % DIFFICULTY_ADJUSTMENT_INTERVAL != 0 {
+            if !network.allows_min_difficulty_blocks() {
+                return Ok(prev_target);
+            }
+
+            // a block more than 20 minutes after its parent may be mined at the lowest difficulty
+            let min_difficulty_time =
+                (prev_block_header.block_header.timestamp as u64).saturating_add(2 * TARGET_SPACING as u64);
+            if block_header.timestamp as u64 > min_difficulty_time {
+                return Ok(network.min_difficulty_target());
+            }
+
+            // otherwise the target of the last block that was not mined at the lowest difficulty
+            // applies. Bitcoin walks back over these blocks, but since all other blocks in a
+            // difficulty period have the target of its first block we can look that one up instead
+            if prev_target != network.min_difficulty_target() {
+                return Ok(prev_target);
+            }
+            let period_start_height =
+                prev_block_header.block_height - prev_block_header.block_height % DIFFICULTY_ADJUSTMENT_INTERVAL;
+            // the first stored block takes its place if the relay was initialized mid-period
+            let period_start =
+                Self::get_ancestor(prev_block_header, period_start_height.max(StartBlockHeight::<T>::get()))?;
+            return Ok(period_start.block_header.target);
+        }
+
+        if network.no_retargeting() {
+            return Ok(prev_target);
+        }
+
+        Self::compute_new_target(prev_block_header, block_height)
+    }
+
+    /// Returns the ancestor of the given block at the given height, following the
+    /// parent chains if the block is on a fork that starts above that height
+    ///
+    /// # Arguments
+    ///
+    /// * `block_header`: the block whose ancestor to return
+    /// * `block_height`: the height of the ancestor
+    fn get_ancestor(
+        block_header: &RichBlockHeader<BlockNumberFor<T>>,
+        block_height: u32,
+    ) -> Result<RichBlockHeader<BlockNumberFor<T>>, DispatchError> {
+        let mut blockchain = Self::get_block_chain_from_id(block_header.chain_id)?;
+        while block_height < blockchain.start_height {
+            blockchain = Self::get_block_chain_from_id(Self::get_parent_chain_id(&blockchain)?)?;
+        }
+        Self::get_block_header_from_height(&blockchain, block_height)
+    }
+
+    /// Returns the median timestamp of the `MEDIAN_TIME_SPAN` blocks ending with the given
+    /// block, or `None` if not all of these blocks are stored
+    ///
+    /// # Arguments
+    ///
+    /// * `block_header`: the most recent block of the window
+    fn get_median_time_past(block_header: &RichBlockHeader<BlockNumberFor<T>>) -> Option<u32> {
+        let mut timestamps = Vec::with_capacity(MEDIAN_TIME_SPAN);
+        let mut header = block_header.block_header;
+        timestamps.push(header.timestamp);
+        while timestamps.len() < MEDIAN_TIME_SPAN {
+            header = Self::get_block_header_from_hash(header.hash_prev_block)
+                .ok()?
+                .block_header;
+            timestamps.push(header.timestamp);
+        }
+        timestamps.sort_unstable();
+        Some(timestamps[MEDIAN_TIME_SPAN / 2])
+    }
+
     /// Computes Bitcoin's PoW retarget algorithm for a given block height
     ///
     /// # Arguments
@@ -980,8 +1443,13 @@ impl<T: Config> Pallet<T> {
 
         // compute new target
         Ok(U256::set_compact(
-            bitcoin::pow::calculate_next_work_required(previous_target, first_block_time, last_block_time)
-                .map_err(Error::<T>::from)?,
+            bitcoin::pow::calculate_next_work_required(
+                Self::bitcoin_network(),
+                previous_target,
+                first_block_time,
+                last_block_time,
+            )
+            .map_err(Error::<T>::from)?,
         )
         .ok_or(Error::<T>::InvalidCompact)?)
     }
@@ -1006,8 +1474,9 @@ impl<T: Config> Pallet<T> {
     /// that the iteration passes through are updated: their start_height is increased appropriately.
     /// Each block header that is iterated over is moved to the main chain. Then, any blocks that used
     /// to be in the main-chain, but that are being replaced by the fork, are moved into the fork that
-    /// overtook the mainchain.The start_height and max_height of the mainchain and the fork are updated
-    /// appropriately. Finally, the best_block and best_block_height are updated.
+    /// overtook the mainchain, including those above the height of the fork if it is shorter. The start_height,
+    /// max_height and chain_work of the mainchain and the fork are updated appropriately. Finally, the
+    /// best_block and best_block_height are updated.
     ///
     /// # Arguments
     ///
@@ -1058,16 +1527,27 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // update the max_height of main chain
+        // a fork with more work may be shorter than the main chain it replaces, in which case
+        // the remaining blocks of the old main chain are moved to `fork` as well
+        let old_main_max_height = Self::get_block_chain_from_id(MAIN_CHAIN_ID)?.max_height;
+        for block_height in fork.max_height.saturating_add(1)..=old_main_max_height {
+            let block_hash = Self::get_block_hash(MAIN_CHAIN_ID, block_height)?;
+            ChainsHashes::<T>::remove(MAIN_CHAIN_ID, block_height);
+            ChainsHashes::<T>::insert(fork.chain_id, block_height, block_hash);
+            BlockHeaders::<T>::mutate(&block_hash, |header| header.chain_id = fork.chain_id);
+        }
+
+        // update the max_height and chain_work of main chain
         Self::mutate_block_chain_from_id(
             MAIN_CHAIN_ID,
             BlockChain {
                 max_height: fork.max_height,
+                chain_work: fork.chain_work,
                 ..Self::get_block_chain_from_id(MAIN_CHAIN_ID)?
             },
         );
 
-        // we swapped main chain and `fork`, so it will need to be resorted. The new max_height of this fork
+        // we swapped main chain and `fork`, so it will need to be resorted. The new chain_work of this fork
         // is strictly smaller than before, so do a single bubble sort pass to the right
         let start = Self::get_chain_position_from_chain_id(fork.chain_id)?;
         // ideally we'd iterate over start..Chains::<T>::len(), but unfortunately Chains does not implement
@@ -1079,9 +1559,9 @@ impl<T: Config> Pallet<T> {
                 break;
             }
 
-            let height1 = Self::get_block_chain_from_id(Self::get_chain_id_from_position(i)?)?.max_height;
-            let height2 = Self::get_block_chain_from_id(Self::get_chain_id_from_position(i + 1)?)?.max_height;
-            if height1 < height2 {
+            let work1 = Self::get_block_chain_from_id(Self::get_chain_id_from_position(i)?)?.chain_work;
+            let work2 = Self::get_block_chain_from_id(Self::get_chain_id_from_position(i + 1)?)?.chain_work;
+            if work1 < work2 {
                 Self::swap_chain(i, i + 1);
             } else {
                 break;
@@ -1145,8 +1625,8 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Checks if a newly inserted fork results in an update to the sorted
-    /// Chains mapping. This happens when the max height of the fork is greater
-    /// than the max height of the previous element in the Chains mapping.
+    /// Chains mapping. This happens when the chain work of the fork is greater
+    /// than the chain work of the previous element in the Chains mapping.
     ///
     /// # Arguments
     ///
@@ -1154,11 +1634,36 @@ impl<T: Config> Pallet<T> {
     fn reorganize_chains(fork: &BlockChain) -> Result<(), DispatchError> {
         // get the position of the fork in Chains
         let fork_position: u32 = Self::get_chain_position_from_chain_id(fork.chain_id)?;
-        // check if the previous element in Chains has a lower block_height
+        // check if the previous element in Chains has less work
         let mut current_position = fork_position;
-        let mut current_height = fork.max_height;
+        let mut current_work = fork.chain_work;
+
+        // the fork replaces the main chain if it has STABLE_TRANSACTION_CONFIRMATIONS
+        // worth of work more than the main chain
+        let main_chain = Self::get_block_chain_from_id(MAIN_CHAIN_ID)?;
+        let is_heavier = main_chain.chain_work < fork.chain_work
+            && main_chain
+                .chain_work
+                .saturating_add(Self::get_stable_confirmations_work()?)
+                <= fork.chain_work;
+        // this is the decision we would have made using the height only
+        let is_longer = main_chain.max_height < fork.max_height
+            && main_chain
+                .max_height
+                .saturating_add(Self::get_stable_transaction_confirmations())
+                <= fork.max_height;
+        if is_heavier != is_longer {
+            Self::deposit_event(Event::<T>::ForkChoiceDisagreement {
+                main_chain_height: main_chain.max_height,
+                main_chain_work: main_chain.chain_work,
+                fork_height: fork.max_height,
+                fork_work: fork.chain_work,
+                fork_id: fork.chain_id,
+                reorg: is_heavier,
+            });
+        }
 
-        // swap elements as long as previous block height is smaller
+        // swap elements as long as previous chain work is smaller
         while current_position > 0 {
             // get the previous position
             let prev_position = current_position.saturating_sub(1);
@@ -1171,17 +1676,17 @@ impl<T: Config> Pallet<T> {
                 continue;
             };
 
-            // get the previous blockchain height
-            let prev_height = Self::get_block_chain_from_id(prev_blockchain_id)?.max_height;
-            // swap elements if block height is greater
-            if prev_height < current_height {
+            // get the previous blockchain work
+            let prev_work = Self::get_block_chain_from_id(prev_blockchain_id)?.chain_work;
+            // swap elements if chain work is greater
+            if prev_work < current_work {
                 // Check if swap occurs on the main chain element
                 if prev_blockchain_id == MAIN_CHAIN_ID {
                     // if the previous position is the top element
-                    // and the current height is more than the
+                    // and the current work is more than the
                     // STABLE_TRANSACTION_CONFIRMATIONS ahead
                     // we are swapping the main chain
-                    if prev_height.saturating_add(Self::get_stable_transaction_confirmations()) <= current_height {
+                    if is_heavier {
                         // Swap the mainchain. As an optimization, this function returns the
                         // new best block hash and its height
                         let (new_chain_tip_hash, new_chain_tip_height) = Self::swap_main_blockchain(&fork)?;
@@ -1195,7 +1700,7 @@ impl<T: Config> Pallet<T> {
                         });
                     } else {
                         Self::deposit_event(Event::<T>::ForkAheadOfMainChain {
-                            main_chain_height: prev_height,
+                            main_chain_height: main_chain.max_height,
                             fork_height: fork.max_height,
                             fork_id: fork.chain_id,
                         });
@@ -1209,7 +1714,7 @@ impl<T: Config> Pallet<T> {
 
                 // update the current chain to the previous one
                 current_position = prev_position;
-                current_height = prev_height;
+                current_work = prev_work;
             } else {
                 break;
             }
@@ -1218,7 +1723,7 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    /// Insert a new fork into the Chains mapping sorted by its max height
+    /// Insert a new fork into the Chains mapping sorted by its chain work
     ///
     /// # Arguments
     ///
@@ -1235,18 +1740,18 @@ impl<T: Config> Pallet<T> {
         let mut position_blockchain = max_chain_element;
 
         // Starting from the second highest element, find where to insert the new fork
-        // the previous element's block height should be higher or equal
-        // the next element's block height should be lower or equal
+        // the previous element's chain work should be higher or equal
+        // the next element's chain work should be lower or equal
         // NOTE: we never want to insert a new main chain through this function
         for (curr_position, curr_chain_id) in chains.iter().skip(1) {
-            // get the height of the current chain_id
-            let curr_height = Self::get_block_chain_from_id(*curr_chain_id)?.max_height;
+            // get the work of the current chain_id
+            let curr_work = Self::get_block_chain_from_id(*curr_chain_id)?.chain_work;
 
-            // if the height of the new blockchain is higher than
+            // if the work of the new blockchain is higher than
             // the current blockchain, it should be inserted at that position
             // NOTE: inequality should be gt to prevent swapping chains
-            // at the same height
-            if blockchain.max_height > curr_height {
+            // with the same work
+            if blockchain.chain_work > curr_work {
                 position_blockchain = *curr_position;
                 break;
             };
@@ -1254,6 +1759,7 @@ impl<T: Config> Pallet<T> {
 
         // insert the new fork into the chains element
         Self::set_chain_from_position_and_id(max_chain_element, blockchain.chain_id);
+        ChainCount::<T>::put(max_chain_element.saturating_add(1));
 
         // starting from the last element swap the positions until
         // the new blockchain is at the position_blockchain
@@ -1312,18 +1818,19 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn ensure_no_ongoing_fork(best_block_height: u32) -> Result<(), DispatchError> {
+    fn ensure_no_ongoing_fork() -> Result<(), DispatchError> {
         // check if there is a next best fork
         match Self::get_chain_id_from_position(1) {
-            // if yes, check that the main chain is at least Self::confirmations() ahead
+            // if yes, check that the main chain is at least Self::confirmations() worth of work ahead
             Ok(id) => {
-                let next_best_fork_height = Self::get_block_chain_from_id(id)?.max_height;
+                let next_best_fork_work = Self::get_block_chain_from_id(id)?.chain_work;
+                let main_chain_work = Self::get_block_chain_from_id(MAIN_CHAIN_ID)?.chain_work;
 
-                runtime_print!("Best block height: {}", best_block_height);
-                runtime_print!("Next best fork height: {}", next_best_fork_height);
+                runtime_print!("Main chain work: {}", main_chain_work);
+                runtime_print!("Next best fork work: {}", next_best_fork_work);
                 // fail if there is an ongoing fork
                 ensure!(
-                    best_block_height >= next_best_fork_height + Self::get_stable_transaction_confirmations(),
+                    main_chain_work >= next_best_fork_work.saturating_add(Self::get_stable_confirmations_work()?),
                     Error::<T>::OngoingFork
                 );
             }
@@ -1333,9 +1840,19 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn store_rich_header(basic_block_header: BlockHeader, block_height: u32, chain_id: u32) {
+    /// Headers that are not yet migrated cannot be decoded, so the relay must not be used
+    /// before the chain work migration has finished
+    fn ensure_chain_work_migrated() -> Result<(), DispatchError> {
+        ensure!(
+            !ChainWorkMigration::<T>::exists(),
+            Error::<T>::ChainWorkMigrationInProgress
+        );
+        Ok(())
+    }
+
+    fn store_rich_header(basic_block_header: BlockHeader, block_height: u32, chain_id: u32, chain_work: U256) {
         let para_height = ext::security::active_block_number::<T>();
-        let block_header = RichBlockHeader::new(basic_block_header, chain_id, block_height, para_height);
+        let block_header = RichBlockHeader::new(basic_block_header, chain_id, block_height, para_height, chain_work);
         Self::set_block_header_from_hash(basic_block_header.hash, &block_header);
     }
 
@@ -1344,9 +1861,221 @@ impl<T: Config> Pallet<T> {
         Self::set_best_block_height(block_height);
     }
 
+    /// Removes stale forks and main chain headers outside of the retention window, as far as
+    /// `remaining_weight` allows. The forks are checked in order of their position, continuing
+    /// where the previous block stopped. Forks are removed as a whole, so a fork that needs more
+    /// weight than available is kept until a later pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining_weight` - the maximum weight that may be consumed
+    ///
+    /// # Returns
+    ///
+    /// The consumed weight
+    pub(crate) fn prune_storage(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads(3);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let stale_fork_depth = Self::stale_fork_depth();
+        let main_chain_retention = Self::main_chain_retention();
+        if stale_fork_depth.is_none() && main_chain_retention.is_none() {
+            return consumed;
+        }
+        let best_block_height = Self::get_best_block_height();
+
+        if let Some(stale_fork_depth) = stale_fork_depth {
+            Self::prune_stale_forks(stale_fork_depth, best_block_height, remaining_weight, &mut consumed);
+        }
+        if let Some(main_chain_retention) = main_chain_retention {
+            Self::prune_main_chain(main_chain_retention, best_block_height, remaining_weight, &mut consumed);
+        }
+
+        consumed
+    }
+
+    /// Checks up to `MAX_FORKS_CHECKED_PER_BLOCK` forks, starting at the `ForkPruningCursor`, and
+    /// removes those that are stale.
+    fn prune_stale_forks(
+        stale_fork_depth: u32,
+        best_block_height: u32,
+        remaining_weight: Weight,
+        consumed: &mut Weight,
+    ) {
+        let fits = |consumed: &Weight, weight: Weight| !consumed.saturating_add(weight).any_gt(remaining_weight);
+
+        let cursor_weight = T::DbWeight::get().reads_writes(2, 1);
+        if !fits(consumed, cursor_weight) {
+            return;
+        }
+        consumed.saturating_accrue(cursor_weight);
+
+        let mut chain_count = Self::get_chain_count();
+        // the main chain at position 0 is never pruned
+        let mut position = ForkPruningCursor::<T>::get().max(1);
+        for _ in 0..MAX_FORKS_CHECKED_PER_BLOCK {
+            if position >= chain_count {
+                // start the next pass in a later block
+                position = 1;
+                break;
+            }
+
+            let read_weight = T::DbWeight::get().reads(2);
+            if !fits(consumed, read_weight) {
+                break;
+            }
+            consumed.saturating_accrue(read_weight);
+            let fork = match Self::get_chain_id_from_position(position).and_then(Self::get_block_chain_from_id) {
+                Ok(fork) => fork,
+                Err(_) => break,
+            };
+
+            if fork.max_height.saturating_add(stale_fork_depth) >= best_block_height {
+                position = position.saturating_add(1);
+                continue;
+            }
+
+            // a fork that other forks branch off from is only removed after its children,
+            // otherwise they could not be linked to the main chain anymore
+            let children_weight = T::DbWeight::get().reads(5u64.saturating_mul(chain_count.into()));
+            if !fits(consumed, children_weight) {
+                break;
+            }
+            consumed.saturating_accrue(children_weight);
+            if Self::has_child_fork(&fork, chain_count) {
+                position = position.saturating_add(1);
+                continue;
+            }
+
+            let prune_weight = <T as Config>::WeightInfo::prune_stale_fork(
+                chain_count,
+                fork.max_height.saturating_sub(fork.start_height).saturating_add(1),
+            );
+            if !fits(consumed, prune_weight) {
+                // the fork is removed in a later pass, once enough weight is available
+                position = position.saturating_add(1);
+                continue;
+            }
+            consumed.saturating_accrue(prune_weight);
+
+            Self::remove_blockchain(&fork);
+            // move the following chains up to keep the keys in `Chains` consecutive,
+            // the next fork to check is then at the current position
+            for next_position in position.saturating_add(1)..chain_count {
+                if let Ok(chain_id) = Self::get_chain_id_from_position(next_position) {
+                    Self::set_chain_from_position_and_id(next_position.saturating_sub(1), chain_id);
+                }
+            }
+            chain_count = chain_count.saturating_sub(1);
+            Chains::<T>::remove(chain_count);
+            ChainCount::<T>::put(chain_count);
+
+            Self::deposit_event(Event::<T>::ForkPruned {
+                fork_id: fork.chain_id,
+                start_height: fork.start_height,
+                max_height: fork.max_height,
+            });
+        }
+
+        ForkPruningCursor::<T>::put(position);
+    }
+
+    /// Check if another fork branches off from `fork`
+    fn has_child_fork(fork: &BlockChain, chain_count: u32) -> bool {
+        (1..chain_count)
+            .filter_map(|position| {
+                Self::get_chain_id_from_position(position)
+                    .and_then(Self::get_block_chain_from_id)
+                    .ok()
+            })
+            // only forks that start within `fork` can branch off from it
+            .filter(|other| {
+                other.chain_id != fork.chain_id
+                    && other.start_height > fork.start_height
+                    && other.start_height <= fork.max_height.saturating_add(1)
+            })
+            .any(|other| Self::get_parent_chain_id(&other).map_or(false, |chain_id| chain_id == fork.chain_id))
+    }
+
+    /// Removes up to `MAX_PRUNED_HEADERS_PER_BLOCK` main chain headers below the retention window.
+    fn prune_main_chain(
+        main_chain_retention: u32,
+        best_block_height: u32,
+        remaining_weight: Weight,
+        consumed: &mut Weight,
+    ) {
+        let fits = |consumed: &Weight, weight: Weight| !consumed.saturating_add(weight).any_gt(remaining_weight);
+
+        // reading the start height of every chain
+        let chain_count = Self::get_chain_count();
+        let read_weight = T::DbWeight::get().reads(2u64.saturating_mul(chain_count.into()).saturating_add(1));
+        if !fits(consumed, read_weight) {
+            return;
+        }
+        consumed.saturating_accrue(read_weight);
+
+        let mut main_chain = match Self::get_block_chain_from_id(MAIN_CHAIN_ID) {
+            Ok(main_chain) => main_chain,
+            Err(_) => return,
+        };
+        // the blocks the forks branch off from are kept, a reorg could not be
+        // linked to the main chain otherwise
+        let prune_below = (1..chain_count)
+            .filter_map(|position| {
+                Self::get_chain_id_from_position(position)
+                    .and_then(Self::get_block_chain_from_id)
+                    .ok()
+            })
+            .fold(
+                best_block_height.saturating_sub(main_chain_retention),
+                |height, fork| height.min(fork.start_height.saturating_sub(1)),
+            );
+
+        let start_height = main_chain.start_height;
+        for _ in 0..MAX_PRUNED_HEADERS_PER_BLOCK {
+            if main_chain.start_height >= prune_below {
+                break;
+            }
+            let weight = <T as Config>::WeightInfo::prune_main_chain_header();
+            if !fits(consumed, weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight);
+
+            let block_hash = ChainsHashes::<T>::take(MAIN_CHAIN_ID, main_chain.start_height);
+            BlockHeaders::<T>::remove(block_hash);
+            main_chain.start_height = main_chain.start_height.saturating_add(1);
+        }
+
+        if main_chain.start_height != start_height {
+            Self::mutate_block_chain_from_id(MAIN_CHAIN_ID, main_chain.clone());
+            Self::deposit_event(Event::<T>::MainChainPruned {
+                start_height: main_chain.start_height,
+            });
+        }
+    }
+
+    /// Get the id of the chain that contains the parent of the first block of `blockchain`
+    fn get_parent_chain_id(blockchain: &BlockChain) -> Result<u32, DispatchError> {
+        let first_block = Self::get_block_header_from_height(blockchain, blockchain.start_height)?;
+        Ok(Self::get_block_header_from_hash(first_block.block_header.hash_prev_block)?.chain_id)
+    }
+
+    /// Remove a blockchain and all of its block headers from storage. This does not
+    /// update `Chains`.
+    fn remove_blockchain(blockchain: &BlockChain) {
+        for block_height in blockchain.start_height..=blockchain.max_height {
+            let block_hash = ChainsHashes::<T>::take(blockchain.chain_id, block_height);
+            BlockHeaders::<T>::remove(block_hash);
+        }
+        ChainsIndex::<T>::remove(blockchain.chain_id);
+    }
+
     /// For internal testing
-    pub fn set_disable_difficulty_check(disabled: bool) {
-        DisableDifficultyCheck::<T>::put(disabled);
+    pub fn set_bitcoin_network(network: Network) {
+        BitcoinNetwork::<T>::put(network);
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -1356,23 +2085,35 @@ impl<T: Config> Pallet<T> {
         vin: u32,
         vout: Vec<TransactionOutput>,
         max_tx_size: usize,
+    ) -> FullTransactionProof {
+        assert!(vin > 0, "Need at least one input");
+        let transaction = TransactionBuilder::build_max(vin, vout);
+        Self::initialize_and_store_transaction_max(relayer, hashes, transaction, max_tx_size)
+    }
+
+    /// Like `initialize_and_store_max`, but includes the given transaction, whose
+    /// first input is padded to reach `max_tx_size`.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub fn initialize_and_store_transaction_max(
+        relayer: T::AccountId,
+        hashes: u32,
+        mut transaction: Transaction,
+        max_tx_size: usize,
     ) -> FullTransactionProof {
         let init_block = BlockBuilder::new()
             .with_version(4)
             .with_coinbase(&BtcAddress::default(), 50, 3)
-            .with_timestamp(u32::MAX)
+            .with_timestamp(1588813835)
             .mine(U256::from(2).pow(254.into()))
             .unwrap();
         let init_block_hash = init_block.header.hash;
         ext::security::set_active_block_number::<T>(1u32.into());
         Self::_initialize(relayer.clone(), init_block.header, 0).unwrap();
 
-        let mut transaction = TransactionBuilder::build_max(vin, vout);
         let min_tx_size = transaction.size_no_witness();
         let padding = max_tx_size
             .checked_sub(min_tx_size)
             .expect("Wrong length bound in benchmark");
-        assert!(vin > 0, "Need at least one input");
         transaction.inputs[0].pad_script(padding);
         assert_eq!(transaction.size_no_witness(), max_tx_size, "Wrong transaction size");
 
@@ -1405,13 +2146,17 @@ impl<T: Config> Pallet<T> {
     #[cfg(feature = "runtime-benchmarks")]
     pub fn mine_blocks(relayer: &T::AccountId, height: u32) {
         let mut block_hash = Self::get_best_block();
+        let mut timestamp = Self::get_block_header_from_hash(block_hash)
+            .map(|header| header.block_header.timestamp)
+            .unwrap_or_default();
 
         for _ in 0..height {
+            timestamp += TARGET_SPACING;
             let block = BlockBuilder::new()
                 .with_previous_hash(block_hash)
                 .with_version(4)
                 .with_coinbase(&BtcAddress::default(), 50, 3)
-                .with_timestamp(u32::MAX)
+                .with_timestamp(timestamp)
                 .mine(U256::from(2).pow(254.into()))
                 .unwrap();
             block_hash = block.header.hash;
@@ -1444,6 +2189,11 @@ impl<T: Config> From<BitcoinError> for Error<T> {
             BitcoinError::InvalidCompact => Self::InvalidCompact,
             BitcoinError::BoundExceeded => Self::BoundExceeded,
             BitcoinError::InvalidTxid => Self::InvalidTxid,
+            BitcoinError::InvalidBase58
+            | BitcoinError::InvalidBase58Checksum
+            | BitcoinError::InvalidBech32
+            | BitcoinError::InvalidBech32Checksum
+            | BitcoinError::UnsupportedWitnessVersion => Self::InvalidBtcAddress,
         }
     }
 }
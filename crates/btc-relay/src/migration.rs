@@ -0,0 +1,686 @@
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+/// The log target.
+const TARGET: &'static str = "runtime::btc-relay::migration::v1";
+
+/// The original data layout of the btc-relay pallet without chain work.
+pub mod v0 {
+    use super::*;
+
+    #[frame_support::storage_alias]
+    pub(super) type BlockHeaders<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, H256Le, RichBlockHeader<BlockNumberFor<T>>, OptionQuery>;
+
+    #[frame_support::storage_alias]
+    pub(super) type ChainsIndex<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u32, BlockChain, OptionQuery>;
+
+    #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct RichBlockHeader<BlockNumber> {
+        pub block_header: BlockHeader,
+        pub block_height: u32,
+        pub chain_id: u32,
+        pub para_height: BlockNumber,
+    }
+
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct BlockChain {
+        pub chain_id: u32,
+        pub start_height: u32,
+        pub max_height: u32,
+    }
+}
+
+pub mod v1 {
+    use super::*;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    /// Temporary index of the chains by their start height, from which the chains are taken in
+    /// order of their start height.
+    #[frame_support::storage_alias]
+    pub(super) type ChainStartHeights<T: Config> =
+        StorageDoubleMap<Pallet<T>, Twox64Concat, u32, Twox64Concat, u32, (), OptionQuery>;
+
+    /// The progress of the chain work migration. The chains are migrated one after another,
+    /// ordered by their start height, so the parent of a header is always migrated before
+    /// the header itself. Every step reads and writes a constant number of entries.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum MigrationStage {
+        /// Index the chain that follows `after` in `ChainsIndex`, or the first chain if not set,
+        /// by its start height. `start_heights` are the lowest and highest start heights so far.
+        IndexChains {
+            after: Option<u32>,
+            start_heights: Option<(u32, u32)>,
+        },
+        /// Select the next chain that starts at `height`, or continue at the next height.
+        SelectChain { height: u32, max_start_height: u32 },
+        /// Migrate the headers of the chain, starting at `height`.
+        MigrateHeaders {
+            chain_id: u32,
+            start_height: u32,
+            max_height: u32,
+            height: u32,
+            max_start_height: u32,
+        },
+        /// All headers are migrated, migrate the chain that follows `after` in `ChainsIndex`.
+        MigrateChains { after: Option<u32> },
+        /// Re-sort the forks by work with a bubble sort that compares the forks at `position`
+        /// and `position + 1`. `swapped` is set if the current pass swapped any forks.
+        SortForks { position: u32, swapped: bool },
+    }
+
+    /// Computes the cumulative chain work of all stored headers (counted from the header
+    /// the relay was initialized with) and re-sorts `Chains` by work. The headers are
+    /// migrated over the following blocks in `on_idle`, see [`migrate_chain_work`].
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "can only upgrade from version 0");
+
+            let header_count = v0::BlockHeaders::<T>::iter().count();
+            let chain_count = v0::ChainsIndex::<T>::iter().count();
+            log::info!(
+                target: TARGET,
+                "{} headers and {} chains will be migrated.",
+                header_count,
+                chain_count
+            );
+
+            Ok((header_count as u32, chain_count as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let weight = T::DbWeight::get().reads(1);
+            if StorageVersion::get::<Pallet<T>>() != 0 {
+                log::warn!(
+                    target: TARGET,
+                    "skipping on_runtime_upgrade: executed on wrong storage version.\
+                Expected version 0"
+                );
+                return weight;
+            }
+
+            log::info!(target: TARGET, "starting chain work migration");
+            ChainWorkMigration::<T>::put(MigrationStage::IndexChains {
+                after: None,
+                start_heights: None,
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            weight.saturating_add(T::DbWeight::get().reads_writes(0, 2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "must upgrade");
+            assert!(ChainWorkMigration::<T>::exists(), "must start the chain work migration");
+
+            let (old_header_count, old_chain_count): (u32, u32) =
+                Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+            log::info!(
+                target: TARGET,
+                "{} headers and {} chains will be migrated in on_idle",
+                old_header_count,
+                old_chain_count
+            );
+            Ok(())
+        }
+    }
+
+    /// Continues the chain work migration as far as `remaining_weight` allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining_weight` - the maximum weight that may be consumed
+    ///
+    /// # Returns
+    ///
+    /// The consumed weight
+    pub(crate) fn migrate_chain_work<T: Config>(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+        let mut stage = match ChainWorkMigration::<T>::get() {
+            Some(stage) => stage,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        loop {
+            let weight = match stage {
+                MigrationStage::IndexChains { .. } => T::DbWeight::get().reads_writes(1, 1),
+                MigrationStage::SelectChain { .. } => T::DbWeight::get().reads_writes(2, 1),
+                MigrationStage::MigrateHeaders { .. } => T::DbWeight::get().reads_writes(3, 1),
+                MigrationStage::MigrateChains { .. } => T::DbWeight::get().reads_writes(3, 1),
+                MigrationStage::SortForks { .. } => T::DbWeight::get().reads_writes(4, 2),
+            };
+            if consumed.saturating_add(weight).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight);
+
+            stage = match stage {
+                MigrationStage::IndexChains { after, start_heights } => {
+                    let mut chains = match after {
+                        Some(chain_id) => {
+                            v0::ChainsIndex::<T>::iter_from(v0::ChainsIndex::<T>::hashed_key_for(chain_id))
+                        }
+                        None => v0::ChainsIndex::<T>::iter(),
+                    };
+                    match (chains.next(), start_heights) {
+                        (Some((chain_id, chain)), _) => {
+                            ChainStartHeights::<T>::insert(chain.start_height, chain_id, ());
+                            let (min, max) = start_heights.unwrap_or((chain.start_height, chain.start_height));
+                            MigrationStage::IndexChains {
+                                after: Some(chain_id),
+                                start_heights: Some((min.min(chain.start_height), max.max(chain.start_height))),
+                            }
+                        }
+                        (None, Some((min_start_height, max_start_height))) => MigrationStage::SelectChain {
+                            height: min_start_height,
+                            max_start_height,
+                        },
+                        (None, None) => MigrationStage::MigrateChains { after: None },
+                    }
+                }
+                MigrationStage::SelectChain {
+                    height,
+                    max_start_height,
+                } => {
+                    let next_chain = ChainStartHeights::<T>::iter_key_prefix(height)
+                        .next()
+                        .and_then(|chain_id| {
+                            ChainStartHeights::<T>::remove(height, chain_id);
+                            v0::ChainsIndex::<T>::get(chain_id)
+                        });
+                    match next_chain {
+                        Some(chain) => {
+                            log::info!(target: TARGET, "migrating headers of chain #{:?}", chain.chain_id);
+                            MigrationStage::MigrateHeaders {
+                                chain_id: chain.chain_id,
+                                start_height: chain.start_height,
+                                max_height: chain.max_height,
+                                height: chain.start_height,
+                                max_start_height,
+                            }
+                        }
+                        None if height < max_start_height => MigrationStage::SelectChain {
+                            height: height.saturating_add(1),
+                            max_start_height,
+                        },
+                        None => MigrationStage::MigrateChains { after: None },
+                    }
+                }
+                MigrationStage::MigrateHeaders {
+                    chain_id,
+                    start_height,
+                    max_height,
+                    height,
+                    max_start_height,
+                } => {
+                    if height > max_height {
+                        // other chains may start at the same height
+                        MigrationStage::SelectChain {
+                            height: start_height,
+                            max_start_height,
+                        }
+                    } else {
+                        migrate_header::<T>(ChainsHashes::<T>::get(chain_id, height));
+                        MigrationStage::MigrateHeaders {
+                            chain_id,
+                            start_height,
+                            max_height,
+                            height: height.saturating_add(1),
+                            max_start_height,
+                        }
+                    }
+                }
+                MigrationStage::MigrateChains { after } => {
+                    // chains that were already migrated are before `after` in key order
+                    let mut chains = match after {
+                        Some(chain_id) => {
+                            v0::ChainsIndex::<T>::iter_from(v0::ChainsIndex::<T>::hashed_key_for(chain_id))
+                        }
+                        None => v0::ChainsIndex::<T>::iter(),
+                    };
+                    match chains.next() {
+                        Some((chain_id, chain)) => {
+                            migrate_chain::<T>(chain_id, chain);
+                            MigrationStage::MigrateChains { after: Some(chain_id) }
+                        }
+                        None => MigrationStage::SortForks {
+                            position: 1,
+                            swapped: false,
+                        },
+                    }
+                }
+                MigrationStage::SortForks { position, swapped } => {
+                    // the main chain stays in the first position, even if a fork now has more work
+                    // - the next header submitted to that fork will trigger the reorg
+                    match (Chains::<T>::get(position), Chains::<T>::get(position.saturating_add(1))) {
+                        (Some(chain_id), Some(next_chain_id)) => {
+                            let work = ChainsIndex::<T>::get(chain_id).map(|chain| chain.chain_work);
+                            let next_work = ChainsIndex::<T>::get(next_chain_id).map(|chain| chain.chain_work);
+                            let swap = work < next_work;
+                            if swap {
+                                Chains::<T>::swap(position, position.saturating_add(1));
+                            }
+                            MigrationStage::SortForks {
+                                position: position.saturating_add(1),
+                                swapped: swapped || swap,
+                            }
+                        }
+                        _ if swapped => MigrationStage::SortForks {
+                            position: 1,
+                            swapped: false,
+                        },
+                        _ => {
+                            ChainWorkMigration::<T>::kill();
+                            log::info!(target: TARGET, "chain work migration finished");
+                            return consumed;
+                        }
+                    }
+                }
+            };
+        }
+
+        ChainWorkMigration::<T>::put(stage);
+        consumed
+    }
+
+    fn migrate_header<T: Config>(hash: H256Le) {
+        let old = match v0::BlockHeaders::<T>::get(hash) {
+            Some(old) => old,
+            None => return,
+        };
+        // the parent of the first block (or of any block whose parent
+        // is not stored) does not contribute any work
+        let prev_work = BlockHeaders::<T>::try_get(old.block_header.hash_prev_block)
+            .map(|prev| prev.chain_work)
+            .unwrap_or_default();
+        let chain_work = prev_work.saturating_add(bitcoin::pow::get_block_proof(old.block_header.target));
+
+        BlockHeaders::<T>::insert(
+            hash,
+            RichBlockHeader::new(
+                old.block_header,
+                old.chain_id,
+                old.block_height,
+                old.para_height,
+                chain_work,
+            ),
+        );
+    }
+
+    fn migrate_chain<T: Config>(chain_id: u32, old: v0::BlockChain) {
+        log::info!(target: TARGET, "migrating chain #{:?}", chain_id);
+
+        let chain_work = BlockHeaders::<T>::try_get(ChainsHashes::<T>::get(chain_id, old.max_height))
+            .map(|header| header.chain_work)
+            .unwrap_or_default();
+        ChainsIndex::<T>::insert(
+            chain_id,
+            BlockChain {
+                chain_id: old.chain_id,
+                start_height: old.start_height,
+                max_height: old.max_height,
+                chain_work,
+            },
+        );
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    /// The log target.
+    const TARGET: &'static str = "runtime::btc-relay::migration::v2";
+
+    /// Enables the pruning of stale forks, unless a depth was already configured, and counts the
+    /// stored chains. The forks accumulated so far are not removed here but over the following
+    /// blocks in `on_idle`.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "can only upgrade from version 1");
+
+            let chain_count = Chains::<T>::iter().count();
+            log::info!(target: TARGET, "{} chains are stored.", chain_count);
+
+            Ok((chain_count as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let weight = T::DbWeight::get().reads(1);
+            if StorageVersion::get::<Pallet<T>>() != 1 {
+                log::warn!(
+                    target: TARGET,
+                    "skipping on_runtime_upgrade: executed on wrong storage version.\
+                Expected version 1"
+                );
+                return weight;
+            }
+
+            if !StaleForkDepth::<T>::exists() {
+                let stale_fork_depth = DEFAULT_STALE_FORK_DEPTH.max(StableBitcoinConfirmations::<T>::get());
+                log::info!(target: TARGET, "setting stale fork depth to {}", stale_fork_depth);
+                StaleForkDepth::<T>::put(stale_fork_depth);
+            }
+
+            // there is one entry per fork, which are few compared to the headers
+            let chain_count = Chains::<T>::iter_keys().count() as u32;
+            ChainCount::<T>::put(chain_count);
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            weight.saturating_add(T::DbWeight::get().reads_writes(2u64.saturating_add(chain_count.into()), 3))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "must upgrade");
+
+            let old_chain_count: u32 =
+                Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+            let new_chain_count = Chains::<T>::iter().count() as u32;
+            assert_eq!(new_chain_count, old_chain_count, "must not remove chains");
+            assert!(StaleForkDepth::<T>::exists(), "must set the stale fork depth");
+            assert_eq!(ChainCount::<T>::get(), old_chain_count, "must count the chains");
+
+            Ok(())
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    /// The log target.
+    const TARGET: &'static str = "runtime::btc-relay::migration::v3";
+
+    #[frame_support::storage_alias]
+    pub(super) type DisableDifficultyCheck<T: Config> = StorageValue<Pallet<T>, bool, ValueQuery>;
+
+    /// Replaces the `DisableDifficultyCheck` flag by the Bitcoin network. Relays that had the
+    /// difficulty check disabled follow regtest, which never retargets the difficulty and so
+    /// accepts every header the relay accepted before. All others follow mainnet.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "can only upgrade from version 2");
+
+            let disable_difficulty_check = DisableDifficultyCheck::<T>::get();
+            log::info!(
+                target: TARGET,
+                "difficulty check disabled: {}",
+                disable_difficulty_check
+            );
+
+            Ok(disable_difficulty_check.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let weight = T::DbWeight::get().reads(1);
+            if StorageVersion::get::<Pallet<T>>() != 2 {
+                log::warn!(
+                    target: TARGET,
+                    "skipping on_runtime_upgrade: executed on wrong storage version.\
+                Expected version 2"
+                );
+                return weight;
+            }
+
+            let network = if DisableDifficultyCheck::<T>::take() {
+                Network::Regtest
+            } else {
+                Network::Mainnet
+            };
+            log::info!(target: TARGET, "setting bitcoin network to {:?}", network);
+            BitcoinNetwork::<T>::put(network);
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 3))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 3, "must upgrade");
+
+            let disable_difficulty_check: bool =
+                Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+            assert!(!DisableDifficultyCheck::<T>::exists(), "must remove the old flag");
+            assert_eq!(
+                BitcoinNetwork::<T>::get() == Network::Regtest,
+                disable_difficulty_check,
+                "must set the bitcoin network"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::{Test as T, *};
+    use frame_support::assert_err;
+
+    fn old_header(prev: H256Le, nonce: u32) -> BlockHeader {
+        let mut header = BlockHeader {
+            target: U256::from(2).pow(254.into()),
+            hash_prev_block: prev,
+            nonce,
+            ..Default::default()
+        };
+        header.update_hash().unwrap();
+        header
+    }
+
+    fn insert_old_header(header: BlockHeader, block_height: u32, chain_id: u32) {
+        v0::BlockHeaders::<T>::insert(
+            header.hash,
+            v0::RichBlockHeader {
+                block_header: header,
+                block_height,
+                chain_id,
+                para_height: 1,
+            },
+        );
+        ChainsHashes::<T>::insert(chain_id, block_height, header.hash);
+    }
+
+    #[test]
+    fn migration_works() {
+        run_test(|| {
+            StorageVersion::new(0).put::<Pallet<T>>();
+
+            // main chain: a -> b -> c, fork: a -> d
+            let a = old_header(H256Le::zero(), 0);
+            let b = old_header(a.hash, 1);
+            let c = old_header(b.hash, 2);
+            let d = old_header(a.hash, 3);
+            // insert out of order to check that parents are migrated first
+            insert_old_header(c, 12, 0);
+            insert_old_header(d, 11, 1);
+            insert_old_header(b, 11, 0);
+            insert_old_header(a, 10, 0);
+
+            v0::ChainsIndex::<T>::insert(
+                0,
+                v0::BlockChain {
+                    chain_id: 0,
+                    start_height: 10,
+                    max_height: 12,
+                },
+            );
+            v0::ChainsIndex::<T>::insert(
+                1,
+                v0::BlockChain {
+                    chain_id: 1,
+                    start_height: 11,
+                    max_height: 11,
+                },
+            );
+            Chains::<T>::insert(0, 0);
+            Chains::<T>::insert(1, 1);
+
+            v1::Migration::<T>::on_runtime_upgrade();
+
+            // the headers are migrated in on_idle, the relay cannot be used until then
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
+            assert_eq!(
+                ChainWorkMigration::<T>::get(),
+                Some(v1::MigrationStage::IndexChains {
+                    after: None,
+                    start_heights: None,
+                })
+            );
+            assert_err!(
+                BTCRelay::verify_block_header_inclusion(c.hash, None),
+                TestError::ChainWorkMigrationInProgress
+            );
+
+            BTCRelay::on_idle(0, Weight::MAX);
+            assert!(!ChainWorkMigration::<T>::exists());
+
+            // every block has a work of 3
+            let new_header = BlockHeaders::<T>::get(c.hash);
+            assert_eq!(new_header.block_header, c);
+            assert_eq!(new_header.block_height, 12);
+            assert_eq!(new_header.chain_id, 0);
+            assert_eq!(new_header.para_height, 1);
+            assert_eq!(new_header.chain_work, U256::from(9));
+            assert_eq!(BlockHeaders::<T>::get(d.hash).chain_work, U256::from(6));
+
+            assert_eq!(
+                ChainsIndex::<T>::get(0).unwrap(),
+                BlockChain {
+                    chain_id: 0,
+                    start_height: 10,
+                    max_height: 12,
+                    chain_work: U256::from(9),
+                }
+            );
+            assert_eq!(ChainsIndex::<T>::get(1).unwrap().chain_work, U256::from(6));
+            assert_eq!(Chains::<T>::get(0), Some(0));
+            assert_eq!(Chains::<T>::get(1), Some(1));
+        });
+    }
+
+    #[test]
+    fn migration_migrates_forks_of_forks_and_sorts_them_by_work() {
+        run_test(|| {
+            StorageVersion::new(0).put::<Pallet<T>>();
+
+            // main chain: a -> b -> c, forks: a -> d and b -> e -> f
+            let a = old_header(H256Le::zero(), 0);
+            let b = old_header(a.hash, 1);
+            let c = old_header(b.hash, 2);
+            let d = old_header(a.hash, 3);
+            let e = old_header(b.hash, 4);
+            let f = old_header(e.hash, 5);
+            insert_old_header(f, 13, 2);
+            insert_old_header(e, 12, 2);
+            insert_old_header(d, 11, 1);
+            insert_old_header(c, 12, 0);
+            insert_old_header(b, 11, 0);
+            insert_old_header(a, 10, 0);
+
+            for (chain_id, start_height, max_height) in [(0, 10, 12), (1, 11, 11), (2, 12, 13)] {
+                v0::ChainsIndex::<T>::insert(
+                    chain_id,
+                    v0::BlockChain {
+                        chain_id,
+                        start_height,
+                        max_height,
+                    },
+                );
+                Chains::<T>::insert(chain_id, chain_id);
+            }
+
+            v1::Migration::<T>::on_runtime_upgrade();
+            BTCRelay::on_idle(0, Weight::MAX);
+            assert!(!ChainWorkMigration::<T>::exists());
+            assert_eq!(v1::ChainStartHeights::<T>::iter().count(), 0);
+
+            assert_eq!(ChainsIndex::<T>::get(0).unwrap().chain_work, U256::from(9));
+            assert_eq!(ChainsIndex::<T>::get(1).unwrap().chain_work, U256::from(6));
+            assert_eq!(ChainsIndex::<T>::get(2).unwrap().chain_work, U256::from(12));
+            // the main chain is kept in the first position, the forks are sorted by work
+            assert_eq!(Chains::<T>::get(0), Some(0));
+            assert_eq!(Chains::<T>::get(1), Some(2));
+            assert_eq!(Chains::<T>::get(2), Some(1));
+        });
+    }
+
+    #[test]
+    fn v2_migration_sets_stale_fork_depth() {
+        run_test(|| {
+            StorageVersion::new(1).put::<Pallet<T>>();
+            Chains::<T>::insert(0, 0);
+            Chains::<T>::insert(1, 2);
+
+            v2::Migration::<T>::on_runtime_upgrade();
+
+            assert_eq!(StaleForkDepth::<T>::get(), Some(DEFAULT_STALE_FORK_DEPTH));
+            assert_eq!(ChainCount::<T>::get(), 2);
+            assert_eq!(MainChainRetention::<T>::get(), None);
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+        });
+    }
+
+    #[test]
+    fn v2_migration_keeps_configured_stale_fork_depth() {
+        run_test(|| {
+            StorageVersion::new(1).put::<Pallet<T>>();
+            StaleForkDepth::<T>::put(1000);
+
+            v2::Migration::<T>::on_runtime_upgrade();
+
+            assert_eq!(StaleForkDepth::<T>::get(), Some(1000));
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+        });
+    }
+
+    #[test]
+    fn v3_migration_replaces_disabled_difficulty_check_with_regtest() {
+        run_test(|| {
+            StorageVersion::new(2).put::<Pallet<T>>();
+            v3::DisableDifficultyCheck::<T>::put(true);
+
+            v3::Migration::<T>::on_runtime_upgrade();
+
+            assert!(!v3::DisableDifficultyCheck::<T>::exists());
+            assert_eq!(BitcoinNetwork::<T>::get(), Network::Regtest);
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 3);
+        });
+    }
+
+    #[test]
+    fn v3_migration_keeps_mainnet_if_difficulty_check_was_enabled() {
+        run_test(|| {
+            StorageVersion::new(2).put::<Pallet<T>>();
+            v3::DisableDifficultyCheck::<T>::put(false);
+
+            v3::Migration::<T>::on_runtime_upgrade();
+
+            assert!(!v3::DisableDifficultyCheck::<T>::exists());
+            assert_eq!(BitcoinNetwork::<T>::get(), Network::Mainnet);
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), 3);
+        });
+    }
+}
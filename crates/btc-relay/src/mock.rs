@@ -1,5 +1,5 @@
 use crate as btc_relay;
-use crate::{Config, Error};
+use crate::{Config, Error, Network};
 use frame_support::{
     parameter_types,
     traits::{ConstU32, Everything},
@@ -103,8 +103,10 @@ impl ExtBuilder {
         btc_relay::GenesisConfig::<Test> {
             bitcoin_confirmations: BITCOIN_CONFIRMATIONS,
             parachain_confirmations: PARACHAIN_CONFIRMATIONS,
-            disable_difficulty_check: false,
+            bitcoin_network: Network::Mainnet,
             disable_inclusion_check: false,
+            stale_fork_depth: None,
+            main_chain_retention: None,
         }
         .assimilate_storage(&mut storage)
         .unwrap();
@@ -1,13 +1,17 @@
 /// Tests for BTC-Relay
 use sp_core::U256;
 
-use crate::{ext, mock::*, types::*, BtcAddress, Error, DIFFICULTY_ADJUSTMENT_INTERVAL};
+use crate::{ext, mock::*, types::*, BtcAddress, Error, Network, DIFFICULTY_ADJUSTMENT_INTERVAL};
 
 type Event = crate::Event<Test>;
 
 use crate::{Chains, ChainsIndex};
 use bitcoin::{merkle::*, parser::*, types::*};
-use frame_support::{assert_err, assert_ok};
+use frame_support::{
+    assert_err, assert_ok,
+    dispatch::{DispatchError, Pays},
+    weights::Weight,
+};
 use mocktopus::mocking::*;
 use sp_std::{
     convert::{TryFrom, TryInto},
@@ -49,6 +53,7 @@ fn get_block_header_from_hash_succeeds() {
             block_height,
             chain_id,
             para_height: Default::default(),
+            chain_work: Default::default(),
         };
 
         BTCRelay::set_block_header_from_hash(rich_header.block_hash(), &rich_header);
@@ -184,6 +189,7 @@ fn store_block_header_on_mainchain_succeeds() {
             block_height,
             chain_id,
             para_height: Default::default(),
+            chain_work: Default::default(),
         };
         BTCRelay::get_block_header_from_hash.mock_safe(move |_| MockResult::Return(Ok(rich_header)));
 
@@ -218,6 +224,7 @@ fn store_block_header_on_fork_succeeds() {
             block_height: block_height - 1,
             chain_id,
             para_height: Default::default(),
+            chain_work: Default::default(),
         };
         BTCRelay::get_block_header_from_hash.mock_safe(move |_| MockResult::Return(Ok(rich_header)));
 
@@ -244,7 +251,7 @@ fn store_block_header_on_fork_succeeds() {
 mod store_block_header_tests {
     use std::iter::successors;
 
-    use crate::MAIN_CHAIN_ID;
+    use crate::{MAIN_CHAIN_ID, MAX_FUTURE_BLOCK_TIME, TARGET_SPACING};
 
     use super::*;
     fn from_prev(nonce: u32, prev: H256Le) -> BlockHeader {
@@ -280,17 +287,21 @@ mod store_block_header_tests {
         }
         let chains_index = chains_index.into_iter().map(|(_, value)| value).collect::<Vec<_>>();
 
-        // for all i > 0, `ChainsIndex[i].maxHeight < ChainsIndex[0].maxHeight + STABLE_BITCOIN_CONFIRMATIONS`
+        // for all i > 0, `ChainsIndex[i].chainWork < ChainsIndex[0].chainWork + STABLE_BITCOIN_CONFIRMATIONS_WORK`
         for chain in chains_index.iter().skip(1) {
-            assert!(chain.max_height < mainchain.max_height + BTCRelay::get_stable_transaction_confirmations());
+            assert!(chain.chain_work < mainchain.chain_work + BTCRelay::get_stable_confirmations_work().unwrap());
         }
 
-        // The values in ``Chains`` MUST be such that for each ``0 < i < j``, ``ChainsIndex[Chains[i]].maxHeight >=
-        // ChainsIndex[Chains[j]].maxHeight``.
+        // The values in ``Chains`` MUST be such that for each ``0 < i < j``, ``ChainsIndex[Chains[i]].chainWork >=
+        // ChainsIndex[Chains[j]].chainWork``.
         for i in 1..chains.len() - 1 {
-            if chains_index[chains[i] as usize].max_height < chains_index[chains[i + 1] as usize].max_height {
-                assert!(chains_index[chains[i] as usize].max_height >= chains_index[chains[i + 1] as usize].max_height);
-            }
+            assert!(chains_index[chains[i] as usize].chain_work >= chains_index[chains[i + 1] as usize].chain_work);
+        }
+
+        // ChainsIndex[i].chainWork MUST be the chainWork of the block at ChainsIndex[i].maxHeight
+        for chain in chains_index.iter() {
+            let tip = BTCRelay::get_block_header_from_height(chain, chain.max_height).unwrap();
+            assert_eq!(chain.chain_work, tip.chain_work);
         }
 
         // ChainsIndex[i].chainRef = i
@@ -340,7 +351,7 @@ mod store_block_header_tests {
     fn assert_is_block(height: u32, block_header: &BlockHeader) {
         Security::set_active_block_number(ext::security::active_block_number::<Test>() + 1000);
 
-        BTCRelay::ensure_no_ongoing_fork.mock_safe(|_| MockResult::Return(Ok(())));
+        BTCRelay::ensure_no_ongoing_fork.mock_safe(|| MockResult::Return(Ok(())));
         assert_ok!(BTCRelay::verify_block_header_inclusion(block_header.hash, Some(0)));
         BTCRelay::ensure_no_ongoing_fork.clear_mock();
 
@@ -399,10 +410,7 @@ mod store_block_header_tests {
     }
 
     fn assert_ongoing_fork() {
-        assert_err!(
-            BTCRelay::ensure_no_ongoing_fork(BTCRelay::get_best_block_height()),
-            TestError::OngoingFork
-        );
+        assert_err!(BTCRelay::ensure_no_ongoing_fork(), TestError::OngoingFork);
     }
 
     fn store_header_and_check_invariants(block: &BlockHeader) {
@@ -585,6 +593,485 @@ mod store_block_header_tests {
             }
         })
     }
+
+    // each block mined at this target has a work of 3
+    fn low_difficulty() -> U256 {
+        U256::from(2).pow(254.into())
+    }
+
+    // each block mined at this target has a work of 256
+    fn high_difficulty() -> U256 {
+        U256::from(2).pow(248.into())
+    }
+
+    // timestamps increase with the seed, so a child must use a higher seed than its parent
+    fn mine_block(prev: H256Le, target: U256, seed: u32) -> BlockHeader {
+        mine_block_with_timestamp(prev, target, seed, seed * TARGET_SPACING)
+    }
+
+    fn mine_block_with_timestamp(prev: H256Le, target: U256, seed: u32, timestamp: u32) -> BlockHeader {
+        BlockBuilder::new()
+            .with_coinbase(&BtcAddress::default(), 50, seed)
+            .with_previous_hash(prev)
+            .with_timestamp(timestamp)
+            .mine(target)
+            .unwrap()
+            .header
+    }
+
+    fn store_chain(prev: H256Le, length: u32, target: U256, seed: u32) -> Vec<BlockHeader> {
+        let mut prev = prev;
+        (0..length)
+            .map(|i| {
+                let block = mine_block(prev, target, seed + i);
+                store_header_and_check_invariants(&block);
+                prev = block.hash;
+                block
+            })
+            .collect()
+    }
+
+    #[test]
+    fn store_block_header_shorter_fork_with_more_work_becomes_main_chain() {
+        run_test(|| {
+            // the chains are mined at different difficulties
+            BTCRelay::get_next_work_required
+                .mock_safe(|block_header, _, _| MockResult::Return(Ok(block_header.target)));
+
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            // main chain has a height of 10 and a work of 33
+            let main_chain = store_chain(genesis.hash, 10, low_difficulty(), 1);
+            assert_eq!(BTCRelay::get_best_block(), main_chain[9].hash);
+
+            // a single block has more work than STABLE_BITCOIN_CONFIRMATIONS blocks on the main chain
+            let fork = store_chain(genesis.hash, 1, high_difficulty(), 100);
+
+            assert_eq!(BTCRelay::get_best_block(), fork[0].hash);
+            assert_eq!(BTCRelay::get_best_block_height(), 1);
+            let main = BTCRelay::get_block_chain_from_id(MAIN_CHAIN_ID).unwrap();
+            assert_eq!(main.max_height, 1);
+            assert_eq!(main.chain_work, U256::from(259));
+            assert_eq!(
+                BTCRelay::get_block_header_from_hash(main_chain[9].hash)
+                    .unwrap()
+                    .chain_id,
+                1
+            );
+
+            let reorg_event = TestEvent::BTCRelay(Event::ChainReorg {
+                new_chain_tip_hash: fork[0].hash,
+                new_chain_tip_height: 1,
+                fork_depth: 0,
+            });
+            assert!(System::events().iter().any(|a| a.event == reorg_event));
+
+            let disagreement_event = TestEvent::BTCRelay(Event::ForkChoiceDisagreement {
+                main_chain_height: 10,
+                main_chain_work: U256::from(33),
+                fork_height: 1,
+                fork_work: U256::from(259),
+                fork_id: 1,
+                reorg: true,
+            });
+            assert!(System::events().iter().any(|a| a.event == disagreement_event));
+        })
+    }
+
+    #[test]
+    fn store_block_header_longer_fork_with_less_work_does_not_become_main_chain() {
+        run_test(|| {
+            // the chains are mined at different difficulties
+            BTCRelay::get_next_work_required
+                .mock_safe(|block_header, _, _| MockResult::Return(Ok(block_header.target)));
+
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            // main chain has a height of 2 and a work of 515
+            let main_chain = store_chain(genesis.hash, 2, high_difficulty(), 1);
+
+            // fork is more than STABLE_BITCOIN_CONFIRMATIONS blocks ahead, but only has a work of 33
+            let fork = store_chain(genesis.hash, 10, low_difficulty(), 100);
+
+            assert_eq!(BTCRelay::get_best_block(), main_chain[1].hash);
+            assert_eq!(BTCRelay::get_best_block_height(), 2);
+            for block in fork.iter() {
+                assert_eq!(BTCRelay::get_block_header_from_hash(block.hash).unwrap().chain_id, 1);
+            }
+            assert_eq!(BTCRelay::get_block_chain_from_id(1).unwrap().chain_work, U256::from(33));
+            assert!(!System::events()
+                .iter()
+                .any(|a| matches!(a.event, TestEvent::BTCRelay(Event::ChainReorg { .. }))));
+
+            let disagreement_event = TestEvent::BTCRelay(Event::ForkChoiceDisagreement {
+                main_chain_height: 2,
+                main_chain_work: U256::from(515),
+                fork_height: 10,
+                fork_work: U256::from(33),
+                fork_id: 1,
+                reorg: false,
+            });
+            assert!(System::events().iter().any(|a| a.event == disagreement_event));
+        })
+    }
+
+    fn mine_chain(prev: H256Le, targets: &[U256], seed: u32) -> Vec<BlockHeader> {
+        let mut prev = prev;
+        targets
+            .iter()
+            .enumerate()
+            .map(|(i, target)| {
+                let block = mine_block(prev, *target, seed + i as u32);
+                prev = block.hash;
+                block
+            })
+            .collect()
+    }
+
+    #[test]
+    fn store_block_headers_succeeds() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            let blocks = mine_chain(genesis.hash, &[low_difficulty(); 5], 1);
+            let post_info = BTCRelay::store_block_headers(RuntimeOrigin::signed(3), blocks.clone(), 2).unwrap();
+            assert_eq!(post_info.pays_fee, Pays::No);
+            // the worst-case reorg weight is refunded for headers extending the main chain
+            assert_eq!(
+                post_info.actual_weight,
+                Some(BTCRelay::store_block_headers_weight(5, 0, 2))
+            );
+
+            assert_eq!(BTCRelay::get_best_block(), blocks[4].hash);
+            assert_eq!(BTCRelay::get_best_block_height(), 5);
+            for (idx, block) in blocks.iter().enumerate() {
+                assert_is_block(idx as u32 + 1, block);
+            }
+            check_store_block_header_invariants();
+        })
+    }
+
+    #[test]
+    fn store_block_headers_charges_reorg_for_fork_headers() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+            let main_chain = mine_chain(genesis.hash, &[low_difficulty(); 2], 1);
+            assert_ok!(BTCRelay::store_block_headers(
+                RuntimeOrigin::signed(3),
+                main_chain.clone(),
+                2
+            ));
+
+            // a longer fork that branches off at height 1 and overtakes the main chain
+            let fork = mine_chain(genesis.hash, &[low_difficulty(); 3], 100);
+            let post_info = BTCRelay::store_block_headers(RuntimeOrigin::signed(3), fork.clone(), 2).unwrap();
+            assert_eq!(
+                post_info.actual_weight,
+                Some(BTCRelay::store_block_headers_weight(3, 3, 2))
+            );
+            assert_eq!(BTCRelay::get_best_block(), fork[2].hash);
+            check_store_block_header_invariants();
+        })
+    }
+
+    #[test]
+    fn store_block_headers_keeps_headers_before_failure() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            // the third header has the wrong difficulty target
+            let blocks = mine_chain(
+                genesis.hash,
+                &[low_difficulty(), low_difficulty(), high_difficulty(), low_difficulty()],
+                1,
+            );
+            let post_info = BTCRelay::store_block_headers(RuntimeOrigin::signed(3), blocks.clone(), 2).unwrap();
+            assert_eq!(post_info.pays_fee, Pays::No);
+            // the failing header is charged with a reorg, the headers after it are refunded
+            assert_eq!(
+                post_info.actual_weight,
+                Some(BTCRelay::store_block_headers_weight(3, 1, 2))
+            );
+
+            assert_eq!(BTCRelay::get_best_block(), blocks[1].hash);
+            assert!(!BTCRelay::block_header_exists(blocks[2].hash));
+            assert!(!BTCRelay::block_header_exists(blocks[3].hash));
+            check_store_block_header_invariants();
+
+            let interrupted_event = TestEvent::BTCRelay(Event::StoreBlockHeadersInterrupted {
+                index: 2,
+                block_hash: blocks[2].hash,
+                error: TestError::DiffTargetHeader.into(),
+            });
+            assert!(System::events().iter().any(|a| a.event == interrupted_event));
+        })
+    }
+
+    #[test]
+    fn store_block_headers_fails_if_first_header_fails() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            let blocks = mine_chain(genesis.hash, &[high_difficulty(), low_difficulty()], 1);
+            assert_err!(
+                BTCRelay::store_block_headers(RuntimeOrigin::signed(3), blocks.clone(), 2),
+                TestError::DiffTargetHeader
+            );
+            assert_eq!(BTCRelay::get_best_block(), genesis.hash);
+        })
+    }
+
+    #[test]
+    fn store_block_headers_with_invalid_batch_fails() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            assert_err!(
+                BTCRelay::store_block_headers(RuntimeOrigin::signed(3), vec![], 2),
+                TestError::InvalidBlockHeaderCount
+            );
+
+            let blocks = mine_chain(
+                genesis.hash,
+                &[low_difficulty(); crate::MAX_BLOCK_HEADERS_PER_BATCH as usize + 1],
+                1,
+            );
+            assert_err!(
+                BTCRelay::store_block_headers(RuntimeOrigin::signed(3), blocks, 2),
+                TestError::InvalidBlockHeaderCount
+            );
+
+            // both headers build on genesis
+            let first = mine_block(genesis.hash, low_difficulty(), 1);
+            let second = mine_block(genesis.hash, low_difficulty(), 2);
+            assert_err!(
+                BTCRelay::store_block_headers(RuntimeOrigin::signed(3), vec![first, second], 2),
+                TestError::NonContiguousBlockHeaders
+            );
+
+            assert_err!(
+                BTCRelay::store_block_headers(RuntimeOrigin::signed(3), vec![first], 1),
+                TestError::WrongForkBound
+            );
+        })
+    }
+
+    #[test]
+    fn prune_storage_removes_stale_forks() {
+        run_test(|| {
+            crate::StaleForkDepth::<Test>::put(BITCOIN_CONFIRMATIONS);
+
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+            let main_chain = store_chain(genesis.hash, 10, low_difficulty(), 1);
+
+            // stale fork at heights 1 and 2, with another stale fork branching off of it
+            let stale_fork = store_chain(genesis.hash, 2, low_difficulty(), 100);
+            let stale_child_fork = store_chain(stale_fork[0].hash, 1, low_difficulty(), 200);
+            // fork that is within the stale fork depth
+            let recent_fork = store_chain(main_chain[7].hash, 1, low_difficulty(), 300);
+
+            assert_eq!(Chains::<Test>::iter().count(), 4);
+            let stale_fork_id = BTCRelay::get_block_header_from_hash(stale_fork[0].hash)
+                .unwrap()
+                .chain_id;
+            let stale_child_fork_id = BTCRelay::get_block_header_from_hash(stale_child_fork[0].hash)
+                .unwrap()
+                .chain_id;
+            let recent_fork_id = BTCRelay::get_block_header_from_hash(recent_fork[0].hash)
+                .unwrap()
+                .chain_id;
+
+            // the parent fork is only removed after its child
+            BTCRelay::prune_storage(Weight::MAX);
+            assert!(ChainsIndex::<Test>::get(stale_child_fork_id).is_none());
+            assert!(!BTCRelay::block_header_exists(stale_child_fork[0].hash));
+            assert!(ChainsIndex::<Test>::get(stale_fork_id).is_some());
+
+            BTCRelay::prune_storage(Weight::MAX);
+            assert!(ChainsIndex::<Test>::get(stale_fork_id).is_none());
+            for block in stale_fork.iter() {
+                assert!(!BTCRelay::block_header_exists(block.hash));
+            }
+            assert!(!crate::ChainsHashes::<Test>::contains_key(stale_fork_id, 1));
+
+            let pruned_event = TestEvent::BTCRelay(Event::ForkPruned {
+                fork_id: stale_fork_id,
+                start_height: 1,
+                max_height: 2,
+            });
+            assert!(System::events().iter().any(|a| a.event == pruned_event));
+
+            // the remaining chains are kept at consecutive positions
+            let mut chains = Chains::<Test>::iter().collect::<Vec<_>>();
+            chains.sort_by_key(|k| k.0);
+            assert_eq!(chains, vec![(0, MAIN_CHAIN_ID), (1, recent_fork_id)]);
+            assert_eq!(crate::ChainCount::<Test>::get(), 2);
+            assert!(BTCRelay::block_header_exists(recent_fork[0].hash));
+
+            // the fork bound only needs to account for the remaining chains
+            let block = mine_block(main_chain[9].hash, low_difficulty(), 400);
+            assert_ok!(BTCRelay::store_block_header(RuntimeOrigin::signed(3), block, 3));
+            assert_eq!(BTCRelay::get_best_block(), block.hash);
+        })
+    }
+
+    #[test]
+    fn prune_storage_removes_main_chain_headers_outside_of_retention() {
+        run_test(|| {
+            crate::MainChainRetention::<Test>::put(4);
+
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+            let main_chain = store_chain(genesis.hash, 10, low_difficulty(), 1);
+            // fork that branches off at height 3
+            store_chain(main_chain[2].hash, 1, low_difficulty(), 100);
+
+            // nothing is pruned without weight
+            assert_eq!(BTCRelay::prune_storage(Weight::zero()), Weight::zero());
+            assert!(BTCRelay::block_header_exists(genesis.hash));
+
+            // the retention would allow pruning up to height 6, but the parent of the fork is kept
+            BTCRelay::prune_storage(Weight::MAX);
+            assert!(!BTCRelay::block_header_exists(genesis.hash));
+            assert!(!BTCRelay::block_header_exists(main_chain[1].hash));
+            assert!(BTCRelay::block_header_exists(main_chain[2].hash));
+            assert_eq!(
+                BTCRelay::get_block_chain_from_id(MAIN_CHAIN_ID).unwrap().start_height,
+                3
+            );
+
+            let pruned_event = TestEvent::BTCRelay(Event::MainChainPruned { start_height: 3 });
+            assert!(System::events().iter().any(|a| a.event == pruned_event));
+
+            assert_err!(
+                BTCRelay::verify_block_header_inclusion(main_chain[0].hash, Some(0)),
+                TestError::BlockNotFound
+            );
+            assert_is_block(6, &main_chain[5]);
+
+            // the main chain can still be extended
+            let block = mine_block(main_chain[9].hash, low_difficulty(), 200);
+            assert_ok!(BTCRelay::_store_block_header(&3, block));
+            assert_eq!(BTCRelay::get_best_block_height(), 11);
+        })
+    }
+
+    #[test]
+    fn set_pruning_config_succeeds() {
+        run_test(|| {
+            assert_err!(
+                BTCRelay::set_pruning_config(RuntimeOrigin::signed(3), Some(BITCOIN_CONFIRMATIONS), None),
+                DispatchError::BadOrigin
+            );
+            assert_err!(
+                BTCRelay::set_pruning_config(RuntimeOrigin::root(), Some(BITCOIN_CONFIRMATIONS - 1), None),
+                TestError::InvalidStaleForkDepth
+            );
+            assert_err!(
+                BTCRelay::set_pruning_config(RuntimeOrigin::root(), None, Some(DIFFICULTY_ADJUSTMENT_INTERVAL - 1)),
+                TestError::InvalidMainChainRetention
+            );
+
+            assert_ok!(BTCRelay::set_pruning_config(
+                RuntimeOrigin::root(),
+                Some(BITCOIN_CONFIRMATIONS),
+                Some(DIFFICULTY_ADJUSTMENT_INTERVAL)
+            ));
+            assert_eq!(BTCRelay::stale_fork_depth(), Some(BITCOIN_CONFIRMATIONS));
+            assert_eq!(BTCRelay::main_chain_retention(), Some(DIFFICULTY_ADJUSTMENT_INTERVAL));
+        })
+    }
+
+    #[test]
+    fn store_block_header_with_timestamp_not_above_median_time_past_fails() {
+        run_test(|| {
+            let genesis = mine_block_with_timestamp(H256Le::zero(), low_difficulty(), 0, 10_000);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            // the median of the timestamps 10_000, 11_000, ..., 20_000 is 15_000
+            let mut prev = genesis.hash;
+            for i in 1..=10 {
+                let block = mine_block_with_timestamp(prev, low_difficulty(), i, 10_000 + i * 1_000);
+                assert_ok!(BTCRelay::_store_block_header(&3, block));
+                prev = block.hash;
+            }
+
+            let block = mine_block_with_timestamp(prev, low_difficulty(), 11, 15_000);
+            assert_err!(BTCRelay::_store_block_header(&3, block), TestError::TimestampTooEarly);
+
+            // the timestamp may be below that of the previous block
+            let block = mine_block_with_timestamp(prev, low_difficulty(), 11, 15_001);
+            assert_ok!(BTCRelay::_store_block_header(&3, block));
+        })
+    }
+
+    #[test]
+    fn store_block_header_with_timestamp_too_far_in_future_fails() {
+        run_test(|| {
+            let now = 1_600_000_000;
+            Timestamp::set_timestamp(now * 1000);
+
+            let genesis = mine_block_with_timestamp(H256Le::zero(), low_difficulty(), 0, now as u32);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            let block = mine_block_with_timestamp(
+                genesis.hash,
+                low_difficulty(),
+                1,
+                (now + MAX_FUTURE_BLOCK_TIME + 1) as u32,
+            );
+            assert_err!(
+                BTCRelay::_store_block_header(&3, block),
+                TestError::TimestampTooFarInFuture
+            );
+
+            let block =
+                mine_block_with_timestamp(genesis.hash, low_difficulty(), 1, (now + MAX_FUTURE_BLOCK_TIME) as u32);
+            assert_ok!(BTCRelay::_store_block_header(&3, block));
+        })
+    }
+
+    #[test]
+    fn get_block_confirmations_and_chains_succeeds() {
+        run_test(|| {
+            let genesis = mine_block(H256Le::zero(), low_difficulty(), 0);
+            assert_ok!(BTCRelay::_initialize(3, genesis, 0));
+
+            // main chain: [0] -> [1] -> [2] -> [3], fork: [1] -> [f2]
+            let main_chain = store_chain(genesis.hash, 3, low_difficulty(), 1);
+            let fork = store_chain(main_chain[0].hash, 1, low_difficulty(), 100);
+
+            assert_eq!(BTCRelay::get_block_confirmations(genesis.hash), Ok(4));
+            assert_eq!(BTCRelay::get_block_confirmations(main_chain[2].hash), Ok(1));
+            assert_eq!(BTCRelay::get_block_confirmations(fork[0].hash), Ok(0));
+            assert_err!(
+                BTCRelay::get_block_confirmations(H256Le::zero()),
+                TestError::BlockNotFound
+            );
+
+            assert_eq!(
+                BTCRelay::get_main_chain_block_header(2).unwrap().block_hash(),
+                main_chain[1].hash
+            );
+            assert_err!(BTCRelay::get_main_chain_block_header(4), TestError::MissingBlockHeight);
+
+            let chains = BTCRelay::get_block_chains();
+            assert_eq!(chains.len(), 2);
+            assert_eq!((chains[0].chain_id, chains[0].max_height), (MAIN_CHAIN_ID, 3));
+            assert_eq!(
+                (chains[1].chain_id, chains[1].start_height, chains[1].max_height),
+                (1, 2, 2)
+            );
+        })
+    }
 }
 
 #[test]
@@ -645,6 +1132,8 @@ fn check_and_do_reorg_swap_fork_position() {
 
         assert_eq!(current_position, fork_position);
 
+        BTCRelay::get_stable_confirmations_work.mock_safe(|| MockResult::Return(Ok(BITCOIN_CONFIRMATIONS.into())));
+
         assert_ok!(BTCRelay::reorganize_chains(&fork));
         // assert that positions have been swapped
         let new_position = BTCRelay::get_chain_position_from_chain_id(fork_chain_id).unwrap();
@@ -687,6 +1176,7 @@ fn check_and_do_reorg_new_fork_is_main_chain() {
         assert_eq!(current_position, fork_position);
 
         BTCRelay::swap_main_blockchain.mock_safe(move |_| MockResult::Return(Ok((best_block_hash, fork_block_height))));
+        BTCRelay::get_stable_confirmations_work.mock_safe(|| MockResult::Return(Ok(BITCOIN_CONFIRMATIONS.into())));
 
         assert_ok!(BTCRelay::reorganize_chains(&fork));
         // assert that the new main chain is set
@@ -729,6 +1219,7 @@ fn check_and_do_reorg_new_fork_below_stable_transaction_confirmations() {
         assert_eq!(current_position, fork_position);
 
         BTCRelay::swap_main_blockchain.mock_safe(move |_| MockResult::Return(Ok((best_block_hash, fork_block_height))));
+        BTCRelay::get_stable_confirmations_work.mock_safe(|| MockResult::Return(Ok(BITCOIN_CONFIRMATIONS.into())));
 
         assert_ok!(BTCRelay::reorganize_chains(&fork));
         // assert that the fork has not overtaken the main chain
@@ -816,8 +1307,13 @@ fn test_verify_block_header_correct_retarget_increase_succeeds() {
         // Sample interval with INCREASING target
         let retarget_headers = sample_retarget_interval_increase();
 
-        let prev_block_header_rich =
-            RichBlockHeader::<BlockNumber>::new(retarget_headers[1], chain_id, block_height, Default::default());
+        let prev_block_header_rich = RichBlockHeader::<BlockNumber>::new(
+            retarget_headers[1],
+            chain_id,
+            block_height,
+            Default::default(),
+            Default::default(),
+        );
 
         let curr_block_header = retarget_headers[2];
         // Prev block exists
@@ -845,8 +1341,13 @@ fn test_verify_block_header_correct_retarget_decrease_succeeds() {
         // Sample interval with DECREASING target
         let retarget_headers = sample_retarget_interval_decrease();
 
-        let prev_block_header_rich =
-            RichBlockHeader::<BlockNumber>::new(retarget_headers[1], chain_id, block_height, Default::default());
+        let prev_block_header_rich = RichBlockHeader::<BlockNumber>::new(
+            retarget_headers[1],
+            chain_id,
+            block_height,
+            Default::default(),
+            Default::default(),
+        );
 
         let curr_block_header = retarget_headers[2];
         // Not duplicate block
@@ -871,8 +1372,13 @@ fn test_verify_block_header_missing_retarget_succeeds() {
         let block_height: u32 = 2015;
         let retarget_headers = sample_retarget_interval_increase();
 
-        let prev_block_header_rich =
-            RichBlockHeader::<BlockNumber>::new(retarget_headers[1], chain_id, block_height, Default::default());
+        let prev_block_header_rich = RichBlockHeader::<BlockNumber>::new(
+            retarget_headers[1],
+            chain_id,
+            block_height,
+            Default::default(),
+            Default::default(),
+        );
 
         let curr_block_header = retarget_headers[2];
         // Not duplicate block
@@ -900,8 +1406,13 @@ fn test_compute_new_target() {
     let retarget_headers = sample_retarget_interval_increase();
 
     let last_retarget_time = retarget_headers[0].timestamp as u64;
-    let prev_block_header =
-        RichBlockHeader::<BlockNumber>::new(retarget_headers[1], chain_id, block_height, Default::default());
+    let prev_block_header = RichBlockHeader::<BlockNumber>::new(
+        retarget_headers[1],
+        chain_id,
+        block_height,
+        Default::default(),
+        Default::default(),
+    );
 
     let curr_block_header = retarget_headers[2];
 
@@ -959,6 +1470,88 @@ fn test_verify_block_header_low_diff_fails() {
     });
 }
 
+#[test]
+fn store_block_header_testnet3_headers_succeeds() {
+    run_test(|| {
+        BTCRelay::set_bitcoin_network(Network::Testnet);
+
+        // the first testnet3 headers, which were all mined at the lowest difficulty - skip the fork headers
+        // https://github.com/bitcoin/bitcoin/blob/d6a59166a1879c1dd5b3a301847961f4b3f17742/test/functional/data/blockheader_testnet3.hex
+        let headers: Vec<BlockHeader> = include_str!("../../../data/blockheader_testnet3.hex")
+            .lines()
+            .filter(|line| !line.starts_with("fork:"))
+            .map(|line| BlockHeader::from_hex(line).unwrap())
+            .collect();
+
+        assert_ok!(BTCRelay::_initialize(3, headers[0], 0));
+        for header in headers.iter().skip(1) {
+            assert_ok!(BTCRelay::_store_block_header(&3, *header));
+        }
+        assert_eq!(BTCRelay::get_best_block(), headers[headers.len() - 1].hash);
+        assert_eq!(BTCRelay::get_best_block_height(), headers.len() as u32 - 1);
+    })
+}
+
+fn mine_regtest_block(prev: H256Le, target: U256, timestamp: u32) -> BlockHeader {
+    BlockBuilder::new()
+        .with_coinbase(&BtcAddress::default(), 50, 0)
+        .with_previous_hash(prev)
+        .with_timestamp(timestamp)
+        .mine(target)
+        .unwrap()
+        .header
+}
+
+#[test]
+fn store_block_header_regtest_min_difficulty_blocks_succeeds() {
+    run_test(|| {
+        BTCRelay::set_bitcoin_network(Network::Regtest);
+        let target = U256::from(2).pow(254.into());
+        let min_difficulty_target = Network::Regtest.min_difficulty_target();
+
+        // regtest may be initialized in the middle of a difficulty period
+        let genesis = mine_regtest_block(H256Le::zero(), target, 10_000);
+        assert_ok!(BTCRelay::_initialize(3, genesis, 100));
+
+        // a block more than 20 minutes after its parent must use the lowest difficulty
+        assert_err!(
+            BTCRelay::_store_block_header(&3, mine_regtest_block(genesis.hash, target, 11_201)),
+            TestError::DiffTargetHeader
+        );
+        let min_difficulty_block = mine_regtest_block(genesis.hash, min_difficulty_target, 11_201);
+        assert_ok!(BTCRelay::_store_block_header(&3, min_difficulty_block));
+
+        // the next block returns to the target before the lowest difficulty block
+        assert_err!(
+            BTCRelay::_store_block_header(
+                &3,
+                mine_regtest_block(min_difficulty_block.hash, min_difficulty_target, 11_800)
+            ),
+            TestError::DiffTargetHeader
+        );
+        assert_ok!(BTCRelay::_store_block_header(
+            &3,
+            mine_regtest_block(min_difficulty_block.hash, target, 11_800)
+        ));
+    })
+}
+
+#[test]
+fn store_block_header_regtest_does_not_retarget() {
+    run_test(|| {
+        BTCRelay::set_bitcoin_network(Network::Regtest);
+        let target = U256::from(2).pow(254.into());
+
+        let genesis = mine_regtest_block(H256Le::zero(), target, 10_000);
+        assert_ok!(BTCRelay::_initialize(3, genesis, DIFFICULTY_ADJUSTMENT_INTERVAL - 1));
+
+        // the first block of the difficulty period keeps the previous target
+        let block = mine_regtest_block(genesis.hash, target, 10_600);
+        assert_ok!(BTCRelay::_store_block_header(&3, block));
+        assert_eq!(BTCRelay::get_best_block_height(), DIFFICULTY_ADJUSTMENT_INTERVAL);
+    })
+}
+
 #[test]
 fn test_validate_transaction_succeeds_with_payment() {
     run_test(|| {
@@ -1049,6 +1642,155 @@ fn test_validate_transaction_succeeds_with_payment_and_refund_and_op_return() {
     });
 }
 
+#[test]
+fn test_validate_batched_transaction_succeeds() {
+    run_test(|| {
+        let first_recipient = BtcAddress::P2SH(H160::from_str(&"66c7060feb882664ae62ffad0051fe843e318e85").unwrap());
+        let second_recipient = BtcAddress::P2SH(H160::zero());
+        let first_id = H256::from_str(&"e5c17d15b8b1fa2811b7e6da66ffa5e1aaa05922c69068bf90cd585b95bb4675").unwrap();
+        let second_id = H256::zero();
+
+        let outputs = vec![
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+            sample_wrong_recipient_payment_output(),
+            sample_zero_data_output(),
+        ];
+        let transaction = sample_transaction_parsed(&outputs);
+
+        assert_eq!(
+            BTCRelay::validate_batched_op_return_transaction(
+                transaction,
+                vec![
+                    (second_id, second_recipient, 2500200000i64),
+                    (first_id, first_recipient, 2500200000i64),
+                ],
+            ),
+            Ok(None)
+        );
+    });
+}
+
+#[test]
+fn test_validate_batched_transaction_with_change_succeeds() {
+    run_test(|| {
+        let recipient = BtcAddress::P2SH(H160::from_str(&"66c7060feb882664ae62ffad0051fe843e318e85").unwrap());
+        let op_return_id = H256::from_str(&"e5c17d15b8b1fa2811b7e6da66ffa5e1aaa05922c69068bf90cd585b95bb4675").unwrap();
+
+        let outputs = vec![
+            sample_wrong_recipient_payment_output(),
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+        ];
+        let transaction = sample_transaction_parsed(&outputs);
+
+        assert_eq!(
+            BTCRelay::validate_batched_op_return_transaction(
+                transaction,
+                vec![(op_return_id, recipient, 2500200000i64)],
+            ),
+            Ok(Some(BtcAddress::P2SH(H160::zero())))
+        );
+    });
+}
+
+#[test]
+fn test_validate_batched_transaction_fails_with_unexpected_payment() {
+    run_test(|| {
+        let recipient = BtcAddress::P2SH(H160::from_str(&"66c7060feb882664ae62ffad0051fe843e318e85").unwrap());
+        let op_return_id = H256::from_str(&"e5c17d15b8b1fa2811b7e6da66ffa5e1aaa05922c69068bf90cd585b95bb4675").unwrap();
+
+        let outputs = vec![
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+            sample_wrong_recipient_payment_output(),
+            sample_zero_data_output(),
+        ];
+        let transaction = sample_transaction_parsed(&outputs);
+
+        assert_err!(
+            BTCRelay::validate_batched_op_return_transaction(
+                transaction,
+                vec![(op_return_id, recipient, 2500200000i64)]
+            ),
+            TestError::InvalidPayment
+        );
+    });
+}
+
+#[test]
+fn test_validate_batched_transaction_fails_with_wrong_amount() {
+    run_test(|| {
+        let recipient = BtcAddress::P2SH(H160::from_str(&"66c7060feb882664ae62ffad0051fe843e318e85").unwrap());
+        let op_return_id = H256::from_str(&"e5c17d15b8b1fa2811b7e6da66ffa5e1aaa05922c69068bf90cd585b95bb4675").unwrap();
+
+        let outputs = vec![sample_insufficient_value_payment_output(), sample_valid_data_output()];
+        let transaction = sample_transaction_parsed(&outputs);
+
+        assert_err!(
+            BTCRelay::validate_batched_op_return_transaction(
+                transaction,
+                vec![(op_return_id, recipient, 2500200000i64)]
+            ),
+            TestError::InvalidPaymentAmount
+        );
+    });
+}
+
+#[test]
+fn test_batched_payment_data_rejects_malformed_transactions() {
+    run_test(|| {
+        // op_return without preceding payment
+        let transaction = sample_transaction_parsed(&vec![sample_valid_data_output(), sample_valid_payment_output()]);
+        assert_err!(
+            BatchedOpReturnPaymentData::<Test>::try_from(transaction),
+            TestError::InvalidOpReturnTransaction
+        );
+
+        // two payments without op_return
+        let transaction = sample_transaction_parsed(&vec![
+            sample_wrong_recipient_payment_output(),
+            sample_wrong_recipient_payment_output(),
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+        ]);
+        assert_err!(
+            BatchedOpReturnPaymentData::<Test>::try_from(transaction),
+            TestError::InvalidOpReturnTransaction
+        );
+
+        // duplicate op_return
+        let transaction = sample_transaction_parsed(&vec![
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+            sample_wrong_recipient_payment_output(),
+            sample_valid_data_output(),
+        ]);
+        assert_err!(
+            BatchedOpReturnPaymentData::<Test>::try_from(transaction),
+            TestError::InvalidOpReturnTransaction
+        );
+
+        // change to one of the recipients
+        let transaction = sample_transaction_parsed(&vec![
+            sample_valid_payment_output(),
+            sample_valid_data_output(),
+            sample_valid_payment_output(),
+        ]);
+        assert_err!(
+            BatchedOpReturnPaymentData::<Test>::try_from(transaction),
+            TestError::InvalidOpReturnTransaction
+        );
+
+        // no payments at all
+        let transaction = sample_transaction_parsed(&vec![sample_wrong_recipient_payment_output()]);
+        assert_err!(
+            BatchedOpReturnPaymentData::<Test>::try_from(transaction),
+            TestError::InvalidOpReturnTransaction
+        );
+    });
+}
+
 #[test]
 fn test_validate_transaction_invalid_no_outputs_fails() {
     run_test(|| {
@@ -1329,6 +2071,7 @@ fn test_verify_transaction_inclusion_fails_with_ongoing_fork() {
     run_test(|| {
         BTCRelay::get_chain_id_from_position.mock_safe(|_| MockResult::Return(Ok(1)));
         BTCRelay::get_block_chain_from_id.mock_safe(|_| MockResult::Return(Ok(BlockChain::default())));
+        BTCRelay::get_stable_confirmations_work.mock_safe(|| MockResult::Return(Ok(BITCOIN_CONFIRMATIONS.into())));
 
         let confirmations = None;
 
@@ -1363,6 +2106,29 @@ fn test_get_and_verify_issue_payment_with_tx_containing_taproot() {
     })
 }
 
+#[test]
+fn test_get_and_verify_issue_payment_to_taproot_address() {
+    run_test(|| {
+        BTCRelay::_verify_transaction_inclusion.mock_safe(|_, _| {
+            let raw_tx = "010000000001013413e41f47eecad702082578c35a2925217056fd0a837b22f1a205fe178a010d0500000000ffffffff19771000000000000017a91415f691c1905082c300362d48540846c30855162d877a1000000000000022512038234fa3e3ca718dfadfb540c320180e68798e67e0a9d4f10d98ea33d37caf047a100000000000001976a914d73838271ee26471aa3640915ed7274b49435b6688acee2000000000000016001470eab26ae0074a58802acc7c38cd9941619c408d14250000000000001976a91479ef95650e8284c3be439d888cf2ee2d1d8ef63088ac3129000000000000160014a558dd2db8167e069f580da2482a9b73dc4f5960217f0000000000001976a91409f3607112083fb1ffe3718214a8e5d5eb0da46188ac04a50000000000001600149215c14609d581aacaa54f629e823cc8abd17ee6c7cd00000000000017a9146da59c9a54a5465402884712bbbe140bc68a4f218728f700000000000017a914ed99cbd06b43b4e3741d1457f7af7b24c2e8d12487ae380100000000001976a91448296f6f29c497f59193ab4e7def5f2e03ef2f9988ac654901000000000017a914ba997376b5daaa3707aefdf30cc09745b579df2187a6a301000000000017a914ffed3c6e71adc2b73939d6951f4655ed1432909b87ec9202000000000017a9147759a1bffe2acca168afdb5b106250b02a703b2887d63603000000000016001439fef3095e8a3bce11ce471aa602bf3e3609d8ddae3703000000000017a914ea0d18bbd804d17a1f2f07ed9aa1670721777d2287cd370300000000001976a914bcc6bcffe584761176d8f510896e882f838208d988ac1d3803000000000016001470eb59ad925fdec71ca0ec50cf7c6b9bbe8dc7592f380300000000001976a91447eb6c94d7b2ac0c11eb3957c0844d333e21d02e88ac724803000000000016001470eb59ad925fdec71ca0ec50cf7c6b9bbe8dc759692e050000000000160014ae26178c1a9b4adb6f24f047fa119e034205900c381b10000000000017a914c9e20b0d7e46d07a878585955ca377db833d181587d32b20000000000017a914bbfcd0b601046e1656ba9b74a98ee8d362d5b63687402f200000000000160014ca146a720a30ca404e979df59d3ddca039e8fd58f22fea0000000000220020935f3eb059cd94bd307e6378bd590724f361f0316fd0964eb5952f274dfb7b4f0400483045022100c9fc44a423e31fc792f5d255ae09ffdc0b224cb70fcebacd52183ce2813ba11d022046c8530230f644be4a05f25bd6a2264b99afc7e3e38531d4bde12d477d03f18001473044022027f50b14154123b173286db76e189a32973a13b0b4ca425329533229cf7f8d9a02202cea81a657ee654c63ab4a01a741931378abae036435a1d695622216596d9e27016952210257bf4070df9735de32305f3bc25320d331edb10c662423e06cd1e50bc58d8fa7210246454540c4e36ba6a481347d0194ffe476640289aecfd2d3f3db1328415b9a5c210248e0a3385d6f744ae81779e10f8ccafbbed7d44debf08a2b0d5250e2f0a0e84853aef0210b00";
+            let tx_bytes = hex::decode(&raw_tx).unwrap();
+            let transaction = parse_transaction(&tx_bytes).unwrap();
+
+            MockResult::Return(Ok(transaction))
+        });
+
+        // check the second output address, which is a witness v1 program
+        let raw_address = "38234fa3e3ca718dfadfb540c320180e68798e67e0a9d4f10d98ea33d37caf04";
+        let address_bytes = hex::decode(&raw_address).unwrap();
+        let recipient_btc_address = BtcAddress::P2TRv1(H256::from_slice(&address_bytes));
+
+        assert_ok!(
+            BTCRelay::get_and_verify_issue_payment::<i64>(sample_unchecked_transaction(), recipient_btc_address),
+            4218
+        );
+    })
+}
+
 #[test]
 fn test_check_bitcoin_confirmations_insecure_succeeds() {
     run_test(|| {
@@ -1743,6 +2509,29 @@ mod op_return_payment_data_tests {
         })
     }
 
+    #[test]
+    fn test_ensure_valid_payment_to_taproot_address_succeeds() {
+        run_test(|| {
+            let amount = 12345;
+            let op_return = H256::from_slice(&[5; 32]);
+            let taproot_address = BtcAddress::P2TRv1(H256::from_slice(&[7; 32]));
+            let transaction = TransactionBuilder::new()
+                .with_version(2)
+                .add_output(TransactionOutput::payment(amount, &taproot_address))
+                .add_output(TransactionOutput::payment(123, &dummy_address2()))
+                .add_output(TransactionOutput::op_return(0, op_return.as_bytes()))
+                .build();
+
+            for transaction in permutations(transaction) {
+                let payment_data = OpReturnPaymentData::<Test>::try_from(transaction).unwrap();
+                assert_ok!(
+                    payment_data.ensure_valid_payment_to(amount, taproot_address, Some(op_return)),
+                    Some(dummy_address2())
+                );
+            }
+        })
+    }
+
     #[test]
     fn test_ensure_valid_payment_to_single_payment_succeeds() {
         run_test(|| {
@@ -1868,6 +2657,7 @@ fn test_check_and_do_reorg() {
                 chain_id: 0,
                 start_height: 1_892_642,
                 max_height: 1_897_317,
+                chain_work: 1_897_317.into(),
             },
         );
 
@@ -1877,6 +2667,7 @@ fn test_check_and_do_reorg() {
                 chain_id: 2,
                 start_height: 1_893_831,
                 max_height: 1_893_831,
+                chain_work: 1_893_831.into(),
             },
         );
 
@@ -1886,6 +2677,7 @@ fn test_check_and_do_reorg() {
                 chain_id: 4,
                 start_height: 1_895_256,
                 max_height: 1_895_256,
+                chain_work: 1_895_256.into(),
             },
         );
 
@@ -1895,6 +2687,7 @@ fn test_check_and_do_reorg() {
                 chain_id: 6,
                 start_height: 1_896_846,
                 max_height: 1_896_846,
+                chain_work: 1_896_846.into(),
             },
         );
 
@@ -1904,10 +2697,12 @@ fn test_check_and_do_reorg() {
                 chain_id: 7,
                 start_height: 1_897_317,
                 max_height: 1_897_910,
+                chain_work: 1_897_910.into(),
             },
         );
 
         BTCRelay::swap_main_blockchain.mock_safe(|_| MockResult::Return(Ok((Default::default(), Default::default()))));
+        BTCRelay::get_stable_confirmations_work.mock_safe(|| MockResult::Return(Ok(BITCOIN_CONFIRMATIONS.into())));
 
         // we should skip empty `Chains`, this can occur if the
         // previous index is accidentally deleted
@@ -1915,6 +2710,7 @@ fn test_check_and_do_reorg() {
             chain_id: 7,
             start_height: 1_897_317,
             max_height: 1_897_910,
+            chain_work: 1_897_910.into(),
         }));
     })
 }
@@ -2002,10 +2798,12 @@ fn sample_unchecked_transaction() -> FullTransactionProof {
 }
 
 fn get_empty_block_chain_from_chain_id_and_height(chain_id: u32, start_height: u32, block_height: u32) -> BlockChain {
+    // use one unit of work per block, so ordering by work is the same as ordering by height
     let blockchain = BlockChain {
         chain_id,
         start_height,
         max_height: block_height,
+        chain_work: block_height.into(),
     };
 
     blockchain
@@ -2022,6 +2820,7 @@ fn sample_parsed_genesis_header(chain_id: u32, block_height: u32) -> RichBlockHe
         block_height,
         chain_id,
         para_height: Default::default(),
+        chain_work: Default::default(),
     }
 }
 
@@ -2045,6 +2844,7 @@ fn sample_parsed_first_block(chain_id: u32, block_height: u32) -> RichBlockHeade
         block_height,
         chain_id,
         para_height: Default::default(),
+        chain_work: Default::default(),
     }
 }
 
@@ -2077,6 +2877,7 @@ fn sample_rich_tx_block_header(chain_id: u32, block_height: u32) -> RichBlockHea
         block_height,
         chain_id,
         para_height: Default::default(),
+        chain_work: Default::default(),
     }
 }
 
@@ -2110,6 +2911,15 @@ fn sample_valid_data_output() -> TransactionOutput {
     }
 }
 
+fn sample_zero_data_output() -> TransactionOutput {
+    TransactionOutput {
+        value: 0,
+        script: "6a200000000000000000000000000000000000000000000000000000000000000000"
+            .try_into()
+            .unwrap(),
+    }
+}
+
 fn sample_incorrect_data_output() -> TransactionOutput {
     TransactionOutput {
         value: 0,
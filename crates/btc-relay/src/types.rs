@@ -1,10 +1,10 @@
-use crate::{Error, ACCEPTED_MAX_TRANSACTION_OUTPUTS};
+use crate::{Error, ACCEPTED_MAX_BATCHED_TRANSACTION_OUTPUTS, ACCEPTED_MAX_TRANSACTION_OUTPUTS, MAX_BATCHED_PAYMENTS};
 use bitcoin::types::{BlockHeader, H256Le, Transaction, Value};
 pub use bitcoin::Address as BtcAddress;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{dispatch::DispatchError, ensure};
 use scale_info::TypeInfo;
-use sp_core::H256;
+use sp_core::{H256, U256};
 use sp_std::{convert::TryFrom, vec::Vec};
 
 /// Bitcoin Enriched Block Headers
@@ -17,6 +17,9 @@ pub struct RichBlockHeader<BlockNumber> {
     pub chain_id: u32,
     /// active_block_number of the parachain at the time this block was submitted
     pub para_height: BlockNumber,
+    /// cumulative work of the chain up to and including this block, starting at
+    /// the block the relay was initialized with
+    pub chain_work: U256,
 }
 
 impl<BlockNumber> RichBlockHeader<BlockNumber> {
@@ -29,12 +32,20 @@ impl<BlockNumber> RichBlockHeader<BlockNumber> {
     /// * `block_height` - chain height
     /// * `account_id` - submitter
     /// * `para_height` - height of the parachain at submission
-    pub fn new(block_header: BlockHeader, chain_id: u32, block_height: u32, para_height: BlockNumber) -> Self {
+    /// * `chain_work` - cumulative work including this block
+    pub fn new(
+        block_header: BlockHeader,
+        chain_id: u32,
+        block_height: u32,
+        para_height: BlockNumber,
+        chain_work: U256,
+    ) -> Self {
         RichBlockHeader {
             block_header,
             block_height,
             chain_id,
             para_height,
+            chain_work,
         }
     }
 
@@ -129,3 +140,105 @@ impl<T: crate::Config> OpReturnPaymentData<T> {
             .find_map(|&(_, address)| if address != recipient { Some(address) } else { None }))
     }
 }
+
+#[cfg_attr(feature = "std", derive(Debug, PartialEq))]
+pub struct BatchedOpReturnPaymentData<T: frame_system::Config> {
+    // vec of (op_return, amount, address)
+    payments: Vec<(H256, Value, BtcAddress)>,
+    // optional return-to-self output
+    change: Option<(Value, BtcAddress)>,
+    _marker: sp_std::marker::PhantomData<T>,
+}
+
+impl<T: crate::Config> TryFrom<Transaction> for BatchedOpReturnPaymentData<T> {
+    type Error = DispatchError;
+
+    fn try_from(transaction: Transaction) -> Result<Self, Self::Error> {
+        // put an upperbound to the number of iterations
+        ensure!(
+            transaction.outputs.len() <= ACCEPTED_MAX_BATCHED_TRANSACTION_OUTPUTS,
+            Error::<T>::InvalidOpReturnTransaction
+        );
+
+        let mut payments: Vec<(H256, Value, BtcAddress)> = Vec::new();
+        let mut change = None;
+        // the most recent payment output that has not yet been matched with an op_return
+        let mut pending: Option<(Value, BtcAddress)> = None;
+        for tx in transaction.outputs {
+            if let Ok(address) = tx.extract_address() {
+                if let Some(unmatched) = pending.replace((tx.value, address)) {
+                    // at most one payment may be left without an op_return, which is the change
+                    ensure!(change.is_none(), Error::<T>::InvalidOpReturnTransaction);
+                    change = Some(unmatched);
+                }
+            } else if let Ok(data) = tx.script.extract_op_return_data() {
+                // make sure the amount is zero
+                ensure!(tx.value == 0, Error::<T>::InvalidOpReturnTransaction);
+                // make sure that the op_return is exactly 32 bytes
+                ensure!(data.len() == 32, Error::<T>::InvalidOpReturnTransaction);
+                // each op_return must directly follow the payment it belongs to
+                let (amount, address) = pending.take().ok_or(Error::<T>::InvalidOpReturnTransaction)?;
+                let op_return = H256::from_slice(&data);
+                ensure!(
+                    !payments.iter().any(|(id, _, _)| *id == op_return),
+                    Error::<T>::InvalidOpReturnTransaction
+                );
+                payments.push((op_return, amount, address));
+            } else {
+                return Err(Error::<T>::InvalidOpReturnTransaction.into());
+            }
+        }
+
+        if let Some(unmatched) = pending {
+            ensure!(change.is_none(), Error::<T>::InvalidOpReturnTransaction);
+            change = Some(unmatched);
+        }
+
+        ensure!(
+            !payments.is_empty() && payments.len() <= MAX_BATCHED_PAYMENTS,
+            Error::<T>::InvalidOpReturnTransaction
+        );
+
+        // the change may not go to any of the recipients, otherwise the vault could pay more than
+        // is allowed
+        if let Some((_, change_address)) = change {
+            ensure!(
+                !payments.iter().any(|(_, _, address)| *address == change_address),
+                Error::<T>::InvalidOpReturnTransaction
+            );
+        }
+
+        Ok(Self {
+            payments,
+            change,
+            _marker: Default::default(),
+        })
+    }
+}
+
+impl<T: crate::Config> BatchedOpReturnPaymentData<T> {
+    pub fn op_returns(&self) -> Vec<H256> {
+        self.payments.iter().map(|(op_return, _, _)| *op_return).collect()
+    }
+
+    // ensures every expected (op_return, recipient, amount) is paid exactly, and that the transaction
+    // contains no other payments. If so, it returns the return-to-self address
+    pub fn ensure_valid_payments_to(
+        &self,
+        expected: &[(H256, BtcAddress, Value)],
+    ) -> Result<Option<BtcAddress>, DispatchError> {
+        ensure!(self.payments.len() == expected.len(), Error::<T>::InvalidPayment);
+
+        for (op_return, recipient, expected_amount) in expected {
+            let (_, paid_amount, address) = self
+                .payments
+                .iter()
+                .find(|(id, _, _)| id == op_return)
+                .ok_or(Error::<T>::InvalidPayment)?;
+            ensure!(address == recipient, Error::<T>::InvalidPayment);
+            ensure!(paid_amount == expected_amount, Error::<T>::InvalidPaymentAmount);
+        }
+
+        Ok(self.change.map(|(_, address)| address))
+    }
+}
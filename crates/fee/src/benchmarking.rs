@@ -117,5 +117,35 @@ pub mod benchmarks {
         set_commission(RawOrigin::Signed(nominator), arbitrary_pair, commission);
     }
 
+    #[benchmark]
+    fn set_vault_issue_fee() {
+        let vault_id = VaultId::new(
+            account("Vault", 0, SEED),
+            T::GetNativeCurrencyId::get(),
+            T::GetWrappedCurrencyId::get(),
+        );
+        T::NominationApi::register_vault(&vault_id);
+        let fee = Fee::<T>::get_max_expected_value();
+        IssueFeeBounds::<T>::put(FeeBounds { min: fee, max: fee });
+
+        #[extrinsic_call]
+        set_vault_issue_fee(RawOrigin::Signed(vault_id.account_id), vault_id.currencies, Some(fee));
+    }
+
+    #[benchmark]
+    fn set_vault_redeem_fee() {
+        let vault_id = VaultId::new(
+            account("Vault", 0, SEED),
+            T::GetNativeCurrencyId::get(),
+            T::GetWrappedCurrencyId::get(),
+        );
+        T::NominationApi::register_vault(&vault_id);
+        let fee = Fee::<T>::get_max_expected_value();
+        RedeemFeeBounds::<T>::put(FeeBounds { min: fee, max: fee });
+
+        #[extrinsic_call]
+        set_vault_redeem_fee(RawOrigin::Signed(vault_id.account_id), vault_id.currencies, Some(fee));
+    }
+
     impl_benchmark_test_suite! { Fee, crate::mock::ExtBuilder::build(), crate::mock::Test }
 }
@@ -42,6 +42,8 @@ pub trait WeightInfo {
 	fn set_punishment_fee() -> Weight;
 	fn set_replace_griefing_collateral() -> Weight;
 	fn set_commission() -> Weight;
+	fn set_vault_issue_fee() -> Weight;
+	fn set_vault_redeem_fee() -> Weight;
 }
 
 /// Weights for fee using the Substrate node and recommended hardware.
@@ -107,6 +109,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: VaultRegistry Vaults (r:1 w:0)
+	// Storage: Fee IssueFeeBounds (r:1 w:0)
+	// Storage: Fee VaultIssueFee (r:0 w:1)
+	fn set_vault_issue_fee() -> Weight {
+		Weight::from_parts(15_307_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: VaultRegistry Vaults (r:1 w:0)
+	// Storage: Fee RedeemFeeBounds (r:1 w:0)
+	// Storage: Fee VaultRedeemFee (r:0 w:1)
+	fn set_vault_redeem_fee() -> Weight {
+		Weight::from_parts(15_307_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -171,5 +191,23 @@ impl WeightInfo for () {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: VaultRegistry Vaults (r:1 w:0)
+	// Storage: Fee IssueFeeBounds (r:1 w:0)
+	// Storage: Fee VaultIssueFee (r:0 w:1)
+	fn set_vault_issue_fee() -> Weight {
+		Weight::from_parts(15_307_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: VaultRegistry Vaults (r:1 w:0)
+	// Storage: Fee RedeemFeeBounds (r:1 w:0)
+	// Storage: Fee VaultRedeemFee (r:0 w:1)
+	fn set_vault_redeem_fee() -> Weight {
+		Weight::from_parts(15_307_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
 
@@ -29,7 +29,7 @@ use codec::{Decode, Encode, EncodeLike};
 use currency::{Amount, CurrencyId, OnSweep, Rounding};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
-    storage,
+    ensure, storage,
     traits::Get,
     transactional, PalletId,
 };
@@ -44,7 +44,7 @@ use sp_std::{
     fmt::Debug,
 };
 use staking::StakingApi;
-use types::{BalanceOf, DefaultVaultCurrencyPair, DefaultVaultId, UnsignedFixedPoint, Version};
+use types::{BalanceOf, DefaultVaultCurrencyPair, DefaultVaultId, FeeBounds, UnsignedFixedPoint, Version};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -111,6 +111,12 @@ pub mod pallet {
         TryIntoIntError,
         /// Value exceeds the expected upper bound for storage fields in this pallet.
         AboveMaxExpectedValue,
+        /// The lower fee bound exceeds the upper fee bound.
+        InvalidFeeBounds,
+        /// Governance has not enabled custom vault fees.
+        CustomFeesDisabled,
+        /// The fee is outside the governance-defined bounds.
+        FeeOutsideBounds,
     }
 
     #[pallet::hooks]
@@ -175,6 +181,26 @@ pub mod pallet {
     pub(super) type Commission<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, UnsignedFixedPoint<T>, OptionQuery>;
 
+    /// Range within which vaults can set their own issue fee. Custom issue fees are disabled if unset.
+    #[pallet::storage]
+    #[pallet::getter(fn issue_fee_bounds)]
+    pub type IssueFeeBounds<T: Config> = StorageValue<_, FeeBounds<UnsignedFixedPoint<T>>, OptionQuery>;
+
+    /// Range within which vaults can set their own redeem fee. Custom redeem fees are disabled if unset.
+    #[pallet::storage]
+    #[pallet::getter(fn redeem_fee_bounds)]
+    pub type RedeemFeeBounds<T: Config> = StorageValue<_, FeeBounds<UnsignedFixedPoint<T>>, OptionQuery>;
+
+    /// Issue fee chosen by the vault, charged instead of the `IssueFee` while custom fees are enabled.
+    #[pallet::storage]
+    pub(super) type VaultIssueFee<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, UnsignedFixedPoint<T>, OptionQuery>;
+
+    /// Redeem fee chosen by the vault, charged instead of the `RedeemFee` while custom fees are enabled.
+    #[pallet::storage]
+    pub(super) type VaultRedeemFee<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, UnsignedFixedPoint<T>, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -351,6 +377,110 @@ pub mod pallet {
             Commission::<T>::insert(vault_id, commission);
             Ok(().into())
         }
+
+        /// Changes the range within which vaults can choose their own issue fee, or disables
+        /// custom issue fees if `None` (only executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `bounds` - the new fee bounds
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_issue_fee())]
+        #[transactional]
+        pub fn set_issue_fee_bounds(
+            origin: OriginFor<T>,
+            bounds: Option<FeeBounds<UnsignedFixedPoint<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(ref bounds) = bounds {
+                Self::ensure_valid_fee_bounds(bounds)?;
+            }
+            IssueFeeBounds::<T>::set(bounds);
+            Ok(().into())
+        }
+
+        /// Changes the range within which vaults can choose their own redeem fee, or disables
+        /// custom redeem fees if `None` (only executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `bounds` - the new fee bounds
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_redeem_fee())]
+        #[transactional]
+        pub fn set_redeem_fee_bounds(
+            origin: OriginFor<T>,
+            bounds: Option<FeeBounds<UnsignedFixedPoint<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(ref bounds) = bounds {
+                Self::ensure_valid_fee_bounds(bounds)?;
+            }
+            RedeemFeeBounds::<T>::set(bounds);
+            Ok(().into())
+        }
+
+        /// Sets the issue fee charged for requests to the caller's vault, or reverts to the
+        /// global issue fee if `None`.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the vault's account
+        /// * `currencies` - currency pair of the vault
+        /// * `fee` - the new fee, which must be within the `IssueFeeBounds`
+        ///
+        /// # Errors
+        ///
+        /// * `VaultNotFound` - if the caller has no vault with the given currencies
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_vault_issue_fee())]
+        #[transactional]
+        pub fn set_vault_issue_fee(
+            origin: OriginFor<T>,
+            currencies: DefaultVaultCurrencyPair<T>,
+            fee: Option<UnsignedFixedPoint<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            let vault_id = VaultId::from_pair(account_id, currencies);
+            T::NominationApi::ensure_vault_exists(&vault_id)?;
+            if let Some(fee) = fee {
+                Self::ensure_within_bounds(fee, IssueFeeBounds::<T>::get())?;
+            }
+            VaultIssueFee::<T>::set(vault_id, fee);
+            Ok(().into())
+        }
+
+        /// Sets the redeem fee charged for requests to the caller's vault, or reverts to the
+        /// global redeem fee if `None`.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the vault's account
+        /// * `currencies` - currency pair of the vault
+        /// * `fee` - the new fee, which must be within the `RedeemFeeBounds`
+        ///
+        /// # Errors
+        ///
+        /// * `VaultNotFound` - if the caller has no vault with the given currencies
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_vault_redeem_fee())]
+        #[transactional]
+        pub fn set_vault_redeem_fee(
+            origin: OriginFor<T>,
+            currencies: DefaultVaultCurrencyPair<T>,
+            fee: Option<UnsignedFixedPoint<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            let vault_id = VaultId::from_pair(account_id, currencies);
+            T::NominationApi::ensure_vault_exists(&vault_id)?;
+            if let Some(fee) = fee {
+                Self::ensure_within_bounds(fee, RedeemFeeBounds::<T>::get())?;
+            }
+            VaultRedeemFee::<T>::set(vault_id, fee);
+            Ok(().into())
+        }
     }
 }
 
@@ -372,6 +502,34 @@ impl<T: Config> Pallet<T> {
         <T as Config>::MaxExpectedValue::get()
     }
 
+    fn ensure_valid_fee_bounds(bounds: &FeeBounds<UnsignedFixedPoint<T>>) -> DispatchResult {
+        ensure!(bounds.min <= bounds.max, Error::<T>::InvalidFeeBounds);
+        ensure!(
+            bounds.max <= Self::get_max_expected_value(),
+            Error::<T>::AboveMaxExpectedValue
+        );
+        Ok(())
+    }
+
+    fn ensure_within_bounds(
+        fee: UnsignedFixedPoint<T>,
+        bounds: Option<FeeBounds<UnsignedFixedPoint<T>>>,
+    ) -> DispatchResult {
+        let bounds = bounds.ok_or(Error::<T>::CustomFeesDisabled)?;
+        ensure!(bounds.min <= fee && fee <= bounds.max, Error::<T>::FeeOutsideBounds);
+        Ok(())
+    }
+
+    /// Returns the vault's custom fee, limited to the current bounds, or `None` if either is unset.
+    fn custom_fee_rate(
+        fee: Option<UnsignedFixedPoint<T>>,
+        bounds: Option<FeeBounds<UnsignedFixedPoint<T>>>,
+    ) -> Option<UnsignedFixedPoint<T>> {
+        let bounds = bounds?;
+        // governance may have changed the bounds after the vault set its fee
+        Some(fee?.clamp(bounds.min, bounds.max))
+    }
+
     // Public functions exposed to other pallets
 
     /// Distribute rewards to participants.
@@ -398,6 +556,26 @@ impl<T: Config> Pallet<T> {
         amount.checked_rounded_mul(&<IssueFee<T>>::get(), Rounding::NearestPrefUp)
     }
 
+    /// Get the issue fee share charged by the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the vault the tokens are issued with
+    pub fn get_vault_issue_fee_value(vault_id: &DefaultVaultId<T>) -> UnsignedFixedPoint<T> {
+        Self::custom_fee_rate(VaultIssueFee::<T>::get(vault_id), IssueFeeBounds::<T>::get())
+            .unwrap_or_else(IssueFee::<T>::get)
+    }
+
+    /// Calculate the required issue fee in tokens when issuing with the given vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the vault the tokens are issued with
+    /// * `amount` - issue amount in tokens
+    pub fn get_vault_issue_fee(vault_id: &DefaultVaultId<T>, amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(&Self::get_vault_issue_fee_value(vault_id), Rounding::NearestPrefUp)
+    }
+
     /// Calculate the required issue griefing collateral.
     ///
     /// # Arguments
@@ -417,6 +595,37 @@ impl<T: Config> Pallet<T> {
         amount.checked_rounded_mul(&<RedeemFee<T>>::get(), Rounding::NearestPrefUp)
     }
 
+    /// Get the redeem fee share charged by the vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the vault the tokens are redeemed with
+    pub fn get_vault_redeem_fee_value(vault_id: &DefaultVaultId<T>) -> UnsignedFixedPoint<T> {
+        Self::custom_fee_rate(VaultRedeemFee::<T>::get(vault_id), RedeemFeeBounds::<T>::get())
+            .unwrap_or_else(RedeemFee::<T>::get)
+    }
+
+    /// Calculate the required redeem fee in tokens when redeeming with the given vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the vault the tokens are redeemed with
+    /// * `amount` - redeem amount in tokens
+    pub fn get_vault_redeem_fee(vault_id: &DefaultVaultId<T>, amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(&Self::get_vault_redeem_fee_value(vault_id), Rounding::NearestPrefUp)
+    }
+
+    /// Removes the commission and the custom fees of a vault that no longer exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the removed vault
+    pub fn remove_vault_fees(vault_id: &DefaultVaultId<T>) {
+        Commission::<T>::remove(vault_id);
+        VaultIssueFee::<T>::remove(vault_id);
+        VaultRedeemFee::<T>::remove(vault_id);
+    }
+
     /// Calculate the premium redeem fee in collateral for a user to get if redeeming
     /// with a Vault below the premium redeem threshold.
     ///
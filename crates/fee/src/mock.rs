@@ -177,6 +177,9 @@ parameter_types! {
     pub const MaxExpectedValue: UnsignedFixedPoint = UnsignedFixedPoint::from_inner(<UnsignedFixedPoint as FixedPointNumber>::DIV);
 }
 
+/// Account of a vault that is not registered, all other vaults are
+pub const UNREGISTERED_VAULT_ACCOUNT: AccountId = 3;
+
 pub struct MockNomination;
 
 impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test>> for MockNomination {
@@ -189,9 +192,22 @@ impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test
     fn ensure_opted_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
         Ok(())
     }
+    fn ensure_vault_exists(vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
+        if vault_id.account_id == UNREGISTERED_VAULT_ACCOUNT {
+            Err(DispatchError::Other("VaultNotFound"))
+        } else {
+            Ok(())
+        }
+    }
+    fn opt_out_of_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
+        Ok(())
+    }
 
     #[cfg(any(feature = "runtime-benchmarks", test))]
     fn opt_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) {}
+
+    #[cfg(any(feature = "runtime-benchmarks", test))]
+    fn register_vault(_vault_id: &VaultId<AccountId, CurrencyId>) {}
 }
 
 impl Config for Test {
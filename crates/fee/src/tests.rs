@@ -1,4 +1,4 @@
-use crate::{mock::*, Commission, IssueFee};
+use crate::{mock::*, types::FeeBounds, Commission, IssueFee, RedeemFee};
 use currency::Amount;
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use primitives::VaultId;
@@ -67,6 +67,164 @@ fn should_set_replace_griefing_collateral() {
     test_setter(Fee::set_replace_griefing_collateral, Fee::replace_griefing_collateral);
 }
 
+fn dummy_vault_id() -> VaultId<AccountId, CurrencyId> {
+    VaultId::new(1, Token(DOT), Token(IBTC))
+}
+
+fn percent(value: u128) -> UnsignedFixedPoint {
+    UnsignedFixedPoint::checked_from_rational(value, 100).unwrap()
+}
+
+#[test]
+fn should_set_fee_bounds() {
+    run_test(|| {
+        assert_noop!(
+            Fee::set_issue_fee_bounds(
+                RuntimeOrigin::signed(6),
+                Some(FeeBounds {
+                    min: percent(1),
+                    max: percent(5)
+                })
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Fee::set_issue_fee_bounds(
+                RuntimeOrigin::root(),
+                Some(FeeBounds {
+                    min: percent(5),
+                    max: percent(1)
+                })
+            ),
+            TestError::InvalidFeeBounds
+        );
+        assert_noop!(
+            Fee::set_redeem_fee_bounds(
+                RuntimeOrigin::root(),
+                Some(FeeBounds {
+                    min: percent(1),
+                    max: percent(101)
+                })
+            ),
+            TestError::AboveMaxExpectedValue
+        );
+
+        let bounds = FeeBounds {
+            min: percent(1),
+            max: percent(5),
+        };
+        assert_ok!(Fee::set_issue_fee_bounds(RuntimeOrigin::root(), Some(bounds)));
+        assert_eq!(Fee::issue_fee_bounds(), Some(bounds));
+        assert_ok!(Fee::set_issue_fee_bounds(RuntimeOrigin::root(), None));
+        assert_eq!(Fee::issue_fee_bounds(), None);
+    })
+}
+
+#[test]
+fn should_set_vault_fee_within_bounds() {
+    run_test(|| {
+        let vault_id = dummy_vault_id();
+        let origin = RuntimeOrigin::signed(vault_id.account_id);
+
+        assert_noop!(
+            Fee::set_vault_issue_fee(origin.clone(), vault_id.currencies.clone(), Some(percent(2))),
+            TestError::CustomFeesDisabled
+        );
+
+        assert_ok!(Fee::set_issue_fee_bounds(
+            RuntimeOrigin::root(),
+            Some(FeeBounds {
+                min: percent(1),
+                max: percent(5)
+            })
+        ));
+        assert_noop!(
+            Fee::set_vault_issue_fee(origin.clone(), vault_id.currencies.clone(), Some(percent(6))),
+            TestError::FeeOutsideBounds
+        );
+        assert_ok!(Fee::set_vault_issue_fee(
+            origin.clone(),
+            vault_id.currencies.clone(),
+            Some(percent(2))
+        ));
+        assert_eq!(Fee::get_vault_issue_fee_value(&vault_id), percent(2));
+
+        // reverts to the global fee
+        <IssueFee<Test>>::put(percent(3));
+        assert_ok!(Fee::set_vault_issue_fee(origin, vault_id.currencies.clone(), None));
+        assert_eq!(Fee::get_vault_issue_fee_value(&vault_id), percent(3));
+    })
+}
+
+#[test]
+fn should_not_set_fee_of_unregistered_vault() {
+    run_test(|| {
+        let vault_id = VaultId::new(UNREGISTERED_VAULT_ACCOUNT, Token(DOT), Token(IBTC));
+        let origin = RuntimeOrigin::signed(vault_id.account_id);
+        let bounds = Some(FeeBounds {
+            min: percent(1),
+            max: percent(5),
+        });
+        assert_ok!(Fee::set_issue_fee_bounds(RuntimeOrigin::root(), bounds));
+        assert_ok!(Fee::set_redeem_fee_bounds(RuntimeOrigin::root(), bounds));
+
+        assert_noop!(
+            Fee::set_vault_issue_fee(origin.clone(), vault_id.currencies.clone(), Some(percent(2))),
+            DispatchError::Other("VaultNotFound")
+        );
+        assert_noop!(
+            Fee::set_vault_redeem_fee(origin, vault_id.currencies.clone(), Some(percent(2))),
+            DispatchError::Other("VaultNotFound")
+        );
+    })
+}
+
+#[test]
+fn should_get_vault_redeem_fee() {
+    run_test(|| {
+        let vault_id = dummy_vault_id();
+        <RedeemFee<Test>>::put(percent(10));
+        assert_ok!(Fee::set_redeem_fee_bounds(
+            RuntimeOrigin::root(),
+            Some(FeeBounds {
+                min: percent(1),
+                max: percent(20)
+            })
+        ));
+        assert_ok!(Fee::set_vault_redeem_fee(
+            RuntimeOrigin::signed(vault_id.account_id),
+            vault_id.currencies.clone(),
+            Some(percent(15))
+        ));
+        assert_ok!(
+            Fee::get_vault_redeem_fee(&vault_id, &Amount::<Test>::new(100, Token(IBTC))),
+            Amount::<Test>::new(15, Token(IBTC))
+        );
+        // other vaults pay the global fee
+        assert_ok!(
+            Fee::get_vault_redeem_fee(
+                &VaultId::new(2, Token(DOT), Token(IBTC)),
+                &Amount::<Test>::new(100, Token(IBTC))
+            ),
+            Amount::<Test>::new(10, Token(IBTC))
+        );
+
+        // custom fee is limited to the current bounds
+        assert_ok!(Fee::set_redeem_fee_bounds(
+            RuntimeOrigin::root(),
+            Some(FeeBounds {
+                min: percent(1),
+                max: percent(12)
+            })
+        ));
+        assert_eq!(Fee::get_vault_redeem_fee_value(&vault_id), percent(12));
+
+        // disabling custom fees reverts to the global fee
+        assert_ok!(Fee::set_redeem_fee_bounds(RuntimeOrigin::root(), None));
+        assert_eq!(Fee::get_vault_redeem_fee_value(&vault_id), percent(10));
+    })
+}
+
 #[test]
 fn compute_vault_rewards_works_with_commission() {
     run_test(|| {
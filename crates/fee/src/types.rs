@@ -11,6 +11,15 @@ pub(crate) type DefaultVaultId<T> = VaultId<<T as frame_system::Config>::Account
 
 pub(crate) type DefaultVaultCurrencyPair<T> = VaultCurrencyPair<CurrencyId<T>>;
 
+/// Governance-defined range within which vaults can choose their own fee rate.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FeeBounds<UnsignedFixedPoint> {
+    /// Lowest fee rate a vault can charge.
+    pub min: UnsignedFixedPoint,
+    /// Highest fee rate a vault can charge.
+    pub max: UnsignedFixedPoint,
+}
+
 /// Storage version.
 #[derive(Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum Version {
@@ -5,16 +5,29 @@
 use codec::Codec;
 use sp_std::vec::Vec;
 
+pub use primitives::query::RequestPage;
+
 sp_api::decl_runtime_apis! {
-    pub trait IssueApi<AccountId, H256, IssueRequest> where
+    /// `RequestQuery` is only used by the calls added in version 2
+    #[api_version(2)]
+    pub trait IssueApi<AccountId, H256, IssueRequest, RequestQuery> where
         AccountId: Codec,
         H256: Codec,
         IssueRequest: Codec,
+        RequestQuery: Codec,
     {
         /// Get all issue requests for a particular account
         fn get_issue_requests(account_id: AccountId) -> Vec<H256>;
 
         /// Get all issue requests for a particular vault
         fn get_vault_issue_requests(vault_id: AccountId) -> Vec<H256>;
+
+        /// Get a page of the issue requests for a particular account
+        #[api_version(2)]
+        fn get_issue_requests_page(account_id: AccountId, query: RequestQuery) -> RequestPage<H256, IssueRequest>;
+
+        /// Get a page of the issue requests for a particular vault
+        #[api_version(2)]
+        fn get_vault_issue_requests_page(vault_id: AccountId, query: RequestQuery) -> RequestPage<H256, IssueRequest>;
     }
 }
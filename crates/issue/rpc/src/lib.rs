@@ -6,20 +6,36 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
-pub use issue_rpc_runtime_api::IssueApi as IssueRuntimeApi;
+pub use issue_rpc_runtime_api::{IssueApi as IssueRuntimeApi, RequestPage};
 
 #[rpc(client, server)]
-pub trait IssueApi<BlockHash, AccountId, H256, IssueRequest> {
+pub trait IssueApi<BlockHash, AccountId, H256, IssueRequest, RequestQuery> {
     #[method(name = "issue_getIssueRequests")]
     fn get_issue_requests(&self, account_id: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
 
     #[method(name = "issue_getVaultIssueRequests")]
     fn get_vault_issue_requests(&self, vault_id: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
+
+    #[method(name = "issue_getIssueRequestsPage")]
+    fn get_issue_requests_page(
+        &self,
+        account_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, IssueRequest>>;
+
+    #[method(name = "issue_getVaultIssueRequestsPage")]
+    fn get_vault_issue_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, IssueRequest>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -30,6 +46,30 @@ fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
     )))
 }
 
+/// The paginated calls were added in version 2 of the runtime api
+fn ensure_api_version<C, Block, AccountId, H256, IssueRequest, RequestQuery>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+) -> RpcResult<()>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: IssueRuntimeApi<Block, AccountId, H256, IssueRequest, RequestQuery>,
+    AccountId: Codec,
+    H256: Codec,
+    IssueRequest: Codec,
+    RequestQuery: Codec,
+{
+    let version = client
+        .runtime_api()
+        .api_version::<dyn IssueRuntimeApi<Block, AccountId, H256, IssueRequest, RequestQuery>>(at)
+        .map_err(|e| internal_err(format!("Unable to get the runtime api version: {:?}", e)))?;
+    match version {
+        Some(version) if version >= 2 => Ok(()),
+        _ => Err(internal_err("Not supported by the runtime at this block")),
+    }
+}
+
 /// A struct that implements the [`IssueApi`].
 pub struct Issue<C, B> {
     client: Arc<C>,
@@ -47,15 +87,16 @@ impl<C, B> Issue<C, B> {
 }
 
 #[async_trait]
-impl<C, Block, AccountId, H256, IssueRequest> IssueApiServer<<Block as BlockT>::Hash, AccountId, H256, IssueRequest>
-    for Issue<C, Block>
+impl<C, Block, AccountId, H256, IssueRequest, RequestQuery>
+    IssueApiServer<<Block as BlockT>::Hash, AccountId, H256, IssueRequest, RequestQuery> for Issue<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: IssueRuntimeApi<Block, AccountId, H256, IssueRequest>,
+    C::Api: IssueRuntimeApi<Block, AccountId, H256, IssueRequest, RequestQuery>,
     AccountId: Codec,
     H256: Codec,
     IssueRequest: Codec,
+    RequestQuery: Codec,
 {
     fn get_issue_requests(&self, account_id: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<H256>> {
         let api = self.client.runtime_api();
@@ -76,4 +117,32 @@ where
         api.get_vault_issue_requests(at, vault_id)
             .map_err(|e| internal_err(format!("Unable to fetch issue requests: {:?}", e)))
     }
+
+    fn get_issue_requests_page(
+        &self,
+        account_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, IssueRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, AccountId, H256, IssueRequest, RequestQuery>(&*self.client, at)?;
+
+        api.get_issue_requests_page(at, account_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch issue requests: {:?}", e)))
+    }
+
+    fn get_vault_issue_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, IssueRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, AccountId, H256, IssueRequest, RequestQuery>(&*self.client, at)?;
+
+        api.get_vault_issue_requests_page(at, vault_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch issue requests: {:?}", e)))
+    }
 }
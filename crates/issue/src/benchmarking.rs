@@ -85,6 +85,22 @@ fn register_vault<T: crate::Config>(vault_id: DefaultVaultId<T>) {
     ));
 }
 
+fn initialize_relay<T: crate::Config>(relayer_id: &T::AccountId) {
+    let init_block = BlockBuilder::new()
+        .with_version(4)
+        .with_coinbase(&BtcAddress::dummy(), 50, 3)
+        .with_timestamp(1588813835)
+        .mine(U256::from(2).pow(254.into()))
+        .unwrap();
+
+    Security::<T>::set_active_block_number(1u32.into());
+    BtcRelay::<T>::_initialize(relayer_id.clone(), init_block.header, 0).unwrap();
+    BtcRelay::<T>::mine_blocks(relayer_id, 1);
+    Security::<T>::set_active_block_number(
+        Security::<T>::active_block_number() + BtcRelay::<T>::parachain_confirmations(),
+    );
+}
+
 fn expire_issue<T: crate::Config>(chain_state: &ChainState<T>) {
     let period = Issue::<T>::issue_period().max(chain_state.issue_request.period);
     let expiry_height = BtcRelay::<T>::bitcoin_expiry_height(chain_state.issue_request.btc_height, period).unwrap();
@@ -188,21 +204,7 @@ pub mod benchmarks {
 
         setup_chain::<T>();
         register_vault::<T>(vault_id.clone());
-
-        // initialize relay
-        let init_block = BlockBuilder::new()
-            .with_version(4)
-            .with_coinbase(&BtcAddress::dummy(), 50, 3)
-            .with_timestamp(u32::MAX)
-            .mine(U256::from(2).pow(254.into()))
-            .unwrap();
-
-        Security::<T>::set_active_block_number(1u32.into());
-        BtcRelay::<T>::_initialize(relayer_id.clone(), init_block.header, 0).unwrap();
-        BtcRelay::<T>::mine_blocks(&relayer_id, 1);
-        Security::<T>::set_active_block_number(
-            Security::<T>::active_block_number() + BtcRelay::<T>::parachain_confirmations(),
-        );
+        initialize_relay::<T>(&relayer_id);
 
         #[extrinsic_call]
         request_issue(
@@ -210,6 +212,53 @@ pub mod benchmarks {
             amount,
             vault_id,
             get_native_currency_id::<T>(),
+            Some(Box::new(VersionedMultiLocation::V3(MultiLocation::parent()))),
+            Some(amount),
+        );
+    }
+
+    #[benchmark]
+    fn request_issue_auto(
+        v: Linear<{ vault_registry::MAX_VAULTS_PER_REQUEST }, { vault_registry::MAX_VAULTS_SCANNED_PER_SELECTION }>,
+    ) {
+        let origin: T::AccountId = account("Origin", 0, 0);
+        let relayer_id: T::AccountId = account("Relayer", 0, 0);
+
+        mint_collateral::<T>(&origin, (1u32 << 31).into());
+        mint_collateral::<T>(&relayer_id, (1u32 << 31).into());
+
+        setup_chain::<T>();
+        let vault_ids = (0..v)
+            .map(|i| {
+                VaultId::new(
+                    account("Vault", i, 0),
+                    get_collateral_currency_id::<T>(),
+                    get_wrapped_currency_id::<T>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        for vault_id in vault_ids.iter() {
+            register_vault::<T>(vault_id.clone());
+        }
+        initialize_relay::<T>(&relayer_id);
+
+        // worst case: all vaults are scanned, and no vault can issue the full amount
+        // such that it is split across the maximum number of vaults
+        let issuable_tokens = VaultRegistry::<T>::get_issuable_tokens_from_vault(&vault_ids[0]).unwrap();
+        let amount = issuable_tokens.amount() * vault_registry::MAX_VAULTS_PER_REQUEST.into();
+
+        #[extrinsic_call]
+        request_issue_auto(
+            RawOrigin::Signed(origin),
+            amount,
+            vault_ids[0].currencies.clone(),
+            get_native_currency_id::<T>(),
+            Some(amount),
+        );
+
+        assert_eq!(
+            IssueRequests::<T>::iter().count() as u32,
+            vault_registry::MAX_VAULTS_PER_REQUEST
         );
     }
 
@@ -38,6 +38,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for issue.
 pub trait WeightInfo {
 	fn request_issue() -> Weight;
+	fn request_issue_auto(v: u32, ) -> Weight;
 	fn execute_issue_exact(h: u32, i: u32, o: u32, b: u32, ) -> Weight;
 	fn execute_issue_overpayment(h: u32, i: u32, o: u32, b: u32, ) -> Weight;
 	fn execute_issue_underpayment(h: u32, i: u32, o: u32, b: u32, ) -> Weight;
@@ -98,6 +99,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(19_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: VaultRegistry Vaults (r:101 w:5)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:100 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:100 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StartBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay StartBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Fee IssueGriefingCollateral (r:1 w:0)
+	/// Proof: Fee IssueGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Issue IssueBtcDustValue (r:1 w:0)
+	/// Proof: Issue IssueBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Fee IssueFee (r:1 w:0)
+	/// Proof: Fee IssueFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:5 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssuePeriod (r:1 w:0)
+	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssueRequests (r:0 w:5)
+	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CurrencyPairVaults (r:100 w:0)
+	/// Proof: VaultRegistry CurrencyPairVaults (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// The range of component `v` is `[5, 100]`.
+	fn request_issue_auto(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3918 + v * (343 ±0)`
+		//  Estimated: `26156 + v * (7865 ±0)`
+		// Minimum execution time: 2_418_306_000 picoseconds.
+		Weight::from_parts(2_134_773_204, 26156)
+			// Standard Error: 61_482
+			.saturating_add(Weight::from_parts(57_103_865, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(39_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(Weight::from_parts(0, 7865).saturating_mul(v.into()))
+	}
+
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
 	/// Storage: BTCRelay DisableInclusionCheck (r:1 w:0)
@@ -122,6 +178,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -129,9 +187,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_issue_exact(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3435`
-		//  Estimated: `20840`
+		//  Estimated: `23417`
 		// Minimum execution time: 142_775_000 picoseconds.
-		Weight::from_parts(117_261_202, 20840)
+		Weight::from_parts(117_261_202, 23417)
 			// Standard Error: 45_583
 			.saturating_add(Weight::from_parts(1_682_032, 0).saturating_mul(h.into()))
 			// Standard Error: 41_054
@@ -140,8 +198,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(287_609, 0).saturating_mul(o.into()))
 			// Standard Error: 308
 			.saturating_add(Weight::from_parts(7_184, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(12_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -179,6 +237,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -186,15 +246,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_issue_overpayment(h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4257`
-		//  Estimated: `32025`
+		//  Estimated: `34602`
 		// Minimum execution time: 212_556_000 picoseconds.
-		Weight::from_parts(219_428_668, 32025)
+		Weight::from_parts(219_428_668, 34602)
 			// Standard Error: 58_266
 			.saturating_add(Weight::from_parts(901_425, 0).saturating_mul(h.into()))
 			// Standard Error: 394
 			.saturating_add(Weight::from_parts(5_933, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(18_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -222,6 +282,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -229,17 +291,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_issue_underpayment(h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3688`
-		//  Estimated: `21351`
+		//  Estimated: `23928`
 		// Minimum execution time: 161_721_000 picoseconds.
-		Weight::from_parts(157_484_537, 21351)
+		Weight::from_parts(157_484_537, 23928)
 			// Standard Error: 51_664
 			.saturating_add(Weight::from_parts(1_573_495, 0).saturating_mul(h.into()))
 			// Standard Error: 46_531
 			.saturating_add(Weight::from_parts(703_443, 0).saturating_mul(i.into()))
 			// Standard Error: 349
 			.saturating_add(Weight::from_parts(3_376, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -265,6 +327,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -272,15 +336,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_expired_issue_exact(h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4358 + o * (8 ±0)`
-		//  Estimated: `20840`
+		//  Estimated: `23417`
 		// Minimum execution time: 146_945_000 picoseconds.
-		Weight::from_parts(131_685_627, 20840)
+		Weight::from_parts(131_685_627, 23417)
 			// Standard Error: 120_641
 			.saturating_add(Weight::from_parts(3_687_159, 0).saturating_mul(h.into()))
 			// Standard Error: 816
 			.saturating_add(Weight::from_parts(15_185, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(12_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -318,6 +382,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -325,9 +391,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_expired_issue_overpayment(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5026 + i * (9 ±0)`
-		//  Estimated: `32025`
+		//  Estimated: `34602`
 		// Minimum execution time: 219_548_000 picoseconds.
-		Weight::from_parts(188_635_084, 32025)
+		Weight::from_parts(188_635_084, 34602)
 			// Standard Error: 145_768
 			.saturating_add(Weight::from_parts(2_393_121, 0).saturating_mul(h.into()))
 			// Standard Error: 131_286
@@ -336,8 +402,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(81_139, 0).saturating_mul(o.into()))
 			// Standard Error: 986
 			.saturating_add(Weight::from_parts(10_300, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(18_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -365,6 +431,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -372,13 +440,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_expired_issue_underpayment(_h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4495 + h * (9 ±0) + i * (13 ±0)`
-		//  Estimated: `21351`
+		//  Estimated: `23928`
 		// Minimum execution time: 173_639_000 picoseconds.
-		Weight::from_parts(195_976_912, 21351)
+		Weight::from_parts(195_976_912, 23928)
 			// Standard Error: 849
 			.saturating_add(Weight::from_parts(13_647, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -390,14 +458,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: VaultRegistry Vaults (r:1 w:1)
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_issue() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2583`
-		//  Estimated: `6968`
+		//  Estimated: `9545`
 		// Minimum execution time: 78_715_000 picoseconds.
-		Weight::from_parts(83_016_000, 6968)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(83_016_000, 9545)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Issue IssuePeriod (r:0 w:1)
 	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -460,6 +530,61 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(19_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: VaultRegistry Vaults (r:101 w:5)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:100 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:100 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StartBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay StartBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Fee IssueGriefingCollateral (r:1 w:0)
+	/// Proof: Fee IssueGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Issue IssueBtcDustValue (r:1 w:0)
+	/// Proof: Issue IssueBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Fee IssueFee (r:1 w:0)
+	/// Proof: Fee IssueFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:5 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssuePeriod (r:1 w:0)
+	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssueRequests (r:0 w:5)
+	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CurrencyPairVaults (r:100 w:0)
+	/// Proof: VaultRegistry CurrencyPairVaults (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// The range of component `v` is `[5, 100]`.
+	fn request_issue_auto(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3918 + v * (343 ±0)`
+		//  Estimated: `26156 + v * (7865 ±0)`
+		// Minimum execution time: 2_418_306_000 picoseconds.
+		Weight::from_parts(2_134_773_204, 26156)
+			// Standard Error: 61_482
+			.saturating_add(Weight::from_parts(57_103_865, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(39_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+			.saturating_add(Weight::from_parts(0, 7865).saturating_mul(v.into()))
+	}
+
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
 	/// Storage: BTCRelay DisableInclusionCheck (r:1 w:0)
@@ -484,6 +609,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -491,9 +618,9 @@ impl WeightInfo for () {
 	fn execute_issue_exact(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3435`
-		//  Estimated: `20840`
+		//  Estimated: `23417`
 		// Minimum execution time: 142_775_000 picoseconds.
-		Weight::from_parts(117_261_202, 20840)
+		Weight::from_parts(117_261_202, 23417)
 			// Standard Error: 45_583
 			.saturating_add(Weight::from_parts(1_682_032, 0).saturating_mul(h.into()))
 			// Standard Error: 41_054
@@ -502,8 +629,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(287_609, 0).saturating_mul(o.into()))
 			// Standard Error: 308
 			.saturating_add(Weight::from_parts(7_184, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -541,6 +668,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -548,15 +677,15 @@ impl WeightInfo for () {
 	fn execute_issue_overpayment(h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4257`
-		//  Estimated: `32025`
+		//  Estimated: `34602`
 		// Minimum execution time: 212_556_000 picoseconds.
-		Weight::from_parts(219_428_668, 32025)
+		Weight::from_parts(219_428_668, 34602)
 			// Standard Error: 58_266
 			.saturating_add(Weight::from_parts(901_425, 0).saturating_mul(h.into()))
 			// Standard Error: 394
 			.saturating_add(Weight::from_parts(5_933, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -584,6 +713,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -591,17 +722,17 @@ impl WeightInfo for () {
 	fn execute_issue_underpayment(h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3688`
-		//  Estimated: `21351`
+		//  Estimated: `23928`
 		// Minimum execution time: 161_721_000 picoseconds.
-		Weight::from_parts(157_484_537, 21351)
+		Weight::from_parts(157_484_537, 23928)
 			// Standard Error: 51_664
 			.saturating_add(Weight::from_parts(1_573_495, 0).saturating_mul(h.into()))
 			// Standard Error: 46_531
 			.saturating_add(Weight::from_parts(703_443, 0).saturating_mul(i.into()))
 			// Standard Error: 349
 			.saturating_add(Weight::from_parts(3_376, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -627,6 +758,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -634,15 +767,15 @@ impl WeightInfo for () {
 	fn execute_expired_issue_exact(h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4358 + o * (8 ±0)`
-		//  Estimated: `20840`
+		//  Estimated: `23417`
 		// Minimum execution time: 146_945_000 picoseconds.
-		Weight::from_parts(131_685_627, 20840)
+		Weight::from_parts(131_685_627, 23417)
 			// Standard Error: 120_641
 			.saturating_add(Weight::from_parts(3_687_159, 0).saturating_mul(h.into()))
 			// Standard Error: 816
 			.saturating_add(Weight::from_parts(15_185, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -680,6 +813,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -687,9 +822,9 @@ impl WeightInfo for () {
 	fn execute_expired_issue_overpayment(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5026 + i * (9 ±0)`
-		//  Estimated: `32025`
+		//  Estimated: `34602`
 		// Minimum execution time: 219_548_000 picoseconds.
-		Weight::from_parts(188_635_084, 32025)
+		Weight::from_parts(188_635_084, 34602)
 			// Standard Error: 145_768
 			.saturating_add(Weight::from_parts(2_393_121, 0).saturating_mul(h.into()))
 			// Standard Error: 131_286
@@ -698,8 +833,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(81_139, 0).saturating_mul(o.into()))
 			// Standard Error: 986
 			.saturating_add(Weight::from_parts(10_300, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -727,6 +862,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -734,13 +871,13 @@ impl WeightInfo for () {
 	fn execute_expired_issue_underpayment(_h: u32, _i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4495 + h * (9 ±0) + i * (13 ±0)`
-		//  Estimated: `21351`
+		//  Estimated: `23928`
 		// Minimum execution time: 173_639_000 picoseconds.
-		Weight::from_parts(195_976_912, 21351)
+		Weight::from_parts(195_976_912, 23928)
 			// Standard Error: 849
 			.saturating_add(Weight::from_parts(13_647, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
@@ -752,14 +889,16 @@ impl WeightInfo for () {
 	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: VaultRegistry Vaults (r:1 w:1)
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_issue() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2583`
-		//  Estimated: `6968`
+		//  Estimated: `9545`
 		// Minimum execution time: 78_715_000 picoseconds.
-		Weight::from_parts(83_016_000, 6968)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(83_016_000, 9545)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Issue IssuePeriod (r:0 w:1)
 	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -39,8 +39,9 @@ pub(crate) mod vault_registry {
     use btc_relay::BtcAddress;
     use frame_support::dispatch::{DispatchError, DispatchResult};
     use sp_core::H256;
+    use sp_std::vec::Vec;
     use vault_registry::{
-        types::{CurrencySource, DefaultVault},
+        types::{CurrencySource, DefaultVault, DefaultVaultCurrencyPair},
         Amount, BtcPublicKey,
     };
 
@@ -52,10 +53,22 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::transfer_funds(from, to, amount)
     }
 
+    pub fn reserve_address<T: crate::Config>(vault_id: &DefaultVaultId<T>, address: &BtcAddress) {
+        <vault_registry::Pallet<T>>::reserve_address(vault_id, address)
+    }
+
     pub fn is_vault_liquidated<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> Result<bool, DispatchError> {
         <vault_registry::Pallet<T>>::is_vault_liquidated(vault_id)
     }
 
+    pub fn record_issue_execution<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+        <vault_registry::Pallet<T>>::record_issue_execution(vault_id)
+    }
+
+    pub fn record_issue_cancellation<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+        <vault_registry::Pallet<T>>::record_issue_cancellation(vault_id)
+    }
+
     pub fn get_active_vault_from_id<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
     ) -> Result<DefaultVault<T>, DispatchError> {
@@ -79,6 +92,14 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::get_issuable_tokens_from_vault(vault_id)
     }
 
+    pub fn select_vaults_for_issue<T: crate::Config>(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+        seed: Option<H256>,
+    ) -> Result<(Vec<(DefaultVaultId<T>, Amount<T>)>, u32), DispatchError> {
+        <vault_registry::Pallet<T>>::select_vaults_for_issue(currency_pair, amount, seed)
+    }
+
     pub fn register_deposit_address<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
         secure_id: H256,
@@ -130,6 +151,7 @@ pub(crate) mod security {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod fee {
+    use crate::DefaultVaultId;
     use currency::Amount;
     use frame_support::dispatch::{DispatchError, DispatchResult};
 
@@ -137,8 +159,11 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::fee_pool_account_id()
     }
 
-    pub fn get_issue_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_issue_fee(amount)
+    pub fn get_issue_fee<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_vault_issue_fee(vault_id, amount)
     }
 
     pub fn get_issue_griefing_collateral<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
@@ -149,3 +174,15 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::distribute_rewards(amount)
     }
 }
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod xcm_transfer {
+    use currency::Amount;
+    use frame_support::dispatch::DispatchResult;
+    use traits::XcmTransferApi;
+    use xcm::v3::MultiLocation;
+
+    pub fn transfer<T: crate::Config>(who: T::AccountId, amount: &Amount<T>, dest: MultiLocation) -> DispatchResult {
+        T::XcmTransfer::transfer(who, amount.currency(), amount.amount(), dest)
+    }
+}
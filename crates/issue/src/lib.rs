@@ -29,20 +29,29 @@ mod ext;
 pub mod types;
 
 #[doc(inline)]
-pub use crate::types::{DefaultIssueRequest, IssueRequest, IssueRequestStatus};
+pub use crate::types::{DefaultIssueRequest, DefaultIssueRequestQuery, IssueRequest, IssueRequestStatus};
 
-use crate::types::{BalanceOf, DefaultVaultId, Version};
+use crate::types::{BalanceOf, DefaultVaultId, MigrationCursor, Version};
 use bitcoin::{merkle::PartialTransactionProof, types::FullTransactionProof};
 use btc_relay::{BtcAddress, BtcPublicKey};
-use currency::Amount;
-use frame_support::{dispatch::DispatchError, ensure, pallet_prelude::Weight, traits::Get, transactional, PalletId};
+use currency::{Amount, Rounding};
+use frame_support::{
+    dispatch::DispatchError, ensure, pallet_prelude::Weight, storage::with_storage_layer, traits::Get, transactional,
+    PalletId,
+};
 use frame_system::{ensure_root, ensure_signed};
 pub use pallet::*;
+use primitives::query::RequestPage;
 use sp_core::H256;
-use sp_runtime::traits::{AccountIdConversion, Convert, Saturating};
-use sp_std::vec::Vec;
+use sp_runtime::traits::{AccountIdConversion, Convert, Saturating, Zero};
+use sp_std::{boxed::Box, vec::Vec};
+use traits::XcmTransferApi;
 use types::IssueRequestExt;
-use vault_registry::{types::CurrencyId, CurrencySource, VaultStatus};
+use vault_registry::{
+    types::{CurrencyId, DefaultVaultCurrencyPair},
+    CurrencySource, VaultStatus,
+};
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
 /// Complexity:
 /// - `O(H + I + O + B)` where:
@@ -94,6 +103,18 @@ pub mod pallet {
 
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
+
+        /// Number of blocks after the end of their issue period that completed and cancelled
+        /// requests are kept in storage.
+        #[pallet::constant]
+        type RequestRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of requests that are checked for expiry or removal per block.
+        #[pallet::constant]
+        type MaxSweptRequestsPerBlock: Get<u32>;
+
+        /// Forwards issued tokens to requesters on other chains.
+        type XcmTransfer: XcmTransferApi<Self::AccountId, CurrencyId<Self>, BalanceOf<Self>, MultiLocation>;
     }
 
     #[pallet::event]
@@ -131,6 +152,18 @@ pub mod pallet {
         IssuePeriodChange {
             period: BlockNumberFor<T>,
         },
+        IssueForwarded {
+            issue_id: H256,
+            requester: T::AccountId,
+            amount: BalanceOf<T>,
+            destination: MultiLocation,
+        },
+        IssueForwardingFailed {
+            issue_id: H256,
+            requester: T::AccountId,
+            amount: BalanceOf<T>,
+            error: DispatchError,
+        },
     }
 
     #[pallet::error]
@@ -153,6 +186,10 @@ pub mod pallet {
         InvalidExecutor,
         /// Issue amount is too small.
         AmountBelowDustAmount,
+        /// Destination uses an unsupported XCM version.
+        InvalidDestination,
+        /// Issue fee is higher than the maximum fee accepted by the requester.
+        FeeAboveMaximum,
     }
 
     /// Users create issue requests to issue tokens. This mapping provides access
@@ -174,6 +211,36 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type IssueBtcDustValue<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// The location on another chain that the tokens of an issue request are forwarded to once
+    /// it is executed. Requests without an entry are issued to the requester on this chain.
+    #[pallet::storage]
+    pub(super) type IssueDestinations<T: Config> = StorageMap<_, Blake2_128Concat, H256, MultiLocation, OptionQuery>;
+
+    /// The last issue request checked by the request sweeper, which continues after it in the
+    /// next block.
+    #[pallet::storage]
+    pub(super) type RequestSweepCursor<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+    /// Index of the issue requests made by each account.
+    #[pallet::storage]
+    pub(super) type IssueRequestsByAccount<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Index of the issue requests to each vault, by the account of the vault.
+    #[pallet::storage]
+    pub(super) type IssueRequestsByVault<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Progress of the migration that reserves the deposit addresses of the existing issue
+    /// requests in the vault registry. Requests are not swept while this is set.
+    #[pallet::storage]
+    pub(super) type DepositAddressMigration<T: Config> = StorageValue<_, MigrationCursor, OptionQuery>;
+
+    /// Progress of the migration that indexes the existing issue requests by requester and
+    /// vault. The indexes are incomplete while this is set, so lookups scan all requests.
+    #[pallet::storage]
+    pub(super) type RequestIndexMigration<T: Config> = StorageValue<_, MigrationCursor, OptionQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V4
@@ -196,6 +263,21 @@ pub mod pallet {
         fn build(&self) {
             IssuePeriod::<T>::put(self.issue_period);
             IssueBtcDustValue::<T>::put(self.issue_btc_dust_value);
+            StorageVersion::<T>::put(Version::V7);
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // swept requests would be missed by the migration
+            if DepositAddressMigration::<T>::exists() {
+                Self::migrate_deposit_addresses(remaining_weight)
+            } else if RequestIndexMigration::<T>::exists() {
+                Self::migrate_request_indexes(remaining_weight)
+            } else {
+                Self::sweep_requests(remaining_weight)
+            }
         }
     }
 
@@ -214,6 +296,11 @@ pub mod pallet {
         /// amount of issued tokens received will be less, because a fee is subtracted.
         /// * `vault` - address of the vault
         /// * `griefing_collateral` - amount of collateral
+        /// * `destination` - optional account on another chain to forward the issued tokens to.
+        /// Fees for execution on the destination chain are paid from the forwarded tokens. If
+        /// forwarding fails, the issued tokens are kept by the requester on this chain.
+        /// * `max_fee` - optional maximum issue fee the requester accepts, protecting against
+        /// the vault raising its fee before the request is included
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::request_issue())]
         #[transactional]
@@ -222,12 +309,44 @@ pub mod pallet {
             #[pallet::compact] amount: BalanceOf<T>,
             vault_id: DefaultVaultId<T>,
             griefing_currency: CurrencyId<T>,
+            destination: Option<Box<VersionedMultiLocation>>,
+            max_fee: Option<BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let requester = ensure_signed(origin)?;
-            Self::_request_issue(requester, amount, vault_id, griefing_currency)?;
+            let destination = destination
+                .map(|destination| MultiLocation::try_from(*destination).map_err(|_| Error::<T>::InvalidDestination))
+                .transpose()?;
+            Self::_request_issue(requester, amount, vault_id, griefing_currency, destination, max_fee)?;
             Ok(().into())
         }
 
+        /// Request the issuance of tokens from vaults selected on-chain, rather than from a vault
+        /// chosen by the caller. The request is split across several vaults if no single vault can
+        /// issue the full amount, creating one issue request (and `RequestIssue` event) per vault.
+        /// See `vault_registry::Pallet::select_vaults_for_issue` for the selection policy.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - sender of the transaction
+        /// * `amount` - amount of BTC the user wants to convert to issued tokens, including fees
+        /// * `currency_pair` - collateral and wrapped currency of the vaults to issue from
+        /// * `griefing_currency` - currency of the griefing collateral
+        /// * `max_fee` - optional maximum of the summed issue fees of all created requests
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_issue_auto(vault_registry::MAX_VAULTS_SCANNED_PER_SELECTION))]
+        #[transactional]
+        pub fn request_issue_auto(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            griefing_currency: CurrencyId<T>,
+            max_fee: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let requester = ensure_signed(origin)?;
+            let (_, scanned) = Self::_request_issue_auto(requester, amount, currency_pair, griefing_currency, max_fee)?;
+            Ok(Some(<T as Config>::WeightInfo::request_issue_auto(scanned)).into())
+        }
+
         /// Finalize the issuance of tokens
         ///
         /// # Arguments
@@ -238,7 +357,7 @@ pub mod pallet {
         /// * `merkle_proof` - raw bytes
         /// * `raw_tx` - raw bytes
         #[pallet::call_index(1)]
-        #[pallet::weight(weight_for_execute_issue::<T>(unchecked_transaction))]
+        #[pallet::weight(weight_for_execute_issue::<T>(unchecked_transaction).saturating_add(T::XcmTransfer::transfer_weight()))]
         #[transactional]
         pub fn execute_issue(
             origin: OriginFor<T>,
@@ -246,8 +365,15 @@ pub mod pallet {
             unchecked_transaction: FullTransactionProof,
         ) -> DispatchResultWithPostInfo {
             let executor = ensure_signed(origin)?;
+            let weight = weight_for_execute_issue::<T>(&unchecked_transaction);
+            // refund the transfer weight if the tokens are not forwarded to another chain
+            let forwarded = IssueDestinations::<T>::contains_key(issue_id);
             Self::_execute_issue(executor, issue_id, unchecked_transaction)?;
-            Ok(().into())
+            if forwarded {
+                Ok(().into())
+            } else {
+                Ok(Some(weight).into())
+            }
         }
 
         /// Cancel the issuance of tokens if expired
@@ -292,12 +418,15 @@ impl<T: Config> Pallet<T> {
         T::TreasuryPalletId::get().into_account_truncating()
     }
 
-    /// Requests CBA issuance, returns unique tracking ID.
-    fn _request_issue(
+    /// Requests the issuance of tokens from the given vault, which are forwarded to `destination`
+    /// on another chain once executed, if any.
+    pub fn _request_issue(
         requester: T::AccountId,
         amount_requested: BalanceOf<T>,
         vault_id: DefaultVaultId<T>,
         griefing_currency: CurrencyId<T>,
+        destination: Option<MultiLocation>,
+        max_fee: Option<BalanceOf<T>>,
     ) -> Result<H256, DispatchError> {
         let amount_requested = Amount::new(amount_requested, vault_id.wrapped_currency());
 
@@ -330,7 +459,10 @@ impl<T: Config> Pallet<T> {
 
         ext::vault_registry::try_increase_to_be_issued_tokens::<T>(&vault_id, &amount_requested)?;
 
-        let fee = ext::fee::get_issue_fee::<T>(&amount_requested)?;
+        let fee = ext::fee::get_issue_fee::<T>(&vault_id, &amount_requested)?;
+        if let Some(max_fee) = max_fee {
+            ensure!(fee.amount() <= max_fee, Error::<T>::FeeAboveMaximum);
+        }
         // calculate the amount of tokens that will be transferred to the user upon execution
         let amount_user = amount_requested.checked_sub(&fee)?;
 
@@ -353,6 +485,9 @@ impl<T: Config> Pallet<T> {
             status: IssueRequestStatus::Pending,
         };
         Self::insert_issue_request(&issue_id, &request);
+        if let Some(destination) = destination {
+            IssueDestinations::<T>::insert(issue_id, destination);
+        }
 
         Self::deposit_event(Event::RequestIssue {
             issue_id,
@@ -368,6 +503,40 @@ impl<T: Config> Pallet<T> {
         Ok(issue_id)
     }
 
+    /// Requests CBA issuance from vaults selected on-chain, returns the tracking IDs of the
+    /// request of each selected vault and the number of vaults that were scanned to select them.
+    fn _request_issue_auto(
+        requester: T::AccountId,
+        amount_requested: BalanceOf<T>,
+        currency_pair: DefaultVaultCurrencyPair<T>,
+        griefing_currency: CurrencyId<T>,
+        max_fee: Option<BalanceOf<T>>,
+    ) -> Result<(Vec<H256>, u32), DispatchError> {
+        let amount_requested = Amount::new(amount_requested, currency_pair.wrapped);
+        let seed = ext::security::get_secure_id::<T>(&requester);
+
+        let (selected, scanned) =
+            ext::vault_registry::select_vaults_for_issue::<T>(&currency_pair, &amount_requested, Some(seed))?;
+        let mut remaining_fee = max_fee;
+        let mut issue_ids = Vec::with_capacity(selected.len());
+        for (vault_id, amount) in selected {
+            let issue_id = Self::_request_issue(
+                requester.clone(),
+                amount.amount(),
+                vault_id,
+                griefing_currency,
+                None,
+                remaining_fee,
+            )?;
+            if let Some(ref mut remaining_fee) = remaining_fee {
+                let fee = Self::get_issue_request_from_id(&issue_id)?.fee;
+                *remaining_fee = remaining_fee.saturating_sub(fee);
+            }
+            issue_ids.push(issue_id);
+        }
+        Ok((issue_ids, scanned))
+    }
+
     /// Completes CBA issuance, removing request from storage and minting token.
     fn _execute_issue(
         executor: T::AccountId,
@@ -436,6 +605,10 @@ impl<T: Config> Pallet<T> {
         // mint issued tokens
         issue_amount.mint_to(&requester)?;
 
+        if let Some(destination) = IssueDestinations::<T>::take(issue_id) {
+            Self::forward_issued_tokens(issue_id, &requester, &issue_amount, destination);
+        }
+
         // mint wrapped fees
         issue_fee.mint_to(&ext::fee::fee_pool_account_id::<T>())?;
 
@@ -443,6 +616,7 @@ impl<T: Config> Pallet<T> {
         ext::fee::distribute_rewards::<T>(&issue_fee)?;
 
         Self::set_issue_status(issue_id, IssueRequestStatus::Completed);
+        ext::vault_registry::record_issue_execution::<T>(&issue.vault);
 
         Self::deposit_event(Event::ExecuteIssue {
             issue_id,
@@ -454,6 +628,25 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Forwards the issued tokens to the requester's account on another chain. If the transfer
+    /// fails the tokens are kept by the requester on this chain, so the issue still completes.
+    fn forward_issued_tokens(issue_id: H256, requester: &T::AccountId, amount: &Amount<T>, destination: MultiLocation) {
+        match with_storage_layer(|| ext::xcm_transfer::transfer::<T>(requester.clone(), amount, destination)) {
+            Ok(()) => Self::deposit_event(Event::IssueForwarded {
+                issue_id,
+                requester: requester.clone(),
+                amount: amount.amount(),
+                destination,
+            }),
+            Err(error) => Self::deposit_event(Event::IssueForwardingFailed {
+                issue_id,
+                requester: requester.clone(),
+                amount: amount.amount(),
+                error,
+            }),
+        }
+    }
+
     /// Cancels CBA issuance if time has expired and slashes collateral.
     fn _cancel_issue(requester: T::AccountId, issue_id: H256) -> Result<(), DispatchError> {
         let issue = Self::get_pending_issue(&issue_id)?;
@@ -512,6 +705,7 @@ impl<T: Config> Pallet<T> {
         ext::vault_registry::decrease_to_be_issued_tokens::<T>(&issue.vault, &full_amount)?;
 
         Self::set_issue_status(issue_id, IssueRequestStatus::Cancelled);
+        ext::vault_registry::record_issue_cancellation::<T>(&issue.vault);
 
         Self::deposit_event(Event::CancelIssue {
             issue_id,
@@ -521,6 +715,156 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Cancels expired issue requests and removes completed and cancelled requests after the
+    /// retention period, continuing from where the previous block stopped.
+    pub(crate) fn sweep_requests(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(3, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        // charge the most expensive outcome, cancelling the request
+        let weight_per_request = T::DbWeight::get()
+            .reads(1)
+            .saturating_add(<T as Config>::WeightInfo::cancel_issue());
+        let previous_cursor = RequestSweepCursor::<T>::get();
+        let requests = match previous_cursor {
+            Some(issue_id) => IssueRequests::<T>::iter_from(IssueRequests::<T>::hashed_key_for(issue_id)),
+            None => IssueRequests::<T>::iter(),
+        };
+
+        let mut swept = Vec::new();
+        let mut cursor = None;
+        for (issue_id, issue) in requests {
+            if swept.len() as u32 >= T::MaxSweptRequestsPerBlock::get()
+                || consumed.saturating_add(weight_per_request).any_gt(remaining_weight)
+            {
+                cursor = swept.last().map(|(issue_id, _)| *issue_id).or(previous_cursor);
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+            swept.push((issue_id, issue));
+        }
+
+        let now = ext::security::active_block_number::<T>();
+        for (issue_id, issue) in swept {
+            let issue_period = Self::issue_period().max(issue.period);
+            match issue.status {
+                IssueRequestStatus::Pending => {
+                    if !ext::btc_relay::has_request_expired::<T>(issue.opentime, issue.btc_height, issue_period)
+                        .unwrap_or(false)
+                    {
+                        continue;
+                    }
+                    if let Err(err) = with_storage_layer(|| Self::_cancel_issue(issue.requester.clone(), issue_id)) {
+                        log::warn!("Failed to cancel expired issue {:?}: {:?}", issue_id, err);
+                    }
+                }
+                IssueRequestStatus::Completed | IssueRequestStatus::Cancelled => {
+                    let retained_until = issue
+                        .opentime
+                        .saturating_add(issue_period)
+                        .saturating_add(T::RequestRetentionPeriod::get());
+                    if now > retained_until {
+                        Self::remove_issue_request(&issue_id, &issue);
+                        // cancelled requests are never forwarded
+                        IssueDestinations::<T>::remove(issue_id);
+                    }
+                }
+            }
+        }
+
+        RequestSweepCursor::<T>::set(cursor);
+        consumed
+    }
+
+    /// Reserves the deposit addresses of the issue requests in the vault registry, continuing
+    /// from where the previous block stopped.
+    pub(crate) fn migrate_deposit_addresses(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut cursor = match DepositAddressMigration::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let mut requests = match cursor {
+            MigrationCursor::Start => IssueRequests::<T>::iter(),
+            MigrationCursor::After(issue_id) => {
+                IssueRequests::<T>::iter_from(IssueRequests::<T>::hashed_key_for(issue_id))
+            }
+        };
+
+        let weight_per_request = T::DbWeight::get().reads_writes(1, 1);
+        for _ in 0..T::MaxSweptRequestsPerBlock::get() {
+            if consumed.saturating_add(weight_per_request).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+
+            match requests.next() {
+                Some((issue_id, issue)) => {
+                    ext::vault_registry::reserve_address::<T>(&issue.vault, &issue.btc_address);
+                    cursor = MigrationCursor::After(issue_id);
+                }
+                None => {
+                    log::info!("Issue deposit address migration finished");
+                    DepositAddressMigration::<T>::kill();
+                    return consumed;
+                }
+            }
+        }
+
+        DepositAddressMigration::<T>::put(cursor);
+        consumed
+    }
+
+    /// Indexes the issue requests by requester and vault, continuing from where the previous
+    /// block stopped.
+    pub(crate) fn migrate_request_indexes(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut cursor = match RequestIndexMigration::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let mut requests = match cursor {
+            MigrationCursor::Start => IssueRequests::<T>::iter(),
+            MigrationCursor::After(issue_id) => {
+                IssueRequests::<T>::iter_from(IssueRequests::<T>::hashed_key_for(issue_id))
+            }
+        };
+
+        let weight_per_request = T::DbWeight::get().reads_writes(1, 2);
+        for _ in 0..T::MaxSweptRequestsPerBlock::get() {
+            if consumed.saturating_add(weight_per_request).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+
+            match requests.next() {
+                Some((issue_id, issue)) => {
+                    IssueRequestsByAccount::<T>::insert(&issue.requester, issue_id, ());
+                    IssueRequestsByVault::<T>::insert(&issue.vault.account_id, issue_id, ());
+                    cursor = MigrationCursor::After(issue_id);
+                }
+                None => {
+                    log::info!("Issue request index migration finished");
+                    RequestIndexMigration::<T>::kill();
+                    return consumed;
+                }
+            }
+        }
+
+        RequestIndexMigration::<T>::put(cursor);
+        consumed
+    }
+
     fn decrease_issue_amount(
         issue_id: &H256,
         issue: &mut DefaultIssueRequest<T>,
@@ -578,10 +922,13 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `account_id` - user account id
     pub fn get_issue_requests_for_account(account_id: T::AccountId) -> Vec<H256> {
-        <IssueRequests<T>>::iter()
-            .filter(|(_, request)| request.requester == account_id)
-            .map(|(key, _)| key)
-            .collect()
+        if RequestIndexMigration::<T>::exists() {
+            return <IssueRequests<T>>::iter()
+                .filter(|(_, request)| request.requester == account_id)
+                .map(|(key, _)| key)
+                .collect();
+        }
+        <IssueRequestsByAccount<T>>::iter_key_prefix(account_id).collect()
     }
 
     /// Fetch all issue requests for the specified vault.
@@ -590,10 +937,51 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `account_id` - vault account id
     pub fn get_issue_requests_for_vault(vault_id: T::AccountId) -> Vec<H256> {
-        <IssueRequests<T>>::iter()
-            .filter(|(_, request)| request.vault.account_id == vault_id)
-            .map(|(key, _)| key)
-            .collect()
+        if RequestIndexMigration::<T>::exists() {
+            return <IssueRequests<T>>::iter()
+                .filter(|(_, request)| request.vault.account_id == vault_id)
+                .map(|(key, _)| key)
+                .collect();
+        }
+        <IssueRequestsByVault<T>>::iter_key_prefix(vault_id).collect()
+    }
+
+    /// Fetch a page of the issue requests made by the specified account.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - user account id
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_issue_requests_page_for_account(
+        account_id: T::AccountId,
+        query: DefaultIssueRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultIssueRequest<T>> {
+        Self::get_issue_requests_page(Self::get_issue_requests_for_account(account_id), query)
+    }
+
+    /// Fetch a page of the issue requests to the specified vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - vault account id
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_issue_requests_page_for_vault(
+        vault_id: T::AccountId,
+        query: DefaultIssueRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultIssueRequest<T>> {
+        Self::get_issue_requests_page(Self::get_issue_requests_for_vault(vault_id), query)
+    }
+
+    fn get_issue_requests_page(
+        issue_ids: Vec<H256>,
+        query: DefaultIssueRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultIssueRequest<T>> {
+        let requests = issue_ids
+            .into_iter()
+            .filter_map(|issue_id| Some((issue_id, IssueRequests::<T>::get(issue_id)?)))
+            .filter(|(_, request)| query.matches_status(&request.status) && query.matches_vault(&request.vault))
+            .collect();
+        query.paginate(requests, |request| request.opentime)
     }
 
     pub fn get_issue_request_from_id(issue_id: &H256) -> Result<DefaultIssueRequest<T>, DispatchError> {
@@ -624,8 +1012,17 @@ impl<T: Config> Pallet<T> {
         transferred_btc: Amount<T>,
         confiscated_griefing_collateral: Amount<T>,
     ) -> Result<(), DispatchError> {
-        // Current vault can handle the surplus; update the issue request
-        issue.fee = ext::fee::get_issue_fee::<T>(&transferred_btc)?.amount();
+        // Current vault can handle the surplus; update the issue request. The fee is charged at
+        // the rate of the request, since the vault may have raised its fee (beyond the maximum
+        // fee of the requester) in the meantime
+        let requested_btc = issue.amount().checked_add(&issue.fee())?;
+        issue.fee = if requested_btc.is_zero() {
+            Zero::zero()
+        } else {
+            transferred_btc
+                .checked_rounded_mul(&issue.fee().ratio(&requested_btc)?, Rounding::NearestPrefUp)?
+                .amount()
+        };
         issue.amount = transferred_btc.checked_sub(&issue.fee())?.amount();
 
         // update storage
@@ -649,9 +1046,17 @@ impl<T: Config> Pallet<T> {
     }
 
     fn insert_issue_request(key: &H256, value: &DefaultIssueRequest<T>) {
+        <IssueRequestsByAccount<T>>::insert(&value.requester, key, ());
+        <IssueRequestsByVault<T>>::insert(&value.vault.account_id, key, ());
         <IssueRequests<T>>::insert(key, value)
     }
 
+    fn remove_issue_request(key: &H256, value: &DefaultIssueRequest<T>) {
+        <IssueRequestsByAccount<T>>::remove(&value.requester, key);
+        <IssueRequestsByVault<T>>::remove(&value.vault.account_id, key);
+        <IssueRequests<T>>::remove(key)
+    }
+
     fn set_issue_status(id: H256, status: IssueRequestStatus) {
         <IssueRequests<T>>::mutate_exists(id, |request| {
             *request = request
@@ -151,6 +151,8 @@ impl staking::Config for Test {
 
 parameter_types! {
     pub const VaultPalletId: PalletId = PalletId(*b"mod/vreg");
+    pub const PublicKeyRotationDelay: BlockNumber = 10;
+    pub const PublicKeyMigrationPeriod: BlockNumber = 20;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -166,6 +168,9 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = ();
 }
 
 impl nomination::Config for Test {
@@ -253,6 +258,8 @@ impl fee::Config for Test {
 
 parameter_types! {
     pub const TreasuryPalletId: PalletId = PalletId(*b"mod/trsy");
+    pub const RequestRetentionPeriod: BlockNumber = 100;
+    pub const MaxSweptRequestsPerBlock: u32 = 10;
 }
 
 pub struct BlockNumberToBalance;
@@ -268,6 +275,9 @@ impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type BlockNumberToBalance = BlockNumberToBalance;
     type WeightInfo = ();
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
+    type XcmTransfer = ();
 }
 
 pub type TestEvent = RuntimeEvent;
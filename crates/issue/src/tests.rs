@@ -1,16 +1,30 @@
-use crate::{ext, mock::*, Event, IssueRequest};
+use crate::{
+    ext,
+    mock::*,
+    types::{MigrationCursor, Version},
+    Event, IssueRequest,
+};
 
 use bitcoin::{merkle::PartialTransactionProof, types::FullTransactionProof};
 use btc_relay::{BtcAddress, BtcPublicKey};
 use currency::Amount;
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::DispatchError,
+    traits::{Get, Hooks, OnRuntimeUpgrade},
+    weights::Weight,
+};
 use mocktopus::mocking::*;
 use orml_traits::MultiCurrency;
-use primitives::issue::IssueRequestStatus;
+use primitives::{
+    issue::IssueRequestStatus,
+    query::{RequestQuery, SortOrder},
+};
 use sp_arithmetic::FixedU128;
 use sp_core::H256;
 use sp_runtime::traits::One;
 use vault_registry::{DefaultVault, DefaultVaultId, Vault, VaultStatus};
+use xcm::v3::MultiLocation;
 
 fn griefing(amount: u128) -> Amount<Test> {
     Amount::new(amount, DEFAULT_NATIVE_CURRENCY)
@@ -27,7 +41,7 @@ fn request_issue(origin: AccountId, amount: Balance, vault: DefaultVaultId<Test>
     ext::vault_registry::register_deposit_address::<Test>
         .mock_safe(|_, _| MockResult::Return(Ok(BtcAddress::random())));
 
-    Issue::_request_issue(origin, amount, vault, DEFAULT_NATIVE_CURRENCY)
+    Issue::_request_issue(origin, amount, vault, DEFAULT_NATIVE_CURRENCY, None, None)
 }
 
 fn request_issue_ok(origin: AccountId, amount: Balance, vault: DefaultVaultId<Test>) -> H256 {
@@ -51,7 +65,7 @@ fn request_issue_ok_with_address(
         ext::vault_registry::register_deposit_address::<Test>.mock_raw(|_, _| MockResult::Return(Ok(address)));
     }
 
-    Issue::_request_issue(origin, amount, vault, DEFAULT_NATIVE_CURRENCY).unwrap()
+    Issue::_request_issue(origin, amount, vault, DEFAULT_NATIVE_CURRENCY, None, None).unwrap()
 }
 
 fn execute_issue(origin: AccountId, issue_id: &H256) -> Result<(), DispatchError> {
@@ -123,7 +137,7 @@ fn test_request_issue_succeeds() {
         ext::vault_registry::get_active_vault_from_id::<Test>
             .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
 
-        ext::fee::get_issue_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(issue_fee))));
+        ext::fee::get_issue_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(issue_fee))));
 
         ext::fee::get_issue_griefing_collateral::<Test>
             .mock_safe(move |_| MockResult::Return(Ok(griefing(issue_griefing_collateral))));
@@ -145,6 +159,93 @@ fn test_request_issue_succeeds() {
     })
 }
 
+#[test]
+fn test_request_issue_fails_with_fee_above_maximum() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::try_increase_to_be_issued_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+        ext::fee::get_issue_fee::<Test>.mock_safe(|_, _| MockResult::Return(Ok(wrapped(2))));
+
+        assert_noop!(
+            Issue::_request_issue(USER, 3, VAULT, DEFAULT_NATIVE_CURRENCY, None, Some(1)),
+            TestError::FeeAboveMaximum
+        );
+    })
+}
+
+#[test]
+fn test_request_issue_auto_limits_the_summed_fees() {
+    run_test(|| {
+        let other_vault = DefaultVaultId::<Test> { account_id: 3, ..VAULT };
+        let selected_vaults = vec![(VAULT, wrapped(3)), (other_vault.clone(), wrapped(2))];
+
+        ext::vault_registry::select_vaults_for_issue::<Test>
+            .mock_safe(move |_, _, _| MockResult::Return(Ok((selected_vaults.clone(), 2))));
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|vault_id| MockResult::Return(Ok(init_zero_vault(vault_id.clone()))));
+        ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::try_increase_to_be_issued_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+        ext::vault_registry::get_bitcoin_public_key::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(BtcPublicKey::default())));
+        ext::vault_registry::register_deposit_address::<Test>
+            .mock_safe(|_, _| MockResult::Return(Ok(BtcAddress::random())));
+        ext::fee::get_issue_fee::<Test>.mock_safe(|_, _| MockResult::Return(Ok(wrapped(1))));
+
+        // each request is within the maximum, but not both of them
+        assert_err!(
+            Issue::_request_issue_auto(USER, 5, VAULT.currencies.clone(), DEFAULT_NATIVE_CURRENCY, Some(1)),
+            TestError::FeeAboveMaximum
+        );
+        assert_ok!(Issue::_request_issue_auto(
+            USER,
+            5,
+            VAULT.currencies,
+            DEFAULT_NATIVE_CURRENCY,
+            Some(2)
+        ));
+    })
+}
+
+#[test]
+fn test_request_issue_auto_requests_from_each_selected_vault() {
+    run_test(|| {
+        let other_vault = DefaultVaultId::<Test> { account_id: 3, ..VAULT };
+        let selected_vaults = vec![(VAULT, wrapped(3)), (other_vault.clone(), wrapped(2))];
+        let issue_fee = 1;
+
+        ext::vault_registry::select_vaults_for_issue::<Test>
+            .mock_safe(move |_, _, _| MockResult::Return(Ok((selected_vaults.clone(), 2))));
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|vault_id| MockResult::Return(Ok(init_zero_vault(vault_id.clone()))));
+        ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::try_increase_to_be_issued_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+        ext::vault_registry::get_bitcoin_public_key::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(BtcPublicKey::default())));
+        ext::vault_registry::register_deposit_address::<Test>
+            .mock_safe(|_, _| MockResult::Return(Ok(BtcAddress::random())));
+        ext::fee::get_issue_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(issue_fee))));
+
+        let (issue_ids, scanned) =
+            Issue::_request_issue_auto(USER, 5, VAULT.currencies, DEFAULT_NATIVE_CURRENCY, None).unwrap();
+        assert_eq!(issue_ids.len(), 2);
+        assert_eq!(scanned, 2);
+
+        let issued_amounts = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                TestEvent::Issue(Event::RequestIssue { vault_id, amount, .. }) => Some((vault_id, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            issued_amounts,
+            vec![(VAULT, 3 - issue_fee), (other_vault, 2 - issue_fee)]
+        );
+    })
+}
+
 #[test]
 fn test_execute_issue_not_found_fails() {
     run_test(|| {
@@ -164,7 +265,7 @@ fn setup_execute(
     ext::vault_registry::issue_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
     ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(|_| MockResult::Return(Ok(false)));
 
-    ext::fee::get_issue_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(issue_fee))));
+    ext::fee::get_issue_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(issue_fee))));
     ext::fee::get_issue_griefing_collateral::<Test>
         .mock_safe(move |_| MockResult::Return(Ok(griefing(griefing_collateral))));
 
@@ -205,6 +306,54 @@ fn test_execute_issue_succeeds() {
     })
 }
 
+#[test]
+fn test_execute_issue_forwards_tokens_to_destination() {
+    run_test(|| {
+        let issue_id = setup_execute(3, 1, 1, 3);
+        crate::IssueDestinations::<Test>::insert(issue_id, MultiLocation::parent());
+        ext::xcm_transfer::transfer::<Test>.mock_safe(|who, amount, dest| {
+            assert_eq!(who, USER);
+            assert_eq!(amount, &wrapped(2));
+            assert_eq!(dest, MultiLocation::parent());
+            MockResult::Return(Ok(()))
+        });
+        assert_ok!(execute_issue(USER, &issue_id));
+
+        assert!(System::events().iter().any(|a| a.event
+            == TestEvent::Issue(Event::IssueForwarded {
+                issue_id,
+                requester: USER,
+                amount: 2,
+                destination: MultiLocation::parent(),
+            })));
+        assert!(!crate::IssueDestinations::<Test>::contains_key(issue_id));
+    })
+}
+
+#[test]
+fn test_execute_issue_keeps_tokens_if_forwarding_fails() {
+    run_test(|| {
+        let issue_id = setup_execute(3, 1, 1, 3);
+        crate::IssueDestinations::<Test>::insert(issue_id, MultiLocation::parent());
+        ext::xcm_transfer::transfer::<Test>
+            .mock_safe(|_, _, _| MockResult::Return(Err(DispatchError::Other("unroutable"))));
+        assert_ok!(execute_issue(USER, &issue_id));
+
+        assert!(System::events().iter().any(|a| a.event
+            == TestEvent::Issue(Event::IssueForwardingFailed {
+                issue_id,
+                requester: USER,
+                amount: 2,
+                error: DispatchError::Other("unroutable"),
+            })));
+        assert_eq!(Tokens::free_balance(DEFAULT_WRAPPED_CURRENCY, &USER), 2);
+        assert_eq!(
+            Issue::issue_requests(&issue_id).unwrap().status,
+            IssueRequestStatus::Completed
+        );
+    })
+}
+
 #[test]
 fn test_execute_issue_overpayment_succeeds() {
     run_test(|| {
@@ -265,6 +414,24 @@ fn test_execute_issue_overpayment_up_to_max_succeeds() {
     })
 }
 
+#[test]
+fn test_execute_issue_overpayment_charges_fee_rate_of_request() {
+    run_test(|| {
+        // fee of 10%
+        let issue_id = setup_execute(10, 1, 0, 20);
+        ext::vault_registry::get_issuable_tokens_from_vault::<Test>.mock_safe(|_| MockResult::Return(Ok(wrapped(10))));
+        ext::vault_registry::try_increase_to_be_issued_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+        // the vault raises its fee after the request
+        ext::fee::get_issue_fee::<Test>.mock_safe(|_, amount| MockResult::Return(Ok(amount.clone())));
+
+        assert_ok!(execute_issue(USER, &issue_id));
+        assert!(matches!(
+            Issue::issue_requests(&issue_id),
+            Some(IssueRequest { amount: 18, fee: 2, .. })
+        ));
+    })
+}
+
 #[test]
 fn test_execute_issue_underpayment_succeeds() {
     run_test(|| {
@@ -393,6 +560,234 @@ fn test_cancel_issue_expired_succeeds() {
     })
 }
 
+#[test]
+fn test_sweep_requests_cancels_expired_and_removes_old_issues() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::vault_registry::decrease_to_be_issued_tokens::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(())));
+        ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(move |_| MockResult::Return(Ok(false)));
+        ext::vault_registry::transfer_funds::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+
+        let issue_id = request_issue_ok(USER, 300, VAULT);
+
+        // the request has not expired yet
+        <security::Pallet<Test>>::set_active_block_number(5);
+        Issue::sweep_requests(Weight::MAX);
+        assert_eq!(
+            Issue::issue_requests(&issue_id).unwrap().status,
+            IssueRequestStatus::Pending
+        );
+
+        ext::btc_relay::has_request_expired::<Test>.mock_safe(move |_, _, _| MockResult::Return(Ok(true)));
+        <security::Pallet<Test>>::set_active_block_number(12);
+        Issue::sweep_requests(Weight::MAX);
+        assert_eq!(
+            Issue::issue_requests(&issue_id).unwrap().status,
+            IssueRequestStatus::Cancelled
+        );
+
+        // issue period is 10 and retention period 100, we issued at block 1, so the request
+        // is kept until block 111
+        <security::Pallet<Test>>::set_active_block_number(111);
+        Issue::sweep_requests(Weight::MAX);
+        assert!(Issue::issue_requests(&issue_id).is_some());
+
+        <security::Pallet<Test>>::set_active_block_number(112);
+        Issue::sweep_requests(Weight::MAX);
+        assert!(Issue::issue_requests(&issue_id).is_none());
+        assert!(Issue::get_issue_requests_for_account(USER).is_empty());
+        assert!(Issue::get_issue_requests_for_vault(VAULT.account_id).is_empty());
+    })
+}
+
+#[test]
+fn test_deposit_address_migration_reserves_addresses_of_existing_issues() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+
+        // more requests than are migrated in a single block
+        let issue_id = request_issue_ok(USER, 300, VAULT);
+        let issue = Issue::issue_requests(&issue_id).unwrap();
+        let addresses = (0..<Test as crate::Config>::MaxSweptRequestsPerBlock::get() + 2)
+            .map(|_| {
+                let address = BtcAddress::random();
+                let request = IssueRequest {
+                    btc_address: address,
+                    ..issue.clone()
+                };
+                crate::IssueRequests::<Test>::insert(H256::random(), request);
+                address
+            })
+            .collect::<Vec<_>>();
+
+        crate::DepositAddressMigration::<Test>::put(MigrationCursor::Start);
+        Issue::on_idle(1, Weight::MAX);
+        assert!(crate::DepositAddressMigration::<Test>::exists());
+        Issue::on_idle(2, Weight::MAX);
+        assert!(!crate::DepositAddressMigration::<Test>::exists());
+
+        for address in addresses.iter().chain([issue.btc_address].iter()) {
+            assert!(VaultRegistry::is_vault_address(&VAULT, address));
+        }
+    })
+}
+
+#[test]
+fn test_request_index_migration_indexes_existing_issues() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+
+        let issue_id = request_issue_ok(USER, 300, VAULT);
+        let issue = Issue::issue_requests(&issue_id).unwrap();
+        // more requests than are migrated in a single block, stored without indexes
+        let mut issue_ids = (0..<Test as crate::Config>::MaxSweptRequestsPerBlock::get() + 2)
+            .map(|_| {
+                let issue_id = H256::random();
+                crate::IssueRequests::<Test>::insert(issue_id, issue.clone());
+                issue_id
+            })
+            .collect::<Vec<_>>();
+        issue_ids.push(issue_id);
+        issue_ids.sort();
+
+        crate::RequestIndexMigration::<Test>::put(MigrationCursor::Start);
+        // requests are found by scanning until the indexes are complete
+        let mut found = Issue::get_issue_requests_for_account(USER);
+        found.sort();
+        assert_eq!(found, issue_ids);
+
+        Issue::on_idle(1, Weight::MAX);
+        assert!(crate::RequestIndexMigration::<Test>::exists());
+        Issue::on_idle(2, Weight::MAX);
+        assert!(!crate::RequestIndexMigration::<Test>::exists());
+
+        let mut found = Issue::get_issue_requests_for_account(USER);
+        found.sort();
+        assert_eq!(found, issue_ids);
+        let mut found = Issue::get_issue_requests_for_vault(VAULT.account_id);
+        found.sort();
+        assert_eq!(found, issue_ids);
+    })
+}
+
+#[test]
+fn test_runtime_upgrade_from_unset_storage_version_migrates_existing_issues() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+
+        let issue_id = request_issue_ok(USER, 300, VAULT);
+        let issue = Issue::issue_requests(&issue_id).unwrap();
+        // a request stored before the upgrade, without index or reserved address
+        let old_issue_id = H256::random();
+        let old_issue = IssueRequest {
+            btc_address: BtcAddress::random(),
+            ..issue.clone()
+        };
+        crate::IssueRequests::<Test>::insert(old_issue_id, old_issue.clone());
+
+        // live chains never wrote the storage version
+        crate::StorageVersion::<Test>::kill();
+        crate::types::v4::Migration::<Test>::on_runtime_upgrade();
+        crate::types::v5::Migration::<Test>::on_runtime_upgrade();
+        crate::types::v6::Migration::<Test>::on_runtime_upgrade();
+        assert!(matches!(Issue::storage_version(), Version::V7));
+        assert!(crate::DepositAddressMigration::<Test>::exists());
+        assert!(crate::RequestIndexMigration::<Test>::exists());
+
+        for n in 1..=3 {
+            Issue::on_idle(n, Weight::MAX);
+        }
+        assert!(!crate::DepositAddressMigration::<Test>::exists());
+        assert!(!crate::RequestIndexMigration::<Test>::exists());
+
+        assert!(VaultRegistry::is_vault_address(&VAULT, &old_issue.btc_address));
+        let mut found = Issue::get_issue_requests_for_account(USER);
+        found.sort();
+        let mut expected = vec![issue_id, old_issue_id];
+        expected.sort();
+        assert_eq!(found, expected);
+    })
+}
+
+#[test]
+fn test_get_issue_requests_page_filters_sorts_and_paginates() {
+    run_test(|| {
+        ext::vault_registry::get_active_vault_from_id::<Test>
+            .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+
+        let issue_id = request_issue_ok(USER, 300, VAULT);
+        let request = Issue::issue_requests(&issue_id).unwrap();
+        let statuses = [
+            IssueRequestStatus::Completed,
+            IssueRequestStatus::Pending,
+            IssueRequestStatus::Cancelled,
+        ];
+        for (i, status) in statuses.into_iter().enumerate() {
+            Issue::insert_issue_request(
+                &H256::repeat_byte(i as u8 + 1),
+                &IssueRequest {
+                    opentime: i as u64 + 10,
+                    status,
+                    ..request.clone()
+                },
+            );
+        }
+
+        let query = RequestQuery {
+            status: None,
+            vault: None,
+            currency: None,
+            order: SortOrder::Descending,
+            offset: 1,
+            limit: 2,
+        };
+        let page = Issue::get_issue_requests_page_for_account(USER, query.clone());
+        assert_eq!(page.total, 4);
+        let ids: Vec<_> = page.requests.into_iter().map(|(issue_id, _)| issue_id).collect();
+        assert_eq!(ids, vec![H256::repeat_byte(2), H256::repeat_byte(1)]);
+
+        let page = Issue::get_issue_requests_page_for_vault(
+            VAULT.account_id,
+            RequestQuery {
+                status: Some(IssueRequestStatus::Pending),
+                order: SortOrder::Ascending,
+                offset: 0,
+                ..query.clone()
+            },
+        );
+        assert_eq!(page.total, 2);
+        let ids: Vec<_> = page.requests.into_iter().map(|(issue_id, _)| issue_id).collect();
+        assert_eq!(ids, vec![issue_id, H256::repeat_byte(2)]);
+
+        let other_vault = DefaultVaultId::<Test> { account_id: 3, ..VAULT };
+        let page = Issue::get_issue_requests_page_for_account(
+            USER,
+            RequestQuery {
+                vault: Some(other_vault),
+                ..query.clone()
+            },
+        );
+        assert_eq!(page.total, 0);
+        let page = Issue::get_issue_requests_page_for_account(
+            USER,
+            RequestQuery {
+                currency: Some(DEFAULT_WRAPPED_CURRENCY),
+                ..query
+            },
+        );
+        assert_eq!(page.total, 0);
+    })
+}
+
 #[test]
 fn test_set_issue_period_only_root() {
     run_test(|| {
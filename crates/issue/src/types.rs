@@ -2,8 +2,9 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use currency::Amount;
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use primitives::issue::{IssueRequest, IssueRequestStatus};
-use primitives::VaultId;
+use primitives::{query::RequestQuery, VaultId};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use vault_registry::types::CurrencyId;
 
 use crate::Config;
@@ -21,6 +22,94 @@ pub enum Version {
     V3,
     /// Removed refund
     V4,
+    /// Deposit addresses are reserved in the vault registry
+    V5,
+    /// Expired requests are cancelled, and finished requests removed, by the request sweeper
+    V6,
+    /// Requests are indexed by requester and vault
+    V7,
+}
+
+/// Position of a migration that visits all issue requests over several blocks.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MigrationCursor {
+    /// No request was visited yet.
+    Start,
+    /// Continue after the request with this id.
+    After(H256),
+}
+
+pub mod v4 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Deposit addresses registered before they were reserved in the vault registry are
+    /// reserved over the following blocks, see `Pallet::migrate_deposit_addresses`.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V4) {
+                log::info!("Not running issue deposit address migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::DepositAddressMigration::<T>::put(MigrationCursor::Start);
+            crate::StorageVersion::<T>::put(Version::V5);
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+    }
+}
+
+pub mod v5 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Finished requests are removed gradually by the request sweeper, so only the version changes.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V5) {
+                log::info!("Not running issue request sweeper migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::StorageVersion::<T>::put(Version::V6);
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
+
+pub mod v6 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// The existing requests are indexed over the following blocks, see
+    /// `Pallet::migrate_request_indexes`.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V6) {
+                log::info!("Not running issue request index migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::RequestIndexMigration::<T>::put(MigrationCursor::Start);
+            crate::StorageVersion::<T>::put(Version::V7);
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+    }
 }
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
@@ -30,6 +119,9 @@ pub(crate) type DefaultVaultId<T> = VaultId<<T as frame_system::Config>::Account
 pub type DefaultIssueRequest<T> =
     IssueRequest<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>, CurrencyId<T>>;
 
+pub type DefaultIssueRequestQuery<T> =
+    RequestQuery<<T as frame_system::Config>::AccountId, CurrencyId<T>, IssueRequestStatus>;
+
 pub trait IssueRequestExt<T: Config> {
     fn amount(&self) -> Amount<T>;
     fn fee(&self) -> Amount<T>;
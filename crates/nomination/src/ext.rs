@@ -16,6 +16,10 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::vault_exists(vault_id)
     }
 
+    pub fn ensure_not_exiting<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        <vault_registry::Pallet<T>>::ensure_not_exiting(vault_id)
+    }
+
     pub fn compute_collateral<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> Result<Amount<T>, DispatchError> {
         <vault_registry::Pallet<T>>::compute_collateral(vault_id)
     }
@@ -309,6 +309,7 @@ impl<T: Config> Pallet<T> {
             ext::vault_registry::vault_exists::<T>(&vault_id),
             Error::<T>::VaultNotFound
         );
+        ext::vault_registry::ensure_not_exiting::<T>(vault_id)?;
         ensure!(
             !<Vaults<T>>::contains_key(vault_id),
             Error::<T>::VaultAlreadyOptedInToNomination
@@ -374,8 +375,28 @@ impl<T: Config> traits::NominationApi<DefaultVaultId<T>, Amount<T>> for Pallet<T
         Ok(())
     }
 
+    fn ensure_vault_exists(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        ensure!(
+            ext::vault_registry::vault_exists::<T>(vault_id),
+            Error::<T>::VaultNotFound
+        );
+        Ok(())
+    }
+
+    fn opt_out_of_nomination(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        if Self::is_opted_in(vault_id) {
+            Self::_opt_out_of_nomination(vault_id)?;
+        }
+        Ok(())
+    }
+
     #[cfg(any(feature = "runtime-benchmarks", test))]
     fn opt_in_to_nomination(vault_id: &DefaultVaultId<T>) {
         Vaults::<T>::insert(vault_id, true);
     }
+
+    #[cfg(any(feature = "runtime-benchmarks", test))]
+    fn register_vault(vault_id: &DefaultVaultId<T>) {
+        vault_registry::Pallet::<T>::insert_vault(vault_id, vault_registry::Vault::new(vault_id.clone()));
+    }
 }
@@ -155,6 +155,8 @@ impl staking::Config for Test {
 
 parameter_types! {
     pub const VaultPalletId: PalletId = PalletId(*b"mod/vreg");
+    pub const PublicKeyRotationDelay: u64 = 10;
+    pub const PublicKeyMigrationPeriod: u64 = 20;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -170,6 +172,9 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = ();
 }
 
 pub struct CurrencyConvert;
@@ -22,6 +22,7 @@ fn collateral(amount: u128) -> Amount<Test> {
 fn should_deposit_against_valid_vault() {
     run_test(|| {
         ext::vault_registry::vault_exists::<Test>.mock_safe(|_| MockResult::Return(true));
+        ext::vault_registry::ensure_not_exiting::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
         ext::vault_registry::get_backing_collateral::<Test>.mock_safe(|_| MockResult::Return(Ok(collateral(10000))));
         ext::vault_registry::compute_collateral::<Test>.mock_safe(|_| MockResult::Return(Ok(collateral(10000))));
         ext::vault_registry::pool_manager::deposit_collateral::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
@@ -35,6 +36,19 @@ fn should_deposit_against_valid_vault() {
     })
 }
 
+#[test]
+fn should_not_opt_in_exiting_vault() {
+    run_test(|| {
+        ext::vault_registry::vault_exists::<Test>.mock_safe(|_| MockResult::Return(true));
+        ext::vault_registry::ensure_not_exiting::<Test>
+            .mock_safe(|_| MockResult::Return(Err(vault_registry::Error::<Test>::VaultExiting.into())));
+        assert_err!(
+            Nomination::_opt_in_to_nomination(&ALICE),
+            vault_registry::Error::<Test>::VaultExiting
+        );
+    })
+}
+
 #[test]
 fn should_not_withdraw_collateral() {
     use orml_traits::MultiCurrency;
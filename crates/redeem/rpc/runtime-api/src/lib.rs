@@ -7,13 +7,18 @@ use frame_support::dispatch::DispatchError;
 use oracle_rpc_runtime_api::BalanceWrapper;
 use sp_std::vec::Vec;
 
+pub use primitives::query::RequestPage;
+
 sp_api::decl_runtime_apis! {
-    pub trait RedeemApi<VaultId, Balance, AccountId, H256, RedeemRequest> where
+    /// `RequestQuery` is only used by the calls added in version 2
+    #[api_version(2)]
+    pub trait RedeemApi<VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery> where
         VaultId: Codec,
         Balance: Codec,
         AccountId: Codec,
         H256: Codec,
         RedeemRequest: Codec,
+        RequestQuery: Codec,
     {
         /// Get all redeem requests for a particular account
         fn get_redeem_requests(account_id: AccountId) -> Vec<H256>;
@@ -23,5 +28,13 @@ sp_api::decl_runtime_apis! {
 
         /// Get all vaults below the premium redeem threshold, ordered in descending order of this amount
         fn get_premium_redeem_vaults() -> Result<Vec<(VaultId, BalanceWrapper<Balance>)>, DispatchError>;
+
+        /// Get a page of the redeem requests for a particular account
+        #[api_version(2)]
+        fn get_redeem_requests_page(account_id: AccountId, query: RequestQuery) -> RequestPage<H256, RedeemRequest>;
+
+        /// Get a page of the redeem requests for a particular vault
+        #[api_version(2)]
+        fn get_vault_redeem_requests_page(vault_id: AccountId, query: RequestQuery) -> RequestPage<H256, RedeemRequest>;
     }
 }
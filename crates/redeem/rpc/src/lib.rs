@@ -7,7 +7,7 @@ use jsonrpsee::{
     types::error::{CallError, ErrorCode, ErrorObject},
 };
 use oracle_rpc_runtime_api::BalanceWrapper;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
     traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
@@ -15,7 +15,7 @@ use sp_runtime::{
 };
 use std::sync::Arc;
 
-pub use redeem_rpc_runtime_api::RedeemApi as RedeemRuntimeApi;
+pub use redeem_rpc_runtime_api::{RedeemApi as RedeemRuntimeApi, RequestPage};
 
 fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError>, E>, msg: String) -> RpcResult<T> {
     result
@@ -24,7 +24,7 @@ fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError
 }
 
 #[rpc(client, server)]
-pub trait RedeemApi<BlockHash, VaultId, Balance, AccountId, H256, RedeemRequest>
+pub trait RedeemApi<BlockHash, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>
 where
     Balance: Codec + MaybeDisplay + MaybeFromStr,
 {
@@ -34,6 +34,22 @@ where
     #[method(name = "redeem_getVaultRedeemRequests")]
     fn get_vault_redeem_requests(&self, vault_id: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
 
+    #[method(name = "redeem_getRedeemRequestsPage")]
+    fn get_redeem_requests_page(
+        &self,
+        account_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, RedeemRequest>>;
+
+    #[method(name = "redeem_getVaultRedeemRequestsPage")]
+    fn get_vault_redeem_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, RedeemRequest>>;
+
     #[method(name = "redeem_getPremiumRedeemVaults", aliases = ["vaultRegistry_getPremiumRedeemVaults"])]
     fn get_premium_redeem_vaults(&self, at: Option<BlockHash>) -> RpcResult<Vec<(VaultId, BalanceWrapper<Balance>)>>;
 }
@@ -46,6 +62,32 @@ fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
     )))
 }
 
+/// The paginated calls were added in version 2 of the runtime api
+fn ensure_api_version<C, Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+) -> RpcResult<()>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: RedeemRuntimeApi<Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>,
+    VaultId: Codec,
+    Balance: Codec + MaybeDisplay + MaybeFromStr,
+    AccountId: Codec,
+    H256: Codec,
+    RedeemRequest: Codec,
+    RequestQuery: Codec,
+{
+    let version = client
+        .runtime_api()
+        .api_version::<dyn RedeemRuntimeApi<Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>>(at)
+        .map_err(|e| internal_err(format!("Unable to get the runtime api version: {:?}", e)))?;
+    match version {
+        Some(version) if version >= 2 => Ok(()),
+        _ => Err(internal_err("Not supported by the runtime at this block")),
+    }
+}
+
 /// A struct that implements the [`RedeemApi`].
 pub struct Redeem<C, B> {
     client: Arc<C>,
@@ -63,17 +105,19 @@ impl<C, B> Redeem<C, B> {
 }
 
 #[async_trait]
-impl<C, Block, VaultId, Balance, AccountId, H256, RedeemRequest>
-    RedeemApiServer<<Block as BlockT>::Hash, VaultId, Balance, AccountId, H256, RedeemRequest> for Redeem<C, Block>
+impl<C, Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>
+    RedeemApiServer<<Block as BlockT>::Hash, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>
+    for Redeem<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: RedeemRuntimeApi<Block, VaultId, Balance, AccountId, H256, RedeemRequest>,
+    C::Api: RedeemRuntimeApi<Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>,
     VaultId: Codec,
     Balance: Codec + MaybeDisplay + MaybeFromStr,
     AccountId: Codec,
     H256: Codec,
     RedeemRequest: Codec,
+    RequestQuery: Codec,
 {
     fn get_redeem_requests(&self, account_id: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<H256>> {
         let api = self.client.runtime_api();
@@ -95,6 +139,40 @@ where
             .map_err(|e| internal_err(format!("Unable to fetch redeem requests: {:?}", e)))
     }
 
+    fn get_redeem_requests_page(
+        &self,
+        account_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, RedeemRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>(
+            &*self.client,
+            at,
+        )?;
+
+        api.get_redeem_requests_page(at, account_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch redeem requests: {:?}", e)))
+    }
+
+    fn get_vault_redeem_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, RedeemRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, VaultId, Balance, AccountId, H256, RedeemRequest, RequestQuery>(
+            &*self.client,
+            at,
+        )?;
+
+        api.get_vault_redeem_requests_page(at, vault_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch redeem requests: {:?}", e)))
+    }
+
     fn get_premium_redeem_vaults(
         &self,
         at: Option<<Block as BlockT>::Hash>,
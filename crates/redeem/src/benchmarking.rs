@@ -1,5 +1,5 @@
 use super::*;
-use bitcoin::types::{BlockBuilder, TransactionOutput};
+use bitcoin::types::{BlockBuilder, TransactionBuilder, TransactionOutput};
 use btc_relay::{BtcAddress, BtcPublicKey};
 use currency::getters::{get_relay_chain_currency_id as get_collateral_currency_id, *};
 use frame_benchmarking::v2::*;
@@ -8,7 +8,7 @@ use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use primitives::CurrencyId;
 use sp_core::{H256, U256};
-use sp_runtime::traits::One;
+use sp_runtime::traits::{One, Zero};
 use sp_std::{fmt::Debug, prelude::*};
 use vault_registry::{
     benchmarking::{activate_lending_and_get_vault_id, mint_lend_tokens},
@@ -90,7 +90,7 @@ fn initialize_and_mine_blocks_until_expiry<T: crate::Config>(request: &DefaultRe
     let init_block = BlockBuilder::new()
         .with_version(4)
         .with_coinbase(&BtcAddress::default(), 50, 3)
-        .with_timestamp(u32::MAX)
+        .with_timestamp(1588813835)
         .mine(U256::from(2).pow(254.into()))
         .unwrap();
 
@@ -157,7 +157,13 @@ pub mod benchmarks {
         ));
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller), amount, btc_address, vault_id.clone());
+        _(
+            RawOrigin::Signed(caller),
+            amount,
+            btc_address,
+            vault_id.clone(),
+            Some(amount),
+        );
         let redeem_vault_request = Redeem::<T>::get_redeem_requests_for_vault(vault_id.account_id.clone());
         let redeem_request_hash = redeem_vault_request
             .first()
@@ -167,6 +173,56 @@ pub mod benchmarks {
         assert!(redeem_struct.premium > 0);
     }
 
+    #[benchmark]
+    pub fn request_redeem_auto(
+        v: Linear<{ vault_registry::MAX_VAULTS_PER_REQUEST }, { vault_registry::MAX_VAULTS_SCANNED_PER_SELECTION }>,
+    ) {
+        let caller = whitelisted_caller();
+        let template_vault_id = activate_lending_and_get_vault_id::<T>();
+        let amount = Redeem::<T>::redeem_btc_dust_value() * BalanceOf::<T>::from(100u32);
+        let btc_address = BtcAddress::dummy();
+
+        initialize_oracle::<T>();
+
+        // worst case: all vaults are scanned, and no vault can redeem the full amount
+        // such that it is split across the maximum number of vaults
+        for i in 0..v {
+            let vault_id = DefaultVaultId::<T> {
+                account_id: account("Vault", i, 0),
+                ..template_vault_id.clone()
+            };
+            register_public_key::<T>(vault_id.clone());
+            let vault = Vault {
+                issued_tokens: amount,
+                id: vault_id.clone(),
+                ..Vault::new(vault_id.clone())
+            };
+            VaultRegistry::<T>::insert_vault(&vault_id, vault);
+        }
+
+        let total_amount = amount * vault_registry::MAX_VAULTS_PER_REQUEST.into();
+        mint_wrapped::<T>(&caller, total_amount);
+
+        assert_ok!(Oracle::<T>::_set_exchange_rate(
+            get_collateral_currency_id::<T>(),
+            UnsignedFixedPoint::<T>::one()
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            total_amount,
+            btc_address,
+            template_vault_id.currencies.clone(),
+            Some(total_amount),
+        );
+
+        assert_eq!(
+            RedeemRequests::<T>::iter().count() as u32,
+            vault_registry::MAX_VAULTS_PER_REQUEST
+        );
+    }
+
     #[benchmark]
     pub fn liquidation_redeem() {
         assert_ok!(Oracle::<T>::_set_exchange_rate(
@@ -252,6 +308,57 @@ pub mod benchmarks {
         _(RawOrigin::Signed(vault_id.account_id.clone()), redeem_id, transaction);
     }
 
+    #[benchmark]
+    pub fn execute_redeems(n: Linear<1, { btc_relay::MAX_BATCHED_PAYMENTS as u32 }>) {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        let relayer_id: T::AccountId = account("Relayer", 0, 0);
+
+        initialize_oracle::<T>();
+
+        let mut redeem_ids = vec![];
+        let mut outputs = vec![];
+        let mut total_amount = BalanceOf::<T>::zero();
+        for i in 0..n {
+            let redeem_id = H256::from_low_u64_be(i as u64);
+            let btc_address = BtcAddress::P2PKH(sp_core::H160::from_low_u64_be(i as u64));
+            let mut redeem_request = test_request::<T>(&vault_id);
+            redeem_request.btc_address = btc_address;
+            Redeem::<T>::insert_redeem_request(&redeem_id, &redeem_request);
+            mint_and_reserve_wrapped::<T>(&redeem_request.redeemer, redeem_request.amount_btc);
+            total_amount += redeem_request.amount_btc;
+
+            outputs.push(TransactionOutput::payment(
+                redeem_request.amount_btc.try_into().unwrap(),
+                &btc_address,
+            ));
+            outputs.push(TransactionOutput::op_return(0, redeem_id.as_bytes()));
+            redeem_ids.push(redeem_id);
+        }
+
+        register_public_key::<T>(vault_id.clone());
+        VaultRegistry::<T>::insert_vault(
+            &vault_id,
+            Vault {
+                id: vault_id.clone(),
+                issued_tokens: total_amount,
+                to_be_redeemed_tokens: total_amount,
+                ..Vault::new(vault_id.clone())
+            },
+        );
+
+        let transaction = TransactionBuilder::build_max(1, outputs);
+        let tx_size = transaction.size_no_witness();
+        let transaction = BtcRelay::<T>::initialize_and_store_transaction_max(relayer_id, 2, transaction, tx_size);
+
+        assert_ok!(Oracle::<T>::_set_exchange_rate(
+            get_collateral_currency_id::<T>(),
+            UnsignedFixedPoint::<T>::one()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(vault_id.account_id.clone()), redeem_ids, transaction);
+    }
+
     #[benchmark]
     pub fn cancel_redeem_reimburse() {
         let caller: T::AccountId = whitelisted_caller();
@@ -8,7 +8,7 @@ pub(crate) mod btc_relay {
     use frame_support::dispatch::DispatchError;
     use frame_system::pallet_prelude::BlockNumberFor;
     use sp_core::H256;
-    use sp_std::convert::TryInto;
+    use sp_std::{convert::TryInto, vec::Vec};
 
     pub fn verify_and_validate_op_return_transaction<T: crate::Config, V: TryInto<Value>>(
         unchecked_transaction: FullTransactionProof,
@@ -24,6 +24,16 @@ pub(crate) mod btc_relay {
         )
     }
 
+    pub fn verify_and_validate_batched_op_return_transaction<T: crate::Config, V: TryInto<Value>>(
+        unchecked_transaction: FullTransactionProof,
+        expected_payments: Vec<(H256, BtcAddress, V)>,
+    ) -> Result<(), DispatchError> {
+        <btc_relay::Pallet<T>>::verify_and_validate_batched_op_return_transaction(
+            unchecked_transaction,
+            expected_payments,
+        )
+    }
+
     pub fn get_best_block_height<T: crate::Config>() -> u32 {
         <btc_relay::Pallet<T>>::get_best_block_height()
     }
@@ -42,8 +52,10 @@ pub(crate) mod vault_registry {
     use crate::DefaultVaultId;
     use currency::Amount;
     use frame_support::dispatch::{DispatchError, DispatchResult};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_core::H256;
     use sp_std::vec::Vec;
-    use vault_registry::types::{CurrencyId, CurrencySource, DefaultVault};
+    use vault_registry::types::{CurrencyId, CurrencySource, DefaultVault, DefaultVaultCurrencyPair};
 
     pub fn calculate_inclusion_fee<T: crate::Config>(
         wrapped_currency: CurrencyId<T>,
@@ -76,6 +88,14 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::get_free_redeemable_tokens(vault_id)
     }
 
+    pub fn select_vaults_for_redeem<T: crate::Config>(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+        seed: Option<H256>,
+    ) -> Result<(Vec<(DefaultVaultId<T>, Amount<T>)>, u32), DispatchError> {
+        <vault_registry::Pallet<T>>::select_vaults_for_redeem(currency_pair, amount, seed)
+    }
+
     pub fn transfer_funds<T: crate::Config>(
         from: CurrencySource<T>,
         to: CurrencySource<T>,
@@ -139,6 +159,14 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::ban_vault(vault_id)
     }
 
+    pub fn record_redeem_execution<T: crate::Config>(vault_id: &DefaultVaultId<T>, opentime: BlockNumberFor<T>) {
+        <vault_registry::Pallet<T>>::record_redeem_execution(vault_id, opentime)
+    }
+
+    pub fn record_redeem_cancellation<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+        <vault_registry::Pallet<T>>::record_redeem_cancellation(vault_id)
+    }
+
     pub fn ensure_not_banned<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> DispatchResult {
         <vault_registry::Pallet<T>>::ensure_not_banned(vault_id)
     }
@@ -209,6 +237,7 @@ pub(crate) mod security {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod fee {
+    use crate::DefaultVaultId;
     use currency::Amount;
     use fee::types::UnsignedFixedPoint;
     use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -217,8 +246,11 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::fee_pool_account_id()
     }
 
-    pub fn get_redeem_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_redeem_fee(amount)
+    pub fn get_redeem_fee<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_vault_redeem_fee(vault_id, amount)
     }
 
     pub fn distribute_rewards<T: crate::Config>(amount: &Amount<T>) -> DispatchResult {
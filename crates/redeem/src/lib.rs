@@ -27,19 +27,21 @@ mod ext;
 pub mod types;
 
 #[doc(inline)]
-pub use crate::types::{DefaultRedeemRequest, RedeemRequest, RedeemRequestStatus};
+pub use crate::types::{DefaultRedeemRequest, DefaultRedeemRequestQuery, RedeemRequest, RedeemRequestStatus};
 
-use crate::types::{BalanceOf, RedeemRequestExt, Version};
+use crate::types::{BalanceOf, MigrationCursor, RedeemRequestExt, Version};
 use bitcoin::types::FullTransactionProof;
-use btc_relay::BtcAddress;
+use btc_relay::{BtcAddress, MAX_BATCHED_PAYMENTS};
 use currency::{Amount, Rounding};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     pallet_prelude::Weight,
+    traits::Get,
     transactional,
 };
 use frame_system::{ensure_root, ensure_signed};
+use primitives::query::RequestPage;
 use sp_core::H256;
 use sp_std::{convert::TryInto, vec::Vec};
 use types::DefaultVaultId;
@@ -88,6 +90,15 @@ pub mod pallet {
 
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
+
+        /// Number of blocks after the end of their redeem period that retried and reimbursed
+        /// requests are kept in storage.
+        #[pallet::constant]
+        type RequestRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of requests that are checked for removal per block.
+        #[pallet::constant]
+        type MaxSweptRequestsPerBlock: Get<u32>;
     }
 
     #[pallet::event]
@@ -157,6 +168,12 @@ pub mod pallet {
         TryIntoIntError,
         /// Redeem amount is too small.
         AmountBelowDustAmount,
+        /// Redeem fee is higher than the maximum fee accepted by the redeemer.
+        FeeAboveMaximum,
+        /// Redeem batch is empty, too large or contains a request twice.
+        InvalidRedeemBatch,
+        /// Redeem batch contains requests to different vaults.
+        RedeemBatchOfMultipleVaults,
     }
 
     /// The time difference in number of blocks between a redeem request is created and required completion time by a
@@ -184,6 +201,26 @@ pub mod pallet {
     #[pallet::getter(fn redeem_transaction_size)]
     pub(super) type RedeemTransactionSize<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// The last redeem request checked by the request sweeper, which continues after it in the
+    /// next block.
+    #[pallet::storage]
+    pub(super) type RequestSweepCursor<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+    /// Index of the redeem requests made by each account.
+    #[pallet::storage]
+    pub(super) type RedeemRequestsByAccount<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Index of the redeem requests to each vault, by the account of the vault.
+    #[pallet::storage]
+    pub(super) type RedeemRequestsByVault<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Progress of the migration that indexes the existing redeem requests by redeemer and vault. The
+    /// indexes are incomplete while this is set, so lookups scan all requests.
+    #[pallet::storage]
+    pub(super) type RequestIndexMigration<T: Config> = StorageValue<_, MigrationCursor, OptionQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V0
@@ -208,11 +245,20 @@ pub mod pallet {
             RedeemPeriod::<T>::put(self.redeem_period);
             RedeemBtcDustValue::<T>::put(self.redeem_btc_dust_value);
             RedeemTransactionSize::<T>::put(self.redeem_transaction_size);
+            StorageVersion::<T>::put(Version::V2);
         }
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            if RequestIndexMigration::<T>::exists() {
+                Self::migrate_request_indexes(remaining_weight)
+            } else {
+                Self::sweep_requests(remaining_weight)
+            }
+        }
+    }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -229,6 +275,8 @@ pub mod pallet {
         /// * `amount` - amount of issued tokens
         /// * `btc_address` - the address to receive BTC
         /// * `vault_id` - address of the vault
+        /// * `max_fee` - optional maximum redeem fee the redeemer accepts, protecting against
+        /// the vault raising its fee before the request is included
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::request_redeem())]
         #[transactional]
@@ -237,12 +285,42 @@ pub mod pallet {
             #[pallet::compact] amount_wrapped: BalanceOf<T>,
             btc_address: BtcAddress,
             vault_id: DefaultVaultId<T>,
+            max_fee: Option<BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let redeemer = ensure_signed(origin)?;
-            Self::_request_redeem(redeemer, amount_wrapped, btc_address, vault_id)?;
+            Self::_request_redeem(redeemer, amount_wrapped, btc_address, vault_id, max_fee)?;
             Ok(().into())
         }
 
+        /// Initializes a request to burn issued tokens against vaults selected on-chain, rather than
+        /// against a vault chosen by the caller. The request is split across several vaults if no
+        /// single vault can redeem the full amount, creating one redeem request (and `RequestRedeem`
+        /// event) per vault, each paying its own Bitcoin inclusion fee. See
+        /// `vault_registry::Pallet::select_vaults_for_redeem` for the selection policy.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - sender of the transaction
+        /// * `amount` - amount of issued tokens
+        /// * `btc_address` - the address to receive BTC
+        /// * `currency_pair` - collateral and wrapped currency of the vaults to redeem from
+        /// * `max_fee` - optional maximum of the summed redeem fees of all created requests
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_redeem_auto(vault_registry::MAX_VAULTS_SCANNED_PER_SELECTION))]
+        #[transactional]
+        pub fn request_redeem_auto(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount_wrapped: BalanceOf<T>,
+            btc_address: BtcAddress,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            max_fee: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let redeemer = ensure_signed(origin)?;
+            let (_, scanned) =
+                Self::_request_redeem_auto(redeemer, amount_wrapped, btc_address, currency_pair, max_fee)?;
+            Ok(Some(<T as Config>::WeightInfo::request_redeem_auto(scanned)).into())
+        }
+
         /// When a Vault is liquidated, its collateral is slashed up to 150% of the liquidated BTC value.
         /// To re-establish the physical 1:1 peg, the bridge allows users to burn issued tokens in return for
         /// collateral at a premium rate.
@@ -295,6 +373,34 @@ pub mod pallet {
             Ok(Pays::No.into())
         }
 
+        /// Completes several redeem requests of the same vault at once using a single Bitcoin
+        /// transaction that pays each of them in its own output, directly followed by the OP_RETURN
+        /// output holding the respective redeem id. The transaction may contain one additional
+        /// return-to-self output. Either all requests are executed, or none.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - anyone executing these redeem requests
+        /// * `redeem_ids` - identifiers of the redeem requests paid by the transaction
+        /// * `unchecked_transaction` - tx containing the payments, with its inclusion proof
+        #[pallet::call_index(8)]
+        #[pallet::weight(weight_for_execute_redeem::<T>(unchecked_transaction).saturating_add(
+            <T as Config>::WeightInfo::execute_redeems(redeem_ids.len() as u32)
+        ))]
+        #[transactional]
+        pub fn execute_redeems(
+            origin: OriginFor<T>,
+            redeem_ids: Vec<H256>,
+            unchecked_transaction: FullTransactionProof,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+
+            Self::_execute_redeems(redeem_ids, unchecked_transaction)?;
+
+            // Don't take tx fees on success, see `execute_redeem`.
+            Ok(Pays::No.into())
+        }
+
         /// If a redeem request is not completed on time, the redeem request can be cancelled.
         /// The user that initially requested the redeem process calls this function to obtain
         /// the Vault’s collateral as compensation for not transferring the BTC back to their address.
@@ -411,7 +517,7 @@ mod self_redeem {
         let fees = if redeemable_tokens.eq(&requested_redeem_amount)? {
             Amount::zero(vault_id.wrapped_currency())
         } else {
-            ext::fee::get_redeem_fee::<T>(&requested_redeem_amount)?
+            ext::fee::get_redeem_fee::<T>(vault_id, &requested_redeem_amount)?
         };
 
         let consumed_issued_tokens = requested_redeem_amount.checked_sub(&fees)?;
@@ -457,11 +563,43 @@ mod self_redeem {
 // "Internal" functions, callable by code.
 #[cfg_attr(test, mockable)]
 impl<T: Config> Pallet<T> {
-    fn _request_redeem(
+    /// Requests the redemption of `amount_wrapped` from vaults selected on-chain, returns the
+    /// tracking IDs of the request of each selected vault and the number of vaults that were
+    /// scanned to select them.
+    fn _request_redeem_auto(
+        redeemer: T::AccountId,
+        amount_wrapped: BalanceOf<T>,
+        btc_address: BtcAddress,
+        currency_pair: DefaultVaultCurrencyPair<T>,
+        max_fee: Option<BalanceOf<T>>,
+    ) -> Result<(Vec<H256>, u32), DispatchError> {
+        let amount_wrapped = Amount::new(amount_wrapped, currency_pair.wrapped);
+        let seed = ext::security::get_secure_id::<T>(&redeemer);
+
+        let (selected, scanned) =
+            ext::vault_registry::select_vaults_for_redeem::<T>(&currency_pair, &amount_wrapped, Some(seed))?;
+        let mut remaining_fee = max_fee;
+        let mut redeem_ids = Vec::with_capacity(selected.len());
+        for (vault_id, amount) in selected {
+            let redeem_id =
+                Self::_request_redeem(redeemer.clone(), amount.amount(), btc_address, vault_id, remaining_fee)?;
+            if let Some(ref mut remaining_fee) = remaining_fee {
+                let fee = Self::get_open_redeem_request_from_id(&redeem_id)?.fee;
+                *remaining_fee = remaining_fee.saturating_sub(fee);
+            }
+            redeem_ids.push(redeem_id);
+        }
+        Ok((redeem_ids, scanned))
+    }
+
+    /// Requests the redemption of `amount_wrapped` from the given vault, locking the tokens of
+    /// `redeemer` until the request is executed or cancelled.
+    pub fn _request_redeem(
         redeemer: T::AccountId,
         amount_wrapped: BalanceOf<T>,
         btc_address: BtcAddress,
         vault_id: DefaultVaultId<T>,
+        max_fee: Option<BalanceOf<T>>,
     ) -> Result<H256, DispatchError> {
         let amount_wrapped = Amount::new(amount_wrapped, vault_id.wrapped_currency());
 
@@ -479,8 +617,11 @@ impl<T: Config> Pallet<T> {
         let fee_wrapped = if redeemer == vault_id.account_id {
             Amount::zero(vault_id.wrapped_currency())
         } else {
-            ext::fee::get_redeem_fee::<T>(&amount_wrapped)?
+            ext::fee::get_redeem_fee::<T>(&vault_id, &amount_wrapped)?
         };
+        if let Some(max_fee) = max_fee {
+            ensure!(fee_wrapped.amount() <= max_fee, Error::<T>::FeeAboveMaximum);
+        }
         let inclusion_fee = Self::get_current_inclusion_fee(vault_id.wrapped_currency())?;
 
         let vault_to_be_burned_tokens = amount_wrapped.checked_sub(&fee_wrapped)?;
@@ -591,6 +732,46 @@ impl<T: Config> Pallet<T> {
             redeem_id,
         )?;
 
+        Self::complete_redeem(redeem_id, redeem)
+    }
+
+    fn _execute_redeems(redeem_ids: Vec<H256>, unchecked_transaction: FullTransactionProof) -> DispatchResult {
+        ensure!(
+            !redeem_ids.is_empty() && redeem_ids.len() <= MAX_BATCHED_PAYMENTS,
+            Error::<T>::InvalidRedeemBatch
+        );
+        let mut redeems: Vec<(H256, DefaultRedeemRequest<T>)> = Vec::with_capacity(redeem_ids.len());
+        for redeem_id in redeem_ids {
+            ensure!(
+                !redeems.iter().any(|(id, _)| *id == redeem_id),
+                Error::<T>::InvalidRedeemBatch
+            );
+            let redeem = Self::get_open_redeem_request_from_id(&redeem_id)?;
+            // a single transaction can only be made by a single vault
+            ensure!(
+                redeems.iter().all(|(_, other)| other.vault == redeem.vault),
+                Error::<T>::RedeemBatchOfMultipleVaults
+            );
+            redeems.push((redeem_id, redeem));
+        }
+
+        // check the transaction inclusion and that it pays exactly these requests
+        ext::btc_relay::verify_and_validate_batched_op_return_transaction::<T, _>(
+            unchecked_transaction,
+            redeems
+                .iter()
+                .map(|(redeem_id, redeem)| (*redeem_id, redeem.btc_address, redeem.amount_btc))
+                .collect(),
+        )?;
+
+        for (redeem_id, redeem) in redeems {
+            Self::complete_redeem(redeem_id, redeem)?;
+        }
+        Ok(())
+    }
+
+    /// Settles a redeem request for which a valid payment has been proven.
+    fn complete_redeem(redeem_id: H256, redeem: DefaultRedeemRequest<T>) -> DispatchResult {
         // burn amount (without parachain fee, but including transfer fee)
         let burn_amount = redeem.amount_btc().checked_add(&redeem.transfer_fee_btc())?;
         burn_amount.burn_from(&redeem.redeemer)?;
@@ -604,6 +785,7 @@ impl<T: Config> Pallet<T> {
         ext::vault_registry::redeem_tokens::<T>(&redeem.vault, &burn_amount, &redeem.premium()?, &redeem.redeemer)?;
 
         Self::set_redeem_status(redeem_id, RedeemRequestStatus::Completed);
+        ext::vault_registry::record_redeem_execution::<T>(&redeem.vault, redeem.opentime);
         Self::deposit_event(Event::<T>::ExecuteRedeem {
             redeem_id,
             redeemer: redeem.redeemer,
@@ -615,6 +797,101 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Removes retried and reimbursed redeem requests after the retention period, continuing from
+    /// where the previous block stopped. Expired requests are left for the redeemer to cancel,
+    /// since only they can choose between retrying and reimbursement. Completed requests are
+    /// kept, so that their payments cannot be reported as theft.
+    pub(crate) fn sweep_requests(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(3, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let weight_per_request = T::DbWeight::get().reads_writes(1, 1);
+        let previous_cursor = RequestSweepCursor::<T>::get();
+        let requests = match previous_cursor {
+            Some(redeem_id) => RedeemRequests::<T>::iter_from(RedeemRequests::<T>::hashed_key_for(redeem_id)),
+            None => RedeemRequests::<T>::iter(),
+        };
+
+        let mut swept = Vec::new();
+        let mut cursor = None;
+        for (redeem_id, redeem) in requests {
+            if swept.len() as u32 >= T::MaxSweptRequestsPerBlock::get()
+                || consumed.saturating_add(weight_per_request).any_gt(remaining_weight)
+            {
+                cursor = swept.last().map(|(redeem_id, _)| *redeem_id).or(previous_cursor);
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+            swept.push((redeem_id, redeem));
+        }
+
+        let now = ext::security::active_block_number::<T>();
+        for (redeem_id, redeem) in swept {
+            // tokens still have to be minted for the vault of `Reimbursed(false)` requests
+            if matches!(
+                redeem.status,
+                RedeemRequestStatus::Retried | RedeemRequestStatus::Reimbursed(true)
+            ) {
+                let retained_until = redeem
+                    .opentime
+                    .saturating_add(Self::redeem_period().max(redeem.period))
+                    .saturating_add(T::RequestRetentionPeriod::get());
+                if now > retained_until {
+                    Self::remove_redeem_request(&redeem_id, &redeem);
+                }
+            }
+        }
+
+        RequestSweepCursor::<T>::set(cursor);
+        consumed
+    }
+
+    /// Indexes the redeem requests by redeemer and vault, continuing from where the previous block
+    /// stopped.
+    pub(crate) fn migrate_request_indexes(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut cursor = match RequestIndexMigration::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let mut requests = match cursor {
+            MigrationCursor::Start => RedeemRequests::<T>::iter(),
+            MigrationCursor::After(redeem_id) => {
+                RedeemRequests::<T>::iter_from(RedeemRequests::<T>::hashed_key_for(redeem_id))
+            }
+        };
+
+        let weight_per_request = T::DbWeight::get().reads_writes(1, 2);
+        for _ in 0..T::MaxSweptRequestsPerBlock::get() {
+            if consumed.saturating_add(weight_per_request).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+
+            match requests.next() {
+                Some((redeem_id, redeem)) => {
+                    RedeemRequestsByAccount::<T>::insert(&redeem.redeemer, redeem_id, ());
+                    RedeemRequestsByVault::<T>::insert(&redeem.vault.account_id, redeem_id, ());
+                    cursor = MigrationCursor::After(redeem_id);
+                }
+                None => {
+                    log::info!("Redeem request index migration finished");
+                    RequestIndexMigration::<T>::kill();
+                    return consumed;
+                }
+            }
+        }
+
+        RequestIndexMigration::<T>::put(cursor);
+        consumed
+    }
+
     fn _cancel_redeem(redeemer: T::AccountId, redeem_id: H256, reimburse: bool) -> DispatchResult {
         let redeem = Self::get_open_redeem_request_from_id(&redeem_id)?;
         ensure!(redeemer == redeem.redeemer, Error::<T>::UnauthorizedRedeemer);
@@ -720,6 +997,7 @@ impl<T: Config> Pallet<T> {
             ext::vault_registry::decrease_to_be_redeemed_tokens::<T>(&vault_id, &vault_to_be_burned_tokens)?;
             Self::set_redeem_status(redeem_id, RedeemRequestStatus::Retried)
         };
+        ext::vault_registry::record_redeem_cancellation::<T>(&vault_id);
 
         Self::deposit_event(Event::<T>::CancelRedeem {
             redeem_id,
@@ -783,9 +1061,17 @@ impl<T: Config> Pallet<T> {
     /// * `key` - 256-bit identifier of the redeem request
     /// * `value` - the redeem request
     fn insert_redeem_request(key: &H256, value: &DefaultRedeemRequest<T>) {
+        <RedeemRequestsByAccount<T>>::insert(&value.redeemer, key, ());
+        <RedeemRequestsByVault<T>>::insert(&value.vault.account_id, key, ());
         <RedeemRequests<T>>::insert(key, value)
     }
 
+    fn remove_redeem_request(key: &H256, value: &DefaultRedeemRequest<T>) {
+        <RedeemRequestsByAccount<T>>::remove(&value.redeemer, key);
+        <RedeemRequestsByVault<T>>::remove(&value.vault.account_id, key);
+        <RedeemRequests<T>>::remove(key)
+    }
+
     fn set_redeem_status(id: H256, status: RedeemRequestStatus) -> RedeemRequestStatus {
         <RedeemRequests<T>>::mutate_exists(id, |request| {
             *request = request.clone().map(|request| DefaultRedeemRequest::<T> {
@@ -813,10 +1099,13 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `account_id` - user account id
     pub fn get_redeem_requests_for_account(account_id: T::AccountId) -> Vec<H256> {
-        <RedeemRequests<T>>::iter()
-            .filter(|(_, request)| request.redeemer == account_id)
-            .map(|(key, _)| key)
-            .collect::<Vec<_>>()
+        if RequestIndexMigration::<T>::exists() {
+            return <RedeemRequests<T>>::iter()
+                .filter(|(_, request)| request.redeemer == account_id)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+        }
+        <RedeemRequestsByAccount<T>>::iter_key_prefix(account_id).collect::<Vec<_>>()
     }
 
     pub fn get_premium_redeem_vaults() -> Result<Vec<(DefaultVaultId<T>, Amount<T>)>, DispatchError> {
@@ -830,10 +1119,51 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `vault_id` - vault account id
     pub fn get_redeem_requests_for_vault(vault_id: T::AccountId) -> Vec<H256> {
-        <RedeemRequests<T>>::iter()
-            .filter(|(_, request)| request.vault.account_id == vault_id)
-            .map(|(key, _)| key)
-            .collect::<Vec<_>>()
+        if RequestIndexMigration::<T>::exists() {
+            return <RedeemRequests<T>>::iter()
+                .filter(|(_, request)| request.vault.account_id == vault_id)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+        }
+        <RedeemRequestsByVault<T>>::iter_key_prefix(vault_id).collect::<Vec<_>>()
+    }
+
+    /// Fetch a page of the redeem requests made by the specified account.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - user account id
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_redeem_requests_page_for_account(
+        account_id: T::AccountId,
+        query: DefaultRedeemRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultRedeemRequest<T>> {
+        Self::get_redeem_requests_page(Self::get_redeem_requests_for_account(account_id), query)
+    }
+
+    /// Fetch a page of the redeem requests to the specified vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - vault account id
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_redeem_requests_page_for_vault(
+        vault_id: T::AccountId,
+        query: DefaultRedeemRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultRedeemRequest<T>> {
+        Self::get_redeem_requests_page(Self::get_redeem_requests_for_vault(vault_id), query)
+    }
+
+    fn get_redeem_requests_page(
+        redeem_ids: Vec<H256>,
+        query: DefaultRedeemRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultRedeemRequest<T>> {
+        let requests = redeem_ids
+            .into_iter()
+            .filter_map(|redeem_id| Some((redeem_id, RedeemRequests::<T>::get(redeem_id)?)))
+            .filter(|(_, request)| query.matches_status(&request.status) && query.matches_vault(&request.vault))
+            .collect();
+        query.paginate(requests, |request| request.opentime)
     }
 
     /// Fetch a pre-existing redeem request or throw. Completed or cancelled
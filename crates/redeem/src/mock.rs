@@ -130,6 +130,8 @@ impl orml_tokens::Config for Test {
 
 parameter_types! {
     pub const VaultPalletId: PalletId = PalletId(*b"mod/vreg");
+    pub const PublicKeyRotationDelay: BlockNumber = 10;
+    pub const PublicKeyMigrationPeriod: BlockNumber = 20;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -145,6 +147,9 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = ();
 }
 
 impl nomination::Config for Test {
@@ -265,9 +270,16 @@ impl loans::Config for Test {
     type OnExchangeRateChange = ();
 }
 
+parameter_types! {
+    pub const RequestRetentionPeriod: BlockNumber = 100;
+    pub const MaxSweptRequestsPerBlock: u32 = 10;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
 }
 
 pub type TestEvent = RuntimeEvent;
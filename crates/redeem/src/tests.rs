@@ -1,14 +1,20 @@
 use crate::{ext, mock::*};
 
-use crate::types::{RedeemRequest, RedeemRequestStatus};
+use crate::types::{MigrationCursor, RedeemRequest, RedeemRequestStatus, Version};
 use bitcoin::{merkle::PartialTransactionProof, types::FullTransactionProof};
 use btc_relay::BtcAddress;
 use currency::Amount;
-use frame_support::{assert_err, assert_noop, assert_ok, dispatch::DispatchError};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::DispatchError,
+    traits::{Get, Hooks, OnRuntimeUpgrade},
+    weights::Weight,
+};
 use mocktopus::mocking::*;
+use primitives::query::{RequestQuery, SortOrder};
 use security::Pallet as Security;
 use sp_core::{H160, H256};
-use vault_registry::{DefaultVault, VaultStatus};
+use vault_registry::{DefaultVault, DefaultVaultId, VaultStatus};
 
 type Event = crate::Event<Test>;
 
@@ -63,12 +69,24 @@ fn test_request_redeem_fails_with_amount_exceeds_user_balance() {
         amount.mint_to(&USER).unwrap();
         let amount = 10_000_000;
         assert_err!(
-            Redeem::request_redeem(RuntimeOrigin::signed(USER), amount, BtcAddress::random(), VAULT),
+            Redeem::request_redeem(RuntimeOrigin::signed(USER), amount, BtcAddress::random(), VAULT, None),
             TestError::AmountExceedsUserBalance
         );
     })
 }
 
+#[test]
+fn test_request_redeem_fails_with_fee_above_maximum() {
+    run_test(|| {
+        wrapped(100).mint_to(&USER).unwrap();
+        ext::fee::get_redeem_fee::<Test>.mock_safe(|_, _| MockResult::Return(Ok(wrapped(5))));
+        assert_noop!(
+            Redeem::request_redeem(RuntimeOrigin::signed(USER), 90, BtcAddress::random(), VAULT, Some(4)),
+            TestError::FeeAboveMaximum
+        );
+    })
+}
+
 #[test]
 fn test_request_redeem_fails_with_amount_below_minimum() {
     run_test(|| {
@@ -101,7 +119,7 @@ fn test_request_redeem_fails_with_amount_below_minimum() {
         });
 
         assert_err!(
-            Redeem::request_redeem(RuntimeOrigin::signed(redeemer), 1, BtcAddress::random(), VAULT),
+            Redeem::request_redeem(RuntimeOrigin::signed(redeemer), 1, BtcAddress::random(), VAULT, None),
             TestError::AmountBelowDustAmount
         );
     })
@@ -111,7 +129,7 @@ fn test_request_redeem_fails_with_amount_below_minimum() {
 fn test_request_redeem_fails_with_vault_not_found() {
     run_test(|| {
         assert_err!(
-            Redeem::request_redeem(RuntimeOrigin::signed(USER), 1500, BtcAddress::random(), VAULT),
+            Redeem::request_redeem(RuntimeOrigin::signed(USER), 1500, BtcAddress::random(), VAULT, None),
             VaultRegistryError::VaultNotFound
         );
     })
@@ -124,7 +142,7 @@ fn test_request_redeem_fails_with_vault_banned() {
             .mock_safe(|_| MockResult::Return(Err(VaultRegistryError::VaultBanned.into())));
 
         assert_err!(
-            Redeem::request_redeem(RuntimeOrigin::signed(USER), 1500, BtcAddress::random(), VAULT),
+            Redeem::request_redeem(RuntimeOrigin::signed(USER), 1500, BtcAddress::random(), VAULT, None),
             VaultRegistryError::VaultBanned
         );
     })
@@ -135,7 +153,7 @@ fn test_request_redeem_fails_with_vault_liquidated() {
     run_test(|| {
         ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
         assert_err!(
-            Redeem::request_redeem(RuntimeOrigin::signed(USER), 3000, BtcAddress::random(), VAULT),
+            Redeem::request_redeem(RuntimeOrigin::signed(USER), 3000, BtcAddress::random(), VAULT, None),
             VaultRegistryError::VaultNotFound
         );
     })
@@ -182,14 +200,15 @@ fn test_request_redeem_succeeds_with_normal_redeem() {
         });
 
         ext::security::get_secure_id::<Test>.mock_safe(move |_| MockResult::Return(H256([0; 32])));
-        ext::fee::get_redeem_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(redeem_fee))));
+        ext::fee::get_redeem_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(redeem_fee))));
         let btc_fee = Redeem::get_current_inclusion_fee(DEFAULT_WRAPPED_CURRENCY).unwrap();
 
         assert_ok!(Redeem::request_redeem(
             RuntimeOrigin::signed(redeemer),
             amount,
             btc_address,
-            VAULT
+            VAULT,
+            None
         ));
 
         assert_emitted!(Event::RequestRedeem {
@@ -225,6 +244,46 @@ fn test_request_redeem_succeeds_with_normal_redeem() {
     })
 }
 
+#[test]
+fn test_request_redeem_auto_requests_from_each_selected_vault() {
+    run_test(|| {
+        convert_to.mock_safe(|_, x| MockResult::Return(Ok(x)));
+        let other_vault = DefaultVaultId::<Test> { account_id: 4, ..VAULT };
+        <vault_registry::Pallet<Test>>::insert_vault(&VAULT, default_vault());
+        <vault_registry::Pallet<Test>>::insert_vault(
+            &other_vault,
+            vault_registry::Vault {
+                id: other_vault.clone(),
+                ..default_vault()
+            },
+        );
+
+        let selected_vaults = vec![(VAULT, wrapped(50)), (other_vault.clone(), wrapped(40))];
+        ext::vault_registry::select_vaults_for_redeem::<Test>
+            .mock_safe(move |_, _, _| MockResult::Return(Ok((selected_vaults.clone(), 2))));
+        ext::vault_registry::try_increase_to_be_redeemed_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+        Amount::<Test>::lock_on.mock_safe(|_, _| MockResult::Return(Ok(())));
+        ext::fee::get_redeem_fee::<Test>.mock_safe(|_, _| MockResult::Return(Ok(wrapped(5))));
+
+        assert_ok!(Redeem::request_redeem_auto(
+            RuntimeOrigin::signed(USER),
+            90,
+            BtcAddress::random(),
+            VAULT.currencies.clone(),
+            None
+        ));
+
+        let redeemed_vaults = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                TestEvent::Redeem(Event::RequestRedeem { vault_id, fee, .. }) => Some((vault_id, fee)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(redeemed_vaults, vec![(VAULT, 5), (other_vault, 5)]);
+    })
+}
+
 #[test]
 fn test_request_redeem_fails_with_default_btc_address() {
     run_test(|| {
@@ -238,7 +297,8 @@ fn test_request_redeem_fails_with_default_btc_address() {
                 RuntimeOrigin::signed(redeemer),
                 amount,
                 BtcAddress::P2PKH(H160::zero()),
-                VAULT
+                VAULT,
+                None
             ),
             btc_relay::Error::<Test>::InvalidBtcHash
         );
@@ -291,7 +351,8 @@ fn test_request_redeem_succeeds_with_self_redeem() {
             RuntimeOrigin::signed(redeemer),
             amount,
             btc_address,
-            VAULT
+            VAULT,
+            None
         ));
 
         assert_emitted!(Event::RequestRedeem {
@@ -461,6 +522,138 @@ fn test_execute_redeem_succeeds_with_another_account() {
     })
 }
 
+#[test]
+fn test_execute_redeems_fails_with_duplicate_redeem_id() {
+    run_test(|| {
+        assert_err!(
+            Redeem::_execute_redeems(vec![H256([0u8; 32]), H256([0u8; 32])], get_some_unchecked_transaction()),
+            TestError::InvalidRedeemBatch
+        );
+        assert_err!(
+            Redeem::_execute_redeems(vec![], get_some_unchecked_transaction()),
+            TestError::InvalidRedeemBatch
+        );
+    })
+}
+
+#[test]
+fn test_execute_redeems_fails_with_requests_of_multiple_vaults() {
+    run_test(|| {
+        Security::<Test>::set_active_block_number(40);
+        let other_vault = DefaultVaultId::<Test> {
+            account_id: CAROL,
+            ..VAULT
+        };
+        for (redeem_id, vault) in [(H256([0u8; 32]), VAULT), (H256([1u8; 32]), other_vault)] {
+            inject_redeem_request(
+                redeem_id,
+                RedeemRequest {
+                    period: 0,
+                    vault,
+                    opentime: 40,
+                    fee: 0,
+                    amount_btc: 100,
+                    premium: 0,
+                    redeemer: USER,
+                    btc_address: BtcAddress::random(),
+                    btc_height: 0,
+                    status: RedeemRequestStatus::Pending,
+                    transfer_fee_btc: 0,
+                },
+            );
+        }
+
+        assert_err!(
+            Redeem::_execute_redeems(vec![H256([0u8; 32]), H256([1u8; 32])], get_some_unchecked_transaction()),
+            TestError::RedeemBatchOfMultipleVaults
+        );
+    })
+}
+
+#[test]
+fn test_execute_redeems_succeeds() {
+    run_test(|| {
+        convert_to.mock_safe(|_, x| MockResult::Return(Ok(x)));
+        Security::<Test>::set_active_block_number(40);
+        <vault_registry::Pallet<Test>>::insert_vault(
+            &VAULT,
+            vault_registry::Vault {
+                id: VAULT,
+                to_be_replaced_tokens: 0,
+                to_be_issued_tokens: 0,
+                issued_tokens: 300,
+                to_be_redeemed_tokens: 300,
+                replace_collateral: 0,
+                active_replace_collateral: 0,
+                banned_until: None,
+                secure_collateral_threshold: None,
+                status: VaultStatus::Active(true),
+                liquidated_collateral: 0,
+            },
+        );
+
+        let btc_fee = Redeem::get_current_inclusion_fee(DEFAULT_WRAPPED_CURRENCY).unwrap();
+        let first_address = BtcAddress::random();
+        let second_address = BtcAddress::random();
+
+        for (redeem_id, amount_btc, btc_address) in [
+            (H256([0u8; 32]), 100, first_address),
+            (H256([1u8; 32]), 150, second_address),
+        ] {
+            inject_redeem_request(
+                redeem_id,
+                RedeemRequest {
+                    period: 0,
+                    vault: VAULT,
+                    opentime: 40,
+                    fee: 0,
+                    amount_btc,
+                    premium: 0,
+                    redeemer: USER,
+                    btc_address,
+                    btc_height: 0,
+                    status: RedeemRequestStatus::Pending,
+                    transfer_fee_btc: btc_fee.amount(),
+                },
+            );
+        }
+
+        ext::btc_relay::verify_and_validate_batched_op_return_transaction::<Test, Balance>.mock_safe(
+            move |_, expected_payments| {
+                assert_eq!(
+                    expected_payments,
+                    vec![
+                        (H256([0u8; 32]), first_address, 100),
+                        (H256([1u8; 32]), second_address, 150)
+                    ]
+                );
+                MockResult::Return(Ok(()))
+            },
+        );
+        Amount::<Test>::burn_from.mock_safe(|_, _| MockResult::Return(Ok(())));
+        ext::vault_registry::redeem_tokens::<Test>.mock_safe(|_, _, _, _| MockResult::Return(Ok(())));
+
+        assert_ok!(Redeem::_execute_redeems(
+            vec![H256([0u8; 32]), H256([1u8; 32])],
+            get_some_unchecked_transaction()
+        ));
+        for (redeem_id, amount) in [(H256([0u8; 32]), 100), (H256([1u8; 32]), 150)] {
+            assert_emitted!(Event::ExecuteRedeem {
+                redeem_id,
+                redeemer: USER,
+                vault_id: VAULT,
+                amount,
+                fee: 0,
+                transfer_fee: btc_fee.amount(),
+            });
+            assert_err!(
+                Redeem::get_open_redeem_request_from_id(&redeem_id),
+                TestError::RedeemCompleted,
+            );
+        }
+    })
+}
+
 #[test]
 fn test_execute_redeem_succeeds() {
     run_test(|| {
@@ -729,6 +922,202 @@ fn test_mint_tokens_for_reimbursed_redeem() {
     });
 }
 
+#[test]
+fn test_sweep_requests_removes_finished_redeems_after_retention_period() {
+    run_test(|| {
+        let redeem_request = |status| RedeemRequest {
+            period: 0,
+            vault: VAULT,
+            opentime: 40,
+            fee: 0,
+            amount_btc: 100,
+            premium: 0,
+            redeemer: USER,
+            btc_address: BtcAddress::random(),
+            btc_height: 0,
+            status,
+            transfer_fee_btc: 1,
+        };
+        let statuses = [
+            RedeemRequestStatus::Retried,
+            RedeemRequestStatus::Reimbursed(true),
+            RedeemRequestStatus::Reimbursed(false),
+            RedeemRequestStatus::Completed,
+        ];
+        for (i, status) in statuses.into_iter().enumerate() {
+            inject_redeem_request(H256::repeat_byte(i as u8), redeem_request(status));
+        }
+
+        // redeem period is 10 and retention period 100
+        Security::<Test>::set_active_block_number(150);
+        Redeem::sweep_requests(Weight::MAX);
+        assert_eq!(crate::RedeemRequests::<Test>::iter().count(), 4);
+
+        Security::<Test>::set_active_block_number(151);
+        Redeem::sweep_requests(Weight::MAX);
+        assert!(crate::RedeemRequests::<Test>::get(H256::repeat_byte(0)).is_none());
+        assert!(crate::RedeemRequests::<Test>::get(H256::repeat_byte(1)).is_none());
+        // the vault may still mint tokens for this request
+        assert!(crate::RedeemRequests::<Test>::get(H256::repeat_byte(2)).is_some());
+        // completed requests are kept, since their payment could otherwise be reported as theft
+        assert!(crate::RedeemRequests::<Test>::get(H256::repeat_byte(3)).is_some());
+        assert_eq!(
+            Redeem::get_redeem_requests_for_account(USER),
+            Redeem::get_redeem_requests_for_vault(VAULT.account_id)
+        );
+        assert_eq!(Redeem::get_redeem_requests_for_account(USER).len(), 2);
+    })
+}
+
+#[test]
+fn test_request_index_migration_indexes_existing_redeems() {
+    run_test(|| {
+        let redeem_request = RedeemRequest {
+            period: 0,
+            vault: VAULT,
+            opentime: 40,
+            fee: 0,
+            amount_btc: 100,
+            premium: 0,
+            redeemer: USER,
+            btc_address: BtcAddress::random(),
+            btc_height: 0,
+            status: RedeemRequestStatus::Pending,
+            transfer_fee_btc: 1,
+        };
+        // more requests than are migrated in a single block, stored without indexes
+        let mut redeem_ids = (0..<Test as crate::Config>::MaxSweptRequestsPerBlock::get() + 2)
+            .map(|_| {
+                let redeem_id = H256::random();
+                crate::RedeemRequests::<Test>::insert(redeem_id, redeem_request.clone());
+                redeem_id
+            })
+            .collect::<Vec<_>>();
+        redeem_ids.sort();
+
+        crate::RequestIndexMigration::<Test>::put(MigrationCursor::Start);
+        // requests are found by scanning until the indexes are complete
+        let mut found = Redeem::get_redeem_requests_for_account(USER);
+        found.sort();
+        assert_eq!(found, redeem_ids);
+
+        Redeem::on_idle(1, Weight::MAX);
+        assert!(crate::RequestIndexMigration::<Test>::exists());
+        Redeem::on_idle(2, Weight::MAX);
+        assert!(!crate::RequestIndexMigration::<Test>::exists());
+
+        let mut found = Redeem::get_redeem_requests_for_account(USER);
+        found.sort();
+        assert_eq!(found, redeem_ids);
+        let mut found = Redeem::get_redeem_requests_for_vault(VAULT.account_id);
+        found.sort();
+        assert_eq!(found, redeem_ids);
+    })
+}
+
+#[test]
+fn test_runtime_upgrade_from_unset_storage_version_indexes_existing_redeems() {
+    run_test(|| {
+        let redeem_id = H256::random();
+        crate::RedeemRequests::<Test>::insert(
+            redeem_id,
+            RedeemRequest {
+                period: 0,
+                vault: VAULT,
+                opentime: 40,
+                fee: 0,
+                amount_btc: 100,
+                premium: 0,
+                redeemer: USER,
+                btc_address: BtcAddress::random(),
+                btc_height: 0,
+                status: RedeemRequestStatus::Pending,
+                transfer_fee_btc: 1,
+            },
+        );
+
+        // live chains never wrote the storage version
+        crate::StorageVersion::<Test>::kill();
+        crate::types::v0::Migration::<Test>::on_runtime_upgrade();
+        crate::types::v1::Migration::<Test>::on_runtime_upgrade();
+        assert!(matches!(Redeem::storage_version(), Version::V2));
+        assert!(crate::RequestIndexMigration::<Test>::exists());
+
+        Redeem::on_idle(1, Weight::MAX);
+        assert!(!crate::RequestIndexMigration::<Test>::exists());
+        assert_eq!(Redeem::get_redeem_requests_for_account(USER), vec![redeem_id]);
+        assert_eq!(Redeem::get_redeem_requests_for_vault(VAULT.account_id), vec![redeem_id]);
+    })
+}
+
+#[test]
+fn test_get_redeem_requests_page_filters_sorts_and_paginates() {
+    run_test(|| {
+        let redeem_request = |opentime, status| RedeemRequest {
+            period: 0,
+            vault: VAULT,
+            opentime,
+            fee: 0,
+            amount_btc: 100,
+            premium: 0,
+            redeemer: USER,
+            btc_address: BtcAddress::random(),
+            btc_height: 0,
+            status,
+            transfer_fee_btc: 1,
+        };
+        inject_redeem_request(H256::repeat_byte(1), redeem_request(30, RedeemRequestStatus::Pending));
+        inject_redeem_request(H256::repeat_byte(2), redeem_request(10, RedeemRequestStatus::Completed));
+        inject_redeem_request(
+            H256::repeat_byte(3),
+            redeem_request(20, RedeemRequestStatus::Reimbursed(false)),
+        );
+        inject_redeem_request(
+            H256::repeat_byte(4),
+            RedeemRequest {
+                redeemer: CAROL,
+                ..redeem_request(40, RedeemRequestStatus::Pending)
+            },
+        );
+
+        let query = RequestQuery {
+            status: None,
+            vault: None,
+            currency: None,
+            order: SortOrder::Ascending,
+            offset: 0,
+            limit: 2,
+        };
+        let page = Redeem::get_redeem_requests_page_for_account(USER, query.clone());
+        assert_eq!(page.total, 3);
+        let ids: Vec<_> = page.requests.into_iter().map(|(redeem_id, _)| redeem_id).collect();
+        assert_eq!(ids, vec![H256::repeat_byte(2), H256::repeat_byte(3)]);
+
+        let page = Redeem::get_redeem_requests_page_for_vault(
+            VAULT.account_id,
+            RequestQuery {
+                status: Some(RedeemRequestStatus::Pending),
+                order: SortOrder::Descending,
+                ..query.clone()
+            },
+        );
+        assert_eq!(page.total, 2);
+        let ids: Vec<_> = page.requests.into_iter().map(|(redeem_id, _)| redeem_id).collect();
+        assert_eq!(ids, vec![H256::repeat_byte(4), H256::repeat_byte(1)]);
+
+        let page = Redeem::get_redeem_requests_page_for_vault(
+            VAULT.account_id,
+            RequestQuery {
+                currency: Some(DEFAULT_COLLATERAL_CURRENCY),
+                offset: 3,
+                ..query
+            },
+        );
+        assert_eq!(page.total, 4);
+        assert_eq!(page.requests.len(), 1);
+    })
+}
+
 #[test]
 fn test_set_redeem_period_only_root() {
     run_test(|| {
@@ -780,7 +1169,8 @@ mod spec_based_tests {
                 RuntimeOrigin::signed(USER),
                 amount_to_redeem,
                 BtcAddress::random(),
-                VAULT
+                VAULT,
+                None
             ));
         })
     }
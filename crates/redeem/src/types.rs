@@ -1,6 +1,7 @@
 pub use primitives::redeem::{RedeemRequest, RedeemRequestStatus};
-use primitives::VaultId;
+use primitives::{query::RequestQuery, VaultId};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::DispatchError;
 use vault_registry::types::CurrencyId;
 
@@ -14,6 +15,67 @@ use frame_system::pallet_prelude::BlockNumberFor;
 pub enum Version {
     /// Initial version.
     V0,
+    /// Retried and reimbursed requests are removed by the request sweeper
+    V1,
+    /// Requests are indexed by redeemer and vault
+    V2,
+}
+
+/// Position of a migration that visits all redeem requests over several blocks.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MigrationCursor {
+    /// No request was visited yet.
+    Start,
+    /// Continue after the request with this id.
+    After(H256),
+}
+
+pub mod v0 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Finished requests are removed gradually by the request sweeper, so only the version changes.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V0) {
+                log::info!("Not running redeem request sweeper migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::StorageVersion::<T>::put(Version::V1);
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// The existing requests are indexed over the following blocks, see
+    /// `Pallet::migrate_request_indexes`.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V1) {
+                log::info!("Not running redeem request index migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::RequestIndexMigration::<T>::put(MigrationCursor::Start);
+            crate::StorageVersion::<T>::put(Version::V2);
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+    }
 }
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
@@ -23,6 +85,9 @@ pub(crate) type DefaultVaultId<T> = VaultId<<T as frame_system::Config>::Account
 pub type DefaultRedeemRequest<T> =
     RedeemRequest<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>, CurrencyId<T>>;
 
+pub type DefaultRedeemRequestQuery<T> =
+    RequestQuery<<T as frame_system::Config>::AccountId, CurrencyId<T>, RedeemRequestStatus>;
+
 pub trait RedeemRequestExt<T: Config> {
     fn amount_btc(&self) -> Amount<T>;
     fn fee(&self) -> Amount<T>;
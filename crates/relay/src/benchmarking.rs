@@ -0,0 +1,120 @@
+use super::*;
+use bitcoin::types::{TransactionBuilder, TransactionInputBuilder, TransactionOutput};
+use btc_relay::{BtcAddress, BtcPublicKey};
+use currency::{
+    getters::{get_relay_chain_currency_id as get_collateral_currency_id, *},
+    Amount,
+};
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use primitives::VaultId;
+use sp_core::H256;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::prelude::*;
+
+// Pallets
+use crate::Pallet as Relay;
+use btc_relay::Pallet as BtcRelay;
+use oracle::Pallet as Oracle;
+use vault_registry::Pallet as VaultRegistry;
+
+fn setup_vault<T: crate::Config>() -> DefaultVaultId<T> {
+    let vault_id: DefaultVaultId<T> = VaultId::new(
+        account("Vault", 0, 0),
+        get_collateral_currency_id::<T>(),
+        get_wrapped_currency_id::<T>(),
+    );
+
+    Oracle::<T>::_set_exchange_rate(
+        vault_id.collateral_currency(),
+        <T as currency::Config>::UnsignedFixedPoint::one(),
+    )
+    .unwrap();
+
+    VaultRegistry::<T>::set_minimum_collateral(
+        RawOrigin::Root.into(),
+        vault_id.collateral_currency(),
+        100_000u32.into(),
+    )
+    .unwrap();
+    VaultRegistry::<T>::_set_system_collateral_ceiling(vault_id.currencies.clone(), 1_000_000_000u32.into());
+    VaultRegistry::<T>::_set_secure_collateral_threshold(
+        vault_id.currencies.clone(),
+        <T as currency::Config>::UnsignedFixedPoint::checked_from_rational(1, 100000).unwrap(),
+    );
+    VaultRegistry::<T>::_set_premium_redeem_threshold(
+        vault_id.currencies.clone(),
+        <T as currency::Config>::UnsignedFixedPoint::checked_from_rational(1, 200000).unwrap(),
+    );
+    VaultRegistry::<T>::_set_liquidation_collateral_threshold(
+        vault_id.currencies.clone(),
+        <T as currency::Config>::UnsignedFixedPoint::checked_from_rational(1, 300000).unwrap(),
+    );
+
+    assert_ok!(Amount::<T>::new((1u32 << 31).into(), get_collateral_currency_id::<T>()).mint_to(&vault_id.account_id));
+    assert_ok!(Amount::<T>::new((1u32 << 31).into(), get_native_currency_id::<T>()).mint_to(&vault_id.account_id));
+
+    assert_ok!(VaultRegistry::<T>::register_public_key(
+        RawOrigin::Signed(vault_id.account_id.clone()).into(),
+        BtcPublicKey::dummy()
+    ));
+    assert_ok!(VaultRegistry::<T>::_register_vault(
+        vault_id.clone(),
+        100000000u32.into()
+    ));
+
+    let issued_tokens = Amount::new(200000u32.into(), vault_id.wrapped_currency());
+    VaultRegistry::<T>::try_increase_to_be_issued_tokens(&vault_id, &issued_tokens).unwrap();
+    VaultRegistry::<T>::issue_tokens(&vault_id, &issued_tokens).unwrap();
+
+    vault_id
+}
+
+fn setup_theft<T: crate::Config>(hashes: u32, vin: u32, vout: u32, tx_size: u32) -> FullTransactionProof {
+    let relayer_id: T::AccountId = account("Relayer", 0, 0);
+
+    // the op_return does not belong to any request, so the payment is never authorized
+    let mut outputs = vec![
+        TransactionOutput::payment(100, &BtcAddress::dummy()),
+        TransactionOutput::op_return(0, H256::zero().as_bytes()),
+    ];
+    if vout == 3 {
+        outputs.push(TransactionOutput::payment(0, &BtcAddress::P2PKH(sp_core::H160::zero())));
+    }
+
+    // the last input is signed by the vault, so all other inputs are parsed first. The
+    // first input is padded to the transaction size, so it can't be the vault input
+    assert!(vin > 1, "Need at least two inputs");
+    let mut transaction = TransactionBuilder::build_max(vin, outputs);
+    let source = transaction.inputs[vin as usize - 1].source.clone();
+    transaction.inputs[vin as usize - 1] = TransactionInputBuilder::new()
+        .with_source(source)
+        .with_p2wpkh(&BtcPublicKey::dummy(), vec![0; 72])
+        .build();
+
+    BtcRelay::<T>::initialize_and_store_transaction_max(relayer_id, hashes, transaction, tx_size as usize)
+}
+
+#[benchmarks]
+pub mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn report_vault_theft(h: Linear<2, 10>, i: Linear<2, 10>, o: Linear<2, 3>, b: Linear<541, 2_048>) {
+        let vault_id = setup_vault::<T>();
+        let transaction = setup_theft::<T>(h, i, o, b);
+        let reporter_id: T::AccountId = account("Reporter", 0, 0);
+
+        #[extrinsic_call]
+        report_vault_theft(RawOrigin::Signed(reporter_id), vault_id.clone(), transaction);
+
+        assert!(VaultRegistry::<T>::is_vault_liquidated(&vault_id).unwrap());
+    }
+
+    impl_benchmark_test_suite! {
+        Relay,
+        crate::mock::ExtBuilder::build_with(Default::default()),
+        crate::mock::Test
+    }
+}
@@ -0,0 +1,272 @@
+
+//! Autogenerated weights for relay
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-05-05, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `enterprise`, CPU: `Intel(R) Core(TM) i7-9700K CPU @ 3.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kintsugi-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// relay
+// --extrinsic
+// *
+// --chain
+// kintsugi-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// crates/relay/src/default_weights.rs
+// --template
+// .deploy/default-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for relay.
+pub trait WeightInfo {
+	fn report_vault_theft(h: u32, i: u32, o: u32, b: u32, ) -> Weight;
+}
+
+/// Weights for relay using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Relay TheftReports (r:1 w:1)
+	/// Proof: Relay TheftReports (max_values: None, max_size: Some(131), added: 2606, mode: MaxEncodedLen)
+	/// Storage: BTCRelay DisableInclusionCheck (r:1 w:0)
+	/// Proof: BTCRelay DisableInclusionCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:1 w:0)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:1 w:0)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableParachainConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableParachainConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PendingPublicKeyRotation (r:1 w:0)
+	/// Proof: VaultRegistry PendingPublicKeyRotation (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry RetiredBitcoinPublicKeys (r:1 w:0)
+	/// Proof: VaultRegistry RetiredBitcoinPublicKeys (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ReservedAddresses (r:1 w:0)
+	/// Proof: VaultRegistry ReservedAddresses (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinMultisig (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinMultisig (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
+	/// Storage: Redeem RedeemRequests (r:1 w:0)
+	/// Proof: Redeem RedeemRequests (max_values: None, max_size: Some(270), added: 2745, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceRequests (r:1 w:0)
+	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PunishmentDelay (r:1 w:0)
+	/// Proof: VaultRegistry PunishmentDelay (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:1)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashPerToken (r:1 w:1)
+	/// Proof: VaultStaking SlashPerToken (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashTally (r:1 w:1)
+	/// Proof: VaultStaking SlashTally (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:1)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardPerToken (r:2 w:0)
+	/// Proof: VaultCapacity RewardPerToken (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardTally (r:2 w:2)
+	/// Proof: VaultCapacity RewardTally (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalRewards (r:2 w:2)
+	/// Proof: VaultCapacity TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:0)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalRewards (r:2 w:2)
+	/// Proof: VaultRewards TotalRewards (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Fee Commission (r:1 w:0)
+	/// Proof: Fee Commission (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardPerToken (r:2 w:2)
+	/// Proof: VaultStaking RewardPerToken (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalStake (r:1 w:1)
+	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:1)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardTally (r:2 w:2)
+	/// Proof: VaultStaking RewardTally (max_values: None, max_size: Some(149), added: 2624, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardCurrencies (r:1 w:0)
+	/// Proof: VaultRewards RewardCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Fee PunishmentFee (r:1 w:0)
+	/// Proof: Fee PunishmentFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 10]`.
+	/// The range of component `i` is `[2, 10]`.
+	/// The range of component `o` is `[2, 3]`.
+	/// The range of component `b` is `[541, 2048]`.
+	fn report_vault_theft(h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6952`
+		//  Estimated: `124453`
+		// Minimum execution time: 1_302_517_000 picoseconds.
+		Weight::from_parts(1_318_094_372, 124453)
+			// Standard Error: 154_208
+			.saturating_add(Weight::from_parts(1_027_614, 0).saturating_mul(h.into()))
+			// Standard Error: 46_385
+			.saturating_add(Weight::from_parts(412_387, 0).saturating_mul(i.into()))
+			// Standard Error: 291
+			.saturating_add(Weight::from_parts(3_144, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(56_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Relay TheftReports (r:1 w:1)
+	/// Proof: Relay TheftReports (max_values: None, max_size: Some(131), added: 2606, mode: MaxEncodedLen)
+	/// Storage: BTCRelay DisableInclusionCheck (r:1 w:0)
+	/// Proof: BTCRelay DisableInclusionCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:1 w:0)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:1 w:0)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableParachainConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableParachainConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PendingPublicKeyRotation (r:1 w:0)
+	/// Proof: VaultRegistry PendingPublicKeyRotation (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry RetiredBitcoinPublicKeys (r:1 w:0)
+	/// Proof: VaultRegistry RetiredBitcoinPublicKeys (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ReservedAddresses (r:1 w:0)
+	/// Proof: VaultRegistry ReservedAddresses (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinMultisig (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinMultisig (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
+	/// Storage: Redeem RedeemRequests (r:1 w:0)
+	/// Proof: Redeem RedeemRequests (max_values: None, max_size: Some(270), added: 2745, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceRequests (r:1 w:0)
+	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PunishmentDelay (r:1 w:0)
+	/// Proof: VaultRegistry PunishmentDelay (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:1)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashPerToken (r:1 w:1)
+	/// Proof: VaultStaking SlashPerToken (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashTally (r:1 w:1)
+	/// Proof: VaultStaking SlashTally (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:1)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardPerToken (r:2 w:0)
+	/// Proof: VaultCapacity RewardPerToken (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardTally (r:2 w:2)
+	/// Proof: VaultCapacity RewardTally (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalRewards (r:2 w:2)
+	/// Proof: VaultCapacity TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:0)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalRewards (r:2 w:2)
+	/// Proof: VaultRewards TotalRewards (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Fee Commission (r:1 w:0)
+	/// Proof: Fee Commission (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardPerToken (r:2 w:2)
+	/// Proof: VaultStaking RewardPerToken (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalStake (r:1 w:1)
+	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:1)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardTally (r:2 w:2)
+	/// Proof: VaultStaking RewardTally (max_values: None, max_size: Some(149), added: 2624, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardCurrencies (r:1 w:0)
+	/// Proof: VaultRewards RewardCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Fee PunishmentFee (r:1 w:0)
+	/// Proof: Fee PunishmentFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 10]`.
+	/// The range of component `i` is `[2, 10]`.
+	/// The range of component `o` is `[2, 3]`.
+	/// The range of component `b` is `[541, 2048]`.
+	fn report_vault_theft(h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6952`
+		//  Estimated: `124453`
+		// Minimum execution time: 1_302_517_000 picoseconds.
+		Weight::from_parts(1_318_094_372, 124453)
+			// Standard Error: 154_208
+			.saturating_add(Weight::from_parts(1_027_614, 0).saturating_mul(h.into()))
+			// Standard Error: 46_385
+			.saturating_add(Weight::from_parts(412_387, 0).saturating_mul(i.into()))
+			// Standard Error: 291
+			.saturating_add(Weight::from_parts(3_144, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(56_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+use mocktopus::macros::mockable;
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod btc_relay {
+    use bitcoin::types::{FullTransactionProof, H256Le, Transaction, Value};
+    use btc_relay::{BtcAddress, RichBlockHeader};
+    use frame_support::dispatch::DispatchError;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_core::H256;
+    use sp_std::{convert::TryInto, vec::Vec};
+
+    pub fn verify_transaction_inclusion<T: crate::Config>(
+        unchecked_transaction: FullTransactionProof,
+    ) -> Result<Transaction, DispatchError> {
+        <btc_relay::Pallet<T>>::_verify_transaction_inclusion(unchecked_transaction, None)
+    }
+
+    pub fn validate_op_return_transaction<T: crate::Config, V: TryInto<Value>>(
+        transaction: Transaction,
+        recipient_btc_address: BtcAddress,
+        expected_btc: V,
+        op_return_id: H256,
+    ) -> Result<Option<BtcAddress>, DispatchError> {
+        <btc_relay::Pallet<T>>::validate_op_return_transaction(
+            transaction,
+            recipient_btc_address,
+            expected_btc,
+            op_return_id,
+        )
+    }
+
+    pub fn validate_batched_op_return_transaction<T: crate::Config, V: TryInto<Value>>(
+        transaction: Transaction,
+        expected_payments: Vec<(H256, BtcAddress, V)>,
+    ) -> Result<Option<BtcAddress>, DispatchError> {
+        <btc_relay::Pallet<T>>::validate_batched_op_return_transaction(transaction, expected_payments)
+    }
+
+    pub fn get_block_header_from_hash<T: crate::Config>(
+        block_hash: H256Le,
+    ) -> Result<RichBlockHeader<BlockNumberFor<T>>, DispatchError> {
+        <btc_relay::Pallet<T>>::get_block_header_from_hash(block_hash)
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod vault_registry {
+    use btc_relay::{BtcAddress, BtcPublicKey};
+    use frame_support::dispatch::{DispatchError, DispatchResult};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use vault_registry::DefaultVaultId;
+
+    pub fn get_bitcoin_public_key<T: crate::Config>(account_id: &T::AccountId) -> Result<BtcPublicKey, DispatchError> {
+        <vault_registry::Pallet<T>>::get_bitcoin_public_key(account_id)
+    }
+
+    pub fn is_vault_public_key<T: crate::Config>(vault_id: &DefaultVaultId<T>, public_key: &BtcPublicKey) -> bool {
+        <vault_registry::Pallet<T>>::is_vault_public_key(vault_id, public_key)
+    }
+
+    pub fn is_vault_address<T: crate::Config>(vault_id: &DefaultVaultId<T>, address: &BtcAddress) -> bool {
+        <vault_registry::Pallet<T>>::is_vault_address(vault_id, address)
+    }
+
+    pub fn is_migration_period_open<T: crate::Config>(account_id: &T::AccountId, height: BlockNumberFor<T>) -> bool {
+        <vault_registry::Pallet<T>>::is_migration_period_open(account_id, height)
+    }
+
+    pub fn liquidate_theft_vault<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        reporter_id: T::AccountId,
+    ) -> DispatchResult {
+        <vault_registry::Pallet<T>>::liquidate_theft_vault(vault_id, reporter_id)
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod redeem {
+    use frame_support::dispatch::DispatchError;
+    use redeem::DefaultRedeemRequest;
+    use sp_core::H256;
+
+    pub fn get_open_or_completed_redeem_request_from_id<T: crate::Config>(
+        id: &H256,
+    ) -> Result<DefaultRedeemRequest<T>, DispatchError> {
+        <redeem::Pallet<T>>::get_open_or_completed_redeem_request_from_id(id)
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod replace {
+    use frame_support::dispatch::DispatchError;
+    use replace::DefaultReplaceRequest;
+    use sp_core::H256;
+
+    pub fn get_open_or_completed_replace_request<T: crate::Config>(
+        id: &H256,
+    ) -> Result<DefaultReplaceRequest<T>, DispatchError> {
+        <replace::Pallet<T>>::get_open_or_completed_replace_request(id)
+    }
+}
@@ -0,0 +1,337 @@
+//! # Relay Pallet
+//! Based on the [specification](https://spec.interlay.io/spec/relay.html).
+//!
+//! Allows anyone to report a vault that moved BTC out of its addresses without
+//! an authorized redeem or replace request. A reported vault is banned and
+//! liquidated, and the reporter is rewarded from the vault's collateral.
+
+#![deny(warnings)]
+#![cfg_attr(test, feature(proc_macro_hygiene))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod default_weights;
+mod ext;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+extern crate mocktopus;
+
+#[cfg(test)]
+use mocktopus::macros::mockable;
+
+use bitcoin::types::{FullTransactionProof, H256Le, Transaction, TransactionInput, Value};
+use btc_relay::{BatchedOpReturnPaymentData, BtcAddress, OpReturnPaymentData};
+pub use default_weights::WeightInfo;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    ensure,
+    pallet_prelude::Weight,
+    transactional,
+};
+use frame_system::ensure_signed;
+use sp_core::H256;
+use sp_std::{
+    convert::{TryFrom, TryInto},
+    vec::Vec,
+};
+use vault_registry::DefaultVaultId;
+
+pub use pallet::*;
+
+/// Complexity:
+/// - `O(H + I + O + B)` where:
+///   - `H` is the number of hashes in the merkle tree
+///   - `I` is the number of transaction inputs
+///   - `O` is the number of transaction outputs
+///   - `B` is `transaction` size in bytes (length-fee-bounded)
+fn weight_for_report_vault_theft<T: Config>(proof: &FullTransactionProof) -> Weight {
+    let h = proof.user_tx_proof.merkle_proof.hashes.len() as u32;
+    let i = proof.user_tx_proof.transaction.inputs.len() as u32;
+    let o = proof.user_tx_proof.transaction.outputs.len() as u32;
+    let b = proof.user_tx_proof.tx_encoded_len;
+    <T as Config>::WeightInfo::report_vault_theft(h, i, o, b)
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    /// ## Configuration
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + security::Config
+        + vault_registry::Config
+        + btc_relay::Config
+        + redeem::Config
+        + replace::Config
+        + fee::Config
+    {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for the extrinsics in this module.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        VaultTheft { vault_id: DefaultVaultId<T>, tx_id: H256Le },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Vault already reported for this transaction.
+        VaultAlreadyReported,
+        /// No input of the transaction spends from the vault.
+        VaultNoInputToTransaction,
+        /// Transaction is a valid payment for a redeem request of the vault.
+        ValidRedeemTransaction,
+        /// Transaction is a valid payment for a replace request of the vault.
+        ValidReplaceTransaction,
+        /// Transaction migrates the vault's BTC to its current public key after a key rotation.
+        ValidMigrationTransaction,
+        /// Transaction only moves BTC between addresses of the vault.
+        ValidSelfTransfer,
+    }
+
+    /// Mapping of Bitcoin transaction identifiers to the vaults that were reported for them,
+    /// used to prevent duplicate theft reports.
+    #[pallet::storage]
+    pub(super) type TheftReports<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, H256Le, Blake2_128Concat, DefaultVaultId<T>, (), OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    // The pallet's dispatchable functions.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Report a vault for moving BTC out of its addresses without an authorized redeem
+        /// or replace request. On success the vault is banned and liquidated, the reporter
+        /// is rewarded and the transaction fee is refunded.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the reporter
+        /// * `vault_id` - the vault that signed the transaction
+        /// * `unchecked_transaction` - the transaction and its inclusion proof
+        #[pallet::call_index(0)]
+        #[pallet::weight(weight_for_report_vault_theft::<T>(unchecked_transaction))]
+        #[transactional]
+        pub fn report_vault_theft(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            unchecked_transaction: FullTransactionProof,
+        ) -> DispatchResultWithPostInfo {
+            let reporter_id = ensure_signed(origin)?;
+
+            let block_hash = unchecked_transaction.user_tx_proof.merkle_proof.block_header.hash;
+            let transaction = ext::btc_relay::verify_transaction_inclusion::<T>(unchecked_transaction)?;
+            let tx_id = transaction.tx_id();
+
+            // liquidated vaults are rejected by the vault registry, but the same
+            // transaction may still be used to report another vault of this account
+            ensure!(
+                !TheftReports::<T>::contains_key(&tx_id, &vault_id),
+                Error::<T>::VaultAlreadyReported
+            );
+
+            ensure!(
+                !Self::is_public_key_migration(&vault_id.account_id, &transaction, block_hash)?,
+                Error::<T>::ValidMigrationTransaction
+            );
+            Self::_is_parsed_transaction_invalid(&vault_id, transaction)?;
+
+            ext::vault_registry::liquidate_theft_vault::<T>(&vault_id, reporter_id)?;
+
+            TheftReports::<T>::insert(&tx_id, &vault_id, ());
+
+            Self::deposit_event(Event::VaultTheft { vault_id, tx_id });
+
+            // don't take tx fees on success
+            Ok(Pays::No.into())
+        }
+    }
+}
+
+// "Internal" functions, callable by code.
+#[cfg_attr(test, mockable)]
+impl<T: Config> Pallet<T> {
+    /// Checks that the transaction spends from the vault, and that it is neither a transfer
+    /// between the vault's own addresses nor a valid payment for one of the vault's redeem
+    /// or replace requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - the vault that is reported
+    /// * `transaction` - the parsed bitcoin transaction
+    pub fn _is_parsed_transaction_invalid(vault_id: &DefaultVaultId<T>, transaction: Transaction) -> DispatchResult {
+        ensure!(
+            transaction
+                .inputs
+                .iter()
+                .any(|input| Self::is_vault_input(vault_id, input)),
+            Error::<T>::VaultNoInputToTransaction
+        );
+
+        // the vault may consolidate its deposits or move them between its own addresses
+        ensure!(
+            !Self::is_self_transfer(vault_id, &transaction),
+            Error::<T>::ValidSelfTransfer
+        );
+
+        // transactions without a well-formed op_return can't fulfill any request
+        let request_id = match OpReturnPaymentData::<T>::try_from(transaction.clone()) {
+            Ok(payment_data) => payment_data.op_return,
+            Err(_) => {
+                // the transaction may still pay several redeem requests at once
+                ensure!(
+                    !Self::is_valid_batched_redeem_payment(vault_id, transaction),
+                    Error::<T>::ValidRedeemTransaction
+                );
+                return Ok(());
+            }
+        };
+
+        // completed requests are included since they were paid by this transaction
+        if let Ok(request) = ext::redeem::get_open_or_completed_redeem_request_from_id::<T>(&request_id) {
+            ensure!(
+                request.vault != *vault_id
+                    || !Self::is_valid_payment(
+                        vault_id,
+                        transaction.clone(),
+                        request.btc_address,
+                        request.amount_btc,
+                        request_id,
+                    ),
+                Error::<T>::ValidRedeemTransaction
+            );
+        }
+
+        if let Ok(request) = ext::replace::get_open_or_completed_replace_request::<T>(&request_id) {
+            ensure!(
+                request.old_vault != *vault_id
+                    || !Self::is_valid_payment(vault_id, transaction, request.btc_address, request.amount, request_id),
+                Error::<T>::ValidReplaceTransaction
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks if all outputs of the transaction pay to addresses of the vault.
+    fn is_self_transfer(vault_id: &DefaultVaultId<T>, transaction: &Transaction) -> bool {
+        !transaction.outputs.is_empty()
+            && transaction.outputs.iter().all(|output| match output.extract_address() {
+                Ok(address) => ext::vault_registry::is_vault_address::<T>(vault_id, &address),
+                Err(_) => false,
+            })
+    }
+
+    /// Checks if the transaction pays the request, and returns any change to the vault. The
+    /// return-to-self may go to a deposit address or to the address of the vault's master keys,
+    /// which is a P2WSH multisig script for multisig vaults.
+    fn is_valid_payment<V: TryInto<Value>>(
+        vault_id: &DefaultVaultId<T>,
+        transaction: Transaction,
+        recipient_btc_address: BtcAddress,
+        expected_btc: V,
+        request_id: H256,
+    ) -> bool {
+        match ext::btc_relay::validate_op_return_transaction::<T, _>(
+            transaction,
+            recipient_btc_address,
+            expected_btc,
+            request_id,
+        ) {
+            Ok(None) => true,
+            Ok(Some(return_to_self)) => ext::vault_registry::is_vault_address::<T>(vault_id, &return_to_self),
+            Err(_) => false,
+        }
+    }
+
+    /// Checks if the transaction pays several redeem requests of the vault at once, and returns
+    /// any change to the vault.
+    fn is_valid_batched_redeem_payment(vault_id: &DefaultVaultId<T>, transaction: Transaction) -> bool {
+        let payment_data = match BatchedOpReturnPaymentData::<T>::try_from(transaction.clone()) {
+            Ok(payment_data) => payment_data,
+            Err(_) => return false,
+        };
+
+        // completed requests are included since they were paid by this transaction
+        let mut expected_payments = Vec::new();
+        for request_id in payment_data.op_returns() {
+            match ext::redeem::get_open_or_completed_redeem_request_from_id::<T>(&request_id) {
+                Ok(request) if request.vault == *vault_id => {
+                    expected_payments.push((request_id, request.btc_address, request.amount_btc))
+                }
+                _ => return false,
+            }
+        }
+
+        match ext::btc_relay::validate_batched_op_return_transaction::<T, _>(transaction, expected_payments) {
+            Ok(None) => true,
+            Ok(Some(return_to_self)) => ext::vault_registry::is_vault_address::<T>(vault_id, &return_to_self),
+            Err(_) => false,
+        }
+    }
+
+    /// Checks if the transaction only pays to the account's current public key, and was included
+    /// in a Bitcoin block while the migration period of one of its retired keys was open.
+    fn is_public_key_migration(
+        account_id: &T::AccountId,
+        transaction: &Transaction,
+        block_hash: H256Le,
+    ) -> Result<bool, DispatchError> {
+        let public_key_hash = match ext::vault_registry::get_bitcoin_public_key::<T>(account_id) {
+            Ok(public_key) => public_key.to_hash(),
+            Err(_) => return Ok(false),
+        };
+        let pays_to_public_key = !transaction.outputs.is_empty()
+            && transaction.outputs.iter().all(|output| match output.extract_address() {
+                Ok(BtcAddress::P2WPKHv0(hash)) | Ok(BtcAddress::P2PKH(hash)) => hash == public_key_hash,
+                _ => false,
+            });
+        if !pays_to_public_key {
+            return Ok(false);
+        }
+
+        // the period is checked against the parachain height at which the block was relayed
+        // so that late theft reports can't invalidate a timely migration
+        let inclusion_height = ext::btc_relay::get_block_header_from_hash::<T>(block_hash)?.para_height;
+        Ok(ext::vault_registry::is_migration_period_open::<T>(
+            account_id,
+            inclusion_height,
+        ))
+    }
+
+    /// Checks if the input is signed by the vault's public key or spends from
+    /// one of its deposit addresses.
+    fn is_vault_input(vault_id: &DefaultVaultId<T>, input: &TransactionInput) -> bool {
+        let spends_from_public_key = input
+            .extract_public_key()
+            .map(|public_key| ext::vault_registry::is_vault_public_key::<T>(vault_id, &public_key))
+            .unwrap_or(false);
+        let spends_from_address = input
+            .extract_address()
+            .map(|address| ext::vault_registry::is_vault_address::<T>(vault_id, &address))
+            .unwrap_or(false);
+        spends_from_public_key || spends_from_address
+    }
+}
@@ -0,0 +1,444 @@
+use crate::{mock::*, *};
+
+use bitcoin::{
+    merkle::PartialTransactionProof,
+    types::{TransactionBuilder, TransactionInputBuilder, TransactionOutput},
+    Script,
+};
+use btc_relay::{BtcAddress, BtcPublicKey, RichBlockHeader};
+use frame_support::{assert_err, assert_ok};
+use mocktopus::mocking::*;
+use primitives::VaultId;
+use redeem::{RedeemRequest, RedeemRequestStatus};
+use replace::{ReplaceRequest, ReplaceRequestStatus};
+use sp_core::H256;
+
+type Event = crate::Event<Test>;
+
+fn dummy_proof() -> FullTransactionProof {
+    FullTransactionProof {
+        user_tx_proof: PartialTransactionProof {
+            transaction: Default::default(),
+            tx_encoded_len: u32::MAX,
+            merkle_proof: Default::default(),
+        },
+        coinbase_proof: PartialTransactionProof {
+            transaction: Default::default(),
+            tx_encoded_len: u32::MAX,
+            merkle_proof: Default::default(),
+        },
+    }
+}
+
+fn vault_transaction(outputs: Vec<TransactionOutput>) -> Transaction {
+    let mut builder = TransactionBuilder::new();
+    builder.with_version(2).add_input(
+        TransactionInputBuilder::new()
+            .with_p2wpkh(&BtcPublicKey::dummy(), vec![1; 72])
+            .build(),
+    );
+    for output in outputs {
+        builder.add_output(output);
+    }
+    builder.build()
+}
+
+fn payment(request_id: H256, btc_address: BtcAddress, amount: i64) -> Vec<TransactionOutput> {
+    vec![
+        TransactionOutput::payment(amount, &btc_address),
+        TransactionOutput::op_return(0, request_id.as_bytes()),
+    ]
+}
+
+fn redeem_request(btc_address: BtcAddress, amount_btc: u128) -> redeem::DefaultRedeemRequest<Test> {
+    RedeemRequest {
+        period: 0,
+        vault: VAULT,
+        opentime: 1,
+        fee: 0,
+        amount_btc,
+        premium: 0,
+        redeemer: REPORTER,
+        btc_address,
+        btc_height: 0,
+        status: RedeemRequestStatus::Pending,
+        transfer_fee_btc: 0,
+    }
+}
+
+fn replace_request(btc_address: BtcAddress, amount: u128) -> replace::DefaultReplaceRequest<Test> {
+    ReplaceRequest {
+        period: 0,
+        new_vault: VaultId::new(REPORTER, DEFAULT_COLLATERAL_CURRENCY, DEFAULT_WRAPPED_CURRENCY),
+        old_vault: VAULT,
+        accept_time: 1,
+        amount,
+        griefing_collateral: 0,
+        btc_address,
+        collateral: 0,
+        btc_height: 0,
+        status: ReplaceRequestStatus::Pending,
+    }
+}
+
+fn setup_mocks(transaction: Transaction) {
+    ext::btc_relay::verify_transaction_inclusion::<Test>
+        .mock_safe(move |_| MockResult::Return(Ok(transaction.clone())));
+    ext::vault_registry::is_vault_public_key::<Test>
+        .mock_safe(|_, public_key| MockResult::Return(public_key == &BtcPublicKey::dummy()));
+    ext::vault_registry::is_vault_address::<Test>.mock_safe(|_, _| MockResult::Return(false));
+    ext::vault_registry::liquidate_theft_vault::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+}
+
+#[test]
+fn report_vault_theft_succeeds() {
+    run_test(|| {
+        let transaction = vault_transaction(vec![TransactionOutput::payment(100, &BtcAddress::random())]);
+        let tx_id = transaction.tx_id();
+        setup_mocks(transaction);
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+        assert!(System::events()
+            .iter()
+            .any(|a| a.event == TestEvent::Relay(Event::VaultTheft { vault_id: VAULT, tx_id })));
+        assert!(TheftReports::<Test>::contains_key(&tx_id, &VAULT));
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_when_already_reported() {
+    run_test(|| {
+        setup_mocks(vault_transaction(vec![TransactionOutput::payment(
+            100,
+            &BtcAddress::random(),
+        )]));
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::VaultAlreadyReported
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_from_deposit_address_succeeds() {
+    run_test(|| {
+        let deposit_public_key = BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+        let deposit_address = BtcAddress::P2WPKHv0(deposit_public_key.to_hash());
+        let transaction = TransactionBuilder::new()
+            .with_version(2)
+            .add_input(
+                TransactionInputBuilder::new()
+                    .with_p2wpkh(&deposit_public_key, vec![1; 72])
+                    .build(),
+            )
+            .add_output(TransactionOutput::payment(100, &BtcAddress::random()))
+            .build();
+        setup_mocks(transaction);
+        ext::vault_registry::is_vault_address::<Test>
+            .mock_safe(move |_, address| MockResult::Return(address == &deposit_address));
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_from_multisig_deposit_address_succeeds() {
+    run_test(|| {
+        let public_keys = [
+            BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap(),
+            BtcPublicKey::dummy()
+                .new_deposit_public_key(H256::repeat_byte(1))
+                .unwrap(),
+        ];
+        let witness_script = Script::multisig(2, &public_keys).unwrap();
+        let deposit_address = BtcAddress::from_witness_script(witness_script.as_bytes());
+        let transaction = TransactionBuilder::new()
+            .with_version(2)
+            .add_input(
+                TransactionInputBuilder::new()
+                    .add_witness(&[])
+                    .add_witness(&[1; 72])
+                    .add_witness(&[2; 72])
+                    .add_witness(witness_script.as_bytes())
+                    .build(),
+            )
+            .add_output(TransactionOutput::payment(100, &BtcAddress::random()))
+            .build();
+        setup_mocks(transaction);
+        ext::vault_registry::is_vault_address::<Test>
+            .mock_safe(move |_, address| MockResult::Return(address == &deposit_address));
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_self_transfer() {
+    run_test(|| {
+        // consolidates deposits to the vault's master address, without an op_return
+        let master_address = BtcAddress::P2WPKHv0(BtcPublicKey::dummy().to_hash());
+        setup_mocks(vault_transaction(vec![
+            TransactionOutput::payment(100, &master_address),
+            TransactionOutput::payment(50, &master_address),
+        ]));
+        ext::vault_registry::is_vault_address::<Test>
+            .mock_safe(move |_, address| MockResult::Return(address == &master_address));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidSelfTransfer
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_without_vault_input() {
+    run_test(|| {
+        setup_mocks(vault_transaction(vec![TransactionOutput::payment(
+            100,
+            &BtcAddress::random(),
+        )]));
+        ext::vault_registry::is_vault_public_key::<Test>.mock_safe(|_, _| MockResult::Return(false));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::VaultNoInputToTransaction
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_valid_redeem_transaction() {
+    run_test(|| {
+        let redeem_id = H256::random();
+        let btc_address = BtcAddress::random();
+        setup_mocks(vault_transaction(payment(redeem_id, btc_address, 100)));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(redeem_request(btc_address, 100))));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidRedeemTransaction
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_valid_batched_redeem_transaction() {
+    run_test(|| {
+        let requests = [
+            (H256::random(), BtcAddress::random(), 100),
+            (H256::random(), BtcAddress::random(), 200),
+        ];
+        let mut outputs = payment(requests[0].0, requests[0].1, 100);
+        outputs.extend(payment(requests[1].0, requests[1].1, 200));
+        setup_mocks(vault_transaction(outputs));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>.mock_safe(move |redeem_id| {
+            let (_, btc_address, amount) = requests.iter().find(|(id, _, _)| id == redeem_id).unwrap();
+            MockResult::Return(Ok(redeem_request(*btc_address, *amount)))
+        });
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidRedeemTransaction
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_with_overpaid_batched_redeem_succeeds() {
+    run_test(|| {
+        let requests = [
+            (H256::random(), BtcAddress::random(), 100),
+            (H256::random(), BtcAddress::random(), 200),
+        ];
+        let mut outputs = payment(requests[0].0, requests[0].1, 100);
+        outputs.extend(payment(requests[1].0, requests[1].1, 300));
+        setup_mocks(vault_transaction(outputs));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>.mock_safe(move |redeem_id| {
+            let (_, btc_address, amount) = requests.iter().find(|(id, _, _)| id == redeem_id).unwrap();
+            MockResult::Return(Ok(redeem_request(*btc_address, *amount)))
+        });
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_with_invalid_redeem_payment_succeeds() {
+    run_test(|| {
+        let redeem_id = H256::random();
+        let btc_address = BtcAddress::random();
+        setup_mocks(vault_transaction(payment(redeem_id, btc_address, 200)));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(redeem_request(btc_address, 100))));
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_with_foreign_return_to_self_succeeds() {
+    run_test(|| {
+        let redeem_id = H256::random();
+        let btc_address = BtcAddress::random();
+        let mut outputs = payment(redeem_id, btc_address, 100);
+        outputs.push(TransactionOutput::payment(50, &BtcAddress::random()));
+        setup_mocks(vault_transaction(outputs));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(redeem_request(btc_address, 100))));
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_multisig_return_to_self() {
+    run_test(|| {
+        let redeem_id = H256::random();
+        let btc_address = BtcAddress::random();
+        let public_keys = [
+            BtcPublicKey::dummy(),
+            BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap(),
+        ];
+        let vault_address = BtcAddress::from_witness_script(Script::multisig(2, &public_keys).unwrap().as_bytes());
+        let mut outputs = payment(redeem_id, btc_address, 100);
+        outputs.push(TransactionOutput::payment(50, &vault_address));
+        setup_mocks(vault_transaction(outputs));
+        ext::vault_registry::is_vault_address::<Test>
+            .mock_safe(move |_, address| MockResult::Return(address == &vault_address));
+        ext::redeem::get_open_or_completed_redeem_request_from_id::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(redeem_request(btc_address, 100))));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidRedeemTransaction
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_valid_replace_transaction() {
+    run_test(|| {
+        let replace_id = H256::random();
+        let btc_address = BtcAddress::random();
+        setup_mocks(vault_transaction(payment(replace_id, btc_address, 100)));
+        ext::replace::get_open_or_completed_replace_request::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(replace_request(btc_address, 100))));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidReplaceTransaction
+        );
+    })
+}
+
+fn setup_migration(migration_period_open: bool) {
+    let new_public_key = BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+    setup_mocks(vault_transaction(vec![TransactionOutput::payment(
+        100,
+        &BtcAddress::P2WPKHv0(new_public_key.to_hash()),
+    )]));
+    ext::vault_registry::get_bitcoin_public_key::<Test>.mock_safe(move |_| MockResult::Return(Ok(new_public_key)));
+    ext::btc_relay::get_block_header_from_hash::<Test>.mock_safe(|_| {
+        MockResult::Return(Ok(RichBlockHeader {
+            para_height: 5,
+            ..Default::default()
+        }))
+    });
+    ext::vault_registry::is_migration_period_open::<Test>.mock_safe(move |_, height| {
+        assert_eq!(height, 5);
+        MockResult::Return(migration_period_open)
+    });
+}
+
+#[test]
+fn report_vault_theft_fails_with_valid_migration_transaction() {
+    run_test(|| {
+        setup_migration(true);
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidMigrationTransaction
+        );
+    })
+}
+
+#[test]
+fn report_vault_theft_with_late_migration_transaction_succeeds() {
+    run_test(|| {
+        setup_migration(false);
+
+        assert_ok!(Relay::report_vault_theft(
+            RuntimeOrigin::signed(REPORTER),
+            VAULT,
+            dummy_proof()
+        ));
+    })
+}
+
+#[test]
+fn report_vault_theft_fails_with_migration_before_rotation_is_activated() {
+    run_test(|| {
+        let new_public_key = BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+        assert_ok!(VaultRegistry::register_public_key(
+            RuntimeOrigin::signed(VAULT.account_id),
+            BtcPublicKey::dummy()
+        ));
+        assert_ok!(VaultRegistry::propose_public_key(
+            RuntimeOrigin::signed(VAULT.account_id),
+            new_public_key.clone()
+        ));
+        // the rotation is due, but nothing has activated it in storage yet
+        Security::set_active_block_number(15);
+
+        let transaction = vault_transaction(vec![TransactionOutput::payment(
+            100,
+            &BtcAddress::P2WPKHv0(new_public_key.to_hash()),
+        )]);
+        ext::btc_relay::verify_transaction_inclusion::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(transaction.clone())));
+        ext::btc_relay::get_block_header_from_hash::<Test>.mock_safe(|_| {
+            MockResult::Return(Ok(RichBlockHeader {
+                para_height: 15,
+                ..Default::default()
+            }))
+        });
+        ext::vault_registry::liquidate_theft_vault::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
+
+        assert_err!(
+            Relay::report_vault_theft(RuntimeOrigin::signed(REPORTER), VAULT, dummy_proof()),
+            TestError::ValidMigrationTransaction
+        );
+    })
+}
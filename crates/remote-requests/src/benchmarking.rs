@@ -0,0 +1,171 @@
+use super::*;
+use bitcoin::types::BlockBuilder;
+use btc_relay::BtcPublicKey;
+use currency::{
+    getters::{get_relay_chain_currency_id as get_collateral_currency_id, *},
+    Amount,
+};
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use oracle::OracleKey;
+use orml_traits::MultiCurrency;
+use primitives::{CurrencyId, VaultId};
+use sp_core::U256;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::prelude::*;
+
+// Pallets
+use crate::Pallet as RemoteRequests;
+use btc_relay::Pallet as BtcRelay;
+use oracle::Pallet as Oracle;
+use redeem::Pallet as Redeem;
+use security::Pallet as Security;
+use vault_registry::Pallet as VaultRegistry;
+
+type UnsignedFixedPoint<T> = <T as currency::Config>::UnsignedFixedPoint;
+
+fn deposit_tokens<T: crate::Config>(currency_id: CurrencyId, account_id: &T::AccountId, amount: BalanceOf<T>) {
+    assert_ok!(<orml_tokens::Pallet<T>>::deposit(currency_id, account_id, amount));
+}
+
+fn mint_collateral<T: crate::Config>(account_id: &T::AccountId, amount: BalanceOf<T>) {
+    deposit_tokens::<T>(get_collateral_currency_id::<T>(), account_id, amount);
+    deposit_tokens::<T>(get_native_currency_id::<T>(), account_id, amount);
+}
+
+fn get_vault_id<T: crate::Config>() -> DefaultVaultId<T> {
+    VaultId::new(
+        account("Vault", 0, 0),
+        get_collateral_currency_id::<T>(),
+        get_wrapped_currency_id::<T>(),
+    )
+}
+
+/// Returns the origin of a remote request and the local account acting for it.
+fn remote_origin<T: crate::Config>() -> (T::RuntimeOrigin, T::AccountId) {
+    let origin = T::RemoteOrigin::try_successful_origin().unwrap();
+    let (_, account_id) = T::RemoteOrigin::try_origin(origin.clone()).map_err(|_| ()).unwrap();
+    (origin, account_id)
+}
+
+fn setup_chain<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+    let oracle_id: T::AccountId = account("Oracle", 12, 0);
+    Oracle::<T>::_feed_values(
+        oracle_id,
+        vec![
+            (
+                OracleKey::ExchangeRate(get_collateral_currency_id::<T>()),
+                UnsignedFixedPoint::<T>::one(),
+            ),
+            (
+                // for griefing collateral
+                OracleKey::ExchangeRate(get_native_currency_id::<T>()),
+                UnsignedFixedPoint::<T>::one(),
+            ),
+            (
+                OracleKey::FeeEstimation,
+                UnsignedFixedPoint::<T>::checked_from_rational(3, 1).unwrap(),
+            ),
+        ],
+    );
+    Oracle::<T>::begin_block(0u32.into());
+
+    VaultRegistry::<T>::set_minimum_collateral(
+        RawOrigin::Root.into(),
+        vault_id.collateral_currency(),
+        100_000u32.into(),
+    )
+    .unwrap();
+    VaultRegistry::<T>::_set_system_collateral_ceiling(vault_id.currencies.clone(), 1_000_000_000u32.into());
+    VaultRegistry::<T>::_set_secure_collateral_threshold(
+        vault_id.currencies.clone(),
+        UnsignedFixedPoint::<T>::checked_from_rational(1, 100000).unwrap(),
+    );
+    VaultRegistry::<T>::_set_premium_redeem_threshold(
+        vault_id.currencies.clone(),
+        UnsignedFixedPoint::<T>::checked_from_rational(1, 200000).unwrap(),
+    );
+    VaultRegistry::<T>::_set_liquidation_collateral_threshold(
+        vault_id.currencies.clone(),
+        UnsignedFixedPoint::<T>::checked_from_rational(1, 300000).unwrap(),
+    );
+
+    let relayer_id: T::AccountId = account("Relayer", 0, 0);
+    mint_collateral::<T>(&relayer_id, (1u32 << 31).into());
+    let init_block = BlockBuilder::new()
+        .with_version(4)
+        .with_coinbase(&BtcAddress::dummy(), 50, 3)
+        .with_timestamp(1588813835)
+        .mine(U256::from(2).pow(254.into()))
+        .unwrap();
+    Security::<T>::set_active_block_number(1u32.into());
+    BtcRelay::<T>::_initialize(relayer_id.clone(), init_block.header, 0).unwrap();
+    BtcRelay::<T>::mine_blocks(&relayer_id, 1);
+    Security::<T>::set_active_block_number(
+        Security::<T>::active_block_number() + BtcRelay::<T>::parachain_confirmations(),
+    );
+}
+
+fn register_vault<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+    let origin = RawOrigin::Signed(vault_id.account_id.clone());
+    mint_collateral::<T>(&vault_id.account_id, (1u32 << 31).into());
+
+    assert_ok!(VaultRegistry::<T>::register_public_key(
+        origin.into(),
+        BtcPublicKey::dummy()
+    ));
+    assert_ok!(VaultRegistry::<T>::_register_vault(
+        vault_id.clone(),
+        100000000u32.into()
+    ));
+}
+
+#[benchmarks]
+pub mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn request_issue() {
+        let (origin, account_id) = remote_origin::<T>();
+        let amount: BalanceOf<T> = 100_000u32.into();
+        let vault_id = get_vault_id::<T>();
+
+        mint_collateral::<T>(&account_id, (1u32 << 31).into());
+        setup_chain::<T>(&vault_id);
+        register_vault::<T>(&vault_id);
+
+        #[extrinsic_call]
+        request_issue(
+            origin,
+            amount,
+            vault_id,
+            get_native_currency_id::<T>(),
+            Box::new(VersionedMultiLocation::V3(MultiLocation::parent())),
+            Some(amount),
+        );
+    }
+
+    #[benchmark]
+    fn request_redeem() {
+        let (origin, account_id) = remote_origin::<T>();
+        let amount = Redeem::<T>::redeem_btc_dust_value() * BalanceOf::<T>::from(100u32);
+        let vault_id = get_vault_id::<T>();
+
+        setup_chain::<T>(&vault_id);
+        register_vault::<T>(&vault_id);
+        let mut vault = VaultRegistry::<T>::get_vault_from_id(&vault_id).unwrap();
+        vault.issued_tokens = amount;
+        VaultRegistry::<T>::insert_vault(&vault_id, vault);
+        assert_ok!(Amount::<T>::new(amount, get_wrapped_currency_id::<T>()).mint_to(&account_id));
+
+        #[extrinsic_call]
+        request_redeem(origin, amount, BtcAddress::dummy(), vault_id, Some(amount));
+    }
+
+    impl_benchmark_test_suite!(
+        RemoteRequests,
+        crate::mock::ExtBuilder::build_with(Default::default()),
+        crate::mock::Test
+    );
+}
@@ -0,0 +1,280 @@
+
+//! Autogenerated weights for remote_requests
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-04, STEPS: `50`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `Nakuls-MacBook-Pro.local`, CPU: `<UNKNOWN>`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("kintsugi-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// remote-requests
+// --extrinsic
+// *
+// --wasm-execution=compiled
+// --steps
+// 50
+// --repeat
+// 10
+// --template
+// .deploy/default-weight-template.hbs
+// --chain
+// kintsugi-dev
+// --output
+// ./crates/remote-requests/src/default_weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for remote_requests.
+pub trait WeightInfo {
+	fn request_issue() -> Weight;
+	fn request_redeem() -> Weight;
+}
+
+/// Weights for remote_requests using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+
+	/// Storage: `BTCRelay::StartBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::StartBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::StableBitcoinConfirmations` (r:1 w:0)
+	/// Proof: `BTCRelay::StableBitcoinConfirmations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ParachainStatus` (r:1 w:0)
+	/// Proof: `Security::ParachainStatus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Aggregate` (r:2 w:0)
+	/// Proof: `Oracle::Aggregate` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::IssueGriefingCollateral` (r:1 w:0)
+	/// Proof: `Fee::IssueGriefingCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssueBtcDustValue` (r:1 w:0)
+	/// Proof: `Issue::IssueBtcDustValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::SecureCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::SecureCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::Nonce` (r:1 w:0)
+	/// Proof: `VaultStaking::Nonce` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::TotalCurrentStake` (r:1 w:0)
+	/// Proof: `VaultStaking::TotalCurrentStake` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::IssueFee` (r:1 w:0)
+	/// Proof: `Fee::IssueFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Security::Nonce` (r:1 w:1)
+	/// Proof: `Security::Nonce` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultBitcoinPublicKey` (r:1 w:0)
+	/// Proof: `VaultRegistry::VaultBitcoinPublicKey` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssuePeriod` (r:1 w:0)
+	/// Proof: `Issue::IssuePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssueRequests` (r:0 w:1)
+	/// Proof: `Issue::IssueRequests` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3572`
+		//  Estimated: `26156`
+		// Minimum execution time: 450_294_000 picoseconds.
+		Weight::from_parts(452_439_000, 26156)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Tokens::Accounts` (r:2 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::RedeemFee` (r:1 w:0)
+	/// Proof: `Fee::RedeemFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemTransactionSize` (r:1 w:0)
+	/// Proof: `Redeem::RedeemTransactionSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Aggregate` (r:2 w:0)
+	/// Proof: `Oracle::Aggregate` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemBtcDustValue` (r:1 w:0)
+	/// Proof: `Redeem::RedeemBtcDustValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::PremiumRedeemThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::PremiumRedeemThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::Nonce` (r:1 w:0)
+	/// Proof: `VaultStaking::Nonce` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::TotalCurrentStake` (r:1 w:0)
+	/// Proof: `VaultStaking::TotalCurrentStake` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Loans::UnderlyingAssetId` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Markets` (r:2 w:0)
+	/// Proof: `Loans::Markets` (`max_values`: None, `max_size`: Some(160), added: 2635, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::LastAccruedInterestTime` (r:1 w:1)
+	/// Proof: `Loans::LastAccruedInterestTime` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalBorrows` (r:1 w:0)
+	/// Proof: `Loans::TotalBorrows` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalReserves` (r:1 w:0)
+	/// Proof: `Loans::TotalReserves` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MinExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MinExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MaxExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MaxExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::SecureCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::SecureCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::PremiumRedeemFee` (r:1 w:0)
+	/// Proof: `Fee::PremiumRedeemFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Security::Nonce` (r:1 w:1)
+	/// Proof: `Security::Nonce` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemPeriod` (r:1 w:0)
+	/// Proof: `Redeem::RedeemPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemRequests` (r:0 w:1)
+	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3219`
+		//  Estimated: `6260`
+		// Minimum execution time: 226_000_000 picoseconds.
+		Weight::from_parts(230_000_000, 6260)
+			.saturating_add(T::DbWeight::get().reads(30_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+
+	/// Storage: `BTCRelay::StartBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::StartBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::StableBitcoinConfirmations` (r:1 w:0)
+	/// Proof: `BTCRelay::StableBitcoinConfirmations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ParachainStatus` (r:1 w:0)
+	/// Proof: `Security::ParachainStatus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Aggregate` (r:2 w:0)
+	/// Proof: `Oracle::Aggregate` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::IssueGriefingCollateral` (r:1 w:0)
+	/// Proof: `Fee::IssueGriefingCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssueBtcDustValue` (r:1 w:0)
+	/// Proof: `Issue::IssueBtcDustValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::SecureCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::SecureCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::Nonce` (r:1 w:0)
+	/// Proof: `VaultStaking::Nonce` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::TotalCurrentStake` (r:1 w:0)
+	/// Proof: `VaultStaking::TotalCurrentStake` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::IssueFee` (r:1 w:0)
+	/// Proof: `Fee::IssueFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Security::Nonce` (r:1 w:1)
+	/// Proof: `Security::Nonce` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultBitcoinPublicKey` (r:1 w:0)
+	/// Proof: `VaultRegistry::VaultBitcoinPublicKey` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssuePeriod` (r:1 w:0)
+	/// Proof: `Issue::IssuePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Issue::IssueRequests` (r:0 w:1)
+	/// Proof: `Issue::IssueRequests` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3572`
+		//  Estimated: `26156`
+		// Minimum execution time: 450_294_000 picoseconds.
+		Weight::from_parts(452_439_000, 26156)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Tokens::Accounts` (r:2 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::RedeemFee` (r:1 w:0)
+	/// Proof: `Fee::RedeemFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemTransactionSize` (r:1 w:0)
+	/// Proof: `Redeem::RedeemTransactionSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Aggregate` (r:2 w:0)
+	/// Proof: `Oracle::Aggregate` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemBtcDustValue` (r:1 w:0)
+	/// Proof: `Redeem::RedeemBtcDustValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::PremiumRedeemThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::PremiumRedeemThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::Nonce` (r:1 w:0)
+	/// Proof: `VaultStaking::Nonce` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::TotalCurrentStake` (r:1 w:0)
+	/// Proof: `VaultStaking::TotalCurrentStake` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Loans::UnderlyingAssetId` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Markets` (r:2 w:0)
+	/// Proof: `Loans::Markets` (`max_values`: None, `max_size`: Some(160), added: 2635, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::LastAccruedInterestTime` (r:1 w:1)
+	/// Proof: `Loans::LastAccruedInterestTime` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalBorrows` (r:1 w:0)
+	/// Proof: `Loans::TotalBorrows` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalReserves` (r:1 w:0)
+	/// Proof: `Loans::TotalReserves` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MinExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MinExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MaxExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MaxExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::SecureCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::SecureCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::PremiumRedeemFee` (r:1 w:0)
+	/// Proof: `Fee::PremiumRedeemFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Security::Nonce` (r:1 w:1)
+	/// Proof: `Security::Nonce` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemPeriod` (r:1 w:0)
+	/// Proof: `Redeem::RedeemPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemRequests` (r:0 w:1)
+	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3219`
+		//  Estimated: `6260`
+		// Minimum execution time: 226_000_000 picoseconds.
+		Weight::from_parts(230_000_000, 6260)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+use mocktopus::macros::mockable;
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod issue {
+    use crate::BalanceOf;
+    use frame_support::dispatch::DispatchError;
+    use sp_core::H256;
+    use vault_registry::{types::CurrencyId, DefaultVaultId};
+    use xcm::v3::MultiLocation;
+
+    pub fn request_issue<T: crate::Config>(
+        requester: T::AccountId,
+        amount: BalanceOf<T>,
+        vault_id: DefaultVaultId<T>,
+        griefing_currency: CurrencyId<T>,
+        destination: MultiLocation,
+        max_fee: Option<BalanceOf<T>>,
+    ) -> Result<H256, DispatchError> {
+        <issue::Pallet<T>>::_request_issue(
+            requester,
+            amount,
+            vault_id,
+            griefing_currency,
+            Some(destination),
+            max_fee,
+        )
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod redeem {
+    use crate::BalanceOf;
+    use btc_relay::BtcAddress;
+    use frame_support::dispatch::DispatchError;
+    use sp_core::H256;
+    use vault_registry::DefaultVaultId;
+
+    pub fn request_redeem<T: crate::Config>(
+        redeemer: T::AccountId,
+        amount_wrapped: BalanceOf<T>,
+        btc_address: BtcAddress,
+        vault_id: DefaultVaultId<T>,
+        max_fee: Option<BalanceOf<T>>,
+    ) -> Result<H256, DispatchError> {
+        <redeem::Pallet<T>>::_request_redeem(redeemer, amount_wrapped, btc_address, vault_id, max_fee)
+    }
+}
@@ -0,0 +1,180 @@
+//! # Remote Requests Module
+//! Lets other chains request issue and redeem for their users through XCM `Transact`, without
+//! the users holding an account on this chain. Requests are made from the local account derived
+//! from the origin of the message, e.g. the sovereign account of a sibling parachain, and every
+//! event is indexed by the hash of that origin so that it can be queried through
+//! `frame_system::EventTopics`.
+
+#![deny(warnings)]
+#![cfg_attr(test, feature(proc_macro_hygiene))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod default_weights;
+pub use default_weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+extern crate mocktopus;
+
+mod ext;
+
+use btc_relay::BtcAddress;
+use frame_support::{dispatch::DispatchResultWithPostInfo, traits::EnsureOrigin, transactional};
+use sp_core::H256;
+use sp_runtime::traits::Hash;
+use sp_std::boxed::Box;
+use vault_registry::{types::CurrencyId, DefaultVaultId};
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
+
+pub use pallet::*;
+
+pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// ## Configuration
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + issue::Config + redeem::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin of a remote request, which resolves to its location and the local account
+        /// acting for it.
+        type RemoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = (MultiLocation, Self::AccountId)>;
+
+        /// Weight information for the extrinsics in this module.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    pub enum Event<T: Config> {
+        RemoteIssueRequested {
+            origin: MultiLocation,
+            account_id: T::AccountId,
+            issue_id: H256,
+            beneficiary: MultiLocation,
+        },
+        RemoteRedeemRequested {
+            origin: MultiLocation,
+            account_id: T::AccountId,
+            redeem_id: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Beneficiary uses an unsupported XCM version.
+        InvalidBeneficiary,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Request the issuance of tokens on behalf of a remote beneficiary. The griefing
+        /// collateral is locked on the account of the origin, and the issued tokens are
+        /// forwarded to the beneficiary once the request is executed.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - remote origin of the request
+        /// * `amount` - amount of BTC the beneficiary wants to convert to issued tokens, including fees
+        /// * `vault_id` - id of the vault to issue from
+        /// * `griefing_currency` - currency of the griefing collateral
+        /// * `beneficiary` - account on another chain that receives the issued tokens
+        /// * `max_fee` - optional maximum issue fee accepted for the request
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_issue())]
+        #[transactional]
+        pub fn request_issue(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            vault_id: DefaultVaultId<T>,
+            griefing_currency: CurrencyId<T>,
+            beneficiary: Box<VersionedMultiLocation>,
+            max_fee: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let (location, account_id) = T::RemoteOrigin::ensure_origin(origin)?;
+            let beneficiary = MultiLocation::try_from(*beneficiary).map_err(|_| Error::<T>::InvalidBeneficiary)?;
+
+            let issue_id = ext::issue::request_issue::<T>(
+                account_id.clone(),
+                amount,
+                vault_id,
+                griefing_currency,
+                beneficiary,
+                max_fee,
+            )?;
+
+            Self::deposit_event_for(
+                &location,
+                Event::RemoteIssueRequested {
+                    origin: location,
+                    account_id,
+                    issue_id,
+                    beneficiary,
+                },
+            );
+            Ok(().into())
+        }
+
+        /// Request the redemption of wrapped tokens that were sent to the account of the origin,
+        /// e.g. through a reserve transfer.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - remote origin of the request
+        /// * `amount_wrapped` - amount of wrapped tokens to redeem, including fees
+        /// * `btc_address` - the address to receive BTC
+        /// * `vault_id` - id of the vault to redeem from
+        /// * `max_fee` - optional maximum redeem fee accepted for the request
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_redeem())]
+        #[transactional]
+        pub fn request_redeem(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount_wrapped: BalanceOf<T>,
+            btc_address: BtcAddress,
+            vault_id: DefaultVaultId<T>,
+            max_fee: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let (location, account_id) = T::RemoteOrigin::ensure_origin(origin)?;
+
+            let redeem_id =
+                ext::redeem::request_redeem::<T>(account_id.clone(), amount_wrapped, btc_address, vault_id, max_fee)?;
+
+            Self::deposit_event_for(
+                &location,
+                Event::RemoteRedeemRequested {
+                    origin: location,
+                    account_id,
+                    redeem_id,
+                },
+            );
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Deposits the event with the hash of `origin` as its topic.
+    fn deposit_event_for(origin: &MultiLocation, event: Event<T>) {
+        let topic = <T as frame_system::Config>::Hashing::hash_of(origin);
+        let event: <T as Config>::RuntimeEvent = event.into();
+        frame_system::Pallet::<T>::deposit_event_indexed(&[topic], event.into());
+    }
+}
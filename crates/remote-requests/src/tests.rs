@@ -0,0 +1,140 @@
+use crate::{ext, mock::*};
+
+use btc_relay::BtcAddress;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+use mocktopus::mocking::*;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use xcm::{
+    v3::{Junction::*, Junctions::*, MultiLocation},
+    VersionedMultiLocation,
+};
+
+type Event = crate::Event<Test>;
+
+macro_rules! assert_emitted {
+    ($event:expr) => {
+        let test_event = TestEvent::RemoteRequests($event);
+        assert!(System::events().iter().any(|a| a.event == test_event));
+    };
+}
+
+fn beneficiary() -> MultiLocation {
+    MultiLocation::new(1, X2(Parachain(REMOTE_PARA_ID), GeneralIndex(3)))
+}
+
+fn request_issue(origin: RuntimeOrigin, beneficiary: VersionedMultiLocation) -> Result<(), DispatchError> {
+    RemoteRequests::request_issue(origin, 3, VAULT, DEFAULT_NATIVE_CURRENCY, Box::new(beneficiary), None)
+        .map(|_| ())
+        .map_err(|err| err.error)
+}
+
+fn request_redeem(origin: RuntimeOrigin) -> Result<(), DispatchError> {
+    RemoteRequests::request_redeem(origin, 3, BtcAddress::random(), VAULT, None)
+        .map(|_| ())
+        .map_err(|err| err.error)
+}
+
+fn assert_indexed_by(location: &MultiLocation) {
+    assert_eq!(System::event_topics(BlakeTwo256::hash_of(location)).len(), 1);
+}
+
+#[test]
+fn test_request_issue_fails_with_non_remote_origin() {
+    run_test(|| {
+        ext::issue::request_issue::<Test>.mock_safe(|_, _, _, _, _, _| panic!("Should not call issue"));
+
+        assert_noop!(
+            request_issue(RuntimeOrigin::root(), beneficiary().into()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            request_issue(RuntimeOrigin::none(), beneficiary().into()),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn test_request_issue_fails_with_invalid_beneficiary() {
+    run_test(|| {
+        ext::issue::request_issue::<Test>.mock_safe(|_, _, _, _, _, _| panic!("Should not call issue"));
+
+        // named networks can not be expressed in the current XCM version
+        let beneficiary = xcm::v2::MultiLocation::new(
+            1,
+            xcm::v2::Junctions::X1(xcm::v2::Junction::AccountId32 {
+                network: xcm::v2::NetworkId::Named(b"remote".to_vec().try_into().unwrap()),
+                id: [3; 32],
+            }),
+        );
+        assert_noop!(
+            request_issue(RuntimeOrigin::signed(USER), VersionedMultiLocation::V2(beneficiary)),
+            TestError::InvalidBeneficiary
+        );
+    })
+}
+
+#[test]
+fn test_request_issue_forwards_error_of_issue() {
+    run_test(|| {
+        // the relay has not been initialized
+        assert_noop!(
+            request_issue(RuntimeOrigin::signed(USER), beneficiary().into()),
+            issue::Error::<Test>::WaitingForRelayerInitialization
+        );
+    })
+}
+
+#[test]
+fn test_request_issue_succeeds_for_remote_account() {
+    run_test(|| {
+        let issue_id = H256::from_low_u64_be(1);
+        ext::issue::request_issue::<Test>.mock_safe(move |requester, amount, vault_id, _, destination, _| {
+            assert_eq!(requester, USER);
+            assert_eq!(amount, 3);
+            assert_eq!(vault_id, VAULT);
+            assert_eq!(destination, beneficiary());
+            MockResult::Return(Ok(issue_id))
+        });
+
+        assert_ok!(request_issue(RuntimeOrigin::signed(USER), beneficiary().into()));
+        assert_emitted!(Event::RemoteIssueRequested {
+            origin: remote_location(USER),
+            account_id: USER,
+            issue_id,
+            beneficiary: beneficiary(),
+        });
+        assert_indexed_by(&remote_location(USER));
+    })
+}
+
+#[test]
+fn test_request_redeem_fails_with_non_remote_origin() {
+    run_test(|| {
+        ext::redeem::request_redeem::<Test>.mock_safe(|_, _, _, _, _| panic!("Should not call redeem"));
+
+        assert_noop!(request_redeem(RuntimeOrigin::root()), DispatchError::BadOrigin);
+    })
+}
+
+#[test]
+fn test_request_redeem_succeeds_for_remote_account() {
+    run_test(|| {
+        let redeem_id = H256::from_low_u64_be(1);
+        ext::redeem::request_redeem::<Test>.mock_safe(move |redeemer, amount_wrapped, _, vault_id, _| {
+            assert_eq!(redeemer, USER);
+            assert_eq!(amount_wrapped, 3);
+            assert_eq!(vault_id, VAULT);
+            MockResult::Return(Ok(redeem_id))
+        });
+
+        assert_ok!(request_redeem(RuntimeOrigin::signed(USER)));
+        assert_emitted!(Event::RemoteRedeemRequested {
+            origin: remote_location(USER),
+            account_id: USER,
+            redeem_id,
+        });
+        assert_indexed_by(&remote_location(USER));
+    })
+}
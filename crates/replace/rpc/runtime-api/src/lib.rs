@@ -5,16 +5,29 @@
 use codec::Codec;
 use sp_std::vec::Vec;
 
+pub use primitives::query::RequestPage;
+
 sp_api::decl_runtime_apis! {
-    pub trait ReplaceApi<AccountId, H256, ReplaceRequest> where
+    /// `RequestQuery` is only used by the calls added in version 2
+    #[api_version(2)]
+    pub trait ReplaceApi<AccountId, H256, ReplaceRequest, RequestQuery> where
         AccountId: Codec,
         H256: Codec,
         ReplaceRequest: Codec,
+        RequestQuery: Codec,
     {
         /// Get all replace requests from a particular vault
         fn get_old_vault_replace_requests(vault_id: AccountId) -> Vec<H256>;
 
         /// Get all replace requests to a particular vault
         fn get_new_vault_replace_requests(vault_id: AccountId) -> Vec<H256>;
+
+        /// Get a page of the replace requests from a particular vault
+        #[api_version(2)]
+        fn get_old_vault_replace_requests_page(vault_id: AccountId, query: RequestQuery) -> RequestPage<H256, ReplaceRequest>;
+
+        /// Get a page of the replace requests to a particular vault
+        #[api_version(2)]
+        fn get_new_vault_replace_requests_page(vault_id: AccountId, query: RequestQuery) -> RequestPage<H256, ReplaceRequest>;
     }
 }
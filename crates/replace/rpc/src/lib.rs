@@ -6,20 +6,36 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
-pub use replace_rpc_runtime_api::ReplaceApi as ReplaceRuntimeApi;
+pub use replace_rpc_runtime_api::{ReplaceApi as ReplaceRuntimeApi, RequestPage};
 
 #[rpc(client, server)]
-pub trait ReplaceApi<BlockHash, AccountId, H256, ReplaceRequest> {
+pub trait ReplaceApi<BlockHash, AccountId, H256, ReplaceRequest, RequestQuery> {
     #[method(name = "replace_getOldVaultReplaceRequests")]
     fn get_old_vault_replace_requests(&self, vault_id: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
 
     #[method(name = "replace_getNewVaultReplaceRequests")]
     fn get_new_vault_replace_requests(&self, vault_id: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
+
+    #[method(name = "replace_getOldVaultReplaceRequestsPage")]
+    fn get_old_vault_replace_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, ReplaceRequest>>;
+
+    #[method(name = "replace_getNewVaultReplaceRequestsPage")]
+    fn get_new_vault_replace_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RequestPage<H256, ReplaceRequest>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -30,6 +46,30 @@ fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
     )))
 }
 
+/// The paginated calls were added in version 2 of the runtime api
+fn ensure_api_version<C, Block, AccountId, H256, ReplaceRequest, RequestQuery>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+) -> RpcResult<()>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: ReplaceRuntimeApi<Block, AccountId, H256, ReplaceRequest, RequestQuery>,
+    AccountId: Codec,
+    H256: Codec,
+    ReplaceRequest: Codec,
+    RequestQuery: Codec,
+{
+    let version = client
+        .runtime_api()
+        .api_version::<dyn ReplaceRuntimeApi<Block, AccountId, H256, ReplaceRequest, RequestQuery>>(at)
+        .map_err(|e| internal_err(format!("Unable to get the runtime api version: {:?}", e)))?;
+    match version {
+        Some(version) if version >= 2 => Ok(()),
+        _ => Err(internal_err("Not supported by the runtime at this block")),
+    }
+}
+
 /// A struct that implements the [`ReplaceApi`].
 pub struct Replace<C, B> {
     client: Arc<C>,
@@ -47,15 +87,16 @@ impl<C, B> Replace<C, B> {
 }
 
 #[async_trait]
-impl<C, Block, AccountId, H256, ReplaceRequest>
-    ReplaceApiServer<<Block as BlockT>::Hash, AccountId, H256, ReplaceRequest> for Replace<C, Block>
+impl<C, Block, AccountId, H256, ReplaceRequest, RequestQuery>
+    ReplaceApiServer<<Block as BlockT>::Hash, AccountId, H256, ReplaceRequest, RequestQuery> for Replace<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: ReplaceRuntimeApi<Block, AccountId, H256, ReplaceRequest>,
+    C::Api: ReplaceRuntimeApi<Block, AccountId, H256, ReplaceRequest, RequestQuery>,
     AccountId: Codec,
     H256: Codec,
     ReplaceRequest: Codec,
+    RequestQuery: Codec,
 {
     fn get_old_vault_replace_requests(
         &self,
@@ -80,4 +121,32 @@ where
         api.get_new_vault_replace_requests(at, vault_id)
             .map_err(|e| internal_err(format!("Unable to fetch replace requests: {:?}", e)))
     }
+
+    fn get_old_vault_replace_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, ReplaceRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, AccountId, H256, ReplaceRequest, RequestQuery>(&*self.client, at)?;
+
+        api.get_old_vault_replace_requests_page(at, vault_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch replace requests: {:?}", e)))
+    }
+
+    fn get_new_vault_replace_requests_page(
+        &self,
+        vault_id: AccountId,
+        query: RequestQuery,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RequestPage<H256, ReplaceRequest>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<_, Block, AccountId, H256, ReplaceRequest, RequestQuery>(&*self.client, at)?;
+
+        api.get_new_vault_replace_requests_page(at, vault_id, query)
+            .map_err(|e| internal_err(format!("Unable to fetch replace requests: {:?}", e)))
+    }
 }
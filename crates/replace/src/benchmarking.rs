@@ -324,6 +324,100 @@ pub mod benchmarks {
         set_replace_period(RawOrigin::Root, 1u32.into());
     }
 
+    #[benchmark]
+    fn request_replace_auction() {
+        let ChainState {
+            old_vault_id,
+            issued_tokens,
+            to_be_replaced,
+            ..
+        } = setup_chain::<T>();
+        ReplacePeriod::<T>::set(10u32.into());
+
+        let amount = (issued_tokens.checked_sub(&to_be_replaced).unwrap()).amount();
+
+        #[extrinsic_call]
+        request_replace_auction(
+            RawOrigin::Signed(old_vault_id.account_id.clone()),
+            old_vault_id.currencies.clone(),
+            amount,
+            10u32.into(),
+        );
+    }
+
+    #[benchmark]
+    fn bid_on_replace_auction() {
+        let ChainState {
+            old_vault_id,
+            new_vault_id,
+            issued_tokens,
+            to_be_replaced,
+        } = setup_chain::<T>();
+        ReplacePeriod::<T>::set(10u32.into());
+
+        let amount = (issued_tokens.checked_sub(&to_be_replaced).unwrap()).amount();
+        assert_ok!(Replace::<T>::_request_replace_auction(
+            old_vault_id.clone(),
+            amount,
+            10u32.into()
+        ));
+        // outbid a previous bid to hit the release of its commitments
+        assert_ok!(Replace::<T>::_bid_on_replace_auction(
+            old_vault_id.clone(),
+            ReplaceBid {
+                new_vault: new_vault_id.clone(),
+                collateral: 100000000u32.into(),
+                premium: 1000u32.into(),
+                btc_address: BtcAddress::dummy(),
+            }
+        ));
+
+        #[extrinsic_call]
+        bid_on_replace_auction(
+            RawOrigin::Signed(new_vault_id.account_id.clone()),
+            new_vault_id.currencies.clone(),
+            old_vault_id,
+            100000000u32.into(),
+            2000u32.into(),
+            BtcAddress::dummy(),
+        );
+    }
+
+    #[benchmark]
+    fn settle_replace_auction() {
+        let ChainState {
+            old_vault_id,
+            new_vault_id,
+            issued_tokens,
+            to_be_replaced,
+        } = setup_chain::<T>();
+        ReplacePeriod::<T>::set(10u32.into());
+
+        let amount = (issued_tokens.checked_sub(&to_be_replaced).unwrap()).amount();
+        assert_ok!(Replace::<T>::_request_replace_auction(
+            old_vault_id.clone(),
+            amount,
+            10u32.into()
+        ));
+        assert_ok!(Replace::<T>::_bid_on_replace_auction(
+            old_vault_id.clone(),
+            ReplaceBid {
+                new_vault: new_vault_id.clone(),
+                collateral: 100000000u32.into(),
+                premium: 1000u32.into(),
+                btc_address: BtcAddress::dummy(),
+            }
+        ));
+        let auction = ReplaceAuctions::<T>::take(&old_vault_id).unwrap();
+
+        #[block]
+        {
+            Replace::<T>::settle_replace_auction(old_vault_id.clone(), auction);
+        }
+
+        assert!(!Replace::<T>::get_replace_requests_for_new_vault(new_vault_id.account_id).is_empty());
+    }
+
     impl_benchmark_test_suite! {
         Replace,
         crate::mock::ExtBuilder::build_with(Default::default()),
@@ -44,6 +44,9 @@ pub trait WeightInfo {
 	fn execute_cancelled_replace(h: u32, i: u32, o: u32, b: u32, ) -> Weight;
 	fn cancel_replace() -> Weight;
 	fn set_replace_period() -> Weight;
+	fn request_replace_auction() -> Weight;
+	fn bid_on_replace_auction() -> Weight;
+	fn settle_replace_auction() -> Weight;
 }
 
 /// Weights for replace using the Substrate node and recommended hardware.
@@ -182,6 +185,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:1 w:1)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -189,13 +194,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_pending_replace(h: u32, _i: u32, _o: u32, _b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4990`
-		//  Estimated: `18451`
+		//  Estimated: `21028`
 		// Minimum execution time: 143_961_000 picoseconds.
-		Weight::from_parts(191_182_902, 18451)
+		Weight::from_parts(191_182_902, 21028)
 			// Standard Error: 132_362
 			.saturating_add(Weight::from_parts(961_843, 0).saturating_mul(h.into()))
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -225,6 +230,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
 	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
 	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -232,15 +239,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn execute_cancelled_replace(_h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5335`
-		//  Estimated: `26535`
+		//  Estimated: `29112`
 		// Minimum execution time: 177_761_000 picoseconds.
-		Weight::from_parts(197_448_409, 26535)
+		Weight::from_parts(197_448_409, 29112)
 			// Standard Error: 42_163
 			.saturating_add(Weight::from_parts(84_783, 0).saturating_mul(i.into()))
 			// Standard Error: 268
 			.saturating_add(Weight::from_parts(3_017, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -300,14 +307,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: VaultRewards TotalStake (r:1 w:0)
 	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_replace() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5955`
-		//  Estimated: `89455`
+		//  Estimated: `92032`
 		// Minimum execution time: 332_975_000 picoseconds.
-		Weight::from_parts(340_302_000, 89455)
-			.saturating_add(T::DbWeight::get().reads(38_u64))
-			.saturating_add(T::DbWeight::get().writes(19_u64))
+		Weight::from_parts(340_302_000, 92032)
+			.saturating_add(T::DbWeight::get().reads(39_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
 	}
 	/// Storage: Replace ReplacePeriod (r:0 w:1)
 	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -319,6 +328,105 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(22_419_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Fee ReplaceGriefingCollateral (r:1 w:0)
+	/// Proof: Fee ReplaceGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceAuctionEnds (r:1 w:1)
+	/// Proof: Replace ReplaceAuctionEnds (max_values: None, max_size: Some(5014), added: 7489, mode: MaxEncodedLen)
+	fn request_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3118`
+		//  Estimated: `24593`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(121_338_000, 24593)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn bid_on_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2614`
+		//  Estimated: `29013`
+		// Minimum execution time: 86_927_000 picoseconds.
+		Weight::from_parts(88_512_000, 29013)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:2 w:2)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceRequests (r:0 w:1)
+	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	fn settle_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5672`
+		//  Estimated: `108971`
+		// Minimum execution time: 461_538_000 picoseconds.
+		Weight::from_parts(468_913_000, 108971)
+			.saturating_add(T::DbWeight::get().reads(48_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -456,6 +564,8 @@ impl WeightInfo for () {
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:1 w:1)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -463,13 +573,13 @@ impl WeightInfo for () {
 	fn execute_pending_replace(h: u32, _i: u32, _o: u32, _b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4990`
-		//  Estimated: `18451`
+		//  Estimated: `21028`
 		// Minimum execution time: 143_961_000 picoseconds.
-		Weight::from_parts(191_182_902, 18451)
+		Weight::from_parts(191_182_902, 21028)
 			// Standard Error: 132_362
 			.saturating_add(Weight::from_parts(961_843, 0).saturating_mul(h.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -499,6 +609,8 @@ impl WeightInfo for () {
 	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
 	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
 	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -506,15 +618,15 @@ impl WeightInfo for () {
 	fn execute_cancelled_replace(_h: u32, i: u32, _o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5335`
-		//  Estimated: `26535`
+		//  Estimated: `29112`
 		// Minimum execution time: 177_761_000 picoseconds.
-		Weight::from_parts(197_448_409, 26535)
+		Weight::from_parts(197_448_409, 29112)
 			// Standard Error: 42_163
 			.saturating_add(Weight::from_parts(84_783, 0).saturating_mul(i.into()))
 			// Standard Error: 268
 			.saturating_add(Weight::from_parts(3_017, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -574,14 +686,16 @@ impl WeightInfo for () {
 	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: VaultRewards TotalStake (r:1 w:0)
 	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_replace() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5955`
-		//  Estimated: `89455`
+		//  Estimated: `92032`
 		// Minimum execution time: 332_975_000 picoseconds.
-		Weight::from_parts(340_302_000, 89455)
-			.saturating_add(RocksDbWeight::get().reads(38_u64))
-			.saturating_add(RocksDbWeight::get().writes(19_u64))
+		Weight::from_parts(340_302_000, 92032)
+			.saturating_add(RocksDbWeight::get().reads(39_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
 	}
 	/// Storage: Replace ReplacePeriod (r:0 w:1)
 	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -593,4 +707,103 @@ impl WeightInfo for () {
 		Weight::from_parts(22_419_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Fee ReplaceGriefingCollateral (r:1 w:0)
+	/// Proof: Fee ReplaceGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceAuctionEnds (r:1 w:1)
+	/// Proof: Replace ReplaceAuctionEnds (max_values: None, max_size: Some(5014), added: 7489, mode: MaxEncodedLen)
+	fn request_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3118`
+		//  Estimated: `24593`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(121_338_000, 24593)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn bid_on_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2614`
+		//  Estimated: `29013`
+		// Minimum execution time: 86_927_000 picoseconds.
+		Weight::from_parts(88_512_000, 29013)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:2 w:2)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceRequests (r:0 w:1)
+	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	fn settle_replace_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5672`
+		//  Estimated: `108971`
+		// Minimum execution time: 461_538_000 picoseconds.
+		Weight::from_parts(468_913_000, 108971)
+			.saturating_add(RocksDbWeight::get().reads(48_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
 }
@@ -73,6 +73,14 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::is_vault_liquidated(vault_id)
     }
 
+    pub fn record_replace_execution<T: crate::Config>(old_vault_id: &DefaultVaultId<T>) {
+        <vault_registry::Pallet<T>>::record_replace_execution(old_vault_id)
+    }
+
+    pub fn record_replace_cancellation<T: crate::Config>(old_vault_id: &DefaultVaultId<T>) {
+        <vault_registry::Pallet<T>>::record_replace_cancellation(old_vault_id)
+    }
+
     pub fn try_increase_to_be_redeemed_tokens<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
         tokens: &Amount<T>,
@@ -84,6 +92,10 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::ensure_not_banned(vault_id)
     }
 
+    pub fn ensure_not_exiting<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        <vault_registry::Pallet<T>>::ensure_not_exiting(vault_id)
+    }
+
     pub fn try_increase_to_be_issued_tokens<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
         amount: &Amount<T>,
@@ -91,6 +103,13 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::try_increase_to_be_issued_tokens(vault_id, amount)
     }
 
+    pub fn get_issuable_tokens_with_collateral<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        collateral: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <vault_registry::Pallet<T>>::get_issuable_tokens_with_collateral(vault_id, collateral)
+    }
+
     pub fn requestable_to_be_replaced_tokens<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
     ) -> Result<Amount<T>, DispatchError> {
@@ -25,8 +25,11 @@ extern crate mocktopus;
 #[cfg(test)]
 use mocktopus::macros::mockable;
 
-use crate::types::{BalanceOf, ReplaceRequestExt, Version};
-pub use crate::types::{DefaultReplaceRequest, ReplaceRequest, ReplaceRequestStatus};
+use crate::types::{BalanceOf, MigrationCursor, ReplaceBidExt, ReplaceRequestExt, Version};
+pub use crate::types::{
+    DefaultReplaceAuction, DefaultReplaceBid, DefaultReplaceRequest, DefaultReplaceRequestQuery, ReplaceAuction,
+    ReplaceBid, ReplaceRequest, ReplaceRequestStatus,
+};
 use bitcoin::types::FullTransactionProof;
 use btc_relay::BtcAddress;
 use currency::Amount;
@@ -35,11 +38,14 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     pallet_prelude::Weight,
+    storage::with_storage_layer,
     traits::Get,
     transactional,
 };
-use frame_system::{ensure_root, ensure_signed};
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::BlockNumberFor};
+use primitives::query::RequestPage;
 use sp_core::H256;
+use sp_runtime::traits::Zero;
 use sp_std::vec::Vec;
 use types::DefaultVaultId;
 use vault_registry::{types::CurrencyId, CurrencySource};
@@ -95,6 +101,19 @@ pub mod pallet {
 
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
+
+        /// Number of blocks after the end of their replace period that cancelled requests are
+        /// kept in storage.
+        #[pallet::constant]
+        type RequestRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of requests that are checked for expiry or removal per block.
+        #[pallet::constant]
+        type MaxSweptRequestsPerBlock: Get<u32>;
+
+        /// Maximum number of replace auctions that end in the same block.
+        #[pallet::constant]
+        type MaxReplaceAuctionsPerBlock: Get<u32>;
     }
 
     #[pallet::event]
@@ -132,6 +151,37 @@ pub mod pallet {
         ReplacePeriodChange {
             period: BlockNumberFor<T>,
         },
+        StartReplaceAuction {
+            old_vault_id: DefaultVaultId<T>,
+            amount: BalanceOf<T>,
+            griefing_collateral: BalanceOf<T>,
+            end_block: BlockNumberFor<T>,
+        },
+        BidOnReplaceAuction {
+            old_vault_id: DefaultVaultId<T>,
+            new_vault_id: DefaultVaultId<T>,
+            collateral: BalanceOf<T>,
+            premium: BalanceOf<T>,
+        },
+        SettleReplaceAuction {
+            replace_id: H256,
+            old_vault_id: DefaultVaultId<T>,
+            new_vault_id: DefaultVaultId<T>,
+            premium: BalanceOf<T>,
+        },
+        /// The auction ended without a bid, or the best bid could not be accepted. The tokens
+        /// remain open for replacement.
+        CloseReplaceAuction {
+            old_vault_id: DefaultVaultId<T>,
+            new_vault_id: Option<DefaultVaultId<T>>,
+        },
+        /// The new vault no longer backed its best bid when the auction ended. Its premium was
+        /// paid to the old vault, whose tokens remain open for replacement.
+        SlashReplaceAuctionBid {
+            old_vault_id: DefaultVaultId<T>,
+            new_vault_id: DefaultVaultId<T>,
+            premium: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -158,6 +208,19 @@ pub mod pallet {
         ReplaceIdNotFound,
         /// Vault cannot replace different currency.
         InvalidWrappedCurrency,
+        /// Auction duration is zero or longer than the replace period.
+        InvalidReplaceAuctionDuration,
+        /// The vault's tokens are being auctioned.
+        ReplaceAuctionRunning,
+        /// The vault has no running replace auction.
+        NoRunningReplaceAuction,
+        /// The premium does not exceed that of the best bid.
+        ReplaceAuctionBidTooLow,
+        /// Too many replace auctions end in the same block.
+        TooManyReplaceAuctions,
+        /// The new vault cannot back the auctioned tokens at the secure threshold, even with the
+        /// collateral of the bid.
+        InsufficientBidCollateral,
     }
 
     /// Vaults create replace requests to transfer locked collateral.
@@ -179,6 +242,41 @@ pub mod pallet {
     #[pallet::getter(fn replace_btc_dust_value)]
     pub(super) type ReplaceBtcDustValue<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Running replace auctions, by the old vault whose tokens are auctioned.
+    #[pallet::storage]
+    pub(super) type ReplaceAuctions<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, DefaultReplaceAuction<T>, OptionQuery>;
+
+    /// The old vaults whose replace auctions are settled in a given active block.
+    #[pallet::storage]
+    pub(super) type ReplaceAuctionEnds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<DefaultVaultId<T>, T::MaxReplaceAuctionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The last replace request checked by the request sweeper, which continues after it in the
+    /// next block.
+    #[pallet::storage]
+    pub(super) type RequestSweepCursor<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+    /// Index of the replace requests from each vault, by the account of the old vault.
+    #[pallet::storage]
+    pub(super) type ReplaceRequestsByOldVault<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Index of the replace requests to each vault, by the account of the new vault.
+    #[pallet::storage]
+    pub(super) type ReplaceRequestsByNewVault<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Progress of the migration that indexes the existing replace requests by old and new vault. The
+    /// indexes are incomplete while this is set, so lookups scan all requests.
+    #[pallet::storage]
+    pub(super) type RequestIndexMigration<T: Config> = StorageValue<_, MigrationCursor, OptionQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V0
@@ -201,11 +299,24 @@ pub mod pallet {
         fn build(&self) {
             ReplacePeriod::<T>::put(self.replace_period);
             ReplaceBtcDustValue::<T>::put(self.replace_btc_dust_value);
+            StorageVersion::<T>::put(Version::V2);
         }
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::settle_replace_auctions()
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            if RequestIndexMigration::<T>::exists() {
+                Self::migrate_request_indexes(remaining_weight)
+            } else {
+                Self::sweep_requests(remaining_weight)
+            }
+        }
+    }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -329,16 +440,78 @@ pub mod pallet {
             Self::deposit_event(Event::ReplacePeriodChange { period });
             Ok(().into())
         }
+
+        /// Auction issued tokens of the vault for replacement. New vaults bid until `duration`
+        /// blocks have passed, after which the best bid is accepted. Until then, the tokens
+        /// cannot be accepted or withdrawn.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - sender of the transaction: the old vault
+        /// * `amount` - amount of issued tokens
+        /// * `duration` - number of blocks that bids are accepted for, at most the replace period
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_replace_auction())]
+        #[transactional]
+        pub fn request_replace_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let old_vault = VaultId::new(ensure_signed(origin)?, currency_pair.collateral, currency_pair.wrapped);
+            Self::_request_replace_auction(old_vault, amount, duration)?;
+            Ok(().into())
+        }
+
+        /// Bid on the replace auction of a vault. The collateral and premium are locked until the
+        /// bid is outbid or the auction ends.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the initiator of the transaction: the new vault
+        /// * `old_vault` - id of the old vault whose tokens are auctioned
+        /// * `collateral` - the collateral for replacement
+        /// * `premium` - amount of griefing currency paid to the old vault if the bid wins
+        /// * `btc_address` - the address that old-vault should transfer the btc to
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::bid_on_replace_auction())]
+        #[transactional]
+        pub fn bid_on_replace_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            old_vault: DefaultVaultId<T>,
+            #[pallet::compact] collateral: BalanceOf<T>,
+            #[pallet::compact] premium: BalanceOf<T>,
+            btc_address: BtcAddress,
+        ) -> DispatchResultWithPostInfo {
+            let new_vault = VaultId::new(ensure_signed(origin)?, currency_pair.collateral, currency_pair.wrapped);
+            Self::_bid_on_replace_auction(
+                old_vault,
+                ReplaceBid {
+                    new_vault,
+                    collateral,
+                    premium,
+                    btc_address,
+                },
+            )?;
+            Ok(().into())
+        }
     }
 }
 
 // "Internal" functions, callable by code.
 #[cfg_attr(test, mockable)]
 impl<T: Config> Pallet<T> {
-    fn _request_replace(vault_id: DefaultVaultId<T>, amount_btc: BalanceOf<T>) -> DispatchResult {
+    fn _request_replace(
+        vault_id: DefaultVaultId<T>,
+        amount_btc: BalanceOf<T>,
+    ) -> Result<(Amount<T>, Amount<T>), DispatchError> {
         // check vault is not banned
         ext::vault_registry::ensure_not_banned::<T>(&vault_id)?;
 
+        Self::ensure_no_running_auction(&vault_id)?;
+
         let amount_btc = Amount::new(amount_btc, vault_id.wrapped_currency());
 
         ensure!(
@@ -379,10 +552,12 @@ impl<T: Config> Pallet<T> {
             amount: to_be_replaced_increase.amount(),
             griefing_collateral: griefing_collateral.amount(),
         });
-        Ok(())
+        Ok((to_be_replaced_increase, griefing_collateral))
     }
 
     fn _withdraw_replace_request(vault_id: DefaultVaultId<T>, amount: BalanceOf<T>) -> Result<(), DispatchError> {
+        Self::ensure_no_running_auction(&vault_id)?;
+
         let amount = Amount::new(amount, vault_id.wrapped_currency());
         // decrease to-be-replaced tokens, so that the vault is free to use its issued tokens again.
         let (withdrawn_tokens, to_withdraw_collateral) =
@@ -421,7 +596,9 @@ impl<T: Config> Pallet<T> {
         amount_btc: BalanceOf<T>,
         collateral: BalanceOf<T>,
         btc_address: BtcAddress,
-    ) -> Result<(), DispatchError> {
+    ) -> Result<H256, DispatchError> {
+        Self::ensure_no_running_auction(&old_vault_id)?;
+
         let new_vault_currency_id = new_vault_id.collateral_currency();
         let amount_btc = Amount::new(amount_btc, old_vault_id.wrapped_currency());
         let collateral = Amount::new(collateral, new_vault_currency_id);
@@ -439,6 +616,9 @@ impl<T: Config> Pallet<T> {
         // Check that new vault is not currently banned
         ext::vault_registry::ensure_not_banned::<T>(&new_vault_id)?;
 
+        // Check that new vault is not in the process of exiting
+        ext::vault_registry::ensure_not_exiting::<T>(&new_vault_id)?;
+
         // decrease old-vault's to-be-replaced tokens
         let (redeemable_tokens, griefing_collateral) =
             ext::vault_registry::decrease_to_be_replaced_tokens::<T>(&old_vault_id, &amount_btc)?;
@@ -490,7 +670,7 @@ impl<T: Config> Pallet<T> {
             btc_address: replace.btc_address,
         });
 
-        Ok(())
+        Ok(replace_id)
     }
 
     fn _execute_replace(replace_id: H256, unchecked_transaction: FullTransactionProof) -> DispatchResult {
@@ -542,6 +722,7 @@ impl<T: Config> Pallet<T> {
         // decrease old-vault's issued & to-be-redeemed tokens, and
         // change new-vault's to-be-issued tokens to issued tokens
         ext::vault_registry::replace_tokens::<T>(&old_vault_id, &new_vault_id, &amount, &collateral)?;
+        ext::vault_registry::record_replace_execution::<T>(&old_vault_id);
 
         // Emit ExecuteReplace event.
         Self::deposit_event(Event::<T>::ExecuteReplace {
@@ -596,6 +777,7 @@ impl<T: Config> Pallet<T> {
 
         // Remove the ReplaceRequest from ReplaceRequests
         Self::set_replace_status(&replace_id, ReplaceRequestStatus::Cancelled);
+        ext::vault_registry::record_replace_cancellation::<T>(&replace.old_vault);
 
         // Emit CancelReplace event.
         Self::deposit_event(Event::<T>::CancelReplace {
@@ -607,16 +789,330 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    fn _request_replace_auction(
+        vault_id: DefaultVaultId<T>,
+        amount_btc: BalanceOf<T>,
+        duration: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        ensure!(
+            !duration.is_zero() && duration <= Self::replace_period(),
+            Error::<T>::InvalidReplaceAuctionDuration
+        );
+
+        let (amount, griefing_collateral) = Self::_request_replace(vault_id.clone(), amount_btc)?;
+
+        let end_block = ext::security::active_block_number::<T>().saturating_add(duration);
+        // settled in the block after the last bid
+        ReplaceAuctionEnds::<T>::try_mutate(end_block.saturating_add(1u32.into()), |vault_ids| {
+            vault_ids.try_push(vault_id.clone())
+        })
+        .map_err(|_| Error::<T>::TooManyReplaceAuctions)?;
+        ReplaceAuctions::<T>::insert(
+            &vault_id,
+            ReplaceAuction {
+                amount: amount.amount(),
+                end_block,
+                best_bid: None,
+            },
+        );
+
+        Self::deposit_event(Event::<T>::StartReplaceAuction {
+            old_vault_id: vault_id,
+            amount: amount.amount(),
+            griefing_collateral: griefing_collateral.amount(),
+            end_block,
+        });
+        Ok(())
+    }
+
+    fn _bid_on_replace_auction(old_vault_id: DefaultVaultId<T>, bid: DefaultReplaceBid<T>) -> DispatchResult {
+        let new_vault_id = bid.new_vault.clone();
+
+        let mut auction = ReplaceAuctions::<T>::get(&old_vault_id)
+            .filter(|auction| ext::security::active_block_number::<T>() <= auction.end_block)
+            .ok_or(Error::<T>::NoRunningReplaceAuction)?;
+
+        Self::ensure_bid_is_backed(&old_vault_id, &auction, &bid)?;
+
+        if let Some(best_bid) = auction.best_bid.take() {
+            ensure!(bid.premium > best_bid.premium, Error::<T>::ReplaceAuctionBidTooLow);
+            Self::release_bid(&best_bid)?;
+        }
+
+        bid.collateral().lock_on(&new_vault_id.account_id)?;
+        bid.premium().lock_on(&new_vault_id.account_id)?;
+
+        Self::deposit_event(Event::<T>::BidOnReplaceAuction {
+            old_vault_id: old_vault_id.clone(),
+            new_vault_id,
+            collateral: bid.collateral,
+            premium: bid.premium,
+        });
+
+        auction.best_bid = Some(bid);
+        ReplaceAuctions::<T>::insert(&old_vault_id, auction);
+        Ok(())
+    }
+
+    /// Settles the replace auctions that ended in the previous active block.
+    fn settle_replace_auctions() -> Weight {
+        let vault_ids = ReplaceAuctionEnds::<T>::take(ext::security::active_block_number::<T>());
+        let weight = T::DbWeight::get()
+            .reads_writes(2, 1)
+            .saturating_add(<T as Config>::WeightInfo::settle_replace_auction().saturating_mul(vault_ids.len() as u64));
+        for vault_id in vault_ids {
+            if let Some(auction) = ReplaceAuctions::<T>::take(&vault_id) {
+                Self::settle_replace_auction(vault_id, auction);
+            }
+        }
+        weight
+    }
+
+    /// Accepts the best bid of the auction. If there is none, or it cannot be accepted, the
+    /// tokens remain open for replacement. A bid that the new vault no longer backs loses its
+    /// premium to the old vault.
+    fn settle_replace_auction(old_vault_id: DefaultVaultId<T>, auction: DefaultReplaceAuction<T>) {
+        let bid = match auction.best_bid {
+            Some(bid) => bid,
+            None => {
+                Self::deposit_event(Event::<T>::CloseReplaceAuction {
+                    old_vault_id,
+                    new_vault_id: None,
+                });
+                return;
+            }
+        };
+
+        // release the commitments either way, on success they pay for the replacement
+        if let Err(err) = Self::release_bid(&bid) {
+            log::warn!(
+                "Failed to release replace auction bid of {:?}: {:?}",
+                bid.new_vault,
+                err
+            );
+        }
+
+        // the bid was backed when it was placed, so the new vault has since withdrawn collateral,
+        // been banned or started to exit - its premium compensates the old vault for the blocked tokens
+        if let Err(err) = Self::ensure_bid_is_backed(&old_vault_id, &auction, &bid) {
+            log::warn!(
+                "Replace auction bid of {:?} is no longer backed: {:?}",
+                bid.new_vault,
+                err
+            );
+            if let Err(err) = bid
+                .premium()
+                .transfer(&bid.new_vault.account_id, &old_vault_id.account_id)
+            {
+                log::warn!(
+                    "Failed to slash replace auction premium of {:?}: {:?}",
+                    bid.new_vault,
+                    err
+                );
+            }
+            Self::deposit_event(Event::<T>::SlashReplaceAuctionBid {
+                old_vault_id,
+                new_vault_id: bid.new_vault,
+                premium: bid.premium,
+            });
+            return;
+        }
+
+        let result = with_storage_layer(|| {
+            let replace_id = Self::_accept_replace(
+                old_vault_id.clone(),
+                bid.new_vault.clone(),
+                auction.amount,
+                bid.collateral,
+                bid.btc_address,
+            )?;
+            bid.premium()
+                .transfer(&bid.new_vault.account_id, &old_vault_id.account_id)?;
+            Ok::<_, DispatchError>(replace_id)
+        });
+
+        match result {
+            Ok(replace_id) => Self::deposit_event(Event::<T>::SettleReplaceAuction {
+                replace_id,
+                old_vault_id,
+                new_vault_id: bid.new_vault,
+                premium: bid.premium,
+            }),
+            Err(err) => {
+                log::warn!("Failed to accept replace auction bid of {:?}: {:?}", bid.new_vault, err);
+                Self::deposit_event(Event::<T>::CloseReplaceAuction {
+                    old_vault_id,
+                    new_vault_id: Some(bid.new_vault),
+                });
+            }
+        }
+    }
+
+    /// Checks that the new vault may replace the old vault, and that it can back the auctioned
+    /// tokens at the secure threshold with its free collateral and the collateral of the bid.
+    fn ensure_bid_is_backed(
+        old_vault_id: &DefaultVaultId<T>,
+        auction: &DefaultReplaceAuction<T>,
+        bid: &DefaultReplaceBid<T>,
+    ) -> DispatchResult {
+        let new_vault_id = &bid.new_vault;
+        ensure!(old_vault_id != new_vault_id, Error::<T>::ReplaceSelfNotAllowed);
+        ensure!(
+            old_vault_id.wrapped_currency() == new_vault_id.wrapped_currency(),
+            Error::<T>::InvalidWrappedCurrency
+        );
+        ext::vault_registry::ensure_not_banned::<T>(new_vault_id)?;
+        ext::vault_registry::ensure_not_exiting::<T>(new_vault_id)?;
+
+        let amount = Amount::new(auction.amount, old_vault_id.wrapped_currency());
+        let issuable_tokens =
+            ext::vault_registry::get_issuable_tokens_with_collateral::<T>(new_vault_id, &bid.collateral())?;
+        ensure!(issuable_tokens.ge(&amount)?, Error::<T>::InsufficientBidCollateral);
+        Ok(())
+    }
+
+    fn release_bid(bid: &DefaultReplaceBid<T>) -> DispatchResult {
+        bid.collateral().unlock_on(&bid.new_vault.account_id)?;
+        bid.premium().unlock_on(&bid.new_vault.account_id)
+    }
+
+    fn ensure_no_running_auction(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        ensure!(
+            !ReplaceAuctions::<T>::contains_key(vault_id),
+            Error::<T>::ReplaceAuctionRunning
+        );
+        Ok(())
+    }
+
+    /// Get the replace auction of the vault's tokens, if any.
+    pub fn get_replace_auction(vault_id: &DefaultVaultId<T>) -> Option<DefaultReplaceAuction<T>> {
+        ReplaceAuctions::<T>::get(vault_id)
+    }
+
+    /// Cancels expired replace requests and removes cancelled requests after the retention
+    /// period, continuing from where the previous block stopped. Completed requests are kept,
+    /// so that their payments cannot be reported as theft.
+    pub(crate) fn sweep_requests(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(3, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        // charge the most expensive outcome, cancelling the request
+        let weight_per_request = T::DbWeight::get()
+            .reads(1)
+            .saturating_add(<T as Config>::WeightInfo::cancel_replace());
+        let previous_cursor = RequestSweepCursor::<T>::get();
+        let requests = match previous_cursor {
+            Some(replace_id) => ReplaceRequests::<T>::iter_from(ReplaceRequests::<T>::hashed_key_for(replace_id)),
+            None => ReplaceRequests::<T>::iter(),
+        };
+
+        let mut swept = Vec::new();
+        let mut cursor = None;
+        for (replace_id, replace) in requests {
+            if swept.len() as u32 >= T::MaxSweptRequestsPerBlock::get()
+                || consumed.saturating_add(weight_per_request).any_gt(remaining_weight)
+            {
+                cursor = swept.last().map(|(replace_id, _)| *replace_id).or(previous_cursor);
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+            swept.push((replace_id, replace));
+        }
+
+        let now = ext::security::active_block_number::<T>();
+        for (replace_id, replace) in swept {
+            let replace_period = Self::replace_period().max(replace.period);
+            match replace.status {
+                ReplaceRequestStatus::Pending => {
+                    if !ext::btc_relay::has_request_expired::<T>(
+                        replace.accept_time,
+                        replace.btc_height,
+                        replace_period,
+                    )
+                    .unwrap_or(false)
+                    {
+                        continue;
+                    }
+                    if let Err(err) = with_storage_layer(|| Self::_cancel_replace(replace_id)) {
+                        log::warn!("Failed to cancel expired replace {:?}: {:?}", replace_id, err);
+                    }
+                }
+                ReplaceRequestStatus::Cancelled => {
+                    let retained_until = replace
+                        .accept_time
+                        .saturating_add(replace_period)
+                        .saturating_add(T::RequestRetentionPeriod::get());
+                    if now > retained_until {
+                        Self::remove_replace_request(&replace_id, &replace);
+                    }
+                }
+                ReplaceRequestStatus::Completed => {}
+            }
+        }
+
+        RequestSweepCursor::<T>::set(cursor);
+        consumed
+    }
+
+    /// Indexes the replace requests by old and new vault, continuing from where the previous block
+    /// stopped.
+    pub(crate) fn migrate_request_indexes(remaining_weight: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut cursor = match RequestIndexMigration::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let mut requests = match cursor {
+            MigrationCursor::Start => ReplaceRequests::<T>::iter(),
+            MigrationCursor::After(replace_id) => {
+                ReplaceRequests::<T>::iter_from(ReplaceRequests::<T>::hashed_key_for(replace_id))
+            }
+        };
+
+        let weight_per_request = T::DbWeight::get().reads_writes(1, 2);
+        for _ in 0..T::MaxSweptRequestsPerBlock::get() {
+            if consumed.saturating_add(weight_per_request).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight_per_request);
+
+            match requests.next() {
+                Some((replace_id, replace)) => {
+                    ReplaceRequestsByOldVault::<T>::insert(&replace.old_vault.account_id, replace_id, ());
+                    ReplaceRequestsByNewVault::<T>::insert(&replace.new_vault.account_id, replace_id, ());
+                    cursor = MigrationCursor::After(replace_id);
+                }
+                None => {
+                    log::info!("Replace request index migration finished");
+                    RequestIndexMigration::<T>::kill();
+                    return consumed;
+                }
+            }
+        }
+
+        RequestIndexMigration::<T>::put(cursor);
+        consumed
+    }
+
     /// Fetch all replace requests from the specified vault.
     ///
     /// # Arguments
     ///
     /// * `account_id` - user account id
     pub fn get_replace_requests_for_old_vault(vault_id: T::AccountId) -> Vec<H256> {
-        <ReplaceRequests<T>>::iter()
-            .filter(|(_, request)| request.old_vault.account_id == vault_id)
-            .map(|(key, _)| key)
-            .collect::<Vec<_>>()
+        if RequestIndexMigration::<T>::exists() {
+            return <ReplaceRequests<T>>::iter()
+                .filter(|(_, request)| request.old_vault.account_id == vault_id)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+        }
+        <ReplaceRequestsByOldVault<T>>::iter_key_prefix(vault_id).collect::<Vec<_>>()
     }
 
     /// Fetch all replace requests to the specified vault.
@@ -625,10 +1121,57 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `account_id` - user account id
     pub fn get_replace_requests_for_new_vault(vault_id: T::AccountId) -> Vec<H256> {
-        <ReplaceRequests<T>>::iter()
-            .filter(|(_, request)| request.new_vault.account_id == vault_id)
-            .map(|(key, _)| key)
-            .collect::<Vec<_>>()
+        if RequestIndexMigration::<T>::exists() {
+            return <ReplaceRequests<T>>::iter()
+                .filter(|(_, request)| request.new_vault.account_id == vault_id)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+        }
+        <ReplaceRequestsByNewVault<T>>::iter_key_prefix(vault_id).collect::<Vec<_>>()
+    }
+
+    /// Fetch a page of the replace requests from the specified vault. The vault and currency
+    /// filters match either the old or the new vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - account id of the old vault
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_replace_requests_page_for_old_vault(
+        vault_id: T::AccountId,
+        query: DefaultReplaceRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultReplaceRequest<T>> {
+        Self::get_replace_requests_page(Self::get_replace_requests_for_old_vault(vault_id), query)
+    }
+
+    /// Fetch a page of the replace requests to the specified vault. The vault and currency
+    /// filters match either the old or the new vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - account id of the new vault
+    /// * `query` - filters, ordering and pagination of the requests
+    pub fn get_replace_requests_page_for_new_vault(
+        vault_id: T::AccountId,
+        query: DefaultReplaceRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultReplaceRequest<T>> {
+        Self::get_replace_requests_page(Self::get_replace_requests_for_new_vault(vault_id), query)
+    }
+
+    fn get_replace_requests_page(
+        replace_ids: Vec<H256>,
+        query: DefaultReplaceRequestQuery<T>,
+    ) -> RequestPage<H256, DefaultReplaceRequest<T>> {
+        let requests = replace_ids
+            .into_iter()
+            .filter_map(|replace_id| Some((replace_id, ReplaceRequests::<T>::get(replace_id)?)))
+            .filter(|(_, request)| {
+                query.matches_status(&request.status)
+                    && (query.matches_vault(&request.old_vault) || query.matches_vault(&request.new_vault))
+            })
+            .collect();
+        // replace requests are opened when they are accepted
+        query.paginate(requests, |request| request.accept_time)
     }
 
     /// Get a replace request by id. Completed or cancelled requests are not returned.
@@ -662,9 +1205,17 @@ impl<T: Config> Pallet<T> {
     }
 
     fn insert_replace_request(key: &H256, value: &DefaultReplaceRequest<T>) {
+        <ReplaceRequestsByOldVault<T>>::insert(&value.old_vault.account_id, key, ());
+        <ReplaceRequestsByNewVault<T>>::insert(&value.new_vault.account_id, key, ());
         <ReplaceRequests<T>>::insert(key, value)
     }
 
+    fn remove_replace_request(key: &H256, value: &DefaultReplaceRequest<T>) {
+        <ReplaceRequestsByOldVault<T>>::remove(&value.old_vault.account_id, key);
+        <ReplaceRequestsByNewVault<T>>::remove(&value.new_vault.account_id, key);
+        <ReplaceRequests<T>>::remove(key)
+    }
+
     fn set_replace_status(key: &H256, status: ReplaceRequestStatus) {
         <ReplaceRequests<T>>::mutate_exists(key, |request| {
             *request = request.clone().map(|request| DefaultReplaceRequest::<T> {
@@ -678,3 +1229,9 @@ impl<T: Config> Pallet<T> {
         Amount::new(ReplaceBtcDustValue::<T>::get(), currency_id)
     }
 }
+
+impl<T: Config> traits::ReplaceApi<DefaultVaultId<T>, Amount<T>> for Pallet<T> {
+    fn request_replace(vault_id: &DefaultVaultId<T>, amount: &Amount<T>) -> DispatchResult {
+        Pallet::<T>::_request_replace(vault_id.clone(), amount.amount()).map(|_| ())
+    }
+}
@@ -151,6 +151,8 @@ impl staking::Config for Test {
 
 parameter_types! {
     pub const VaultPalletId: PalletId = PalletId(*b"mod/vreg");
+    pub const PublicKeyRotationDelay: BlockNumber = 10;
+    pub const PublicKeyMigrationPeriod: BlockNumber = 20;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -192,6 +194,9 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = Replace;
 }
 
 impl nomination::Config for Test {
@@ -250,9 +255,18 @@ impl fee::Config for Test {
     type NominationApi = Nomination;
 }
 
+parameter_types! {
+    pub const RequestRetentionPeriod: BlockNumber = 100;
+    pub const MaxSweptRequestsPerBlock: u32 = 10;
+    pub const MaxReplaceAuctionsPerBlock: u32 = 2;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
+    type MaxReplaceAuctionsPerBlock = MaxReplaceAuctionsPerBlock;
 }
 
 pub type TestEvent = RuntimeEvent;
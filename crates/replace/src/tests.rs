@@ -6,9 +6,10 @@ use crate::{
 use bitcoin::merkle::PartialTransactionProof;
 use btc_relay::BtcAddress;
 use currency::Amount;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, weights::Weight};
 use mocktopus::mocking::*;
 use sp_core::H256;
+use vault_registry::Error as VaultRegistryError;
 
 type Event = crate::Event<Test>;
 
@@ -130,6 +131,7 @@ mod accept_replace_tests {
 
     fn setup_mocks() {
         ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::ensure_not_exiting::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
         ext::vault_registry::decrease_to_be_replaced_tokens::<Test>
             .mock_safe(|_, _| MockResult::Return(Ok((wrapped(5), griefing(10)))));
         ext::vault_registry::try_deposit_collateral::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
@@ -187,6 +189,19 @@ mod accept_replace_tests {
             );
         })
     }
+
+    #[test]
+    fn test_accept_replace_by_exiting_vault_fails() {
+        run_test(|| {
+            setup_mocks();
+            ext::vault_registry::ensure_not_exiting::<Test>
+                .mock_safe(|_| MockResult::Return(Err(VaultRegistryError::<Test>::VaultExiting.into())));
+            assert_err!(
+                Replace::_accept_replace(OLD_VAULT, NEW_VAULT, 5, 10, BtcAddress::random()),
+                VaultRegistryError::<Test>::VaultExiting
+            );
+        })
+    }
 }
 
 mod execute_replace_test {
@@ -288,4 +303,392 @@ mod cancel_replace_tests {
             });
         })
     }
+
+    #[test]
+    fn test_sweep_requests_cancels_expired_and_removes_cancelled_requests() {
+        run_test(|| {
+            Replace::replace_period.mock_safe(|| MockResult::Return(20));
+            ext::btc_relay::has_request_expired::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(true)));
+            ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(|_| MockResult::Return(Ok(false)));
+            ext::vault_registry::cancel_replace_tokens::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
+            ext::vault_registry::transfer_funds::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
+            ext::vault_registry::is_allowed_to_withdraw_collateral::<Test>
+                .mock_safe(|_, _| MockResult::Return(Ok(false)));
+
+            let pending_id = H256::repeat_byte(1);
+            let completed_id = H256::repeat_byte(2);
+            Replace::insert_replace_request(&pending_id, &test_request());
+            Replace::insert_replace_request(
+                &completed_id,
+                &ReplaceRequest {
+                    status: ReplaceRequestStatus::Completed,
+                    ..test_request()
+                },
+            );
+
+            Replace::sweep_requests(Weight::MAX);
+            assert_eq!(
+                ReplaceRequests::<Test>::get(pending_id).unwrap().status,
+                ReplaceRequestStatus::Cancelled
+            );
+
+            // replace period is 20 and retention period 100, the request was accepted at block 1
+            security::Pallet::<Test>::set_active_block_number(122);
+            Replace::sweep_requests(Weight::MAX);
+            assert!(ReplaceRequests::<Test>::get(pending_id).is_none());
+            // completed requests are kept, since their payment could otherwise be reported as theft
+            assert!(ReplaceRequests::<Test>::get(completed_id).is_some());
+            assert_eq!(
+                Replace::get_replace_requests_for_old_vault(OLD_VAULT.account_id),
+                vec![completed_id]
+            );
+            assert_eq!(
+                Replace::get_replace_requests_for_new_vault(NEW_VAULT.account_id),
+                vec![completed_id]
+            );
+        })
+    }
+}
+
+mod request_history_tests {
+    use super::*;
+    use crate::types::{MigrationCursor, Version};
+    use frame_support::traits::{Get, Hooks, OnRuntimeUpgrade};
+    use primitives::query::{RequestQuery, SortOrder};
+
+    #[test]
+    fn test_request_index_migration_indexes_existing_requests() {
+        run_test(|| {
+            // more requests than are migrated in a single block, stored without indexes
+            let mut replace_ids = (0..<Test as crate::Config>::MaxSweptRequestsPerBlock::get() + 2)
+                .map(|_| {
+                    let replace_id = H256::random();
+                    ReplaceRequests::<Test>::insert(replace_id, test_request());
+                    replace_id
+                })
+                .collect::<Vec<_>>();
+            replace_ids.sort();
+
+            RequestIndexMigration::<Test>::put(MigrationCursor::Start);
+            // requests are found by scanning until the indexes are complete
+            let mut found = Replace::get_replace_requests_for_old_vault(OLD_VAULT.account_id);
+            found.sort();
+            assert_eq!(found, replace_ids);
+
+            Replace::on_idle(1, Weight::MAX);
+            assert!(RequestIndexMigration::<Test>::exists());
+            Replace::on_idle(2, Weight::MAX);
+            assert!(!RequestIndexMigration::<Test>::exists());
+
+            let mut found = Replace::get_replace_requests_for_old_vault(OLD_VAULT.account_id);
+            found.sort();
+            assert_eq!(found, replace_ids);
+            let mut found = Replace::get_replace_requests_for_new_vault(NEW_VAULT.account_id);
+            found.sort();
+            assert_eq!(found, replace_ids);
+        })
+    }
+
+    #[test]
+    fn test_runtime_upgrade_from_unset_storage_version_indexes_existing_requests() {
+        run_test(|| {
+            let replace_id = H256::random();
+            ReplaceRequests::<Test>::insert(replace_id, test_request());
+
+            // live chains never wrote the storage version
+            StorageVersion::<Test>::kill();
+            crate::types::v0::Migration::<Test>::on_runtime_upgrade();
+            crate::types::v1::Migration::<Test>::on_runtime_upgrade();
+            assert!(matches!(Replace::storage_version(), Version::V2));
+            assert!(RequestIndexMigration::<Test>::exists());
+
+            Replace::on_idle(1, Weight::MAX);
+            assert!(!RequestIndexMigration::<Test>::exists());
+            assert_eq!(
+                Replace::get_replace_requests_for_old_vault(OLD_VAULT.account_id),
+                vec![replace_id]
+            );
+            assert_eq!(
+                Replace::get_replace_requests_for_new_vault(NEW_VAULT.account_id),
+                vec![replace_id]
+            );
+        })
+    }
+
+    #[test]
+    fn test_get_replace_requests_page_filters_sorts_and_paginates() {
+        run_test(|| {
+            let statuses = [
+                ReplaceRequestStatus::Pending,
+                ReplaceRequestStatus::Completed,
+                ReplaceRequestStatus::Cancelled,
+            ];
+            for (i, status) in statuses.into_iter().enumerate() {
+                Replace::insert_replace_request(
+                    &H256::repeat_byte(i as u8),
+                    &ReplaceRequest {
+                        accept_time: 10 - i as u64,
+                        status,
+                        ..test_request()
+                    },
+                );
+            }
+
+            let query = RequestQuery {
+                status: None,
+                vault: None,
+                currency: None,
+                order: SortOrder::Ascending,
+                offset: 0,
+                limit: 2,
+            };
+            let page = Replace::get_replace_requests_page_for_old_vault(OLD_VAULT.account_id, query.clone());
+            assert_eq!(page.total, 3);
+            let ids: Vec<_> = page.requests.into_iter().map(|(replace_id, _)| replace_id).collect();
+            assert_eq!(ids, vec![H256::repeat_byte(2), H256::repeat_byte(1)]);
+
+            // the vault filter matches either vault of the request
+            let page = Replace::get_replace_requests_page_for_old_vault(
+                OLD_VAULT.account_id,
+                RequestQuery {
+                    status: Some(ReplaceRequestStatus::Pending),
+                    vault: Some(NEW_VAULT),
+                    ..query.clone()
+                },
+            );
+            assert_eq!(page.total, 1);
+            assert_eq!(page.requests[0].0, H256::repeat_byte(0));
+
+            let page = Replace::get_replace_requests_page_for_old_vault(NEW_VAULT.account_id, query.clone());
+            assert_eq!(page.total, 0);
+            let page = Replace::get_replace_requests_page_for_new_vault(
+                NEW_VAULT.account_id,
+                RequestQuery {
+                    currency: Some(DEFAULT_WRAPPED_CURRENCY),
+                    ..query
+                },
+            );
+            assert_eq!(page.total, 0);
+        })
+    }
+}
+
+mod replace_auction_tests {
+    use super::*;
+    use orml_traits::{MultiCurrency, MultiReservableCurrency};
+    use primitives::{VaultCurrencyPair, VaultId};
+
+    const THIRD_VAULT: VaultId<AccountId, CurrencyId> = VaultId {
+        account_id: 3,
+        currencies: VaultCurrencyPair {
+            collateral: DEFAULT_COLLATERAL_CURRENCY,
+            wrapped: DEFAULT_WRAPPED_CURRENCY,
+        },
+    };
+
+    fn setup_mocks() {
+        ext::vault_registry::ensure_not_banned::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::ensure_not_exiting::<Test>.mock_safe(|_| MockResult::Return(Ok(())));
+        ext::vault_registry::get_issuable_tokens_with_collateral::<Test>
+            .mock_safe(|_, _| MockResult::Return(Ok(wrapped(1000))));
+        ext::vault_registry::requestable_to_be_replaced_tokens::<Test>
+            .mock_safe(move |_| MockResult::Return(Ok(wrapped(1000000))));
+        ext::vault_registry::try_increase_to_be_replaced_tokens::<Test>
+            .mock_safe(|_, _| MockResult::Return(Ok(wrapped(5))));
+        ext::fee::get_replace_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(20))));
+        ext::vault_registry::transfer_funds::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
+    }
+
+    fn bid(new_vault: VaultId<AccountId, CurrencyId>, premium: Balance) -> DefaultReplaceBid<Test> {
+        ReplaceBid {
+            new_vault,
+            collateral: 100,
+            premium,
+            btc_address: BtcAddress::random(),
+        }
+    }
+
+    fn start_auction() {
+        setup_mocks();
+        assert_ok!(Replace::_request_replace_auction(OLD_VAULT, 5, 5));
+        assert_ok!(griefing(1000).mint_to(&NEW_VAULT.account_id));
+        assert_ok!(griefing(1000).mint_to(&THIRD_VAULT.account_id));
+        assert_ok!(Amount::<Test>::new(1000, DEFAULT_COLLATERAL_CURRENCY).mint_to(&THIRD_VAULT.account_id));
+    }
+
+    #[test]
+    fn test_request_replace_auction_blocks_other_replace_paths() {
+        run_test(|| {
+            start_auction();
+            assert_event_matches!(Event::StartReplaceAuction {
+                old_vault_id: OLD_VAULT,
+                amount: 5,
+                griefing_collateral: 20,
+                end_block: 6,
+            });
+            assert_err!(
+                Replace::_request_replace(OLD_VAULT, 5),
+                TestError::ReplaceAuctionRunning
+            );
+            assert_err!(
+                Replace::_withdraw_replace_request(OLD_VAULT, 5),
+                TestError::ReplaceAuctionRunning
+            );
+            assert_err!(
+                Replace::_accept_replace(OLD_VAULT, NEW_VAULT, 5, 10, BtcAddress::random()),
+                TestError::ReplaceAuctionRunning
+            );
+        })
+    }
+
+    #[test]
+    fn test_request_replace_auction_longer_than_replace_period_fails() {
+        run_test(|| {
+            setup_mocks();
+            assert_err!(
+                Replace::_request_replace_auction(OLD_VAULT, 5, 11),
+                TestError::InvalidReplaceAuctionDuration
+            );
+            assert_err!(
+                Replace::_request_replace_auction(OLD_VAULT, 5, 0),
+                TestError::InvalidReplaceAuctionDuration
+            );
+        })
+    }
+
+    #[test]
+    fn test_bid_on_replace_auction_must_raise_premium() {
+        run_test(|| {
+            start_auction();
+            assert_ok!(Replace::_bid_on_replace_auction(OLD_VAULT, bid(NEW_VAULT, 10)));
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::reserved_balance(DEFAULT_NATIVE_CURRENCY, &NEW_VAULT.account_id),
+                10
+            );
+
+            assert_err!(
+                Replace::_bid_on_replace_auction(OLD_VAULT, bid(THIRD_VAULT, 10)),
+                TestError::ReplaceAuctionBidTooLow
+            );
+            assert_ok!(Replace::_bid_on_replace_auction(OLD_VAULT, bid(THIRD_VAULT, 11)));
+
+            // commitments of the outbid vault are released
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::reserved_balance(DEFAULT_NATIVE_CURRENCY, &NEW_VAULT.account_id),
+                0
+            );
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::reserved_balance(DEFAULT_COLLATERAL_CURRENCY, &NEW_VAULT.account_id),
+                0
+            );
+            let auction = Replace::get_replace_auction(&OLD_VAULT).unwrap();
+            assert_eq!(auction.best_bid.unwrap().new_vault, THIRD_VAULT);
+        })
+    }
+
+    #[test]
+    fn test_bid_on_replace_auction_fails_without_sufficient_collateral() {
+        run_test(|| {
+            start_auction();
+            ext::vault_registry::get_issuable_tokens_with_collateral::<Test>.mock_safe(|vault_id, collateral| {
+                assert_eq!(vault_id, &NEW_VAULT);
+                assert_eq!(collateral.amount(), 100);
+                MockResult::Return(Ok(wrapped(4)))
+            });
+            assert_err!(
+                Replace::_bid_on_replace_auction(OLD_VAULT, bid(NEW_VAULT, 10)),
+                TestError::InsufficientBidCollateral
+            );
+        })
+    }
+
+    #[test]
+    fn test_bid_on_ended_replace_auction_fails() {
+        run_test(|| {
+            start_auction();
+            Security::set_active_block_number(7);
+            assert_err!(
+                Replace::_bid_on_replace_auction(OLD_VAULT, bid(NEW_VAULT, 10)),
+                TestError::NoRunningReplaceAuction
+            );
+        })
+    }
+
+    #[test]
+    fn test_settle_replace_auction_accepts_best_bid() {
+        run_test(|| {
+            start_auction();
+            assert_ok!(Replace::_bid_on_replace_auction(OLD_VAULT, bid(NEW_VAULT, 10)));
+            Replace::_accept_replace.mock_safe(|old_vault_id, new_vault_id, amount, collateral, _| {
+                assert_eq!(old_vault_id, OLD_VAULT);
+                assert_eq!(new_vault_id, NEW_VAULT);
+                assert_eq!(amount, 5);
+                assert_eq!(collateral, 100);
+                MockResult::Return(Ok(H256::zero()))
+            });
+
+            Security::set_active_block_number(7);
+            Replace::settle_replace_auctions();
+
+            assert_event_matches!(Event::SettleReplaceAuction {
+                replace_id: _,
+                old_vault_id: OLD_VAULT,
+                new_vault_id: NEW_VAULT,
+                premium: 10,
+            });
+            assert_eq!(Replace::get_replace_auction(&OLD_VAULT), None);
+            // the premium is paid to the old vault
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::free_balance(DEFAULT_NATIVE_CURRENCY, &OLD_VAULT.account_id),
+                10
+            );
+        })
+    }
+
+    #[test]
+    fn test_settle_replace_auction_slashes_premium_of_unbacked_bid() {
+        run_test(|| {
+            start_auction();
+            assert_ok!(Replace::_bid_on_replace_auction(OLD_VAULT, bid(NEW_VAULT, 10)));
+            // the new vault withdrew collateral after bidding
+            ext::vault_registry::get_issuable_tokens_with_collateral::<Test>
+                .mock_safe(|_, _| MockResult::Return(Ok(wrapped(4))));
+            Replace::_accept_replace.mock_safe(|_, _, _, _, _| panic!("Should not accept unbacked bid"));
+
+            Security::set_active_block_number(7);
+            Replace::settle_replace_auctions();
+
+            assert_event_matches!(Event::SlashReplaceAuctionBid {
+                old_vault_id: OLD_VAULT,
+                new_vault_id: NEW_VAULT,
+                premium: 10,
+            });
+            assert_eq!(Replace::get_replace_auction(&OLD_VAULT), None);
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::free_balance(DEFAULT_NATIVE_CURRENCY, &OLD_VAULT.account_id),
+                10
+            );
+            assert_eq!(
+                <orml_tokens::Pallet<Test>>::reserved_balance(DEFAULT_COLLATERAL_CURRENCY, &NEW_VAULT.account_id),
+                0
+            );
+            assert_ok!(Replace::_request_replace(OLD_VAULT, 5));
+        })
+    }
+
+    #[test]
+    fn test_settle_replace_auction_without_bids_closes_auction() {
+        run_test(|| {
+            start_auction();
+            Security::set_active_block_number(7);
+            Replace::settle_replace_auctions();
+
+            assert_event_matches!(Event::CloseReplaceAuction {
+                old_vault_id: OLD_VAULT,
+                new_vault_id: None,
+            });
+            assert_eq!(Replace::get_replace_auction(&OLD_VAULT), None);
+            assert_ok!(Replace::_request_replace(OLD_VAULT, 5));
+        })
+    }
 }
@@ -1,12 +1,13 @@
 use crate::Config;
+use btc_relay::BtcAddress;
 use codec::{Decode, Encode, MaxEncodedLen};
 use currency::Amount;
 use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use primitives::replace::{ReplaceRequest, ReplaceRequestStatus};
-use primitives::VaultId;
+use primitives::{query::RequestQuery, VaultId};
 use scale_info::TypeInfo;
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_runtime::DispatchError;
 use vault_registry::types::CurrencyId;
 
@@ -15,6 +16,67 @@ use vault_registry::types::CurrencyId;
 pub enum Version {
     /// Initial version.
     V0,
+    /// Expired requests are cancelled, and cancelled requests removed, by the request sweeper
+    V1,
+    /// Requests are indexed by old and new vault
+    V2,
+}
+
+/// Position of a migration that visits all replace requests over several blocks.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MigrationCursor {
+    /// No request was visited yet.
+    Start,
+    /// Continue after the request with this id.
+    After(H256),
+}
+
+pub mod v0 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Cancelled requests are removed gradually by the request sweeper, so only the version changes.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V0) {
+                log::info!("Not running replace request sweeper migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::StorageVersion::<T>::put(Version::V1);
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// The existing requests are indexed over the following blocks, see
+    /// `Pallet::migrate_request_indexes`.
+    pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !matches!(crate::StorageVersion::<T>::get(), Version::V1) {
+                log::info!("Not running replace request index migration");
+                return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+            }
+
+            crate::RequestIndexMigration::<T>::put(MigrationCursor::Start);
+            crate::StorageVersion::<T>::put(Version::V2);
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+    }
 }
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
@@ -37,6 +99,9 @@ pub(crate) struct ReplaceRequestV0<AccountId, BlockNumber, Balance> {
 pub type DefaultReplaceRequest<T> =
     ReplaceRequest<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>, CurrencyId<T>>;
 
+pub type DefaultReplaceRequestQuery<T> =
+    RequestQuery<<T as frame_system::Config>::AccountId, CurrencyId<T>, ReplaceRequestStatus>;
+
 pub trait ReplaceRequestExt<T: Config> {
     fn amount(&self) -> Amount<T>;
     fn griefing_collateral(&self) -> Amount<T>;
@@ -54,3 +119,48 @@ impl<T: Config> ReplaceRequestExt<T> for DefaultReplaceRequest<T> {
         Ok(Amount::new(self.collateral, self.new_vault.collateral_currency()))
     }
 }
+
+/// An auction of an old vault's to-be-replaced tokens, which the best bid wins at `end_block`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ReplaceAuction<VaultId, Balance, BlockNumber> {
+    /// Tokens to be replaced
+    pub amount: Balance,
+    /// Last block at which bids are accepted
+    pub end_block: BlockNumber,
+    /// Best bid so far, if any
+    pub best_bid: Option<ReplaceBid<VaultId, Balance>>,
+}
+
+/// A new vault's bid in a replace auction. The collateral and premium are locked until the
+/// bid is outbid or the auction ends.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ReplaceBid<VaultId, Balance> {
+    /// Vault that replaces the old vault
+    pub new_vault: VaultId,
+    /// Collateral committed by the new vault
+    pub collateral: Balance,
+    /// Premium paid to the old vault, in the griefing collateral currency
+    pub premium: Balance,
+    /// Address that the old vault transfers the BTC to
+    pub btc_address: BtcAddress,
+}
+
+pub type DefaultReplaceAuction<T> = ReplaceAuction<DefaultVaultId<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+pub type DefaultReplaceBid<T> = ReplaceBid<DefaultVaultId<T>, BalanceOf<T>>;
+
+pub trait ReplaceBidExt<T: Config> {
+    fn collateral(&self) -> Amount<T>;
+    fn premium(&self) -> Amount<T>;
+}
+
+impl<T: Config> ReplaceBidExt<T> for DefaultReplaceBid<T> {
+    fn collateral(&self) -> Amount<T> {
+        Amount::new(self.collateral, self.new_vault.collateral_currency())
+    }
+    fn premium(&self) -> Amount<T> {
+        Amount::new(self.premium, T::GetGriefingCollateralCurrencyId::get())
+    }
+}
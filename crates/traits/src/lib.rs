@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, weights::Weight};
 use num_bigint::{BigUint, ToBigUint};
 
 pub mod loans;
@@ -23,9 +23,49 @@ pub trait OracleApi<Amount, CurrencyId> {
 pub trait NominationApi<VaultId, Amount> {
     fn deposit_vault_collateral(vault_id: &VaultId, amount: &Amount) -> Result<(), DispatchError>;
     fn ensure_opted_in_to_nomination(vault_id: &VaultId) -> Result<(), DispatchError>;
+    /// Ensure that the vault is registered.
+    fn ensure_vault_exists(vault_id: &VaultId) -> Result<(), DispatchError>;
+    /// Refund all nominators of the vault, if it has opted in to nomination.
+    fn opt_out_of_nomination(vault_id: &VaultId) -> Result<(), DispatchError>;
 
     #[cfg(any(feature = "runtime-benchmarks", test))]
     fn opt_in_to_nomination(vault_id: &VaultId);
+
+    #[cfg(any(feature = "runtime-benchmarks", test))]
+    fn register_vault(vault_id: &VaultId);
+}
+
+pub trait ReplaceApi<VaultId, Amount> {
+    fn request_replace(vault_id: &VaultId, amount: &Amount) -> Result<(), DispatchError>;
+}
+
+impl<VaultId, Amount> ReplaceApi<VaultId, Amount> for () {
+    fn request_replace(_vault_id: &VaultId, _amount: &Amount) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+
+pub trait XcmTransferApi<AccountId, CurrencyId, Balance, Location> {
+    /// Transfer `amount` of `currency_id` from `who` to the account at `dest` on another chain.
+    fn transfer(who: AccountId, currency_id: CurrencyId, amount: Balance, dest: Location) -> Result<(), DispatchError>;
+    /// Upper bound on the weight of a single `transfer`.
+    fn transfer_weight() -> Weight;
+}
+
+/// Used by chains without cross-chain transfers, where every transfer fails.
+impl<AccountId, CurrencyId, Balance, Location> XcmTransferApi<AccountId, CurrencyId, Balance, Location> for () {
+    fn transfer(
+        _who: AccountId,
+        _currency_id: CurrencyId,
+        _amount: Balance,
+        _dest: Location,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Cross-chain transfers are not supported"))
+    }
+
+    fn transfer_weight() -> Weight {
+        Weight::zero()
+    }
 }
 
 pub trait OnExchangeRateChange<CurrencyId> {
@@ -2,11 +2,61 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchError;
 use oracle_rpc_runtime_api::BalanceWrapper;
+use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Collateralization of a vault and the exchange rates at which it crosses the thresholds.
+/// Exchange rates are in collateral per wrapped token, like those of the oracle.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "std",
+    serde(bound(
+        serialize = "VaultId: Serialize, Balance: std::fmt::Display, UnsignedFixedPoint: Serialize",
+        deserialize = "VaultId: Deserialize<'de>, Balance: std::str::FromStr, UnsignedFixedPoint: Deserialize<'de>"
+    ))
+)]
+pub struct VaultHealth<VaultId, Balance, UnsignedFixedPoint> {
+    pub vault_id: VaultId,
+    /// collateral deposited by the vault itself
+    pub collateral: BalanceWrapper<Balance>,
+    /// collateral deposited by nominators
+    pub nominated_collateral: BalanceWrapper<Balance>,
+    pub issued_tokens: BalanceWrapper<Balance>,
+    pub to_be_issued_tokens: BalanceWrapper<Balance>,
+    pub to_be_redeemed_tokens: BalanceWrapper<Balance>,
+    /// none if the vault has no issued tokens
+    pub collateralization: Option<UnsignedFixedPoint>,
+    /// exchange rate above which the vault is below the premium redeem threshold
+    pub premium_redeem_exchange_rate: Option<UnsignedFixedPoint>,
+    /// exchange rate above which the vault is below the liquidation threshold
+    pub liquidation_exchange_rate: Option<UnsignedFixedPoint>,
+}
+
+/// Service record of a vault. Durations are in active blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct VaultStatistics {
+    pub executed_issues: u32,
+    pub cancelled_issues: u32,
+    pub executed_redeems: u32,
+    pub cancelled_redeems: u32,
+    /// none if no redeem was executed yet
+    pub average_redeem_execution_time: Option<u32>,
+    pub executed_replaces: u32,
+    pub cancelled_replaces: u32,
+    /// number of times the vault was banned
+    pub punishments: u32,
+}
+
 sp_api::decl_runtime_apis! {
     pub trait VaultRegistryApi<VaultId, Balance, UnsignedFixedPoint, CurrencyId, AccountId> where
         VaultId: Codec,
@@ -46,5 +96,35 @@ sp_api::decl_runtime_apis! {
         /// Get the amount of collateral required for the given vault to be at the
         /// current SecureCollateralThreshold with the current exchange rate
         fn get_required_collateral_for_vault(vault_id: VaultId) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the collateral that is left in the running auction of the liquidation vault
+        /// with the given collateral currency
+        fn get_auctioned_collateral(currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the amount of wrapped tokens that a bid for the given collateral costs in the
+        /// running auction at the current block
+        fn get_auction_bid_price(collateral: BalanceWrapper<Balance>, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the collateral, tokens and collateralization of an active vault, and the exchange
+        /// rates at which it crosses the premium redeem and liquidation thresholds
+        fn get_vault_health(vault_id: VaultId) -> Result<VaultHealth<VaultId, Balance, UnsignedFixedPoint>, DispatchError>;
+
+        /// Get the health of all active vaults
+        fn get_vaults_health() -> Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>;
+
+        /// Get the health of all active vaults registered by an accountId
+        fn get_vaults_health_by_account_id(account_id: AccountId) -> Result<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>, DispatchError>;
+
+        /// Get the health of all active vaults whose collateralization is below the given threshold
+        fn get_vaults_below_threshold(threshold: UnsignedFixedPoint) -> Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>;
+
+        /// Get the fee charged for issuing the given amount of tokens with a vault
+        fn get_issue_fee_quote(vault_id: VaultId, amount_btc: BalanceWrapper<Balance>) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the fee charged for redeeming the given amount of tokens with a vault
+        fn get_redeem_fee_quote(vault_id: VaultId, amount_btc: BalanceWrapper<Balance>) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the executed and cancelled requests and the punishments of a vault
+        fn get_vault_statistics(vault_id: VaultId) -> Result<VaultStatistics, DispatchError>;
     }
 }
@@ -15,7 +15,7 @@ use sp_runtime::{
 };
 use std::sync::Arc;
 
-pub use vault_registry_rpc_runtime_api::VaultRegistryApi as VaultRegistryRuntimeApi;
+pub use vault_registry_rpc_runtime_api::{VaultHealth, VaultRegistryApi as VaultRegistryRuntimeApi, VaultStatistics};
 
 #[rpc(client, server)]
 pub trait VaultRegistryApi<BlockHash, VaultId, Balance, UnsignedFixedPoint, CurrencyId, AccountId>
@@ -88,6 +88,67 @@ where
         vault_id: VaultId,
         at: Option<BlockHash>,
     ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getAuctionedCollateral")]
+    fn get_auctioned_collateral(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getAuctionBidPrice")]
+    fn get_auction_bid_price(
+        &self,
+        collateral: BalanceWrapper<Balance>,
+        currency_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getVaultHealth")]
+    fn get_vault_health(
+        &self,
+        vault_id: VaultId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>;
+
+    #[method(name = "vaultRegistry_getVaultsHealth")]
+    fn get_vaults_health(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>>;
+
+    #[method(name = "vaultRegistry_getVaultsHealthByAccountId")]
+    fn get_vaults_health_by_account_id(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>>;
+
+    #[method(name = "vaultRegistry_getVaultsBelowThreshold")]
+    fn get_vaults_below_threshold(
+        &self,
+        threshold: UnsignedFixedPoint,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>>;
+
+    #[method(name = "vaultRegistry_getIssueFeeQuote")]
+    fn get_issue_fee_quote(
+        &self,
+        vault_id: VaultId,
+        amount_btc: BalanceWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getRedeemFeeQuote")]
+    fn get_redeem_fee_quote(
+        &self,
+        vault_id: VaultId,
+        amount_btc: BalanceWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getVaultStatistics")]
+    fn get_vault_statistics(&self, vault_id: VaultId, at: Option<BlockHash>) -> RpcResult<VaultStatistics>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -274,4 +335,127 @@ where
             "Unable to get required collateral for vault".into(),
         )
     }
+
+    fn get_auctioned_collateral(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_auctioned_collateral(at, currency_id),
+            "Unable to get the auctioned collateral".into(),
+        )
+    }
+
+    fn get_auction_bid_price(
+        &self,
+        collateral: BalanceWrapper<Balance>,
+        currency_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_auction_bid_price(at, collateral, currency_id),
+            "Unable to get the auction bid price".into(),
+        )
+    }
+    fn get_vault_health(
+        &self,
+        vault_id: VaultId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VaultHealth<VaultId, Balance, UnsignedFixedPoint>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_vault_health(at, vault_id),
+            "Unable to get the vault's health".into(),
+        )
+    }
+
+    fn get_vaults_health(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_vaults_health(at)
+            .map_err(|e| internal_err(format!("Unable to get the health of the vaults: {:?}", e)))
+    }
+
+    fn get_vaults_health_by_account_id(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_vaults_health_by_account_id(at, account_id),
+            "Unable to get the health of the vaults".into(),
+        )
+    }
+
+    fn get_vaults_below_threshold(
+        &self,
+        threshold: UnsignedFixedPoint,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_vaults_below_threshold(at, threshold)
+            .map_err(|e| internal_err(format!("Unable to get the vaults below the threshold: {:?}", e)))
+    }
+
+    fn get_issue_fee_quote(
+        &self,
+        vault_id: VaultId,
+        amount_btc: BalanceWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_issue_fee_quote(at, vault_id, amount_btc),
+            "Unable to get the issue fee quote".into(),
+        )
+    }
+
+    fn get_redeem_fee_quote(
+        &self,
+        vault_id: VaultId,
+        amount_btc: BalanceWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_redeem_fee_quote(at, vault_id, amount_btc),
+            "Unable to get the redeem fee quote".into(),
+        )
+    }
+
+    fn get_vault_statistics(
+        &self,
+        vault_id: VaultId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VaultStatistics> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_vault_statistics(at, vault_id),
+            "Unable to get the vault's statistics".into(),
+        )
+    }
 }
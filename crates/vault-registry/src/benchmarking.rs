@@ -1,7 +1,11 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{types::BtcPublicKey, Pallet as VaultRegistry};
+use crate::{
+    types::{AuctionParameters, BtcPublicKey},
+    Pallet as VaultRegistry,
+};
+use bitcoin::types::MAX_MULTISIG_PUBLIC_KEYS;
 use currency::getters::{get_relay_chain_currency_id as get_collateral_currency_id, *};
 use frame_benchmarking::v2::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::assert_ok;
@@ -10,7 +14,7 @@ use loans::{InterestRateModel, JumpModel, Market, MarketState, Pallet as Loans};
 use oracle::Pallet as Oracle;
 use orml_traits::MultiCurrency;
 use primitives::{CurrencyId, Rate, Ratio};
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber};
 use sp_std::prelude::*;
 use traits::LoansApi;
 
@@ -130,6 +134,44 @@ pub fn activate_lending_and_get_vault_id<T: crate::Config + loans::Config>() ->
     vault_id
 }
 
+/// Inserts as many retired public keys as an account can have: since every rotation needs the
+/// rotation delay, and expired keys are pruned, there are at most this many of them.
+fn insert_retired_public_keys<T: crate::Config>(account_id: &T::AccountId) {
+    let retired_keys: u32 = (T::PublicKeyMigrationPeriod::get() / T::PublicKeyRotationDelay::get().max(1u32.into()))
+        .saturating_add(1u32.into())
+        .unique_saturated_into();
+    for i in 0..retired_keys.min(u8::MAX.into()) {
+        RetiredBitcoinPublicKeys::<T>::insert(
+            account_id,
+            BtcPublicKey::from([(i as u8).wrapping_add(10); 33]),
+            0u32.into(),
+        );
+    }
+}
+
+fn setup_auction<T: crate::Config + loans::Config>() -> DefaultVaultCurrencyPair<T> {
+    let vault_id = activate_lending_and_get_vault_id::<T>();
+    register_vault_with_collateral::<T>(vault_id.clone());
+
+    let amount = VaultRegistry::<T>::get_issuable_tokens_from_vault(&vault_id).unwrap();
+    assert!(!amount.is_zero());
+    VaultRegistry::<T>::try_increase_to_be_issued_tokens(&vault_id, &amount).unwrap();
+    VaultRegistry::<T>::issue_tokens(&vault_id, &amount).unwrap();
+    VaultRegistry::<T>::liquidate_vault(&vault_id).unwrap();
+
+    assert_ok!(VaultRegistry::<T>::set_auction_parameters(
+        RawOrigin::Root.into(),
+        vault_id.currencies.clone(),
+        Some(AuctionParameters {
+            lot_size: (1u32 << 31).into(),
+            duration: 100u32.into(),
+            start_price: UnsignedFixedPoint::<T>::one(),
+            end_price: UnsignedFixedPoint::<T>::one(),
+        }),
+    ));
+    vault_id.currencies
+}
+
 #[benchmarks(where T: loans::Config)]
 pub mod benchmarks {
     use super::*;
@@ -229,6 +271,16 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_partial_liquidation_penalty() {
+        #[extrinsic_call]
+        set_partial_liquidation_penalty(
+            RawOrigin::Root,
+            get_currency_pair::<T>(),
+            Some(UnsignedFixedPoint::<T>::one()),
+        );
+    }
+
     #[benchmark]
     fn report_undercollateralized_vault() {
         let vault_id = activate_lending_and_get_vault_id::<T>();
@@ -273,6 +325,119 @@ pub mod benchmarks {
         recover_vault_id(RawOrigin::Signed(vault_id.account_id), vault_id.currencies.clone());
     }
 
+    #[benchmark]
+    fn propose_public_key() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault_with_collateral::<T>(vault_id.clone());
+        // worst case: a previous rotation is due and gets activated first
+        PendingPublicKeyRotation::<T>::insert(
+            &vault_id.account_id,
+            PublicKeyRotation {
+                public_key: BtcPublicKey::from([2; 33]),
+                activation_block: 0u32.into(),
+            },
+        );
+        // and the retired keys whose migration period has ended get pruned
+        security::Pallet::<T>::set_active_block_number(1u32.into());
+        insert_retired_public_keys::<T>(&vault_id.account_id);
+
+        #[extrinsic_call]
+        propose_public_key(RawOrigin::Signed(vault_id.account_id), BtcPublicKey::from([3; 33]));
+    }
+
+    #[benchmark]
+    fn register_multisig_public_keys() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        let public_keys: BtcMultisigPublicKeys = (0..MAX_MULTISIG_PUBLIC_KEYS)
+            .map(|i| BtcPublicKey::from([i; 33]))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        #[extrinsic_call]
+        register_multisig_public_keys(
+            RawOrigin::Signed(vault_id.account_id),
+            public_keys,
+            MAX_MULTISIG_PUBLIC_KEYS,
+        );
+    }
+
+    #[benchmark]
+    fn set_auction_parameters() {
+        // worst case: disabling the auctions also removes the running auction
+        #[extrinsic_call]
+        set_auction_parameters(RawOrigin::Root, get_currency_pair::<T>(), None);
+    }
+
+    #[benchmark]
+    fn start_auction() {
+        let currency_pair = setup_auction::<T>();
+        let origin: T::AccountId = account("Origin", 0, 0);
+
+        #[extrinsic_call]
+        start_auction(RawOrigin::Signed(origin), currency_pair.clone());
+
+        assert!(VaultRegistry::<T>::get_auctioned_collateral(&currency_pair).is_ok());
+    }
+
+    #[benchmark]
+    fn bid_on_auction() {
+        let currency_pair = setup_auction::<T>();
+        let bidder: T::AccountId = account("Bidder", 0, 0);
+        assert_ok!(VaultRegistry::<T>::start_auction(
+            RawOrigin::Signed(bidder.clone()).into(),
+            currency_pair.clone()
+        ));
+        deposit_tokens::<T>(currency_pair.wrapped, &bidder, (1u32 << 31).into());
+
+        // the auction is not sold out, so it is written back to storage
+        let collateral = VaultRegistry::<T>::get_auctioned_collateral(&currency_pair)
+            .unwrap()
+            .amount()
+            / 2u32.into();
+
+        #[extrinsic_call]
+        bid_on_auction(
+            RawOrigin::Signed(bidder),
+            currency_pair,
+            collateral,
+            (1u32 << 31).into(),
+        );
+    }
+
+    #[benchmark]
+    fn request_vault_exit() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault_with_collateral::<T>(vault_id.clone());
+
+        #[extrinsic_call]
+        request_vault_exit(
+            RawOrigin::Signed(vault_id.account_id.clone()),
+            vault_id.currencies.clone(),
+        );
+
+        assert!(VaultRegistry::<T>::ensure_not_exiting(&vault_id).is_err());
+    }
+
+    #[benchmark]
+    fn remove_exited_vault() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault_with_collateral::<T>(vault_id.clone());
+        assert_ok!(VaultRegistry::<T>::request_vault_exit(
+            RawOrigin::Signed(vault_id.account_id.clone()).into(),
+            vault_id.currencies.clone()
+        ));
+        // worst case: the last vault of the account is removed, along with all its keys
+        insert_retired_public_keys::<T>(&vault_id.account_id);
+
+        #[block]
+        {
+            VaultRegistry::<T>::remove_exited_vaults(Weight::MAX);
+        }
+
+        assert!(!VaultRegistry::<T>::vault_exists(&vault_id));
+    }
+
     impl_benchmark_test_suite! {
         VaultRegistry,
         crate::mock::ExtBuilder::build_with(Default::default()),
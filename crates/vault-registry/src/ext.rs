@@ -123,8 +123,9 @@ pub(crate) mod oracle {
 #[cfg_attr(test, mockable)]
 pub(crate) mod fee {
     use crate::DefaultVaultId;
+    use currency::Amount;
     use fee::types::UnsignedFixedPoint;
-    use frame_support::dispatch::DispatchResult;
+    use frame_support::dispatch::{DispatchError, DispatchResult};
 
     pub fn distribute_all_vault_rewards<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> DispatchResult {
         <fee::Pallet<T>>::distribute_all_vault_rewards(vault_id)
@@ -134,7 +135,15 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::premium_redeem_reward_rate()
     }
 
-    pub fn get_redeem_fee_value<T: crate::Config>() -> UnsignedFixedPoint<T> {
-        <fee::Pallet<T>>::get_redeem_fee_value()
+    pub fn get_redeem_fee_value<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> UnsignedFixedPoint<T> {
+        <fee::Pallet<T>>::get_vault_redeem_fee_value(vault_id)
+    }
+
+    pub fn get_punishment_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_punishment_fee(amount)
+    }
+
+    pub fn remove_vault_fees<T: crate::Config>(vault_id: &DefaultVaultId<T>) {
+        <fee::Pallet<T>>::remove_vault_fees(vault_id)
     }
 }
@@ -31,22 +31,30 @@ extern crate mocktopus;
 use mocktopus::macros::mockable;
 use primitives::VaultCurrencyPair;
 
+use codec::Encode;
+
 use crate::types::{
-    BalanceOf, BtcAddress, CurrencyId, DefaultSystemVault, RichSystemVault, RichVault, UnsignedFixedPoint, Version,
+    BalanceOf, BtcAddress, CollateralAuction, CurrencyId, DefaultAuctionParameters, DefaultCollateralAuction,
+    DefaultSystemVault, DefaultVaultHealth, DefaultVaultStatistics, MultisigPublicKeys, PublicKeyRotation,
+    RichSystemVault, RichVault, UnsignedFixedPoint, Version,
 };
 
 use crate::types::DefaultVaultCurrencyPair;
 #[doc(inline)]
 pub use crate::types::{
-    BtcPublicKey, CurrencySource, DefaultVault, DefaultVaultId, SystemVault, Vault, VaultId, VaultStatus,
+    BtcMultisigPublicKeys, BtcPublicKey, CurrencySource, DefaultVault, DefaultVaultId, SystemVault, Vault, VaultHealth,
+    VaultId, VaultStatistics, VaultStatus,
 };
 pub use currency::Amount;
 use currency::Rounding;
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
+    storage::{with_storage_layer, StoragePrefixedMap},
     traits::Get,
-    transactional, PalletId,
+    transactional,
+    weights::Weight,
+    Blake2_128Concat, PalletId, StorageHasher,
 };
 use frame_system::{
     ensure_signed,
@@ -60,11 +68,17 @@ use sp_runtime::{
     ArithmeticError, FixedPointNumber,
 };
 use sp_std::{convert::TryInto, vec::Vec};
-use traits::NominationApi;
+use traits::{NominationApi, ReplaceApi};
 
 // value taken from https://github.com/substrate-developer-hub/recipes/blob/master/pallets/ocw-demo/src/lib.rs
 pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
 
+/// Maximum number of vaults that an issue or redeem request without a chosen vault is split across.
+pub const MAX_VAULTS_PER_REQUEST: u32 = 5;
+
+/// Maximum number of vaults that are read to select the vaults for such a request.
+pub const MAX_VAULTS_SCANNED_PER_SELECTION: u32 = 100;
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -102,6 +116,18 @@ pub mod pallet {
         /// Currency used for griefing collateral, e.g. DOT.
         #[pallet::constant]
         type GetGriefingCollateralCurrencyId: Get<CurrencyId<Self>>;
+
+        /// Number of blocks after which a proposed Bitcoin public key replaces the vault's current key.
+        #[pallet::constant]
+        type PublicKeyRotationDelay: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks after a key rotation during which the vault may move its BTC
+        /// from the retired key to the new key.
+        #[pallet::constant]
+        type PublicKeyMigrationPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Used to offer the issued tokens of exiting vaults for replacement.
+        type ReplaceApi: ReplaceApi<DefaultVaultId<Self>, Amount<Self>>;
     }
 
     #[pallet::hooks]
@@ -111,8 +137,15 @@ pub mod pallet {
             Self::_offchain_worker();
         }
 
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::remove_exited_vaults(remaining_weight)
+        }
+
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             crate::types::v1::migrate_v1_to_v6::<T>()
+                .saturating_add(crate::types::v6::migrate_v6_to_v7::<T>())
+                .saturating_add(crate::types::v7::migrate_v7_to_v8::<T>())
+                .saturating_add(crate::types::v8::migrate_v8_to_v9::<T>())
         }
     }
 
@@ -181,7 +214,8 @@ pub mod pallet {
             let account_id = ensure_signed(origin)?;
 
             ensure!(
-                !VaultBitcoinPublicKey::<T>::get(&account_id).is_some(),
+                !VaultBitcoinPublicKey::<T>::get(&account_id).is_some()
+                    && !VaultBitcoinMultisig::<T>::contains_key(&account_id),
                 Error::<T>::PublicKeyAlreadyRegistered
             );
 
@@ -210,6 +244,7 @@ pub mod pallet {
             let account_id = ensure_signed(origin)?;
             let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
             let mut vault = Self::get_active_rich_vault_from_id(&vault_id)?;
+            ensure!(!vault.data.is_exiting(), Error::<T>::VaultExiting);
             vault.set_accept_new_issues(accept_new_issues)?;
             PoolManager::<T>::on_vault_settings_change(&vault_id)?;
             Self::deposit_event(Event::<T>::SetAcceptNewIssues {
@@ -258,7 +293,7 @@ pub mod pallet {
             let liquidation_threshold =
                 Self::liquidation_collateral_threshold(&vault_id.currencies).ok_or(Error::<T>::ThresholdNotSet)?;
             if Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold)? {
-                Self::liquidate_vault(&vault_id)?;
+                Self::liquidate_undercollateralized_vault(&vault_id)?;
                 Ok(().into())
             } else {
                 log::info!("Not liquidating; vault not below liquidation threshold");
@@ -369,6 +404,28 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Enables partial liquidation for a currency pair, or disables it if `penalty` is `None`
+        /// (only executable by the Root account). Partially liquidated vaults move just enough
+        /// tokens to the liquidation vault to restore their secure threshold, together with
+        /// collateral worth the tokens plus the penalty.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair to change
+        /// * `penalty` - the share of the liquidated tokens' value slashed on top of their value
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_partial_liquidation_penalty())]
+        #[transactional]
+        pub fn set_partial_liquidation_penalty(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            penalty: Option<UnsignedFixedPoint<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            PartialLiquidationPenalty::<T>::set(&currency_pair, penalty);
+            Self::deposit_event(Event::<T>::SetPartialLiquidationPenalty { currency_pair, penalty });
+            Ok(())
+        }
+
         /// Recover vault ID from a liquidated status.
         ///
         /// # Arguments
@@ -389,6 +446,280 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Proposes a new Bitcoin public key for the vault's account. After `PublicKeyRotationDelay`
+        /// blocks, new deposit addresses are derived from the new key. Addresses derived from the
+        /// current key remain valid for pending issue requests, and the vault has
+        /// `PublicKeyMigrationPeriod` blocks after the activation to move its BTC to the new key.
+        ///
+        /// # Arguments
+        /// * `public_key` - the BTC public key that replaces the current key
+        ///
+        /// # Errors
+        /// * `NoBitcoinPublicKey` - if no public key is registered for the account
+        /// * `PublicKeyAlreadyRegistered` - if the key is already the account's current key
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::propose_public_key())]
+        #[transactional]
+        pub fn propose_public_key(origin: OriginFor<T>, public_key: BtcPublicKey) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+
+            // a rotation that is due must take effect before it can be superseded
+            Self::try_activate_public_key(&account_id);
+
+            let current_public_key = Self::get_bitcoin_public_key(&account_id)?;
+            ensure!(current_public_key != public_key, Error::<T>::PublicKeyAlreadyRegistered);
+
+            let activation_block =
+                ext::security::active_block_number::<T>().saturating_add(T::PublicKeyRotationDelay::get());
+            PendingPublicKeyRotation::<T>::insert(
+                &account_id,
+                PublicKeyRotation {
+                    public_key: public_key.clone(),
+                    activation_block,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::ProposePublicKey {
+                account_id,
+                public_key,
+                activation_block,
+            });
+            Ok(().into())
+        }
+
+        /// Registers an m-of-n set of Bitcoin public keys for the vault's account, instead of
+        /// a single key. Deposit addresses are P2WSH multisig scripts over the keys, each
+        /// tweaked with the secure id of the request. Multisig keys can't be rotated.
+        ///
+        /// # Arguments
+        /// * `public_keys` - the BTC public keys of the signers
+        /// * `threshold` - the number of signatures required to spend
+        ///
+        /// # Errors
+        /// * `PublicKeyAlreadyRegistered` - if a key or key set is already registered for the account
+        /// * `InvalidMultisig` - if the threshold or the keys don't form a valid multisig script
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_multisig_public_keys())]
+        #[transactional]
+        pub fn register_multisig_public_keys(
+            origin: OriginFor<T>,
+            public_keys: BtcMultisigPublicKeys,
+            threshold: u8,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(
+                !VaultBitcoinPublicKey::<T>::get(&account_id).is_some()
+                    && !VaultBitcoinMultisig::<T>::contains_key(&account_id),
+                Error::<T>::PublicKeyAlreadyRegistered
+            );
+
+            let multisig = MultisigPublicKeys {
+                threshold,
+                public_keys: public_keys.clone(),
+            };
+            ensure!(multisig.to_address(None).is_some(), Error::<T>::InvalidMultisig);
+
+            VaultBitcoinMultisig::<T>::insert(&account_id, multisig);
+
+            Self::deposit_event(Event::<T>::RegisterMultisigPublicKeys {
+                account_id,
+                public_keys,
+                threshold,
+            });
+            Ok(().into())
+        }
+
+        /// Sets the parameters of the auctions that sell the liquidation vault's collateral for
+        /// a currency pair, or disables the auctions if `parameters` is `None` (only executable
+        /// by the Root account). Disabling the auctions also stops the running auction.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair to change
+        /// * `parameters` - the lot size, duration and start and end price of new auctions
+        ///
+        /// # Errors
+        /// * `InvalidAuctionParameters` - if the lot size or duration is zero, or the price increases
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_auction_parameters())]
+        #[transactional]
+        pub fn set_auction_parameters(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultAuctionParameters<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some(ref parameters) = parameters {
+                ensure!(
+                    !parameters.lot_size.is_zero()
+                        && !parameters.duration.is_zero()
+                        && parameters.end_price <= parameters.start_price,
+                    Error::<T>::InvalidAuctionParameters
+                );
+            } else {
+                CollateralAuctions::<T>::remove(&currency_pair);
+            }
+            CollateralAuctionParameters::<T>::set(&currency_pair, parameters.clone());
+            Self::deposit_event(Event::<T>::SetAuctionParameters {
+                currency_pair,
+                parameters,
+            });
+            Ok(())
+        }
+
+        /// Starts a descending-price auction of the liquidation vault's collateral. The lot is
+        /// limited to the collateral backing the liquidation vault's redeemable tokens.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the liquidation vault
+        ///
+        /// # Errors
+        /// * `AuctionParametersNotSet` - if auctions are disabled for the currency pair
+        /// * `AuctionAlreadyRunning` - if the previous auction has not ended or sold out yet
+        /// * `NoAuctionableCollateral` - if the liquidation vault has no collateral to sell
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::start_auction())]
+        #[transactional]
+        pub fn start_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let parameters =
+                CollateralAuctionParameters::<T>::get(&currency_pair).ok_or(Error::<T>::AuctionParametersNotSet)?;
+            ensure!(
+                Self::get_running_auction(&currency_pair).is_none(),
+                Error::<T>::AuctionAlreadyRunning
+            );
+
+            let lot = Self::get_auctionable_collateral(&currency_pair)?
+                .min(&Amount::new(parameters.lot_size, currency_pair.collateral))?;
+            ensure!(!lot.is_zero(), Error::<T>::NoAuctionableCollateral);
+
+            let start_block = ext::security::active_block_number::<T>();
+            let end_block = start_block.saturating_add(parameters.duration);
+            CollateralAuctions::<T>::insert(
+                &currency_pair,
+                CollateralAuction {
+                    collateral: lot.amount(),
+                    start_block,
+                    end_block,
+                    start_price: parameters.start_price,
+                    end_price: parameters.end_price,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::StartAuction {
+                currency_pair,
+                collateral: lot.amount(),
+                start_block,
+                end_block,
+            });
+            Ok(().into())
+        }
+
+        /// Buys collateral from the running auction at the current price. The wrapped tokens
+        /// paid are burned, reducing the tokens issued by the liquidation vault.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the liquidation vault
+        /// * `collateral` - the amount of collateral to buy
+        /// * `max_wrapped` - the maximum amount of wrapped tokens to pay
+        ///
+        /// # Errors
+        /// * `NoRunningAuction` - if there is no running auction for the currency pair
+        /// * `AuctionLotExceeded` - if the auction has less collateral left
+        /// * `AuctionBidTooLow` - if the collateral is worth less than one unit of the wrapped token
+        /// * `AuctionPriceAboveLimit` - if the collateral costs more than `max_wrapped`
+        /// * `InsufficientTokensCommitted` - if the liquidation vault has fewer redeemable tokens
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::bid_on_auction())]
+        #[transactional]
+        pub fn bid_on_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            #[pallet::compact] collateral: BalanceOf<T>,
+            #[pallet::compact] max_wrapped: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let bidder_id = ensure_signed(origin)?;
+
+            let mut auction = Self::get_running_auction(&currency_pair).ok_or(Error::<T>::NoRunningAuction)?;
+            let collateral = Amount::new(collateral, currency_pair.collateral);
+            ensure!(
+                collateral.amount() <= auction.collateral,
+                Error::<T>::AuctionLotExceeded
+            );
+            // redeem requests against the liquidation vault may have claimed part of the lot
+            ensure!(
+                collateral.le(&Self::get_auctionable_collateral(&currency_pair)?)?,
+                Error::<T>::InsufficientCollateral
+            );
+
+            let wrapped = Self::calculate_auction_bid_price(&auction, &collateral, currency_pair.wrapped)?;
+            ensure!(!wrapped.is_zero(), Error::<T>::AuctionBidTooLow);
+            ensure!(wrapped.amount() <= max_wrapped, Error::<T>::AuctionPriceAboveLimit);
+
+            let liquidation_vault = Self::get_rich_liquidation_vault(&currency_pair);
+            ensure!(
+                liquidation_vault.redeemable_tokens()?.ge(&wrapped)?,
+                Error::<T>::InsufficientTokensCommitted
+            );
+
+            wrapped.lock_on(&bidder_id)?;
+            wrapped.burn_from(&bidder_id)?;
+
+            Self::transfer_funds(
+                CurrencySource::LiquidationVault(currency_pair.clone()),
+                CurrencySource::FreeBalance(bidder_id.clone()),
+                &collateral,
+            )?;
+
+            // need to requery since the liquidation vault gets modified in `transfer_funds`
+            let mut liquidation_vault = Self::get_rich_liquidation_vault(&currency_pair);
+            liquidation_vault.burn_issued(&wrapped)?;
+
+            auction.collateral = auction.collateral.saturating_sub(collateral.amount());
+            if auction.collateral.is_zero() {
+                CollateralAuctions::<T>::remove(&currency_pair);
+            } else {
+                CollateralAuctions::<T>::insert(&currency_pair, auction);
+            }
+
+            Self::deposit_event(Event::<T>::BidOnAuction {
+                currency_pair,
+                bidder_id,
+                collateral: collateral.amount(),
+                burned_tokens: wrapped.amount(),
+            });
+            Ok(().into())
+        }
+
+        /// Starts the deregistration of the vault. The vault stops accepting issue requests and
+        /// replacing other vaults, its nominators are refunded and its issued tokens are offered
+        /// for replacement. Once it has no issued, to-be-issued or to-be-redeemed tokens left, its
+        /// collateral is released and the vault is removed.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the vault
+        ///
+        /// # Errors
+        /// * `VaultExiting` - if the vault has already requested to exit
+        /// * `VaultLiquidated` - if the vault has been liquidated
+        /// * `CollateralizationTooLow` - if the vault cannot refund its nominators
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_vault_exit())]
+        #[transactional]
+        pub fn request_vault_exit(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
+            Self::_request_vault_exit(&vault_id)?;
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -412,6 +743,16 @@ pub mod pallet {
             account_id: T::AccountId,
             public_key: BtcPublicKey,
         },
+        ProposePublicKey {
+            account_id: T::AccountId,
+            public_key: BtcPublicKey,
+            activation_block: BlockNumberFor<T>,
+        },
+        RegisterMultisigPublicKeys {
+            account_id: T::AccountId,
+            public_keys: BtcMultisigPublicKeys,
+            threshold: u8,
+        },
         RegisterAddress {
             vault_id: DefaultVaultId<T>,
             address: BtcAddress,
@@ -505,10 +846,42 @@ pub mod pallet {
             currency_pair: DefaultVaultCurrencyPair<T>,
             threshold: UnsignedFixedPoint<T>,
         },
+        SetPartialLiquidationPenalty {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            penalty: Option<UnsignedFixedPoint<T>>,
+        },
+        PartiallyLiquidateVault {
+            vault_id: DefaultVaultId<T>,
+            liquidated_tokens: BalanceOf<T>,
+            liquidated_collateral: BalanceOf<T>,
+        },
+        SetAuctionParameters {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultAuctionParameters<T>>,
+        },
+        StartAuction {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            collateral: BalanceOf<T>,
+            start_block: BlockNumberFor<T>,
+            end_block: BlockNumberFor<T>,
+        },
+        BidOnAuction {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            bidder_id: T::AccountId,
+            collateral: BalanceOf<T>,
+            burned_tokens: BalanceOf<T>,
+        },
         SetCustomSecureThreshold {
             vault_id: DefaultVaultId<T>,
             custom_threshold: Option<UnsignedFixedPoint<T>>,
         },
+        RequestVaultExit {
+            vault_id: DefaultVaultId<T>,
+        },
+        VaultExited {
+            vault_id: DefaultVaultId<T>,
+            released_collateral: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -567,6 +940,28 @@ pub mod pallet {
 
         // Minimum collateral was not found for the given currency
         MinimumCollateralNotSet,
+
+        /// The threshold is not between one and the number of distinct multisig public keys.
+        InvalidMultisig,
+
+        /// The lot size or duration is zero, or the end price is above the start price.
+        InvalidAuctionParameters,
+        /// Auctions are disabled for the currency pair.
+        AuctionParametersNotSet,
+        /// The previous auction of the currency pair has not ended or sold out yet.
+        AuctionAlreadyRunning,
+        /// There is no running auction for the currency pair.
+        NoRunningAuction,
+        /// The liquidation vault has no collateral backing redeemable tokens.
+        NoAuctionableCollateral,
+        /// The bid is for more collateral than is left in the auction.
+        AuctionLotExceeded,
+        /// The collateral is worth less than one unit of the wrapped token.
+        AuctionBidTooLow,
+        /// The collateral costs more wrapped tokens than the bidder is willing to pay.
+        AuctionPriceAboveLimit,
+        /// Vault has requested to exit and no longer accepts new requests.
+        VaultExiting,
     }
 
     /// The minimum collateral (e.g. DOT/KSM) a Vault needs to provide to register.
@@ -610,6 +1005,26 @@ pub mod pallet {
     pub(super) type LiquidationCollateralThreshold<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, UnsignedFixedPoint<T>>;
 
+    /// Currency pairs whose undercollateralized vaults are liquidated partially, mapped to the
+    /// penalty on the value of the liquidated tokens. Vaults of other pairs are fully liquidated.
+    #[pallet::storage]
+    #[pallet::getter(fn partial_liquidation_penalty)]
+    pub(super) type PartialLiquidationPenalty<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, UnsignedFixedPoint<T>>;
+
+    /// Parameters of the auctions that sell the liquidation vault's collateral. Auctions are
+    /// disabled for currency pairs without parameters.
+    #[pallet::storage]
+    #[pallet::getter(fn auction_parameters)]
+    pub(super) type CollateralAuctionParameters<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, DefaultAuctionParameters<T>>;
+
+    /// The last auction of the liquidation vault's collateral for each currency pair. Sold out
+    /// auctions are removed, ended auctions are replaced by the next auction.
+    #[pallet::storage]
+    pub(super) type CollateralAuctions<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, DefaultCollateralAuction<T>>;
+
     #[pallet::storage]
     pub(super) type LiquidationVault<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, DefaultSystemVault<T>, OptionQuery>;
@@ -618,12 +1033,59 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, DefaultVault<T>>;
 
+    /// The accounts of the vaults of each currency pair, which are scanned when selecting
+    /// vaults for requests that don't specify a vault.
+    #[pallet::storage]
+    pub(super) type CurrencyPairVaults<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DefaultVaultCurrencyPair<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
     /// Mapping of Vaults, using the respective Vault account identifier as key.
     #[pallet::storage]
     pub(super) type VaultBitcoinPublicKey<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BtcPublicKey, OptionQuery>;
 
-    /// Mapping of reserved BTC addresses to the registered account
+    /// Mapping of multisig vault accounts to their m-of-n Bitcoin public keys.
+    #[pallet::storage]
+    pub(super) type VaultBitcoinMultisig<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, MultisigPublicKeys, OptionQuery>;
+
+    /// Bitcoin public keys proposed by vault accounts that are not yet in use.
+    #[pallet::storage]
+    pub(super) type PendingPublicKeyRotation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PublicKeyRotation<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Bitcoin public keys that were replaced through a rotation, mapped to the block
+    /// until which the vault may migrate its BTC from them. Expired entries are pruned when the
+    /// next rotation is activated, so every account has only a few of them.
+    #[pallet::storage]
+    pub(super) type RetiredBitcoinPublicKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BtcPublicKey,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// The end of the migration period of the account's most recently retired public key. Kept
+    /// separately from `RetiredBitcoinPublicKeys`, whose expired entries are pruned, so that a
+    /// late theft report can't turn a timely migration into theft.
+    #[pallet::storage]
+    pub(super) type PublicKeyMigrationDeadline<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Mapping of reserved BTC addresses to the registered account. Entries outlive the vault:
+    /// the addresses are derived from the account's keys, so a vault that is registered again
+    /// with the same keys still has to recognize payments from them as its own, and an address
+    /// must never be handed out for a second request.
     #[pallet::storage]
     pub(super) type ReservedAddresses<T: Config> =
         StorageMap<_, Blake2_128Concat, BtcAddress, DefaultVaultId<T>, OptionQuery>;
@@ -633,6 +1095,45 @@ pub mod pallet {
     pub(super) type TotalUserVaultCollateral<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, BalanceOf<T>, ValueQuery>;
 
+    /// Active vaults with issued tokens, bucketed by their liquidation price: the ratio of their
+    /// issued tokens to their collateral. A vault falls below the liquidation threshold once the
+    /// value of its collateral (in wrapped per collateral unit) drops below this price times
+    /// the threshold, so the index doesn't change with the exchange rate.
+    #[pallet::storage]
+    pub(super) type LiquidationPriceIndex<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (DefaultVaultCurrencyPair<T>, u32),
+        Blake2_128Concat,
+        DefaultVaultId<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// The bucket of each vault in the `LiquidationPriceIndex`.
+    #[pallet::storage]
+    pub(super) type VaultLiquidationPriceBucket<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, u32, OptionQuery>;
+
+    /// The highest bucket of the `LiquidationPriceIndex` that was used by each currency pair.
+    #[pallet::storage]
+    pub(super) type MaxLiquidationPriceBucket<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, u32, ValueQuery>;
+
+    /// Service record of each vault: executed and cancelled requests, and punishments.
+    #[pallet::storage]
+    pub(super) type VaultStats<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, DefaultVaultStatistics<T>, ValueQuery>;
+
+    /// Vaults that have requested to exit and are removed once they no longer back any tokens.
+    #[pallet::storage]
+    pub(super) type ExitingVaults<T: Config> = StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, (), OptionQuery>;
+
+    /// Number of vaults registered by each account, the Bitcoin keys of the account are removed
+    /// along with its last vault.
+    #[pallet::storage]
+    pub(super) type AccountVaultCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V6
@@ -717,8 +1218,10 @@ impl<T: Config> Pallet<T> {
             Error::<T>::CeilingNotSet
         );
 
-        // make sure a public key is registered
-        let _ = Self::get_bitcoin_public_key(&vault_id.account_id)?;
+        // make sure a public key or multisig key set is registered
+        if !VaultBitcoinMultisig::<T>::contains_key(&vault_id.account_id) {
+            let _ = Self::get_bitcoin_public_key(&vault_id.account_id)?;
+        }
 
         let collateral_currency = vault_id.currencies.collateral;
         let amount = Amount::new(collateral, collateral_currency);
@@ -763,8 +1266,105 @@ impl<T: Config> Pallet<T> {
         vault.get_secure_threshold()
     }
 
+    /// Returns the key from which new deposit addresses are derived, i.e. the proposed key
+    /// once its activation block is reached and the registered key otherwise
     pub fn get_bitcoin_public_key(account_id: &T::AccountId) -> Result<BtcPublicKey, DispatchError> {
-        VaultBitcoinPublicKey::<T>::get(account_id).ok_or(Error::<T>::NoBitcoinPublicKey.into())
+        match Self::get_due_public_key_rotation(account_id) {
+            Some(rotation) => Ok(rotation.public_key),
+            None => VaultBitcoinPublicKey::<T>::get(account_id).ok_or(Error::<T>::NoBitcoinPublicKey.into()),
+        }
+    }
+
+    /// Returns the proposed key of the account if its activation block is reached. The rotation
+    /// is only activated in storage by the next call that needs it, so reads that depend on the
+    /// active key must treat a due rotation as activated.
+    fn get_due_public_key_rotation(account_id: &T::AccountId) -> Option<PublicKeyRotation<BlockNumberFor<T>>> {
+        PendingPublicKeyRotation::<T>::get(account_id)
+            .filter(|rotation| rotation.activation_block <= ext::security::active_block_number::<T>())
+    }
+
+    /// Returns the m-of-n key set of the account, if it registered one instead of a single key
+    pub fn get_bitcoin_multisig(account_id: &T::AccountId) -> Option<MultisigPublicKeys> {
+        VaultBitcoinMultisig::<T>::get(account_id)
+    }
+
+    /// Replaces the account's public key by its proposed key if the rotation delay has passed.
+    /// The replaced key is retired, opening the window in which the vault can migrate its BTC.
+    fn try_activate_public_key(account_id: &T::AccountId) {
+        let rotation = match Self::get_due_public_key_rotation(account_id) {
+            Some(rotation) => rotation,
+            None => return,
+        };
+        PendingPublicKeyRotation::<T>::remove(account_id);
+
+        // keys whose migration period has ended no longer belong to the vault, every rotation
+        // needs the rotation delay, so this bounds the number of retired keys per account
+        let now = ext::security::active_block_number::<T>();
+        let expired_public_keys: Vec<_> = RetiredBitcoinPublicKeys::<T>::iter_prefix(account_id)
+            .filter(|(_, migration_deadline)| *migration_deadline < now)
+            .map(|(public_key, _)| public_key)
+            .collect();
+        for public_key in expired_public_keys {
+            RetiredBitcoinPublicKeys::<T>::remove(account_id, public_key);
+        }
+
+        if let Some(retired_public_key) = VaultBitcoinPublicKey::<T>::get(account_id) {
+            let migration_deadline = rotation
+                .activation_block
+                .saturating_add(T::PublicKeyMigrationPeriod::get());
+            RetiredBitcoinPublicKeys::<T>::insert(account_id, retired_public_key, migration_deadline);
+            PublicKeyMigrationDeadline::<T>::insert(account_id, migration_deadline);
+        }
+        VaultBitcoinPublicKey::<T>::insert(account_id, &rotation.public_key);
+
+        Self::deposit_event(Event::<T>::UpdatePublicKey {
+            account_id: account_id.clone(),
+            public_key: rotation.public_key,
+        });
+    }
+
+    /// Checks if the public key is the master public key of the vault's account, including
+    /// proposed and retired keys since they control funds that the vault is responsible for
+    pub fn is_vault_public_key(vault_id: &DefaultVaultId<T>, public_key: &BtcPublicKey) -> bool {
+        let account_id = &vault_id.account_id;
+        VaultBitcoinPublicKey::<T>::get(account_id).as_ref() == Some(public_key)
+            || PendingPublicKeyRotation::<T>::get(account_id)
+                .map(|rotation| rotation.public_key)
+                .as_ref()
+                == Some(public_key)
+            || RetiredBitcoinPublicKeys::<T>::contains_key(account_id, public_key)
+    }
+
+    /// Checks if the account retired a public key whose migration period was open at the given height
+    pub fn is_migration_period_open(account_id: &T::AccountId, height: BlockNumberFor<T>) -> bool {
+        let migration_deadline = match Self::get_due_public_key_rotation(account_id) {
+            Some(rotation) if VaultBitcoinPublicKey::<T>::contains_key(account_id) => Some(
+                rotation
+                    .activation_block
+                    .saturating_add(T::PublicKeyMigrationPeriod::get()),
+            ),
+            _ => PublicKeyMigrationDeadline::<T>::get(account_id),
+        };
+        migration_deadline.map_or(false, |migration_deadline| height <= migration_deadline)
+    }
+
+    /// Checks if the address is a deposit address that was registered for the vault, or an
+    /// address of the account's master keys, e.g. to which the vault returns change
+    pub fn is_vault_address(vault_id: &DefaultVaultId<T>, address: &BtcAddress) -> bool {
+        ReservedAddresses::<T>::get(address).as_ref() == Some(vault_id)
+            || Self::is_master_address(&vault_id.account_id, address)
+    }
+
+    /// Checks if the address is the P2WSH address of the account's untweaked multisig script,
+    /// or a P2WPKH or P2PKH address of its current public key
+    fn is_master_address(account_id: &T::AccountId, address: &BtcAddress) -> bool {
+        if let Some(multisig) = VaultBitcoinMultisig::<T>::get(account_id) {
+            return multisig.to_address(None).as_ref() == Some(address);
+        }
+        match (Self::get_bitcoin_public_key(account_id), address) {
+            (Ok(public_key), BtcAddress::P2WPKHv0(hash) | BtcAddress::P2PKH(hash)) => public_key.to_hash() == *hash,
+            _ => false,
+        }
     }
 
     pub fn get_vault_from_id(vault_id: &DefaultVaultId<T>) -> Result<DefaultVault<T>, DispatchError> {
@@ -833,7 +1433,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_active_vault_from_id(vault_id: &DefaultVaultId<T>) -> Result<DefaultVault<T>, DispatchError> {
         let vault = Self::get_vault_from_id(vault_id)?;
         match vault.status {
-            VaultStatus::Active(_) => Ok(vault),
+            VaultStatus::Active(_) | VaultStatus::Exiting => Ok(vault),
             VaultStatus::Liquidated => Err(Error::<T>::VaultLiquidated.into()),
         }
     }
@@ -1022,8 +1622,10 @@ impl<T: Config> Pallet<T> {
     /// # Arguments
     /// * `issue_id` - secure id for generating deposit address
     pub fn register_deposit_address(vault_id: &DefaultVaultId<T>, issue_id: H256) -> Result<BtcAddress, DispatchError> {
+        Self::try_activate_public_key(&vault_id.account_id);
         let mut vault = Self::get_active_rich_vault_from_id(&vault_id)?;
         let btc_address = vault.new_deposit_address(issue_id)?;
+        ReservedAddresses::<T>::insert(&btc_address, vault.id());
         Self::deposit_event(Event::<T>::RegisterAddress {
             vault_id: vault.id(),
             address: btc_address,
@@ -1031,6 +1633,16 @@ impl<T: Config> Pallet<T> {
         Ok(btc_address)
     }
 
+    /// Reserves a deposit address that was registered for the vault before deposit
+    /// addresses were reserved
+    ///
+    /// # Arguments
+    /// * `vault_id` - the vault the address was registered for
+    /// * `address` - the deposit address
+    pub fn reserve_address(vault_id: &DefaultVaultId<T>, address: &BtcAddress) {
+        ReservedAddresses::<T>::insert(address, vault_id);
+    }
+
     /// returns the amount of tokens that a vault can request to be replaced on top of the
     /// current to-be-replaced tokens
     pub fn requestable_to_be_replaced_tokens(vault_id: &DefaultVaultId<T>) -> Result<Amount<T>, DispatchError> {
@@ -1353,6 +1965,80 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Get the collateral that is left in the running auction of the currency pair
+    ///
+    /// # Errors
+    /// * `NoRunningAuction` - if there is no running auction for the currency pair
+    pub fn get_auctioned_collateral(currency_pair: &DefaultVaultCurrencyPair<T>) -> Result<Amount<T>, DispatchError> {
+        let auction = Self::get_running_auction(currency_pair).ok_or(Error::<T>::NoRunningAuction)?;
+        Ok(Amount::new(auction.collateral, currency_pair.collateral))
+    }
+
+    /// Get the amount of wrapped tokens that a bid for the given collateral costs in the
+    /// running auction of the currency pair at the current block
+    ///
+    /// # Errors
+    /// * `NoRunningAuction` - if there is no running auction for the currency pair
+    pub fn get_auction_bid_price(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        collateral: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let auction = Self::get_running_auction(currency_pair).ok_or(Error::<T>::NoRunningAuction)?;
+        Self::calculate_auction_bid_price(&auction, collateral, currency_pair.wrapped)
+    }
+
+    fn get_running_auction(currency_pair: &DefaultVaultCurrencyPair<T>) -> Option<DefaultCollateralAuction<T>> {
+        CollateralAuctions::<T>::get(currency_pair)
+            .filter(|auction| ext::security::active_block_number::<T>() <= auction.end_block)
+    }
+
+    /// The liquidation vault's collateral that backs its redeemable tokens. The remainder
+    /// is reserved for the tokens of pending redeem requests.
+    fn get_auctionable_collateral(currency_pair: &DefaultVaultCurrencyPair<T>) -> Result<Amount<T>, DispatchError> {
+        let liquidation_vault = Self::get_rich_liquidation_vault(currency_pair);
+        let redeemable_tokens = liquidation_vault.redeemable_tokens()?;
+        if redeemable_tokens.is_zero() {
+            return Ok(Amount::zero(currency_pair.collateral));
+        }
+        Self::calculate_collateral(
+            &CurrencySource::<T>::LiquidationVault(currency_pair.clone()).current_balance(currency_pair.collateral)?,
+            &redeemable_tokens,
+            &liquidation_vault.to_be_backed_tokens()?,
+        )
+    }
+
+    /// Values the collateral at the oracle exchange rate, multiplied by the auction's price
+    /// which decreases linearly from its start price to its end price.
+    fn calculate_auction_bid_price(
+        auction: &DefaultCollateralAuction<T>,
+        collateral: &Amount<T>,
+        wrapped_currency: CurrencyId<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let elapsed = ext::security::active_block_number::<T>()
+            .saturating_sub(auction.start_block)
+            .saturated_into::<u128>();
+        let duration = auction
+            .end_block
+            .saturating_sub(auction.start_block)
+            .saturated_into::<u128>();
+        let progress = UnsignedFixedPoint::<T>::checked_from_rational(elapsed.min(duration), duration)
+            .ok_or(ArithmeticError::Overflow)?;
+        let price = auction
+            .start_price
+            .checked_sub(
+                &auction
+                    .start_price
+                    .saturating_sub(auction.end_price)
+                    .checked_mul(&progress)
+                    .ok_or(ArithmeticError::Overflow)?,
+            )
+            .ok_or(ArithmeticError::Underflow)?;
+
+        collateral
+            .convert_to(wrapped_currency)?
+            .checked_rounded_mul(&price, Rounding::Up)
+    }
+
     /// Replaces the old vault by the new vault by transferring tokens
     /// from the old vault to the new one
     ///
@@ -1459,15 +2145,121 @@ impl<T: Config> Pallet<T> {
         Ok((withdrawn_tokens, to_withdraw_collateral))
     }
 
+    /// Only checks the vaults in the buckets of the `LiquidationPriceIndex` whose liquidation
+    /// price may have been crossed at the current exchange rate.
     fn undercollateralized_vaults() -> impl Iterator<Item = DefaultVaultId<T>> {
-        <Vaults<T>>::iter().filter_map(|(vault_id, vault)| {
-            if let Some(liquidation_threshold) = Self::liquidation_collateral_threshold(&vault.id.currencies) {
-                if Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold).unwrap_or(false) {
-                    return Some(vault_id);
-                }
+        LiquidationCollateralThreshold::<T>::iter()
+            .flat_map(|(currency_pair, liquidation_threshold)| {
+                Self::indexed_vaults_possibly_below_threshold(currency_pair, liquidation_threshold)
+                    .map(move |vault_id| (vault_id, liquidation_threshold))
+            })
+            .filter_map(|(vault_id, liquidation_threshold)| {
+                let vault = Self::get_vault_from_id(&vault_id).ok()?;
+                Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold)
+                    .unwrap_or(false)
+                    .then_some(vault_id)
+            })
+    }
+
+    /// The vaults of the currency pair in the buckets of the `LiquidationPriceIndex` that may be
+    /// below the given threshold at the current exchange rate.
+    fn indexed_vaults_possibly_below_threshold(
+        currency_pair: DefaultVaultCurrencyPair<T>,
+        threshold: UnsignedFixedPoint<T>,
+    ) -> impl Iterator<Item = DefaultVaultId<T>> {
+        // check all vaults if the exchange rate is unavailable
+        let lowest_bucket = Self::get_lowest_liquidatable_bucket(&currency_pair, threshold).unwrap_or_default();
+        let highest_bucket = MaxLiquidationPriceBucket::<T>::get(&currency_pair);
+        (lowest_bucket..=highest_bucket)
+            .flat_map(move |bucket| LiquidationPriceIndex::<T>::iter_key_prefix((currency_pair.clone(), bucket)))
+    }
+
+    /// Moves the vault to the bucket of its current liquidation price in the
+    /// `LiquidationPriceIndex`, or removes it if it is liquidated or has no issued tokens.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault whose collateral, issued tokens or status changed
+    pub fn update_liquidation_price_index(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        let bucket = match Vaults::<T>::get(vault_id) {
+            Some(vault) if !vault.is_liquidated() && !vault.issued_tokens.is_zero() => {
+                let collateral = Self::get_backing_collateral(vault_id)?;
+                // vaults without collateral go to the highest bucket
+                let liquidation_price =
+                    UnsignedFixedPoint::<T>::checked_from_rational(vault.issued_tokens, collateral.amount())
+                        .unwrap_or_else(UnsignedFixedPoint::<T>::max_value);
+                Some(Self::liquidation_price_bucket(liquidation_price))
             }
-            None
-        })
+            _ => None,
+        };
+
+        let previous_bucket = VaultLiquidationPriceBucket::<T>::get(vault_id);
+        if bucket == previous_bucket {
+            return Ok(());
+        }
+        if let Some(previous_bucket) = previous_bucket {
+            LiquidationPriceIndex::<T>::remove((vault_id.currencies.clone(), previous_bucket), vault_id);
+        }
+        if let Some(bucket) = bucket {
+            LiquidationPriceIndex::<T>::insert((vault_id.currencies.clone(), bucket), vault_id, ());
+            VaultLiquidationPriceBucket::<T>::insert(vault_id, bucket);
+            MaxLiquidationPriceBucket::<T>::mutate(&vault_id.currencies, |highest_bucket| {
+                *highest_bucket = bucket.max(*highest_bucket)
+            });
+        } else {
+            VaultLiquidationPriceBucket::<T>::remove(vault_id);
+        }
+        Ok(())
+    }
+
+    /// Buckets liquidation prices on a logarithmic scale with eight buckets per doubling: the
+    /// position of the highest set bit, refined by the three bits below it. The mapping is
+    /// monotonic, so lower prices never map to higher buckets.
+    fn liquidation_price_bucket(liquidation_price: UnsignedFixedPoint<T>) -> u32 {
+        let inner: u128 = liquidation_price.into_inner().unique_saturated_into();
+        let bits = u128::BITS - inner.leading_zeros();
+        if bits <= 3 {
+            return inner as u32;
+        }
+        bits * 8 + ((inner >> (bits - 4)) & 0b111) as u32
+    }
+
+    /// The lowest bucket of the `LiquidationPriceIndex` that may contain vaults below the
+    /// liquidation threshold, i.e. the bucket of the current value of the collateral divided
+    /// by the threshold.
+    fn get_lowest_liquidatable_bucket(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        liquidation_threshold: UnsignedFixedPoint<T>,
+    ) -> Result<u32, DispatchError> {
+        let unit = UnsignedFixedPoint::<T>::DIV;
+        let value = Amount::<T>::new(unit, currency_pair.collateral).convert_to(currency_pair.wrapped)?;
+        let liquidation_price = UnsignedFixedPoint::<T>::checked_from_rational(value.amount(), unit)
+            .ok_or(ArithmeticError::Overflow)?
+            .checked_div(&liquidation_threshold)
+            .ok_or(ArithmeticError::DivisionByZero)?;
+        // rounding may move vaults at the edge of a bucket into the bucket below
+        Ok(Self::liquidation_price_bucket(liquidation_price).saturating_sub(1))
+    }
+
+    /// Liquidates a vault that is below the liquidation threshold. If partial liquidation is
+    /// enabled for its currency pair, only the tokens needed to restore the secure threshold are
+    /// liquidated, falling back to a full liquidation if the vault can't be restored.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the undercollateralized vault
+    pub fn liquidate_undercollateralized_vault(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        if let Some(penalty) = PartialLiquidationPenalty::<T>::get(&vault_id.currencies) {
+            let mut vault = Self::get_active_rich_vault_from_id(&vault_id)?;
+            if let Some((liquidated_tokens, liquidated_collateral)) = vault.partially_liquidate(penalty)? {
+                Self::deposit_event(Event::<T>::PartiallyLiquidateVault {
+                    vault_id: vault_id.clone(),
+                    liquidated_tokens: liquidated_tokens.amount(),
+                    liquidated_collateral: liquidated_collateral.amount(),
+                });
+                return Ok(());
+            }
+        }
+        Self::liquidate_vault(vault_id)?;
+        Ok(())
     }
 
     /// Liquidates a vault, transferring all of its token balances to the
@@ -1496,6 +2288,26 @@ impl<T: Config> Pallet<T> {
         Ok(to_slash)
     }
 
+    /// Bans and liquidates a vault that moved BTC without authorization. The reporter is
+    /// rewarded with the punishment fee of the liquidated collateral, paid from the
+    /// collateral that was slashed to the liquidation vault.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault that committed theft
+    /// * `reporter_id` - the account that reported the theft
+    pub fn liquidate_theft_vault(vault_id: &DefaultVaultId<T>, reporter_id: T::AccountId) -> DispatchResult {
+        Self::ban_vault(vault_id)?;
+        let liquidated_collateral = Self::liquidate_vault(vault_id)?;
+
+        let reward = ext::fee::get_punishment_fee::<T>(&liquidated_collateral)?;
+        Self::transfer_funds(
+            CurrencySource::LiquidationVault(vault_id.currencies.clone()),
+            CurrencySource::FreeBalance(reporter_id),
+            &reward,
+        )?;
+        Ok(())
+    }
+
     pub fn try_increase_total_backing_collateral(
         currency_pair: &DefaultVaultCurrencyPair<T>,
         amount: &Amount<T>,
@@ -1532,6 +2344,10 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn insert_vault(id: &DefaultVaultId<T>, vault: DefaultVault<T>) {
+        if !Vaults::<T>::contains_key(id) {
+            AccountVaultCount::<T>::mutate(&id.account_id, |count| count.saturating_inc());
+            CurrencyPairVaults::<T>::insert(&id.currencies, &id.account_id, ());
+        }
         Vaults::<T>::insert(id, vault)
     }
 
@@ -1540,6 +2356,7 @@ impl<T: Config> Pallet<T> {
         let mut vault = Self::get_active_rich_vault_from_id(vault_id)?;
         let banned_until = height + Self::punishment_delay();
         vault.ban_until(banned_until);
+        VaultStats::<T>::mutate(vault_id, |stats| stats.punishments.saturating_inc());
         Self::deposit_event(Event::<T>::BanVault {
             vault_id: vault.id(),
             banned_until,
@@ -1552,6 +2369,114 @@ impl<T: Config> Pallet<T> {
         vault.ensure_not_banned()
     }
 
+    pub fn ensure_not_exiting(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        let vault = Self::get_vault_from_id(vault_id)?;
+        ensure!(!vault.is_exiting(), Error::<T>::VaultExiting);
+        Ok(())
+    }
+
+    pub fn _request_vault_exit(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        let mut vault = Self::get_active_rich_vault_from_id(vault_id)?;
+        ensure!(!vault.data.is_exiting(), Error::<T>::VaultExiting);
+        vault.set_exiting()?;
+        PoolManager::<T>::on_vault_settings_change(vault_id)?;
+
+        // nominators are refunded by increasing the nonce of the vault's staking pool
+        T::NominationApi::opt_out_of_nomination(vault_id)?;
+
+        // best effort, the vault may lack the free griefing collateral to request a replace
+        let replaceable_tokens = Self::requestable_to_be_replaced_tokens(vault_id)?;
+        if !replaceable_tokens.is_zero() {
+            if let Err(err) = with_storage_layer(|| T::ReplaceApi::request_replace(vault_id, &replaceable_tokens)) {
+                log::warn!("Failed to request replace for exiting vault {:?}: {:?}", vault_id, err);
+            }
+        }
+
+        ExitingVaults::<T>::insert(vault_id, ());
+        Self::deposit_event(Event::<T>::RequestVaultExit {
+            vault_id: vault_id.clone(),
+        });
+        Ok(())
+    }
+
+    /// Removes the exiting vaults that no longer back any tokens, as far as `remaining_weight` allows.
+    pub(crate) fn remove_exited_vaults(remaining_weight: Weight) -> Weight {
+        let weight_per_vault = <T as Config>::WeightInfo::remove_exited_vault();
+        let mut consumed = Weight::zero();
+
+        let mut vault_ids = Vec::new();
+        for vault_id in ExitingVaults::<T>::iter_keys() {
+            if consumed.saturating_add(weight_per_vault).any_gt(remaining_weight) {
+                break;
+            }
+            consumed.saturating_accrue(weight_per_vault);
+            vault_ids.push(vault_id);
+        }
+
+        for vault_id in vault_ids {
+            match Vaults::<T>::get(&vault_id) {
+                Some(vault) if vault.is_exiting() => {
+                    if let Err(err) = with_storage_layer(|| Self::remove_exited_vault(&vault_id)) {
+                        log::warn!("Failed to remove exited vault {:?}: {:?}", vault_id, err);
+                    }
+                }
+                // the vault was liquidated after requesting to exit
+                _ => ExitingVaults::<T>::remove(&vault_id),
+            }
+        }
+        consumed
+    }
+
+    fn remove_exited_vault(vault_id: &DefaultVaultId<T>) -> DispatchResult {
+        let vault = Self::get_rich_vault_from_id(vault_id)?;
+        if !vault.issued_tokens().is_zero()
+            || !vault.to_be_issued_tokens().is_zero()
+            || !vault.to_be_redeemed_tokens().is_zero()
+        {
+            return Ok(());
+        }
+
+        // release the griefing collateral of the replace request that was not accepted
+        let replace_collateral = Amount::new(vault.data.replace_collateral, T::GetGriefingCollateralCurrencyId::get());
+        if !replace_collateral.is_zero() {
+            Self::transfer_funds(
+                CurrencySource::AvailableReplaceCollateral(vault_id.clone()),
+                CurrencySource::FreeBalance(vault_id.account_id.clone()),
+                &replace_collateral,
+            )?;
+        }
+
+        let collateral = Self::compute_collateral(vault_id)?;
+        Self::force_withdraw_collateral(vault_id, &collateral)?;
+
+        Vaults::<T>::remove(vault_id);
+        CurrencyPairVaults::<T>::remove(&vault_id.currencies, &vault_id.account_id);
+        ExitingVaults::<T>::remove(vault_id);
+        VaultStats::<T>::remove(vault_id);
+        ext::fee::remove_vault_fees::<T>(vault_id);
+        Self::update_liquidation_price_index(vault_id)?;
+
+        // the Bitcoin keys are shared by all vaults of the account
+        let remaining_vaults = AccountVaultCount::<T>::get(&vault_id.account_id).saturating_sub(1);
+        if remaining_vaults == 0 {
+            AccountVaultCount::<T>::remove(&vault_id.account_id);
+            VaultBitcoinPublicKey::<T>::remove(&vault_id.account_id);
+            VaultBitcoinMultisig::<T>::remove(&vault_id.account_id);
+            PendingPublicKeyRotation::<T>::remove(&vault_id.account_id);
+            // bounded by the pruning of expired keys
+            let _ = RetiredBitcoinPublicKeys::<T>::clear_prefix(&vault_id.account_id, u32::MAX, None);
+            PublicKeyMigrationDeadline::<T>::remove(&vault_id.account_id);
+        } else {
+            AccountVaultCount::<T>::insert(&vault_id.account_id, remaining_vaults);
+        }
+
+        Self::deposit_event(Event::<T>::VaultExited {
+            vault_id: vault_id.clone(),
+            released_collateral: collateral.amount(),
+        });
+        Ok(())
+    }
+
     /// Threshold checks
     pub fn is_vault_below_secure_threshold(vault_id: &DefaultVaultId<T>) -> Result<bool, DispatchError> {
         let vault = Self::get_rich_vault_from_id(&vault_id)?;
@@ -1701,7 +2626,7 @@ impl<T: Config> Pallet<T> {
 
                 let vault_to_burn_tokens = burn_wrap.checked_add(&inclusion_fee).ok()?;
 
-                let redeem_fee = ext::fee::get_redeem_fee_value::<T>();
+                let redeem_fee = ext::fee::get_redeem_fee_value::<T>(&vault_id);
                 let amount_wrapped = UnsignedFixedPoint::<T>::one().saturating_sub(redeem_fee);
 
                 let request_redeem_tokens_for_max_premium = vault_to_burn_tokens.checked_div(&amount_wrapped).ok()?;
@@ -1772,6 +2697,157 @@ impl<T: Config> Pallet<T> {
         Ok(vaults_with_redeemable_tokens)
     }
 
+    /// Select the vaults that back an issue request for which the user did not choose a vault.
+    /// Only vaults of the currency pair that accept new issues and are not banned are considered,
+    /// preferring the vaults with the most issuable tokens, i.e. the most collateral above the
+    /// secure threshold. See `scan_vaults` for which vaults are read and `select_vaults` for how
+    /// the amount is assigned to them.
+    ///
+    /// # Arguments
+    /// * `currency_pair` - the collateral and wrapped currency of the vaults
+    /// * `amount` - the wrapped amount to issue, including fees
+    /// * `seed` - randomness used to pick among the vaults that can issue the full amount
+    ///
+    /// # Returns
+    ///
+    /// The selected vaults with their amounts, and the number of vaults that were scanned
+    pub fn select_vaults_for_issue(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+        seed: Option<H256>,
+    ) -> Result<(Vec<(DefaultVaultId<T>, Amount<T>)>, u32), DispatchError> {
+        let (vaults, scanned) = Self::scan_vaults(currency_pair, seed);
+        let mut candidates = vaults
+            .into_iter()
+            .filter(|(vault_id, _)| Self::ensure_not_banned(vault_id).is_ok())
+            .filter_map(|(vault_id, _)| {
+                let issuable_tokens = Self::get_issuable_tokens_from_vault(&vault_id).ok()?;
+                (!issuable_tokens.is_zero()).then_some((vault_id, issuable_tokens))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.1.amount().cmp(&a.1.amount()));
+
+        let selected =
+            Self::select_vaults(candidates, amount, seed).ok_or(Error::<T>::NoVaultWithSufficientCollateral)?;
+        Ok((selected, scanned))
+    }
+
+    /// Select the vaults that fulfil a redeem request for which the user did not choose a vault.
+    /// Only active vaults of the currency pair that are not banned are considered, preferring
+    /// the vaults with the lowest collateralization so that redeeming improves their health.
+    /// See `scan_vaults` for which vaults are read and `select_vaults` for how the amount is
+    /// assigned to them.
+    ///
+    /// # Arguments
+    /// * `currency_pair` - the collateral and wrapped currency of the vaults
+    /// * `amount` - the wrapped amount to redeem, including fees
+    /// * `seed` - randomness used to pick among the vaults that can redeem the full amount
+    ///
+    /// # Returns
+    ///
+    /// The selected vaults with their amounts, and the number of vaults that were scanned
+    pub fn select_vaults_for_redeem(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+        seed: Option<H256>,
+    ) -> Result<(Vec<(DefaultVaultId<T>, Amount<T>)>, u32), DispatchError> {
+        let (vaults, scanned) = Self::scan_vaults(currency_pair, seed);
+        let mut candidates = vaults
+            .into_iter()
+            .filter(|(vault_id, _)| Self::ensure_not_banned(vault_id).is_ok())
+            .filter_map(|(vault_id, vault)| {
+                let redeemable_tokens = Into::<RichVault<T>>::into(vault).redeemable_tokens().ok()?;
+                let collateralization = Self::get_collateralization_from_vault(vault_id.clone(), false).ok()?;
+                (!redeemable_tokens.is_zero()).then_some((vault_id, redeemable_tokens, collateralization))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| a.2.cmp(&b.2));
+
+        let candidates = candidates
+            .into_iter()
+            .map(|(vault_id, redeemable_tokens, _)| (vault_id, redeemable_tokens))
+            .collect();
+        let selected = Self::select_vaults(candidates, amount, seed).ok_or(Error::<T>::NoVaultWithSufficientTokens)?;
+        Ok((selected, scanned))
+    }
+
+    /// Reads up to `MAX_VAULTS_SCANNED_PER_SELECTION` vaults of the currency pair, so that the cost
+    /// of selecting vaults doesn't grow with the number of registered vaults. The scan starts at a
+    /// position in the `CurrencyPairVaults` map derived from the seed and wraps around, such that
+    /// every vault gets the chance to be selected once there are more vaults than that. Without a
+    /// seed it starts at the beginning of the map.
+    ///
+    /// # Returns
+    ///
+    /// The scanned vaults, and their number
+    fn scan_vaults(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        seed: Option<H256>,
+    ) -> (Vec<(DefaultVaultId<T>, DefaultVault<T>)>, u32) {
+        let accounts = match seed {
+            Some(seed) => {
+                let mut start = CurrencyPairVaults::<T>::final_prefix().to_vec();
+                start.extend(currency_pair.using_encoded(Blake2_128Concat::hash));
+                start.extend_from_slice(seed.as_bytes());
+                let from_start = CurrencyPairVaults::<T>::iter_key_prefix_from(currency_pair.clone(), start.clone());
+                // `iter_key_prefix_from` skips a vault whose key equals the start, it is read when wrapping around
+                let wrapped =
+                    CurrencyPairVaults::<T>::iter_key_prefix(currency_pair.clone()).take_while(move |account_id| {
+                        CurrencyPairVaults::<T>::hashed_key_for(currency_pair, account_id) <= start
+                    });
+                from_start
+                    .chain(wrapped)
+                    .take(MAX_VAULTS_SCANNED_PER_SELECTION as usize)
+                    .collect::<Vec<_>>()
+            }
+            None => CurrencyPairVaults::<T>::iter_key_prefix(currency_pair.clone())
+                .take(MAX_VAULTS_SCANNED_PER_SELECTION as usize)
+                .collect::<Vec<_>>(),
+        };
+        let scanned = accounts.len() as u32;
+        let vaults = accounts
+            .into_iter()
+            .filter_map(|account_id| {
+                let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
+                let vault = Vaults::<T>::get(&vault_id)?;
+                Some((vault_id, vault))
+            })
+            .collect();
+        (vaults, scanned)
+    }
+
+    /// Assigns the amount to the candidates, given in order of preference with their capacity.
+    /// If any candidate can take the full amount, it is assigned to a single one of them: the
+    /// first one, or a random one if a seed is given. Otherwise the amount is split across the
+    /// candidates in order of preference, each taking up to its capacity, using at most
+    /// `MAX_VAULTS_PER_REQUEST` vaults. Returns `None` if the candidates can't take the amount.
+    fn select_vaults(
+        candidates: Vec<(DefaultVaultId<T>, Amount<T>)>,
+        amount: &Amount<T>,
+        seed: Option<H256>,
+    ) -> Option<Vec<(DefaultVaultId<T>, Amount<T>)>> {
+        let sufficient = candidates
+            .iter()
+            .filter(|(_, capacity)| capacity.ge(amount).unwrap_or(false))
+            .collect::<Vec<_>>();
+        if !sufficient.is_empty() {
+            let index = seed.map_or(0, |seed| (seed.to_low_u64_be() % sufficient.len() as u64) as usize);
+            return Some(vec![(sufficient[index].0.clone(), amount.clone())]);
+        }
+
+        let mut remaining = amount.clone();
+        let mut selected = Vec::new();
+        for (vault_id, capacity) in candidates.into_iter().take(MAX_VAULTS_PER_REQUEST as usize) {
+            if remaining.is_zero() {
+                break;
+            }
+            let part = capacity.min(&remaining).ok()?;
+            remaining = remaining.checked_sub(&part).ok()?;
+            selected.push((vault_id, part));
+        }
+        remaining.is_zero().then_some(selected)
+    }
+
     /// Get the amount of tokens a vault can issue
     pub fn get_issuable_tokens_from_vault(vault_id: &DefaultVaultId<T>) -> Result<Amount<T>, DispatchError> {
         let vault = Self::get_active_rich_vault_from_id(vault_id)?;
@@ -1784,6 +2860,21 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Get the amount of tokens a vault could back at its secure threshold after depositing
+    /// `collateral`, in addition to the tokens it already backs
+    pub fn get_issuable_tokens_with_collateral(
+        vault_id: &DefaultVaultId<T>,
+        collateral: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let vault = Self::get_active_rich_vault_from_id(vault_id)?;
+        let free_collateral = vault.get_free_collateral()?.checked_add(collateral)?;
+        Self::calculate_max_wrapped_from_collateral_for_threshold(
+            &free_collateral,
+            vault_id.wrapped_currency(),
+            vault.get_secure_threshold()?,
+        )
+    }
+
     pub fn ensure_accepting_new_issues(vault_id: &DefaultVaultId<T>) -> Result<(), DispatchError> {
         let vault = Self::get_active_rich_vault_from_id(vault_id)?;
         ensure!(
@@ -1829,6 +2920,139 @@ impl<T: Config> Pallet<T> {
         Self::get_collateralization(&collateral_in_wrapped, &issued_tokens)
     }
 
+    /// Get the collateral, tokens and collateralization of an active vault, together with the
+    /// exchange rates at which it crosses the premium redeem and liquidation thresholds.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault
+    pub fn get_vault_health(vault_id: &DefaultVaultId<T>) -> Result<DefaultVaultHealth<T>, DispatchError> {
+        let vault = Self::get_active_rich_vault_from_id(vault_id)?;
+        let collateral = vault.get_vault_collateral()?;
+        let total_collateral = vault.get_total_collateral()?;
+        let issued_tokens = vault.issued_tokens();
+
+        let (collateralization, premium_redeem_exchange_rate, liquidation_exchange_rate) = if issued_tokens.is_zero() {
+            (None, None, None)
+        } else {
+            let collateral_in_wrapped = total_collateral.convert_to(vault_id.wrapped_currency())?;
+            let exchange_rate_at = |threshold: Option<UnsignedFixedPoint<T>>| match threshold {
+                Some(threshold) => {
+                    Self::get_exchange_rate_at_threshold(&total_collateral, &issued_tokens, threshold).map(Some)
+                }
+                None => Ok(None),
+            };
+            (
+                Some(Self::get_collateralization(&collateral_in_wrapped, &issued_tokens)?),
+                exchange_rate_at(Self::premium_redeem_threshold(&vault_id.currencies))?,
+                exchange_rate_at(Self::liquidation_collateral_threshold(&vault_id.currencies))?,
+            )
+        };
+
+        Ok(VaultHealth {
+            vault_id: vault_id.clone(),
+            collateral: collateral.amount(),
+            nominated_collateral: total_collateral.saturating_sub(&collateral)?.amount(),
+            issued_tokens: issued_tokens.amount(),
+            to_be_issued_tokens: vault.to_be_issued_tokens().amount(),
+            to_be_redeemed_tokens: vault.to_be_redeemed_tokens().amount(),
+            collateralization,
+            premium_redeem_exchange_rate,
+            liquidation_exchange_rate,
+        })
+    }
+
+    /// Get the health of all active vaults
+    pub fn get_vaults_health() -> Vec<DefaultVaultHealth<T>> {
+        Vaults::<T>::iter_keys()
+            .filter_map(|vault_id| Self::get_vault_health(&vault_id).ok())
+            .collect()
+    }
+
+    /// Get the health of all active vaults registered by the account
+    ///
+    /// # Arguments
+    /// * `account_id` - the account that registered the vaults
+    pub fn get_vaults_health_by_account_id(
+        account_id: T::AccountId,
+    ) -> Result<Vec<DefaultVaultHealth<T>>, DispatchError> {
+        Ok(Self::get_vaults_by_account_id(account_id)?
+            .iter()
+            .filter_map(|vault_id| Self::get_vault_health(vault_id).ok())
+            .collect())
+    }
+
+    /// Get the health of all active vaults whose collateralization is below the threshold, using
+    /// the `LiquidationPriceIndex` to skip the vaults that are safely above it.
+    ///
+    /// # Arguments
+    /// * `threshold` - the collateralization to compare against, e.g. 1.5 for 150%
+    pub fn get_vaults_below_threshold(threshold: UnsignedFixedPoint<T>) -> Vec<DefaultVaultHealth<T>> {
+        MaxLiquidationPriceBucket::<T>::iter_keys()
+            .flat_map(|currency_pair| Self::indexed_vaults_possibly_below_threshold(currency_pair, threshold))
+            .filter_map(|vault_id| Self::get_vault_health(&vault_id).ok())
+            .filter(|health| {
+                health
+                    .collateralization
+                    .map_or(false, |collateralization| collateralization < threshold)
+            })
+            .collect()
+    }
+
+    /// Get the service record of a vault.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault
+    pub fn get_vault_statistics(vault_id: &DefaultVaultId<T>) -> Result<DefaultVaultStatistics<T>, DispatchError> {
+        ensure!(Vaults::<T>::contains_key(vault_id), Error::<T>::VaultNotFound);
+        Ok(VaultStats::<T>::get(vault_id))
+    }
+
+    pub fn record_issue_execution(vault_id: &DefaultVaultId<T>) {
+        VaultStats::<T>::mutate(vault_id, |stats| stats.executed_issues.saturating_inc());
+    }
+
+    pub fn record_issue_cancellation(vault_id: &DefaultVaultId<T>) {
+        VaultStats::<T>::mutate(vault_id, |stats| stats.cancelled_issues.saturating_inc());
+    }
+
+    /// Records an executed redeem, along with the number of active blocks since it was requested.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the vault that executed the redeem
+    /// * `opentime` - active block number at which the redeem was requested
+    pub fn record_redeem_execution(vault_id: &DefaultVaultId<T>, opentime: BlockNumberFor<T>) {
+        let execution_time = ext::security::active_block_number::<T>().saturating_sub(opentime);
+        VaultStats::<T>::mutate(vault_id, |stats| {
+            stats.executed_redeems.saturating_inc();
+            stats.total_redeem_execution_time = stats.total_redeem_execution_time.saturating_add(execution_time);
+        });
+    }
+
+    pub fn record_redeem_cancellation(vault_id: &DefaultVaultId<T>) {
+        VaultStats::<T>::mutate(vault_id, |stats| stats.cancelled_redeems.saturating_inc());
+    }
+
+    pub fn record_replace_execution(old_vault_id: &DefaultVaultId<T>) {
+        VaultStats::<T>::mutate(old_vault_id, |stats| stats.executed_replaces.saturating_inc());
+    }
+
+    pub fn record_replace_cancellation(old_vault_id: &DefaultVaultId<T>) {
+        VaultStats::<T>::mutate(old_vault_id, |stats| stats.cancelled_replaces.saturating_inc());
+    }
+
+    /// The exchange rate, in collateral per wrapped token like the oracle's, at which the
+    /// collateral backs the issued tokens at exactly the given threshold.
+    fn get_exchange_rate_at_threshold(
+        collateral: &Amount<T>,
+        issued_tokens: &Amount<T>,
+        threshold: UnsignedFixedPoint<T>,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        UnsignedFixedPoint::<T>::checked_from_rational(collateral.amount(), issued_tokens.amount())
+            .ok_or(ArithmeticError::Overflow)?
+            .checked_div(&threshold)
+            .ok_or(ArithmeticError::DivisionByZero.into())
+    }
+
     /// Gets the minimum amount of collateral required for the given amount of btc
     /// with the current threshold and exchange rate
     ///
@@ -2014,7 +3238,8 @@ impl<T: Config> Pallet<T> {
     #[cfg(feature = "integration-tests")]
     pub fn collateral_integrity_check() {
         let griefing_currency = T::GetGriefingCollateralCurrencyId::get();
-        for (vault_id, vault) in Vaults::<T>::iter().filter(|(_, vault)| matches!(vault.status, VaultStatus::Active(_)))
+        for (vault_id, vault) in Vaults::<T>::iter()
+            .filter(|(_, vault)| matches!(vault.status, VaultStatus::Active(_) | VaultStatus::Exiting))
         {
             // check that there is enough griefing collateral
             let active_griefing = CurrencySource::<T>::ActiveReplaceCollateral(vault_id.clone())
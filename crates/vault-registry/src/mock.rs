@@ -245,6 +245,8 @@ impl fee::Config for Test {
 
 parameter_types! {
     pub const VaultPalletId: PalletId = PalletId(*b"mod/vreg");
+    pub const PublicKeyRotationDelay: BlockNumber = 10;
+    pub const PublicKeyMigrationPeriod: BlockNumber = 20;
 }
 
 pub struct MockDeposit;
@@ -267,8 +269,18 @@ impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test
     fn ensure_opted_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
         Ok(())
     }
+    fn ensure_vault_exists(vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
+        VaultRegistry::get_vault_from_id(vault_id).map(|_| ())
+    }
+    fn opt_out_of_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
+        Ok(())
+    }
     #[cfg(any(feature = "runtime-benchmarks", test))]
     fn opt_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) {}
+    #[cfg(any(feature = "runtime-benchmarks", test))]
+    fn register_vault(vault_id: &VaultId<AccountId, CurrencyId>) {
+        VaultRegistry::insert_vault(vault_id, vault_registry::Vault::new(vault_id.clone()));
+    }
 }
 
 impl Config for Test {
@@ -276,6 +288,9 @@ impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = ();
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -72,6 +72,9 @@ impl<T: Config> PoolManager<T> {
 
         ext::reward::set_stake(vault_id, &new_reward_stake)?;
 
+        // the vault's collateral may have changed
+        Pallet::<T>::update_liquidation_price_index(vault_id)?;
+
         // also propagate to capacity pool
         Self::update_capacity_stake(vault_id.collateral_currency())
     }
@@ -1,16 +1,17 @@
 use crate::{
     mock::*,
-    types::{BalanceOf, UpdatableVault},
-    BtcPublicKey, CurrencySource, DefaultVaultId, DispatchError, Vault,
+    types::{AuctionParameters, BalanceOf, BtcAddress, UpdatableVault},
+    BtcMultisigPublicKeys, BtcPublicKey, CurrencySource, DefaultVaultId, DispatchError, Vault, VaultStatistics,
+    VaultStatus, MAX_VAULTS_SCANNED_PER_SELECTION,
 };
 use codec::Decode;
 use currency::Amount;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, weights::Weight};
 use mocktopus::mocking::*;
 use pretty_assertions::assert_eq;
 use security::Pallet as Security;
 use sp_arithmetic::{traits::One, FixedPointNumber, FixedU128};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::{
     offchain::{testing::TestTransactionPoolExt, TransactionPoolExt},
     ArithmeticError,
@@ -130,6 +131,220 @@ fn registering_public_key_twice_fails() {
     })
 }
 
+#[test]
+fn propose_public_key_fails_without_registered_key() {
+    run_test(|| {
+        let origin = RuntimeOrigin::signed(DEFAULT_ID.account_id);
+        assert_err!(
+            VaultRegistry::propose_public_key(origin, BtcPublicKey([1u8; 33])),
+            TestError::NoBitcoinPublicKey
+        );
+    })
+}
+
+#[test]
+fn propose_public_key_fails_with_current_key() {
+    run_test(|| {
+        let id = create_sample_vault();
+        let origin = RuntimeOrigin::signed(id.account_id);
+        assert_err!(
+            VaultRegistry::propose_public_key(origin, BtcPublicKey::dummy()),
+            TestError::PublicKeyAlreadyRegistered
+        );
+    })
+}
+
+#[test]
+fn propose_public_key_rotates_after_delay() {
+    run_test(|| {
+        let id = create_sample_vault();
+        let old_public_key = BtcPublicKey::dummy();
+        let new_public_key = BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+        assert_ok!(VaultRegistry::propose_public_key(
+            RuntimeOrigin::signed(id.account_id),
+            new_public_key.clone()
+        ));
+        assert_emitted!(Event::ProposePublicKey {
+            account_id: id.account_id,
+            public_key: new_public_key.clone(),
+            activation_block: 1 + PublicKeyRotationDelay::get(),
+        });
+
+        // deposit addresses are derived from the old key until the delay has passed
+        let old_key_address = VaultRegistry::register_deposit_address(&id, H256::zero()).unwrap();
+        assert_eq!(
+            VaultRegistry::get_bitcoin_public_key(&id.account_id),
+            Ok(old_public_key.clone())
+        );
+        assert!(VaultRegistry::is_vault_public_key(&id, &new_public_key));
+
+        Security::set_active_block_number(1 + PublicKeyRotationDelay::get());
+        assert_eq!(
+            VaultRegistry::get_bitcoin_public_key(&id.account_id),
+            Ok(new_public_key.clone())
+        );
+        let new_key_address = VaultRegistry::register_deposit_address(&id, H256::zero()).unwrap();
+        assert_ne!(old_key_address, new_key_address);
+        assert_emitted!(Event::UpdatePublicKey {
+            account_id: id.account_id,
+            public_key: new_public_key.clone(),
+        });
+
+        // addresses of the old key stay reserved, and its funds can be migrated for a limited time
+        assert!(VaultRegistry::is_vault_address(&id, &old_key_address));
+        assert!(VaultRegistry::is_vault_public_key(&id, &old_public_key));
+        let migration_deadline = 1 + PublicKeyRotationDelay::get() + PublicKeyMigrationPeriod::get();
+        assert!(VaultRegistry::is_migration_period_open(
+            &id.account_id,
+            migration_deadline
+        ));
+        assert!(!VaultRegistry::is_migration_period_open(
+            &id.account_id,
+            migration_deadline + 1
+        ));
+        assert!(!VaultRegistry::is_migration_period_open(&OTHER_ID.account_id, 1));
+    })
+}
+
+fn multisig_public_keys() -> BtcMultisigPublicKeys {
+    vec![
+        BtcPublicKey::dummy(),
+        BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap(),
+        BtcPublicKey::dummy()
+            .new_deposit_public_key(H256::repeat_byte(1))
+            .unwrap(),
+    ]
+    .try_into()
+    .unwrap()
+}
+
+#[test]
+fn register_multisig_public_keys_fails_with_invalid_threshold() {
+    run_test(|| {
+        let origin = RuntimeOrigin::signed(DEFAULT_ID.account_id);
+        assert_err!(
+            VaultRegistry::register_multisig_public_keys(origin.clone(), multisig_public_keys(), 0),
+            TestError::InvalidMultisig
+        );
+        assert_err!(
+            VaultRegistry::register_multisig_public_keys(origin, multisig_public_keys(), 4),
+            TestError::InvalidMultisig
+        );
+    })
+}
+
+#[test]
+fn register_multisig_public_keys_fails_when_key_registered() {
+    run_test(|| {
+        let origin = RuntimeOrigin::signed(DEFAULT_ID.account_id);
+        assert_ok!(VaultRegistry::register_public_key(
+            origin.clone(),
+            BtcPublicKey::dummy()
+        ));
+        assert_err!(
+            VaultRegistry::register_multisig_public_keys(origin.clone(), multisig_public_keys(), 2),
+            TestError::PublicKeyAlreadyRegistered
+        );
+
+        let origin = RuntimeOrigin::signed(OTHER_ID.account_id);
+        assert_ok!(VaultRegistry::register_multisig_public_keys(
+            origin.clone(),
+            multisig_public_keys(),
+            2
+        ));
+        assert_err!(
+            VaultRegistry::register_public_key(origin, BtcPublicKey::dummy()),
+            TestError::PublicKeyAlreadyRegistered
+        );
+    })
+}
+
+#[test]
+fn register_multisig_vault_derives_p2wsh_deposit_addresses() {
+    run_test(|| {
+        let id = DEFAULT_ID;
+        let origin = RuntimeOrigin::signed(id.account_id);
+        VaultRegistry::get_minimum_collateral_vault
+            .mock_safe(move |currency_id| MockResult::Return(Amount::new(DEFAULT_COLLATERAL, currency_id)));
+        assert_ok!(VaultRegistry::register_multisig_public_keys(
+            origin.clone(),
+            multisig_public_keys(),
+            2
+        ));
+        assert_emitted!(Event::RegisterMultisigPublicKeys {
+            account_id: id.account_id,
+            public_keys: multisig_public_keys(),
+            threshold: 2,
+        });
+        assert_ok!(VaultRegistry::register_vault(
+            origin,
+            id.currencies.clone(),
+            DEFAULT_COLLATERAL
+        ));
+
+        let secure_id = H256::random();
+        let deposit_public_keys: Vec<_> = multisig_public_keys()
+            .iter()
+            .map(|public_key| public_key.new_deposit_public_key(secure_id).unwrap())
+            .collect();
+        let witness_script = bitcoin::Script::multisig(2, &deposit_public_keys).unwrap();
+        let deposit_address = VaultRegistry::register_deposit_address(&id, secure_id).unwrap();
+        assert_eq!(
+            deposit_address,
+            BtcAddress::from_witness_script(witness_script.as_bytes())
+        );
+        assert!(VaultRegistry::is_vault_address(&id, &deposit_address));
+
+        // the untweaked script is the address to which the vault returns change
+        let master_script = bitcoin::Script::multisig(2, &multisig_public_keys()).unwrap();
+        assert!(VaultRegistry::is_vault_address(
+            &id,
+            &BtcAddress::from_witness_script(master_script.as_bytes())
+        ));
+        assert!(!VaultRegistry::is_vault_address(
+            &id,
+            &BtcAddress::P2WPKHv0(BtcPublicKey::dummy().to_hash())
+        ));
+    })
+}
+
+#[test]
+fn activating_public_key_prunes_expired_retired_keys() {
+    run_test(|| {
+        let id = create_sample_vault();
+        let old_public_key = BtcPublicKey::dummy();
+        let second_public_key = BtcPublicKey::dummy().new_deposit_public_key(H256::zero()).unwrap();
+        let third_public_key = BtcPublicKey::dummy()
+            .new_deposit_public_key(H256::repeat_byte(1))
+            .unwrap();
+
+        assert_ok!(VaultRegistry::propose_public_key(
+            RuntimeOrigin::signed(id.account_id),
+            second_public_key.clone()
+        ));
+        Security::set_active_block_number(1 + PublicKeyRotationDelay::get());
+        assert_ok!(VaultRegistry::propose_public_key(
+            RuntimeOrigin::signed(id.account_id),
+            third_public_key.clone()
+        ));
+        assert!(VaultRegistry::is_vault_public_key(&id, &old_public_key));
+
+        // the second rotation activates after the migration period of the first key has ended
+        let first_migration_deadline = 1 + PublicKeyRotationDelay::get() + PublicKeyMigrationPeriod::get();
+        Security::set_active_block_number(first_migration_deadline + 1);
+        VaultRegistry::register_deposit_address(&id, H256::zero()).unwrap();
+
+        assert!(!VaultRegistry::is_vault_public_key(&id, &old_public_key));
+        assert!(VaultRegistry::is_vault_public_key(&id, &second_public_key));
+        assert!(VaultRegistry::is_vault_public_key(&id, &third_public_key));
+        // a late report of a transaction within the first migration period is still recognized
+        assert!(VaultRegistry::is_migration_period_open(
+            &id.account_id,
+            first_migration_deadline
+        ));
+    })
+}
+
 #[test]
 fn register_vault_fails_when_given_collateral_too_low() {
     run_test(|| {
@@ -654,6 +869,355 @@ fn cancel_replace_tokens_succeeds() {
     });
 }
 
+#[test]
+fn register_deposit_address_reserves_address() {
+    run_test(|| {
+        let id = create_sample_vault();
+        let address = VaultRegistry::register_deposit_address(&id, H256::random()).unwrap();
+
+        assert!(VaultRegistry::is_vault_address(&id, &address));
+        assert!(!VaultRegistry::is_vault_address(&OTHER_ID, &address));
+        assert!(VaultRegistry::is_vault_public_key(&id, &BtcPublicKey::dummy()));
+        assert!(!VaultRegistry::is_vault_public_key(&OTHER_ID, &BtcPublicKey::dummy()));
+    });
+}
+
+#[test]
+fn liquidate_theft_vault_succeeds() {
+    run_test(|| {
+        let id = create_sample_vault_and_issue_tokens(50);
+        let reporter = 123;
+        crate::ext::fee::get_punishment_fee::<Test>.mock_safe(|_| MockResult::Return(Ok(amount(10))));
+
+        assert_ok!(VaultRegistry::liquidate_theft_vault(&id, reporter));
+
+        assert!(VaultRegistry::is_vault_liquidated(&id).unwrap());
+        assert!(VaultRegistry::get_vault_from_id(&id).unwrap().banned_until.is_some());
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &reporter),
+            amount(10)
+        );
+    });
+}
+
+#[test]
+fn request_vault_exit_blocks_new_issues() {
+    run_test(|| {
+        let id = create_sample_vault_and_issue_tokens(50);
+
+        assert_ok!(VaultRegistry::request_vault_exit(
+            RuntimeOrigin::signed(id.account_id),
+            id.currencies.clone()
+        ));
+        assert_emitted!(Event::RequestVaultExit { vault_id: id.clone() });
+        assert_eq!(
+            VaultRegistry::get_vault_from_id(&id).unwrap().status,
+            VaultStatus::Exiting
+        );
+        assert_err!(VaultRegistry::ensure_not_exiting(&id), TestError::VaultExiting);
+        assert_noop!(
+            VaultRegistry::accept_new_issues(RuntimeOrigin::signed(id.account_id), id.currencies.clone(), true),
+            TestError::VaultExiting
+        );
+        assert_noop!(
+            VaultRegistry::request_vault_exit(RuntimeOrigin::signed(id.account_id), id.currencies.clone()),
+            TestError::VaultExiting
+        );
+        assert!(VaultRegistry::get_issuable_tokens_from_vault(&id).unwrap().is_zero());
+
+        // the vault still backs issued tokens
+        VaultRegistry::remove_exited_vaults(Weight::MAX);
+        assert!(VaultRegistry::vault_exists(&id));
+    });
+}
+
+#[test]
+fn exiting_vault_is_removed_once_it_backs_no_tokens() {
+    run_test(|| {
+        let id = create_sample_vault();
+        crate::RetiredBitcoinPublicKeys::<Test>::insert(&id.account_id, BtcPublicKey::from([2; 33]), 0);
+        assert_ok!(VaultRegistry::request_vault_exit(
+            RuntimeOrigin::signed(id.account_id),
+            id.currencies.clone()
+        ));
+
+        VaultRegistry::remove_exited_vaults(Weight::MAX);
+
+        assert!(!VaultRegistry::vault_exists(&id));
+        assert_err!(
+            VaultRegistry::get_bitcoin_public_key(&id.account_id),
+            TestError::NoBitcoinPublicKey
+        );
+        assert_eq!(
+            crate::RetiredBitcoinPublicKeys::<Test>::iter_prefix(&id.account_id).count(),
+            0
+        );
+        assert!(!crate::AccountVaultCount::<Test>::contains_key(&id.account_id));
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &id.account_id),
+            amount(DEFAULT_COLLATERAL)
+        );
+        assert_emitted!(Event::VaultExited {
+            vault_id: id,
+            released_collateral: DEFAULT_COLLATERAL
+        });
+    });
+}
+
+#[test]
+fn exited_vault_keeps_the_bitcoin_keys_of_other_vaults_of_the_account() {
+    run_test(|| {
+        let id = create_sample_vault();
+        let other_id = DefaultVaultId::<Test> {
+            currencies: WORST_CASE_CURRENCY_PAIR,
+            ..id.clone()
+        };
+        VaultRegistry::insert_vault(&other_id, Vault::new(other_id.clone()));
+        assert_eq!(crate::AccountVaultCount::<Test>::get(&id.account_id), 2);
+
+        assert_ok!(VaultRegistry::request_vault_exit(
+            RuntimeOrigin::signed(id.account_id),
+            id.currencies.clone()
+        ));
+        VaultRegistry::remove_exited_vaults(Weight::MAX);
+
+        assert!(!VaultRegistry::vault_exists(&id));
+        assert_ok!(VaultRegistry::get_bitcoin_public_key(&id.account_id));
+        assert_eq!(crate::AccountVaultCount::<Test>::get(&id.account_id), 1);
+    });
+}
+
+fn setup_partial_liquidation(exchange_rate: u128) -> DefaultVaultId<Test> {
+    // 200% collateralized at an exchange rate of 10
+    let id = create_sample_vault_and_issue_tokens(5000);
+    VaultRegistry::_set_liquidation_collateral_threshold(
+        DEFAULT_CURRENCY_PAIR,
+        FixedU128::checked_from_rational(150, 100).unwrap(),
+    );
+    assert_ok!(VaultRegistry::set_partial_liquidation_penalty(
+        RuntimeOrigin::root(),
+        DEFAULT_CURRENCY_PAIR,
+        Some(FixedU128::checked_from_rational(10, 100).unwrap()),
+    ));
+    <oracle::Pallet<Test>>::_set_exchange_rate(
+        DEFAULT_COLLATERAL_CURRENCY,
+        UnsignedFixedPoint::from_rational(exchange_rate, 1),
+    )
+    .unwrap();
+    id
+}
+
+#[test]
+fn report_undercollateralized_vault_partially_liquidates() {
+    run_test(|| {
+        // 125% collateralized
+        let id = setup_partial_liquidation(16);
+
+        assert_ok!(VaultRegistry::report_undercollateralized_vault(
+            RuntimeOrigin::none(),
+            id.clone()
+        ));
+
+        // tokens worth (2 * 80000 - 100000) / (2 - 1.1) are moved with 110% of their value
+        assert_emitted!(Event::PartiallyLiquidateVault {
+            vault_id: id.clone(),
+            liquidated_tokens: 4167,
+            liquidated_collateral: 73340,
+        });
+        let vault = VaultRegistry::get_vault_from_id(&id).unwrap();
+        assert_eq!(vault.status, VaultStatus::Active(true));
+        assert_eq!(vault.issued_tokens, 833);
+        assert_eq!(
+            VaultRegistry::get_backing_collateral(&id).unwrap(),
+            amount(DEFAULT_COLLATERAL - 73340)
+        );
+        assert!(!VaultRegistry::is_vault_below_secure_threshold(&id).unwrap());
+
+        let liquidation_vault = VaultRegistry::get_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+        assert_eq!(liquidation_vault.issued_tokens, 4167);
+        assert_eq!(liquidation_vault.collateral, 73340);
+    });
+}
+
+#[test]
+fn report_undercollateralized_vault_fully_liquidates_when_not_restorable() {
+    run_test(|| {
+        // 105% collateralized, which is less than the value of the tokens plus the penalty
+        let id = setup_partial_liquidation(19);
+
+        assert_ok!(VaultRegistry::report_undercollateralized_vault(
+            RuntimeOrigin::none(),
+            id.clone()
+        ));
+
+        assert!(VaultRegistry::is_vault_liquidated(&id).unwrap());
+        let liquidation_vault = VaultRegistry::get_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+        assert_eq!(liquidation_vault.issued_tokens, 5000);
+    });
+}
+
+fn setup_auction() {
+    // moves 5000 tokens and 110% of their value (55000) to the liquidation vault
+    let id = create_sample_vault_and_issue_tokens(5000);
+    assert_ok!(VaultRegistry::liquidate_vault(&id));
+    assert_ok!(VaultRegistry::set_auction_parameters(
+        RuntimeOrigin::root(),
+        DEFAULT_CURRENCY_PAIR,
+        Some(AuctionParameters {
+            lot_size: 50000,
+            duration: 100,
+            start_price: FixedU128::checked_from_rational(120, 100).unwrap(),
+            end_price: FixedU128::checked_from_rational(80, 100).unwrap(),
+        }),
+    ));
+    Security::<Test>::set_active_block_number(100);
+}
+
+#[test]
+fn set_auction_parameters_fails_with_increasing_price() {
+    run_test(|| {
+        assert_noop!(
+            VaultRegistry::set_auction_parameters(
+                RuntimeOrigin::root(),
+                DEFAULT_CURRENCY_PAIR,
+                Some(AuctionParameters {
+                    lot_size: 50000,
+                    duration: 100,
+                    start_price: FixedU128::one(),
+                    end_price: FixedU128::checked_from_rational(110, 100).unwrap(),
+                }),
+            ),
+            TestError::InvalidAuctionParameters
+        );
+        assert_noop!(
+            VaultRegistry::start_auction(RuntimeOrigin::signed(5), DEFAULT_CURRENCY_PAIR),
+            TestError::AuctionParametersNotSet
+        );
+    });
+}
+
+#[test]
+fn bid_on_auction_burns_liquidation_vault_tokens() {
+    run_test(|| {
+        let bidder = 5;
+        setup_auction();
+        wrapped(2000).mint_to(&bidder).unwrap();
+
+        assert_ok!(VaultRegistry::start_auction(
+            RuntimeOrigin::signed(bidder),
+            DEFAULT_CURRENCY_PAIR
+        ));
+        assert_emitted!(Event::StartAuction {
+            currency_pair: DEFAULT_CURRENCY_PAIR,
+            collateral: 50000,
+            start_block: 100,
+            end_block: 200,
+        });
+        // 10000 collateral is worth 1000 tokens at the exchange rate of 10
+        assert_eq!(
+            VaultRegistry::get_auction_bid_price(&DEFAULT_CURRENCY_PAIR, &amount(10000)),
+            Ok(wrapped(1200))
+        );
+
+        // the price decreases linearly from 120% to 80% of the collateral's value
+        Security::<Test>::set_active_block_number(150);
+        assert_noop!(
+            VaultRegistry::bid_on_auction(RuntimeOrigin::signed(bidder), DEFAULT_CURRENCY_PAIR, 10000, 999),
+            TestError::AuctionPriceAboveLimit
+        );
+        assert_ok!(VaultRegistry::bid_on_auction(
+            RuntimeOrigin::signed(bidder),
+            DEFAULT_CURRENCY_PAIR,
+            10000,
+            1000
+        ));
+        assert_emitted!(Event::BidOnAuction {
+            currency_pair: DEFAULT_CURRENCY_PAIR,
+            bidder_id: bidder,
+            collateral: 10000,
+            burned_tokens: 1000,
+        });
+
+        let liquidation_vault = VaultRegistry::get_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+        assert_eq!(liquidation_vault.issued_tokens, 4000);
+        assert_eq!(liquidation_vault.collateral, 45000);
+        assert_eq!(
+            VaultRegistry::get_auctioned_collateral(&DEFAULT_CURRENCY_PAIR),
+            Ok(amount(40000))
+        );
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &bidder),
+            amount(10000)
+        );
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &bidder),
+            wrapped(1000)
+        );
+    });
+}
+
+#[test]
+fn start_auction_replaces_ended_auction() {
+    run_test(|| {
+        let bidder = 5;
+        setup_auction();
+        wrapped(2000).mint_to(&bidder).unwrap();
+
+        assert_ok!(VaultRegistry::start_auction(
+            RuntimeOrigin::signed(bidder),
+            DEFAULT_CURRENCY_PAIR
+        ));
+        assert_noop!(
+            VaultRegistry::start_auction(RuntimeOrigin::signed(bidder), DEFAULT_CURRENCY_PAIR),
+            TestError::AuctionAlreadyRunning
+        );
+        assert_noop!(
+            VaultRegistry::bid_on_auction(RuntimeOrigin::signed(bidder), DEFAULT_CURRENCY_PAIR, 50001, 10000),
+            TestError::AuctionLotExceeded
+        );
+
+        Security::<Test>::set_active_block_number(201);
+        assert_noop!(
+            VaultRegistry::bid_on_auction(RuntimeOrigin::signed(bidder), DEFAULT_CURRENCY_PAIR, 10000, 10000),
+            TestError::NoRunningAuction
+        );
+        assert_ok!(VaultRegistry::start_auction(
+            RuntimeOrigin::signed(bidder),
+            DEFAULT_CURRENCY_PAIR
+        ));
+        assert_emitted!(Event::StartAuction {
+            currency_pair: DEFAULT_CURRENCY_PAIR,
+            collateral: 50000,
+            start_block: 201,
+            end_block: 301,
+        });
+    });
+}
+
+#[test]
+fn liquidate_theft_vault_pays_reward_from_slashed_collateral() {
+    run_test(|| {
+        let id = create_sample_vault_and_issue_tokens(50);
+        let reporter = 123;
+        crate::ext::fee::get_punishment_fee::<Test>
+            .mock_safe(|amount| MockResult::Return(Ok(Amount::new(amount.amount() / 10, amount.currency()))));
+        let collateral_before = VaultRegistry::get_backing_collateral(&id).unwrap();
+
+        assert_ok!(VaultRegistry::liquidate_theft_vault(&id, reporter));
+
+        let liquidation_vault = VaultRegistry::get_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+        let remaining_collateral = VaultRegistry::get_backing_collateral(&id).unwrap();
+        let slashed = collateral_before.checked_sub(&remaining_collateral).unwrap();
+        let reward = slashed.amount() / 10;
+        assert!(reward > 0);
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &reporter),
+            amount(reward)
+        );
+        assert_eq!(liquidation_vault.collateral, slashed.amount() - reward);
+    });
+}
+
 #[test]
 fn is_collateral_below_threshold_true_succeeds() {
     run_test(|| {
@@ -997,7 +1561,7 @@ mod get_vaults_below_premium_collaterlization_tests {
             ext::fee::premium_redeem_reward_rate::<Test>
                 .mock_safe(move || MockResult::Return(FixedU128::from_float(0.05)));
             ext::oracle::get_price::<Test>.mock_safe(move |_| MockResult::Return(Ok(3.into())));
-            ext::fee::get_redeem_fee_value::<Test>.mock_safe(move || MockResult::Return(FixedU128::from_float(0.005)));
+            ext::fee::get_redeem_fee_value::<Test>.mock_safe(move |_| MockResult::Return(FixedU128::from_float(0.005)));
 
             test()
         })
@@ -1412,6 +1976,285 @@ fn test_decrease_to_be_replaced_tokens_below_capacity() {
     })
 }
 
+#[test]
+fn liquidation_price_index_follows_issued_tokens_and_collateral() {
+    run_test(|| {
+        let id = create_sample_vault_and_issue_tokens(5000);
+        let bucket = |price: FixedU128| VaultRegistry::liquidation_price_bucket(price);
+        assert_eq!(
+            crate::VaultLiquidationPriceBucket::<Test>::get(&id),
+            Some(bucket(
+                FixedU128::checked_from_rational(5000, DEFAULT_COLLATERAL).unwrap()
+            ))
+        );
+
+        assert_ok!(VaultRegistry::try_deposit_collateral(&id, &amount(DEFAULT_COLLATERAL)));
+        assert_eq!(
+            crate::VaultLiquidationPriceBucket::<Test>::get(&id),
+            Some(bucket(
+                FixedU128::checked_from_rational(5000, 2 * DEFAULT_COLLATERAL).unwrap()
+            ))
+        );
+        assert!(crate::LiquidationPriceIndex::<Test>::contains_key(
+            (
+                DEFAULT_CURRENCY_PAIR,
+                bucket(FixedU128::checked_from_rational(1, 40).unwrap())
+            ),
+            &id
+        ));
+
+        assert_ok!(VaultRegistry::liquidate_vault(&id));
+        assert_eq!(crate::VaultLiquidationPriceBucket::<Test>::get(&id), None);
+        assert_eq!(crate::LiquidationPriceIndex::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn undercollateralized_vaults_only_returns_vaults_past_their_liquidation_price() {
+    run_test(|| {
+        // 200% and 500% collateralized at an exchange rate of 10
+        let id = create_sample_vault_and_issue_tokens(5000);
+        let other_id = create_vault_and_issue_tokens(2000, DEFAULT_COLLATERAL, OTHER_ID);
+        assert_eq!(VaultRegistry::undercollateralized_vaults().count(), 0);
+
+        // 105% and 263% collateralized
+        <oracle::Pallet<Test>>::_set_exchange_rate(
+            DEFAULT_COLLATERAL_CURRENCY,
+            UnsignedFixedPoint::from_rational(19, 1),
+        )
+        .unwrap();
+        assert_eq!(
+            VaultRegistry::undercollateralized_vaults().collect::<Vec<_>>(),
+            vec![id]
+        );
+
+        // the healthy vault is in a bucket below the liquidation price
+        let other_bucket = crate::VaultLiquidationPriceBucket::<Test>::get(&other_id).unwrap();
+        assert!(
+            other_bucket
+                < VaultRegistry::get_lowest_liquidatable_bucket(
+                    &DEFAULT_CURRENCY_PAIR,
+                    VaultRegistry::liquidation_collateral_threshold(&DEFAULT_CURRENCY_PAIR).unwrap()
+                )
+                .unwrap()
+        );
+    });
+}
+
+#[test]
+fn get_vault_health_reports_exchange_rates_at_thresholds() {
+    run_test(|| {
+        let id = create_sample_vault_and_issue_tokens(5000);
+        let exchange_rate_at = |threshold| FixedU128::from(20).checked_div(&threshold).unwrap();
+
+        let health = VaultRegistry::get_vault_health(&id).unwrap();
+        assert_eq!(health.collateral, DEFAULT_COLLATERAL);
+        assert_eq!(health.nominated_collateral, 0);
+        assert_eq!(health.issued_tokens, 5000);
+        assert_eq!(health.collateralization, Some(FixedU128::from(2)));
+        assert_eq!(
+            health.premium_redeem_exchange_rate,
+            Some(exchange_rate_at(FixedU128::checked_from_rational(120, 100).unwrap()))
+        );
+        assert_eq!(
+            health.liquidation_exchange_rate,
+            Some(exchange_rate_at(FixedU128::checked_from_rational(110, 100).unwrap()))
+        );
+
+        create_vault_with_collateral(&OTHER_ID, DEFAULT_COLLATERAL);
+        let health = VaultRegistry::get_vault_health(&OTHER_ID).unwrap();
+        assert_eq!(health.collateralization, None);
+        assert_eq!(health.liquidation_exchange_rate, None);
+        assert_eq!(VaultRegistry::get_vaults_health().len(), 2);
+    });
+}
+
+#[test]
+fn vault_statistics_record_service_history() {
+    run_test(|| {
+        let id = create_sample_vault();
+        assert_err!(VaultRegistry::get_vault_statistics(&OTHER_ID), TestError::VaultNotFound);
+
+        let stats = VaultRegistry::get_vault_statistics(&id).unwrap();
+        assert_eq!(stats, VaultStatistics::default());
+        assert_eq!(stats.average_redeem_execution_time(), None);
+
+        Security::<Test>::set_active_block_number(10);
+        VaultRegistry::record_issue_execution(&id);
+        VaultRegistry::record_issue_cancellation(&id);
+        VaultRegistry::record_redeem_execution(&id, 4);
+        VaultRegistry::record_redeem_execution(&id, 8);
+        VaultRegistry::record_redeem_cancellation(&id);
+        VaultRegistry::record_replace_execution(&id);
+        VaultRegistry::record_replace_cancellation(&id);
+        assert_ok!(VaultRegistry::ban_vault(&id));
+
+        let stats = VaultRegistry::get_vault_statistics(&id).unwrap();
+        assert_eq!(
+            stats,
+            VaultStatistics {
+                executed_issues: 1,
+                cancelled_issues: 1,
+                executed_redeems: 2,
+                cancelled_redeems: 1,
+                total_redeem_execution_time: 8,
+                executed_replaces: 1,
+                cancelled_replaces: 1,
+                punishments: 1,
+            }
+        );
+        assert_eq!(stats.average_redeem_execution_time(), Some(4));
+    });
+}
+
+#[test]
+fn get_vaults_below_threshold_filters_by_collateralization() {
+    run_test(|| {
+        // 200% and 500% collateralized
+        let id = create_sample_vault_and_issue_tokens(5000);
+        create_vault_and_issue_tokens(2000, DEFAULT_COLLATERAL, OTHER_ID);
+
+        let below = |percent| {
+            VaultRegistry::get_vaults_below_threshold(FixedU128::checked_from_rational(percent, 100).unwrap())
+                .into_iter()
+                .map(|health| health.vault_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(below(150), vec![]);
+        assert_eq!(below(250), vec![id]);
+        assert_eq!(below(600).len(), 2);
+    });
+}
+
+#[test]
+fn select_vaults_for_issue_prefers_a_single_vault_and_splits_otherwise() {
+    run_test(|| {
+        create_vault_with_collateral(&DEFAULT_ID, DEFAULT_COLLATERAL);
+        create_vault_with_collateral(&RICH_ID, RICH_COLLATERAL);
+        <oracle::Pallet<Test>>::_set_exchange_rate(
+            DEFAULT_COLLATERAL_CURRENCY,
+            UnsignedFixedPoint::from_rational(10, 1),
+        )
+        .unwrap();
+
+        // 5000 and 10000 issuable tokens
+        let select = |amount, seed| {
+            VaultRegistry::select_vaults_for_issue(&DEFAULT_CURRENCY_PAIR, &wrapped(amount), seed)
+                .map(|(selected, _)| selected)
+        };
+        assert_eq!(select(4000, None), Ok(vec![(RICH_ID, wrapped(4000))]));
+        assert_eq!(
+            select(4000, Some(H256::from_low_u64_be(1))),
+            Ok(vec![(DEFAULT_ID, wrapped(4000))])
+        );
+        assert_eq!(
+            select(12000, None),
+            Ok(vec![(RICH_ID, wrapped(10000)), (DEFAULT_ID, wrapped(2000))])
+        );
+        assert_err!(select(16000, None), TestError::NoVaultWithSufficientCollateral);
+
+        // banned vaults are skipped
+        assert_ok!(VaultRegistry::ban_vault(&RICH_ID));
+        assert_eq!(select(4000, None), Ok(vec![(DEFAULT_ID, wrapped(4000))]));
+    });
+}
+
+#[test]
+fn select_vaults_for_redeem_prefers_the_lowest_collateralization() {
+    run_test(|| {
+        // 500% and 400% collateralized
+        create_vault_and_issue_tokens(2000, DEFAULT_COLLATERAL, DEFAULT_ID);
+        create_vault_and_issue_tokens(5000, RICH_COLLATERAL, RICH_ID);
+
+        let select = |amount| {
+            VaultRegistry::select_vaults_for_redeem(&DEFAULT_CURRENCY_PAIR, &wrapped(amount), None)
+                .map(|(selected, _)| selected)
+        };
+        assert_eq!(select(1000), Ok(vec![(RICH_ID, wrapped(1000))]));
+        assert_eq!(select(3000), Ok(vec![(RICH_ID, wrapped(3000))]));
+        assert_eq!(
+            select(6000),
+            Ok(vec![(RICH_ID, wrapped(5000)), (DEFAULT_ID, wrapped(1000))])
+        );
+        assert_err!(select(8000), TestError::NoVaultWithSufficientTokens);
+        assert_eq!(
+            VaultRegistry::select_vaults_for_redeem(&DEFAULT_CURRENCY_PAIR, &wrapped(1000), None)
+                .map(|(_, scanned)| scanned),
+            Ok(2)
+        );
+    });
+}
+
+#[test]
+fn scan_vaults_reads_a_bounded_number_of_vaults() {
+    run_test(|| {
+        let vault_count = MAX_VAULTS_SCANNED_PER_SELECTION as u64 + 10;
+        for account_id in 0..vault_count {
+            VaultRegistry::insert_vault(&vault_id(account_id), Vault::new(vault_id(account_id)));
+        }
+
+        let (vaults, scanned) = VaultRegistry::scan_vaults(&DEFAULT_CURRENCY_PAIR, None);
+        assert_eq!(scanned, MAX_VAULTS_SCANNED_PER_SELECTION);
+        assert_eq!(vaults.len() as u32, scanned);
+
+        // the scan starts at a random position, wrapping around without reading a vault twice
+        for seed in 0..10 {
+            let (vaults, scanned) = VaultRegistry::scan_vaults(
+                &DEFAULT_CURRENCY_PAIR,
+                Some(H256(sp_core::hashing::blake2_256(&seed.to_be_bytes()))),
+            );
+            assert_eq!(scanned, MAX_VAULTS_SCANNED_PER_SELECTION);
+            let mut vault_ids = vaults.into_iter().map(|(vault_id, _)| vault_id).collect::<Vec<_>>();
+            vault_ids.sort();
+            vault_ids.dedup();
+            assert_eq!(vault_ids.len() as u32, MAX_VAULTS_SCANNED_PER_SELECTION);
+        }
+    });
+}
+
+#[test]
+fn scan_vaults_only_reads_vaults_of_the_currency_pair() {
+    run_test(|| {
+        let other_pair = WORST_CASE_CURRENCY_PAIR;
+        for account_id in 0..MAX_VAULTS_SCANNED_PER_SELECTION as u64 {
+            let other_vault_id = DefaultVaultId::<Test> {
+                account_id,
+                currencies: other_pair.clone(),
+            };
+            VaultRegistry::insert_vault(&other_vault_id, Vault::new(other_vault_id.clone()));
+        }
+        VaultRegistry::insert_vault(&vault_id(1000), Vault::new(vault_id(1000)));
+
+        // the vaults of the other currency pair don't count towards the bound
+        let (vaults, scanned) = VaultRegistry::scan_vaults(&DEFAULT_CURRENCY_PAIR, None);
+        assert_eq!(scanned, 1);
+        assert_eq!(
+            vaults.into_iter().map(|(vault_id, _)| vault_id).collect::<Vec<_>>(),
+            vec![vault_id(1000)]
+        );
+
+        let (_, scanned) = VaultRegistry::scan_vaults(&other_pair, Some(H256::repeat_byte(1)));
+        assert_eq!(scanned, MAX_VAULTS_SCANNED_PER_SELECTION);
+    });
+}
+
+#[test]
+fn migrate_v7_to_v8_indexes_vaults_by_currency_pair() {
+    run_test(|| {
+        VaultRegistry::insert_vault(&vault_id(1), Vault::new(vault_id(1)));
+        crate::CurrencyPairVaults::<Test>::remove(&DEFAULT_CURRENCY_PAIR, 1);
+        crate::StorageVersion::<Test>::put(crate::types::Version::V7);
+
+        crate::types::v7::migrate_v7_to_v8::<Test>();
+
+        assert!(crate::CurrencyPairVaults::<Test>::contains_key(
+            &DEFAULT_CURRENCY_PAIR,
+            1
+        ));
+        assert_eq!(crate::StorageVersion::<Test>::get(), crate::types::Version::V8);
+    });
+}
+
 #[test]
 fn test_offchain_worker_unsigned_transaction_submission() {
     let mut externalities = crate::mock::ExtBuilder::build();
@@ -1424,7 +2267,14 @@ fn test_offchain_worker_unsigned_transaction_submission() {
         System::set_block_number(1);
         Security::<Test>::set_active_block_number(1);
         set_default_thresholds();
-        VaultRegistry::insert_vault(&id, Vault::new(id.clone()));
+        VaultRegistry::insert_vault(
+            &id,
+            Vault {
+                issued_tokens: 100,
+                ..Vault::new(id.clone())
+            },
+        );
+        assert_ok!(VaultRegistry::update_liquidation_price_index(&id));
 
         // mock that all vaults need to be liquidated
         VaultRegistry::is_vault_below_liquidation_threshold.mock_safe(move |_, _| MockResult::Return(Ok(true)));
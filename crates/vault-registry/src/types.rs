@@ -1,23 +1,26 @@
 use crate::{ext, Config, Error, Pallet, PoolManager};
 use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
-use currency::Amount;
+use currency::{Amount, Rounding};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
-    traits::Get,
+    traits::{ConstU32, Get},
+    BoundedVec,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use primitives::{VaultCurrencyPair, VaultId};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
-    traits::{CheckedAdd, CheckedSub, Zero},
+    traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedSub, One, Zero},
     ArithmeticError,
 };
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 use mocktopus::macros::mockable;
 
+use bitcoin::{types::MAX_MULTISIG_PUBLIC_KEYS, Script as BtcScript};
 pub use bitcoin::{Address as BtcAddress, PublicKey as BtcPublicKey};
 
 /// Storage version.
@@ -37,6 +40,12 @@ pub enum Version {
     V5,
     /// Removed wallet
     V6,
+    /// Indexed vaults by liquidation price
+    V7,
+    /// Indexed vaults by currency pair
+    V8,
+    /// Counted the vaults of each account
+    V9,
 }
 
 #[derive(Debug, PartialEq)]
@@ -125,6 +134,72 @@ pub mod v1 {
     }
 }
 
+pub mod v6 {
+    use super::*;
+
+    pub fn migrate_v6_to_v7<T: Config>() -> frame_support::weights::Weight {
+        if !matches!(crate::StorageVersion::<T>::get(), Version::V6) {
+            log::info!("Not running vault liquidation price index migration");
+            return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+        }
+
+        let mut indexed_vaults = 0u64;
+        for vault_id in crate::Vaults::<T>::iter_keys() {
+            if let Err(err) = Pallet::<T>::update_liquidation_price_index(&vault_id) {
+                log::error!("Failed to index vault {:?}: {:?}", vault_id, err);
+            }
+            indexed_vaults += 1;
+        }
+        log::info!("Indexed {} vaults by liquidation price", indexed_vaults);
+
+        crate::StorageVersion::<T>::put(Version::V7);
+        // every vault reads its collateral from the staking pallet and writes its bucket
+        T::DbWeight::get().reads_writes(indexed_vaults * 8 + 1, indexed_vaults * 3 + 1)
+    }
+}
+
+pub mod v7 {
+    use super::*;
+
+    pub fn migrate_v7_to_v8<T: Config>() -> frame_support::weights::Weight {
+        if !matches!(crate::StorageVersion::<T>::get(), Version::V7) {
+            log::info!("Not running vault currency pair index migration");
+            return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+        }
+
+        let mut indexed_vaults = 0u64;
+        for vault_id in crate::Vaults::<T>::iter_keys() {
+            crate::CurrencyPairVaults::<T>::insert(&vault_id.currencies, &vault_id.account_id, ());
+            indexed_vaults += 1;
+        }
+        log::info!("Indexed {} vaults by currency pair", indexed_vaults);
+
+        crate::StorageVersion::<T>::put(Version::V8);
+        T::DbWeight::get().reads_writes(indexed_vaults + 1, indexed_vaults + 1)
+    }
+}
+
+pub mod v8 {
+    use super::*;
+
+    pub fn migrate_v8_to_v9<T: Config>() -> frame_support::weights::Weight {
+        if !matches!(crate::StorageVersion::<T>::get(), Version::V8) {
+            log::info!("Not running vault count migration");
+            return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+        }
+
+        let mut counted_vaults = 0u64;
+        for vault_id in crate::Vaults::<T>::iter_keys() {
+            crate::AccountVaultCount::<T>::mutate(&vault_id.account_id, |count| *count = count.saturating_add(1));
+            counted_vaults += 1;
+        }
+        log::info!("Counted {} vaults", counted_vaults);
+
+        crate::StorageVersion::<T>::put(Version::V9);
+        T::DbWeight::get().reads_writes(counted_vaults * 2 + 1, counted_vaults + 1)
+    }
+}
+
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub enum VaultStatus {
     /// Vault is active - bool=true indicates that the vault accepts new issue requests
@@ -132,6 +207,9 @@ pub enum VaultStatus {
 
     /// Vault has been liquidated
     Liquidated,
+
+    /// Vault has requested to exit - it is removed once its issued tokens are redeemed or replaced
+    Exiting,
 }
 
 impl Default for VaultStatus {
@@ -140,6 +218,125 @@ impl Default for VaultStatus {
     }
 }
 
+/// The Bitcoin public keys of an m-of-n multisig vault.
+pub type BtcMultisigPublicKeys = BoundedVec<BtcPublicKey, ConstU32<{ MAX_MULTISIG_PUBLIC_KEYS as u32 }>>;
+
+/// An m-of-n set of Bitcoin public keys from which a vault's deposit addresses are derived.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MultisigPublicKeys {
+    /// Number of signatures required to spend from the vault's addresses
+    pub threshold: u8,
+    /// The master keys, each of which is tweaked per deposit address
+    pub public_keys: BtcMultisigPublicKeys,
+}
+
+impl MultisigPublicKeys {
+    /// The P2WSH address of the multisig script over the keys. If a `secure_id` is given, every
+    /// key is first tweaked with it in the same way as single-key deposit addresses.
+    pub fn to_address(&self, secure_id: Option<H256>) -> Option<BtcAddress> {
+        let public_keys = match secure_id {
+            Some(secure_id) => self
+                .public_keys
+                .iter()
+                .map(|public_key| public_key.new_deposit_public_key(secure_id).ok())
+                .collect::<Option<Vec<_>>>()?,
+            None => self.public_keys.to_vec(),
+        };
+        let witness_script = BtcScript::multisig(self.threshold, &public_keys).ok()?;
+        Some(BtcAddress::from_witness_script(witness_script.as_bytes()))
+    }
+}
+
+/// A Bitcoin public key proposed by a vault to replace its current key.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PublicKeyRotation<BlockNumber> {
+    /// The key from which new deposit addresses are derived once activated
+    pub public_key: BtcPublicKey,
+    /// Block from which the new key replaces the current key
+    pub activation_block: BlockNumber,
+}
+
+/// Parameters of the descending-price auctions that sell the liquidation vault's collateral.
+/// Prices are multiples of the oracle exchange rate, i.e. a price of one buys collateral
+/// at its market value in wrapped tokens.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AuctionParameters<Balance, BlockNumber, UnsignedFixedPoint> {
+    /// Maximum amount of collateral sold by a single auction
+    pub lot_size: Balance,
+    /// Number of blocks over which the price decreases from `start_price` to `end_price`
+    pub duration: BlockNumber,
+    /// Price at the start of an auction
+    pub start_price: UnsignedFixedPoint,
+    /// Price at the end of an auction
+    pub end_price: UnsignedFixedPoint,
+}
+
+/// A running auction of liquidated collateral.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CollateralAuction<Balance, BlockNumber, UnsignedFixedPoint> {
+    /// Collateral that is left to be sold
+    pub collateral: Balance,
+    /// Block at which the auction started
+    pub start_block: BlockNumber,
+    /// Last block at which bids are accepted
+    pub end_block: BlockNumber,
+    /// Price at `start_block`
+    pub start_price: UnsignedFixedPoint,
+    /// Price at `end_block`
+    pub end_price: UnsignedFixedPoint,
+}
+
+/// Collateralization of a vault and the exchange rates at which it crosses the thresholds.
+/// Exchange rates are in collateral per wrapped token, like those of the oracle.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct VaultHealth<VaultId, Balance, UnsignedFixedPoint> {
+    pub vault_id: VaultId,
+    /// Collateral deposited by the vault itself
+    pub collateral: Balance,
+    /// Collateral deposited by nominators
+    pub nominated_collateral: Balance,
+    pub issued_tokens: Balance,
+    pub to_be_issued_tokens: Balance,
+    pub to_be_redeemed_tokens: Balance,
+    /// Ratio of the total collateral to the issued tokens, none if no tokens are issued
+    pub collateralization: Option<UnsignedFixedPoint>,
+    /// Exchange rate above which the vault is below the premium redeem threshold
+    pub premium_redeem_exchange_rate: Option<UnsignedFixedPoint>,
+    /// Exchange rate above which the vault is below the liquidation threshold
+    pub liquidation_exchange_rate: Option<UnsignedFixedPoint>,
+}
+
+/// Service record of a vault, used to judge its reliability.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct VaultStatistics<BlockNumber> {
+    pub executed_issues: u32,
+    pub cancelled_issues: u32,
+    pub executed_redeems: u32,
+    pub cancelled_redeems: u32,
+    /// Sum of the active blocks between request and execution of all executed redeems
+    pub total_redeem_execution_time: BlockNumber,
+    /// Replaces in which this vault was the one being replaced
+    pub executed_replaces: u32,
+    pub cancelled_replaces: u32,
+    /// Number of times the vault was banned
+    pub punishments: u32,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VaultStatistics<BlockNumber> {
+    /// Average number of active blocks between request and execution of a redeem, none if no
+    /// redeem was executed yet.
+    pub fn average_redeem_execution_time(&self) -> Option<BlockNumber> {
+        self.total_redeem_execution_time
+            .checked_div(&self.executed_redeems.into())
+    }
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct Vault<AccountId, BlockNumber, Balance, CurrencyId: Copy, UnsignedFixedPoint> {
@@ -220,6 +417,10 @@ impl<
     pub fn accepts_new_issues(&self) -> bool {
         matches!(self.status, VaultStatus::Active(true))
     }
+
+    pub fn is_exiting(&self) -> bool {
+        matches!(self.status, VaultStatus::Exiting)
+    }
 }
 
 pub type DefaultVault<T> = Vault<
@@ -232,6 +433,14 @@ pub type DefaultVault<T> = Vault<
 
 pub type DefaultSystemVault<T> = SystemVault<BalanceOf<T>, CurrencyId<T>>;
 
+pub type DefaultAuctionParameters<T> = AuctionParameters<BalanceOf<T>, BlockNumberFor<T>, UnsignedFixedPoint<T>>;
+
+pub type DefaultCollateralAuction<T> = CollateralAuction<BalanceOf<T>, BlockNumberFor<T>, UnsignedFixedPoint<T>>;
+
+pub type DefaultVaultHealth<T> = VaultHealth<DefaultVaultId<T>, BalanceOf<T>, UnsignedFixedPoint<T>>;
+
+pub type DefaultVaultStatistics<T> = VaultStatistics<BlockNumberFor<T>>;
+
 #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
 trait UpdatableVault<T: Config> {
     fn increase_issued(&mut self, tokens: &Amount<T>) -> DispatchResult;
@@ -528,6 +737,13 @@ impl<T: Config> RichVault<T> {
         })
     }
 
+    pub(crate) fn set_exiting(&mut self) -> DispatchResult {
+        self.update(|v| {
+            v.status = VaultStatus::Exiting;
+            Ok(())
+        })
+    }
+
     pub(crate) fn increase_liquidated_collateral(&mut self, amount: &Amount<T>) -> DispatchResult {
         self.update(|v| {
             v.liquidated_collateral = v
@@ -638,6 +854,70 @@ impl<T: Config> RichVault<T> {
         Ok(liquidated_collateral_excluding_to_be_redeemed)
     }
 
+    /// Moves just enough issued tokens to the liquidation vault to restore the vault to its
+    /// secure threshold, together with collateral worth the tokens plus the `penalty`. The
+    /// collateral is slashed proportionally from the vault and its nominators. Returns the
+    /// liquidated tokens and collateral, or `None` if the vault can't be restored this way.
+    pub(crate) fn partially_liquidate(
+        &mut self,
+        penalty: UnsignedFixedPoint<T>,
+    ) -> Result<Option<(Amount<T>, Amount<T>)>, DispatchError> {
+        let vault_id = self.id();
+        let secure_threshold = self.get_secure_threshold()?;
+        let total_collateral = self.get_total_collateral()?;
+        let backed_collateral = self.backed_tokens()?.convert_to(vault_id.collateral_currency())?;
+
+        // liquidating tokens worth `v` with `v * (1 + penalty)` collateral restores the secure
+        // threshold `s` if `(collateral - v * (1 + penalty)) / (backed - v) = s`, which gives
+        // `v = (s * backed - collateral) / (s - 1 - penalty)`
+        let collateral_rate = UnsignedFixedPoint::<T>::one()
+            .checked_add(&penalty)
+            .ok_or(ArithmeticError::Overflow)?;
+        let denominator = match secure_threshold.checked_sub(&collateral_rate) {
+            Some(denominator) if !denominator.is_zero() => denominator,
+            _ => return Ok(None),
+        };
+        let deficit = backed_collateral
+            .checked_rounded_mul(&secure_threshold, Rounding::Up)?
+            .saturating_sub(&total_collateral)?;
+        let liquidated_value = deficit.checked_div(&denominator)?;
+
+        // round up so that the vault ends up at or above the secure threshold
+        let liquidated_tokens = liquidated_value
+            .convert_to(vault_id.wrapped_currency())?
+            .checked_add(&Amount::new(One::one(), vault_id.wrapped_currency()))?;
+        let liquidated_collateral = liquidated_tokens
+            .convert_to(vault_id.collateral_currency())?
+            .checked_rounded_mul(&collateral_rate, Rounding::Up)?;
+
+        // tokens that are being redeemed stay with the vault
+        if liquidated_tokens.gt(&self.freely_redeemable_tokens()?)? || liquidated_collateral.ge(&total_collateral)? {
+            return Ok(None);
+        }
+
+        PoolManager::<T>::slash_collateral(&vault_id, &liquidated_collateral)?;
+        Pallet::<T>::transfer_funds(
+            CurrencySource::LiquidatedCollateral(vault_id.clone()),
+            CurrencySource::LiquidationVault(vault_id.currencies.clone()),
+            &liquidated_collateral,
+        )?;
+
+        // the vault struct may have been modified above - we need to re-fetch
+        *self = Pallet::<T>::get_rich_vault_from_id(&vault_id)?;
+        self.decrease_issued(&liquidated_tokens)?;
+        Pallet::<T>::get_rich_liquidation_vault(&vault_id.currencies).increase_issued(&liquidated_tokens)?;
+
+        // the vault can't be replaced for more tokens than it has left
+        let excess_to_be_replaced = self
+            .to_be_replaced_tokens()
+            .saturating_sub(&self.freely_redeemable_tokens()?)?;
+        if !excess_to_be_replaced.is_zero() {
+            Pallet::<T>::withdraw_replace_request(&vault_id, &excess_to_be_replaced)?;
+        }
+
+        Ok(Some((liquidated_tokens, liquidated_collateral)))
+    }
+
     pub fn ensure_not_banned(&self) -> DispatchResult {
         if self.is_banned() {
             Err(Error::<T>::VaultBanned.into())
@@ -670,6 +950,11 @@ impl<T: Config> RichVault<T> {
     }
 
     pub(crate) fn new_deposit_address(&mut self, secure_id: H256) -> Result<BtcAddress, DispatchError> {
+        if let Some(multisig) = Pallet::<T>::get_bitcoin_multisig(&self.data.id.account_id) {
+            return Ok(multisig
+                .to_address(Some(secure_id))
+                .ok_or(Error::<T>::InvalidPublicKey)?);
+        }
         let public_key = self.new_deposit_public_key(secure_id)?;
         let btc_address = BtcAddress::P2WPKHv0(public_key.to_hash());
         Ok(btc_address)
@@ -679,8 +964,12 @@ impl<T: Config> RichVault<T> {
     where
         F: Fn(&mut DefaultVault<T>) -> DispatchResult,
     {
+        let (issued_tokens, status) = (self.data.issued_tokens, self.data.status);
         func(&mut self.data)?;
         <crate::Vaults<T>>::insert(&self.id(), &self.data);
+        if self.data.issued_tokens != issued_tokens || self.data.status != status {
+            Pallet::<T>::update_liquidation_price_index(&self.id())?;
+        }
         Ok(())
     }
 }
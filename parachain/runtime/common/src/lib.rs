@@ -1,10 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+use codec::{DecodeLimit, Encode};
 use core::marker::PhantomData;
 
 use currency::Amount;
 use frame_support::{
     pallet_prelude::Get,
-    traits::{Currency, OnTimestampSet, OnUnbalanced, ProcessMessageError, TryDrop},
+    traits::{Contains, Currency, OnTimestampSet, OnUnbalanced, ProcessMessageError, TryDrop},
 };
 use primitives::{BlockNumber, UnsignedFixedPoint};
 use sp_runtime::{DispatchError, FixedPointNumber};
@@ -28,7 +29,10 @@ pub type AccountId<T> = <T as frame_system::Config>::AccountId;
 pub type VaultId<T> = primitives::VaultId<AccountId<T>, currency::CurrencyId<T>>;
 pub use currency::CurrencyId;
 use primitives::{Balance, Nonce};
-use xcm::latest::{Instruction, MultiLocation, Weight};
+use xcm::{
+    latest::{Instruction, MultiLocation, Weight},
+    MAX_XCM_DECODE_DEPTH,
+};
 use xcm_executor::traits::ShouldExecute;
 
 fn native_currency_id<T: currency::Config>() -> CurrencyId<T> {
@@ -130,6 +134,38 @@ impl<T: ShouldExecute> ShouldExecute for Transactless<T> {
     }
 }
 
+/// Like `Transactless`, but lets through outer-level `Transact`s of calls matched by `AllowedCalls`.
+pub struct TransactlessExcept<T, RuntimeCall, AllowedCalls>(PhantomData<(T, RuntimeCall, AllowedCalls)>);
+
+impl<T, RuntimeCall, AllowedCalls> ShouldExecute for TransactlessExcept<T, RuntimeCall, AllowedCalls>
+where
+    T: ShouldExecute,
+    RuntimeCall: DecodeLimit,
+    AllowedCalls: Contains<RuntimeCall>,
+{
+    fn should_execute<Call>(
+        origin: &MultiLocation,
+        instructions: &mut [Instruction<Call>],
+        max_weight: Weight,
+        properties: &mut Properties,
+    ) -> Result<(), ProcessMessageError> {
+        let has_disallowed_transact = instructions.iter().any(|x| match x {
+            Instruction::Transact { call, .. } => {
+                // the barrier is generic over the call type, so decode the call from its raw encoding
+                let encoded = Vec::<u8>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &call.encode()[..]);
+                !encoded
+                    .and_then(|encoded| RuntimeCall::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &encoded[..]))
+                    .map_or(false, |call| AllowedCalls::contains(&call))
+            }
+            _ => false,
+        });
+        if has_disallowed_transact {
+            return Err(ProcessMessageError::Unsupported);
+        }
+        T::should_execute(origin, instructions, max_weight, properties)
+    }
+}
+
 pub struct ToTreasury<T, TreasuryAccount, NativeCurrency>(PhantomData<(T, TreasuryAccount, NativeCurrency)>);
 
 impl<T, TreasuryAccount, NativeCurrency, NegImbalance> OnUnbalanced<NegImbalance>
@@ -166,4 +166,34 @@ impl<T: frame_system::Config> fee::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Fee IssueFeeBounds (r:1 w:0)
+	/// Proof: Fee IssueFeeBounds (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Fee VaultIssueFee (r:0 w:1)
+	/// Proof: Fee VaultIssueFee (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	fn set_vault_issue_fee	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `3725`
+		// Minimum execution time: 24_716_000 picoseconds.
+		Weight::from_parts(25_143_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Fee RedeemFeeBounds (r:1 w:0)
+	/// Proof: Fee RedeemFeeBounds (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Fee VaultRedeemFee (r:0 w:1)
+	/// Proof: Fee VaultRedeemFee (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	fn set_vault_redeem_fee	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `3725`
+		// Minimum execution time: 24_902_000 picoseconds.
+		Weight::from_parts(25_388_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
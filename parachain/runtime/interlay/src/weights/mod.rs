@@ -34,6 +34,8 @@ pub mod pallet_xcm;
 pub mod pallet_xcm_benchmarks_fungible;
 pub mod pallet_xcm_benchmarks_generic;
 pub mod redeem;
+pub mod relay;
+pub mod remote_requests;
 pub mod replace;
 pub mod security;
 pub mod supply;
@@ -102,6 +102,75 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(29_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `VaultRegistry::Vaults` (r:100 w:5)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::LiquidationCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::LiquidationCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::Nonce` (r:100 w:0)
+	/// Proof: `VaultStaking::Nonce` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `VaultStaking::TotalCurrentStake` (r:100 w:0)
+	/// Proof: `VaultStaking::TotalCurrentStake` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Aggregate` (r:1 w:0)
+	/// Proof: `Oracle::Aggregate` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemBtcDustValue` (r:1 w:0)
+	/// Proof: `Redeem::RedeemBtcDustValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::PremiumRedeemThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::PremiumRedeemThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Loans::UnderlyingAssetId` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Markets` (r:2 w:0)
+	/// Proof: `Loans::Markets` (`max_values`: None, `max_size`: Some(160), added: 2635, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::LastAccruedInterestTime` (r:1 w:1)
+	/// Proof: `Loans::LastAccruedInterestTime` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalBorrows` (r:1 w:0)
+	/// Proof: `Loans::TotalBorrows` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::TotalReserves` (r:1 w:0)
+	/// Proof: `Loans::TotalReserves` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MinExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MinExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::MaxExchangeRate` (r:1 w:0)
+	/// Proof: `Loans::MaxExchangeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::SecureCollateralThreshold` (r:1 w:0)
+	/// Proof: `VaultRegistry::SecureCollateralThreshold` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Fee::PremiumRedeemFee` (r:1 w:0)
+	/// Proof: `Fee::PremiumRedeemFee` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Security::Nonce` (r:1 w:1)
+	/// Proof: `Security::Nonce` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemPeriod` (r:1 w:0)
+	/// Proof: `Redeem::RedeemPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Redeem::RedeemRequests` (r:0 w:5)
+	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::CurrencyPairVaults` (r:100 w:0)
+	/// Proof: `VaultRegistry::CurrencyPairVaults` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[5, 100]`.
+	fn request_redeem_auto	(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3604 + v * (351 ±0)`
+		//  Estimated: `6260 + v * (7865 ±0)`
+		// Minimum execution time: 1_207_000_000 picoseconds.
+		Weight::from_parts(1_043_182_617, 6260)
+			// Standard Error: 58_344
+			.saturating_add(Weight::from_parts(48_752_130, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(45_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+			.saturating_add(Weight::from_parts(0, 7865).saturating_mul(v.into()))
+	}
+
 	/// Storage: `Tokens::Accounts` (r:3 w:3)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
@@ -157,6 +226,8 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
 	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
 	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultStats` (r:1 w:1)
+	/// Proof: `VaultRegistry::VaultStats` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -164,9 +235,9 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 	fn execute_redeem	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2295 + o * (1 ±0)`
-		//  Estimated: `3725`
+		//  Estimated: `6302`
 		// Minimum execution time: 89_000_000 picoseconds.
-		Weight::from_parts(55_152_275, 3725)
+		Weight::from_parts(55_152_275, 6302)
 			// Standard Error: 149_738
 			.saturating_add(Weight::from_parts(2_508_576, 0).saturating_mul(h.into()))
 			// Standard Error: 134_990
@@ -175,8 +246,47 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(3_299_475, 0).saturating_mul(o.into()))
 			// Standard Error: 824
 			.saturating_add(Weight::from_parts(5_210, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Redeem::RedeemRequests` (r:16 w:16)
+	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::DisableInclusionCheck` (r:1 w:0)
+	/// Proof: `BTCRelay::DisableInclusionCheck` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BestBlockHeight` (r:1 w:0)
+	/// Proof: `BTCRelay::BestBlockHeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::Chains` (r:1 w:0)
+	/// Proof: `BTCRelay::Chains` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::BlockHeaders` (r:1 w:0)
+	/// Proof: `BTCRelay::BlockHeaders` (`max_values`: None, `max_size`: Some(200), added: 2675, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::StableBitcoinConfirmations` (r:1 w:0)
+	/// Proof: `BTCRelay::StableBitcoinConfirmations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Security::ActiveBlockCount` (r:1 w:0)
+	/// Proof: `Security::ActiveBlockCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BTCRelay::StableParachainConfirmations` (r:1 w:0)
+	/// Proof: `BTCRelay::StableParachainConfirmations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::Vaults` (r:1 w:1)
+	/// Proof: `VaultRegistry::Vaults` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultStats` (r:1 w:1)
+	/// Proof: `VaultRegistry::VaultStats` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn execute_redeems	(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2179 + n * (116 ±0)`
+		//  Estimated: `6302 + n * (2720 ±0)`
+		// Minimum execution time: 96_000_000 picoseconds.
+		Weight::from_parts(67_420_118, 6302)
+			// Standard Error: 86_215
+			.saturating_add(Weight::from_parts(31_874_402, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2720).saturating_mul(n.into()))
 	}
 	/// Storage: `Redeem::RedeemRequests` (r:1 w:1)
 	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
@@ -266,14 +376,16 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 	/// Proof: `Loans::AccountDeposits` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
 	/// Storage: `VaultRegistry::PunishmentDelay` (r:1 w:0)
 	/// Proof: `VaultRegistry::PunishmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultStats` (r:1 w:1)
+	/// Proof: `VaultRegistry::VaultStats` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
 	fn cancel_redeem_reimburse	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5396`
-		//  Estimated: `11350`
+		//  Estimated: `13927`
 		// Minimum execution time: 495_000_000 picoseconds.
-		Weight::from_parts(510_000_000, 11350)
-			.saturating_add(T::DbWeight::get().reads(59_u64))
-			.saturating_add(T::DbWeight::get().writes(29_u64))
+		Weight::from_parts(510_000_000, 13927)
+			.saturating_add(T::DbWeight::get().reads(60_u64))
+			.saturating_add(T::DbWeight::get().writes(30_u64))
 	}
 	/// Storage: `Redeem::RedeemRequests` (r:1 w:1)
 	/// Proof: `Redeem::RedeemRequests` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
@@ -363,14 +475,16 @@ impl<T: frame_system::Config> redeem::WeightInfo for WeightInfo<T> {
 	/// Proof: `Loans::AccountDeposits` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
 	/// Storage: `VaultRegistry::PunishmentDelay` (r:1 w:0)
 	/// Proof: `VaultRegistry::PunishmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `VaultRegistry::VaultStats` (r:1 w:1)
+	/// Proof: `VaultRegistry::VaultStats` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
 	fn cancel_redeem_retry	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5396`
-		//  Estimated: `11350`
+		//  Estimated: `13927`
 		// Minimum execution time: 406_000_000 picoseconds.
-		Weight::from_parts(412_000_000, 11350)
-			.saturating_add(T::DbWeight::get().reads(58_u64))
-			.saturating_add(T::DbWeight::get().writes(28_u64))
+		Weight::from_parts(412_000_000, 13927)
+			.saturating_add(T::DbWeight::get().reads(59_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
 	}
 	/// Storage: `Redeem::RedeemPeriod` (r:0 w:1)
 	/// Proof: `Redeem::RedeemPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
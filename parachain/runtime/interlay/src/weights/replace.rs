@@ -169,6 +169,8 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:1 w:1)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -176,9 +178,9 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 	fn execute_pending_replace	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3962`
-		//  Estimated: `6460`
+		//  Estimated: `9037`
 		// Minimum execution time: 201_203_000 picoseconds.
-		Weight::from_parts(166_625_346, 6460)
+		Weight::from_parts(166_625_346, 9037)
 			// Standard Error: 77_399
 			.saturating_add(Weight::from_parts(3_797_260, 0).saturating_mul(h.into()))
 			// Standard Error: 69_776
@@ -187,8 +189,8 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(3_113_568, 0).saturating_mul(o.into()))
 			// Standard Error: 426
 			.saturating_add(Weight::from_parts(3_662, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -216,6 +218,8 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
 	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
 	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[2, 3]`.
@@ -223,9 +227,9 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 	fn execute_cancelled_replace	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4084`
-		//  Estimated: `6460`
+		//  Estimated: `9037`
 		// Minimum execution time: 244_751_000 picoseconds.
-		Weight::from_parts(221_003_188, 6460)
+		Weight::from_parts(221_003_188, 9037)
 			// Standard Error: 69_845
 			.saturating_add(Weight::from_parts(3_504_737, 0).saturating_mul(h.into()))
 			// Standard Error: 62_966
@@ -234,8 +238,8 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(2_470_971, 0).saturating_mul(o.into()))
 			// Standard Error: 384
 			.saturating_add(Weight::from_parts(4_539, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Replace ReplaceRequests (r:1 w:1)
 	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(250), added: 2725, mode: MaxEncodedLen)
@@ -293,14 +297,16 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: VaultRewards TotalStake (r:1 w:0)
 	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_replace	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4744`
-		//  Estimated: `6460`
+		//  Estimated: `9037`
 		// Minimum execution time: 499_671_000 picoseconds.
-		Weight::from_parts(507_496_000, 6460)
-			.saturating_add(T::DbWeight::get().reads(37_u64))
-			.saturating_add(T::DbWeight::get().writes(19_u64))
+		Weight::from_parts(507_496_000, 9037)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
 	}
 	/// Storage: Replace ReplacePeriod (r:0 w:1)
 	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -312,4 +318,103 @@ impl<T: frame_system::Config> replace::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(18_467_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Fee ReplaceGriefingCollateral (r:1 w:0)
+	/// Proof: Fee ReplaceGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceAuctionEnds (r:1 w:1)
+	/// Proof: Replace ReplaceAuctionEnds (max_values: None, max_size: Some(5014), added: 7489, mode: MaxEncodedLen)
+	fn request_replace_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3118`
+		//  Estimated: `24593`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(121_338_000, 24593)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn bid_on_replace_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2614`
+		//  Estimated: `29013`
+		// Minimum execution time: 86_927_000 picoseconds.
+		Weight::from_parts(88_512_000, 29013)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Replace ReplaceAuctions (r:1 w:1)
+	/// Proof: Replace ReplaceAuctions (max_values: None, max_size: Some(217), added: 2692, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:2 w:2)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:1 w:0)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceBtcDustValue (r:1 w:0)
+	/// Proof: Replace ReplaceBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplacePeriod (r:1 w:0)
+	/// Proof: Replace ReplacePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Replace ReplaceRequests (r:0 w:1)
+	/// Proof: Replace ReplaceRequests (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	fn settle_replace_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5672`
+		//  Estimated: `108971`
+		// Minimum execution time: 461_538_000 picoseconds.
+		Weight::from_parts(468_913_000, 108971)
+			.saturating_add(T::DbWeight::get().reads(48_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
 }
\ No newline at end of file
@@ -127,13 +127,15 @@ impl<T: frame_system::Config> vault_registry::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:1 w:1)
 	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinMultisig (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinMultisig (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
 	fn register_public_key	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `365`
 		//  Estimated: `3546`
 		// Minimum execution time: 30_792_000 picoseconds.
 		Weight::from_parts(31_042_000, 3546)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: VaultRegistry Vaults (r:1 w:1)
@@ -396,13 +398,15 @@ impl<T: frame_system::Config> vault_registry::WeightInfo for WeightInfo<T> {
 	/// Proof: VaultRegistry SystemCollateralCeiling (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
 	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PartialLiquidationPenalty (r:1 w:0)
+	/// Proof: VaultRegistry PartialLiquidationPenalty (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	fn report_undercollateralized_vault	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4926`
 		//  Estimated: `8799`
 		// Minimum execution time: 1_391_186_000 picoseconds.
 		Weight::from_parts(1_425_946_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(57_u64))
+			.saturating_add(T::DbWeight::get().reads(58_u64))
 			.saturating_add(T::DbWeight::get().writes(30_u64))
 	}
 	/// Storage: VaultRegistry Vaults (r:1 w:1)
@@ -416,4 +420,316 @@ impl<T: frame_system::Config> vault_registry::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: VaultRegistry PendingPublicKeyRotation (r:1 w:1)
+	/// Proof: VaultRegistry PendingPublicKeyRotation (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:1 w:1)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry RetiredBitcoinPublicKeys (r:0 w:1)
+	/// Proof: VaultRegistry RetiredBitcoinPublicKeys (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn propose_public_key	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1424`
+		//  Estimated: `3550`
+		// Minimum execution time: 47_310_000 picoseconds.
+		Weight::from_parts(48_072_000, 3550)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:1 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinMultisig (r:1 w:1)
+	/// Proof: VaultRegistry VaultBitcoinMultisig (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
+	fn register_multisig_public_keys	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1390`
+		//  Estimated: `5609`
+		// Minimum execution time: 58_913_000 picoseconds.
+		Weight::from_parts(59_604_000, 5609)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry PartialLiquidationPenalty (r:0 w:1)
+	/// Proof: VaultRegistry PartialLiquidationPenalty (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn set_partial_liquidation_penalty	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_344_000 picoseconds.
+		Weight::from_parts(20_911_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn set_auction_parameters	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 21_032_000 picoseconds.
+		Weight::from_parts(21_587_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:1 w:0)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	fn start_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `8232`
+		// Minimum execution time: 38_604_000 picoseconds.
+		Weight::from_parts(39_251_000, 8232)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof: Loans UnderlyingAssetId (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:2 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof: Loans MinExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof: Loans MaxExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof: Loans RewardSupplyState (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof: Loans RewardSupplySpeed (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplierIndex (r:2 w:2)
+	/// Proof: Loans RewardSupplierIndex (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans RewardAccrued (r:2 w:2)
+	/// Proof: Loans RewardAccrued (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	fn bid_on_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3518`
+		//  Estimated: `60746`
+		// Minimum execution time: 268_417_000 picoseconds.
+		Weight::from_parts(271_904_000, 60746)
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:0)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardPerToken (r:2 w:0)
+	/// Proof: VaultCapacity RewardPerToken (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardTally (r:2 w:2)
+	/// Proof: VaultCapacity RewardTally (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalRewards (r:2 w:2)
+	/// Proof: VaultCapacity TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:0)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:0)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalRewards (r:2 w:2)
+	/// Proof: VaultRewards TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Fee Commission (r:1 w:0)
+	/// Proof: Fee Commission (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardPerToken (r:2 w:2)
+	/// Proof: VaultStaking RewardPerToken (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:0)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof: Loans UnderlyingAssetId (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof: Loans MinExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof: Loans MaxExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry ExitingVaults (r:0 w:1)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	fn request_vault_exit	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3665`
+		//  Estimated: `6260`
+		// Minimum execution time: 372_286_000 picoseconds.
+		Weight::from_parts(375_271_000, 6260)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: VaultRegistry ExitingVaults (r:2 w:1)
+	/// Proof: VaultRegistry ExitingVaults (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:1)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry MinimumCollateralVault (r:1 w:0)
+	/// Proof: VaultRegistry MinimumCollateralVault (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof: Loans UnderlyingAssetId (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof: Loans MinExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof: Loans MaxExchangeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nomination NominationEnabled (r:1 w:0)
+	/// Proof: Nomination NominationEnabled (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:1)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardPerToken (r:2 w:0)
+	/// Proof: VaultCapacity RewardPerToken (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardTally (r:2 w:2)
+	/// Proof: VaultCapacity RewardTally (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalRewards (r:2 w:2)
+	/// Proof: VaultCapacity TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:1)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardCurrencies (r:1 w:1)
+	/// Proof: VaultRewards RewardCurrencies (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:2)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalRewards (r:2 w:2)
+	/// Proof: VaultRewards TotalRewards (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Fee Commission (r:1 w:1)
+	/// Proof: Fee Commission (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardPerToken (r:2 w:2)
+	/// Proof: VaultStaking RewardPerToken (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalRewards (r:2 w:2)
+	/// Proof: VaultStaking TotalRewards (max_values: None, max_size: Some(117), added: 2592, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashPerToken (r:1 w:0)
+	/// Proof: VaultStaking SlashPerToken (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashTally (r:1 w:1)
+	/// Proof: VaultStaking SlashTally (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalStake (r:1 w:1)
+	/// Proof: VaultStaking TotalStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardTally (r:2 w:2)
+	/// Proof: VaultStaking RewardTally (max_values: None, max_size: Some(149), added: 2624, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalStake (r:1 w:1)
+	/// Proof: VaultCapacity TotalStake (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity RewardCurrencies (r:1 w:0)
+	/// Proof: VaultCapacity RewardCurrencies (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof: Loans RewardSupplyState (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof: Loans RewardSupplySpeed (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplierIndex (r:2 w:2)
+	/// Proof: Loans RewardSupplierIndex (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans RewardAccrued (r:2 w:2)
+	/// Proof: Loans RewardAccrued (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:0 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Fee VaultIssueFee (r:0 w:1)
+	/// Proof: Fee VaultIssueFee (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Fee VaultRedeemFee (r:0 w:1)
+	/// Proof: Fee VaultRedeemFee (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultLiquidationPriceBucket (r:1 w:0)
+	/// Proof: VaultRegistry VaultLiquidationPriceBucket (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry AccountVaultCount (r:1 w:1)
+	/// Proof: VaultRegistry AccountVaultCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:0 w:1)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinMultisig (r:0 w:1)
+	/// Proof: VaultRegistry VaultBitcoinMultisig (max_values: None, max_size: Some(578), added: 3053, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PendingPublicKeyRotation (r:0 w:1)
+	/// Proof: VaultRegistry PendingPublicKeyRotation (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry RetiredBitcoinPublicKeys (r:9 w:9)
+	/// Proof: VaultRegistry RetiredBitcoinPublicKeys (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry PublicKeyMigrationDeadline (r:0 w:1)
+	/// Proof: VaultRegistry PublicKeyMigrationDeadline (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn remove_exited_vault	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5912`
+		//  Estimated: `28960`
+		// Minimum execution time: 412_507_000 picoseconds.
+		Weight::from_parts(421_358_000, 28960)
+			.saturating_add(T::DbWeight::get().reads(75_u64))
+			.saturating_add(T::DbWeight::get().writes(54_u64))
+	}
 }
\ No newline at end of file
@@ -4,7 +4,7 @@ use codec::{Decode, Encode};
 use cumulus_primitives_core::ParaId;
 use frame_support::{
     parameter_types,
-    traits::{Everything, Get, Nothing},
+    traits::{ConstU32, Contains, EnsureOrigin, Everything, Get, Nothing},
 };
 use orml_asset_registry::{AssetRegistryTrader, FixedRateAssetRegistryTrader};
 use orml_traits::{
@@ -13,16 +13,20 @@ use orml_traits::{
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
-use runtime_common::Transactless;
+use runtime_common::TransactlessExcept;
 use xcm::latest::{prelude::*, Weight};
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
-    EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, ParentIsPreset, RelayChainAsNative,
-    SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-    SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
+    DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, HashedDescription,
+    ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
+    WithComputedOrigin,
 };
 pub use xcm_executor;
-use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
+use xcm_executor::{
+    traits::{ConvertLocation, WithOriginFilter},
+    XcmExecutor,
+};
 
 parameter_types! {
     pub const ParentLocation: MultiLocation = MultiLocation::parent();
@@ -62,12 +66,50 @@ pub type XcmOriginToTransactDispatchOrigin = (
     XcmPassthrough<RuntimeOrigin>,
 );
 
-pub type Barrier = Transactless<(
-    TakeWeightCredit,
-    AllowTopLevelPaidExecutionFrom<Everything>,
-    AllowKnownQueryResponses<PolkadotXcm>,
-    AllowSubscriptionsFrom<Everything>,
-)>; // required for others to keep track of our xcm version
+pub type Barrier = TransactlessExcept<
+    (
+        TakeWeightCredit,
+        // allow paid execution from accounts on other chains, which descend from their chain's origin
+        WithComputedOrigin<AllowTopLevelPaidExecutionFrom<Everything>, UniversalLocation, ConstU32<8>>,
+        AllowKnownQueryResponses<PolkadotXcm>,
+        AllowSubscriptionsFrom<Everything>, // required for others to keep track of our xcm version
+    ),
+    RuntimeCall,
+    RemoteRequestCalls,
+>;
+
+/// Calls that other chains may dispatch through `Transact`.
+pub struct RemoteRequestCalls;
+impl Contains<RuntimeCall> for RemoteRequestCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::RemoteRequests(..))
+    }
+}
+
+/// Converts the origin of a remote request, e.g. an account on a sibling parachain, to the
+/// hash of its description. Only used by `EnsureRemoteAccount`, such that these accounts can
+/// not be used to transact assets or to dispatch other calls.
+pub type RemoteLocationToAccountId = HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>;
+
+/// Ensures the origin of a `Transact` with `OriginKind::Xcm`, resolving it to its location and
+/// the local account derived from that location.
+pub struct EnsureRemoteAccount;
+impl EnsureOrigin<RuntimeOrigin> for EnsureRemoteAccount {
+    type Success = (MultiLocation, AccountId);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let location = EnsureXcm::<Everything>::try_origin(o)?;
+        match RemoteLocationToAccountId::convert_location(&location) {
+            Some(account_id) => Ok((location, account_id)),
+            None => Err(pallet_xcm::Origin::Xcm(location).into()),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(pallet_xcm::Origin::Xcm(MultiLocation::new(1, X1(Parachain(2000)))).into())
+    }
+}
 
 parameter_types! {
     // One XCM operation is 200_000_000 weight, cross-chain transfer ~= 2x of transfer.
@@ -180,7 +222,11 @@ impl Contains<RuntimeCall> for SafeCallFilter {
                 // these calls can recurse - disallow
                 false
             }
-            RuntimeCall::Issue(..) | RuntimeCall::Replace(..) | RuntimeCall::Redeem(..) | RuntimeCall::BTCRelay(..) => {
+            RuntimeCall::Issue(..)
+            | RuntimeCall::Replace(..)
+            | RuntimeCall::Redeem(..)
+            | RuntimeCall::BTCRelay(..)
+            | RuntimeCall::Relay(..) => {
                 // disallow anything to do with btc transactions since btc tx may be unbounded
                 false
             }
@@ -439,6 +485,33 @@ impl orml_xtokens::Config for Runtime {
     type UniversalLocation = UniversalLocation;
 }
 
+/// Forwards issued tokens to other chains through xtokens. Execution on the destination chain
+/// is paid from the transferred tokens.
+pub struct IssueXcmTransfer;
+
+impl traits::XcmTransferApi<AccountId, CurrencyId, Balance, MultiLocation> for IssueXcmTransfer {
+    fn transfer(
+        who: AccountId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        dest: MultiLocation,
+    ) -> sp_runtime::DispatchResult {
+        <XTokens as orml_traits::XcmTransfer<AccountId, Balance, CurrencyId>>::transfer(
+            who,
+            currency_id,
+            amount,
+            dest,
+            WeightLimit::Unlimited,
+        )
+        .map(|_| ())
+    }
+
+    fn transfer_weight() -> Weight {
+        // xtokens weighs the local message with the same bounds
+        UnitWeightCost::get().saturating_mul(MaxInstructions::get() as u64)
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 use benchmark_impls::*;
 
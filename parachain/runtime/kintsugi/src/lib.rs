@@ -10,7 +10,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 pub mod weights;
 
-use bitcoin::types::H256Le;
+use bitcoin::types::{FullTransactionProof, H256Le};
 use currency::Amount;
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
@@ -75,13 +75,17 @@ pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{FixedU128, Perbill, Permill};
 
 // interBTC exports
-pub use btc_relay::{bitcoin, Call as BtcRelayCall, TARGET_SPACING};
+pub use btc_relay::{bitcoin, Call as BtcRelayCall, DEFAULT_STALE_FORK_DEPTH, TARGET_SPACING};
+pub use btc_relay_rpc_runtime_api::{BlockId, RelayedBlockHeader, RelayedChain, VerifiedTransactionOutput};
 pub use constants::{currency::*, time::*};
 pub use oracle_rpc_runtime_api::BalanceWrapper;
 pub use orml_asset_registry::AssetMetadata;
+pub use vault_registry_rpc_runtime_api::{VaultHealth, VaultStatistics};
 
 pub use primitives::{
-    self, AccountId, Balance, BlockNumber,
+    self,
+    query::{RequestPage, RequestQuery},
+    AccountId, Balance, BlockNumber,
     CurrencyId::{ForeignAsset, LendToken, Token},
     CurrencyInfo, Hash, Liquidity, Moment, Nonce, Rate, Ratio, Shortfall, Signature, SignedFixedPoint, SignedInner,
     StablePoolId, UnsignedFixedPoint, UnsignedInner,
@@ -116,9 +120,9 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("kintsugi-parachain"),
     impl_name: create_runtime_str!("kintsugi-parachain"),
     authoring_version: 1,
-    spec_version: 1025002,
+    spec_version: 1025003,
     impl_version: 1,
-    transaction_version: 4,
+    transaction_version: 5,
     apis: RUNTIME_API_VERSIONS,
     state_version: 0,
 };
@@ -1101,11 +1105,19 @@ impl pallet_proxy::Config for Runtime {
     type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+parameter_types! {
+    pub const PublicKeyRotationDelay: BlockNumber = DAYS;
+    pub const PublicKeyMigrationPeriod: BlockNumber = 7 * DAYS;
+}
+
 impl vault_registry::Config for Runtime {
     type PalletId = VaultRegistryPalletId;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::vault_registry::WeightInfo<Runtime>;
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type PublicKeyRotationDelay = PublicKeyRotationDelay;
+    type PublicKeyMigrationPeriod = PublicKeyMigrationPeriod;
+    type ReplaceApi = Replace;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -1142,6 +1154,12 @@ impl fee::Config for Runtime {
     type NominationApi = Nomination;
 }
 
+parameter_types! {
+    pub const RequestRetentionPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxSweptRequestsPerBlock: u32 = 50;
+    pub const MaxReplaceAuctionsPerBlock: u32 = 20;
+}
+
 pub use issue::IssueRequest;
 
 impl issue::Config for Runtime {
@@ -1149,6 +1167,9 @@ impl issue::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type BlockNumberToBalance = BlockNumberToBalance;
     type WeightInfo = weights::issue::WeightInfo<Runtime>;
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
+    type XcmTransfer = xcm_config::IssueXcmTransfer;
 }
 
 pub use redeem::RedeemRequest;
@@ -1156,6 +1177,14 @@ pub use redeem::RedeemRequest;
 impl redeem::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::redeem::WeightInfo<Runtime>;
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
+}
+
+impl remote_requests::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RemoteOrigin = xcm_config::EnsureRemoteAccount;
+    type WeightInfo = weights::remote_requests::WeightInfo<Runtime>;
 }
 
 pub use replace::ReplaceRequest;
@@ -1163,6 +1192,14 @@ pub use replace::ReplaceRequest;
 impl replace::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::replace::WeightInfo<Runtime>;
+    type RequestRetentionPeriod = RequestRetentionPeriod;
+    type MaxSweptRequestsPerBlock = MaxSweptRequestsPerBlock;
+    type MaxReplaceAuctionsPerBlock = MaxReplaceAuctionsPerBlock;
+}
+
+impl relay::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = weights::relay::WeightInfo<Runtime>;
 }
 
 pub use nomination::Event as NominationEvent;
@@ -1245,7 +1282,7 @@ construct_runtime! {
 
         // # Bitcoin SPV
         BTCRelay: btc_relay::{Pallet, Call, Config<T>, Storage, Event<T>} = 50,
-        // Relay: 51
+        Relay: relay::{Pallet, Call, Storage, Event<T>} = 51,
 
         // # Operational
         Security: security::{Pallet, Call, Storage, Event<T>} = 60,
@@ -1281,6 +1318,7 @@ construct_runtime! {
 
         XTokens: orml_xtokens::{Pallet, Storage, Call, Event<T>} = 94,
         UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 95,
+        RemoteRequests: remote_requests::{Pallet, Call, Event<T>} = 96,
 
         // # Lending & AMM
         Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>} = 100,
@@ -1326,7 +1364,19 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    (evm::SetEvmChainId<Runtime>,),
+    (
+        evm::SetEvmChainId<Runtime>,
+        btc_relay::migration::v1::Migration<Runtime>,
+        btc_relay::migration::v2::Migration<Runtime>,
+        btc_relay::migration::v3::Migration<Runtime>,
+        issue::types::v4::Migration<Runtime>,
+        issue::types::v5::Migration<Runtime>,
+        issue::types::v6::Migration<Runtime>,
+        redeem::types::v0::Migration<Runtime>,
+        redeem::types::v1::Migration<Runtime>,
+        replace::types::v0::Migration<Runtime>,
+        replace::types::v1::Migration<Runtime>,
+    ),
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1434,6 +1484,8 @@ mod benches {
         [nomination, Nomination]
         [oracle, Oracle]
         [redeem, Redeem]
+        [relay, Relay]
+        [remote_requests, RemoteRequests]
         [replace, Replace]
         [security, Security]
         [supply, Supply]
@@ -1461,6 +1513,53 @@ mod benches {
     );
 }
 
+#[cfg(not(feature = "disable-runtime-api"))]
+fn relayed_block_header(
+    rich_header: btc_relay::RichBlockHeader<BlockNumber>,
+) -> RelayedBlockHeader<H256Le, BlockNumber> {
+    let block_header = rich_header.block_header;
+    RelayedBlockHeader {
+        hash: block_header.hash,
+        hash_prev_block: block_header.hash_prev_block,
+        merkle_root: block_header.merkle_root,
+        version: block_header.version,
+        timestamp: block_header.timestamp,
+        target: block_header.target,
+        nonce: block_header.nonce,
+        block_height: rich_header.block_height,
+        chain_id: rich_header.chain_id,
+        para_height: rich_header.para_height,
+        chain_work: rich_header.chain_work,
+    }
+}
+
+#[cfg(not(feature = "disable-runtime-api"))]
+fn vault_health(
+    health: vault_registry::VaultHealth<VaultId, Balance, UnsignedFixedPoint>,
+) -> VaultHealth<VaultId, Balance, UnsignedFixedPoint> {
+    VaultHealth {
+        vault_id: health.vault_id,
+        collateral: BalanceWrapper {
+            amount: health.collateral,
+        },
+        nominated_collateral: BalanceWrapper {
+            amount: health.nominated_collateral,
+        },
+        issued_tokens: BalanceWrapper {
+            amount: health.issued_tokens,
+        },
+        to_be_issued_tokens: BalanceWrapper {
+            amount: health.to_be_issued_tokens,
+        },
+        to_be_redeemed_tokens: BalanceWrapper {
+            amount: health.to_be_redeemed_tokens,
+        },
+        collateralization: health.collateralization,
+        premium_redeem_exchange_rate: health.premium_redeem_exchange_rate,
+        liquidation_exchange_rate: health.liquidation_exchange_rate,
+    }
+}
+
 #[cfg(not(feature = "disable-runtime-api"))]
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
@@ -1636,13 +1735,57 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl btc_relay_rpc_runtime_api::BtcRelayApi<
         Block,
         H256Le,
+        BlockNumber,
+        FullTransactionProof,
     > for Runtime {
         fn verify_block_header_inclusion(block_hash: H256Le) -> Result<(), DispatchError> {
             BTCRelay::verify_block_header_inclusion(block_hash, None).map(|_| ())
         }
+
+        fn get_best_block() -> Result<RelayedBlockHeader<H256Le, BlockNumber>, DispatchError> {
+            let rich_header = BTCRelay::get_block_header_from_hash(BTCRelay::get_best_block())?;
+            Ok(relayed_block_header(rich_header))
+        }
+
+        fn get_block_header(block_id: BlockId<H256Le>) -> Result<RelayedBlockHeader<H256Le, BlockNumber>, DispatchError> {
+            let rich_header = match block_id {
+                BlockId::Hash(block_hash) => BTCRelay::get_block_header_from_hash(block_hash)?,
+                BlockId::Height(block_height) => BTCRelay::get_main_chain_block_header(block_height)?,
+            };
+            Ok(relayed_block_header(rich_header))
+        }
+
+        fn get_chains() -> Vec<RelayedChain> {
+            BTCRelay::get_block_chains()
+                .into_iter()
+                .map(|chain| RelayedChain {
+                    chain_id: chain.chain_id,
+                    start_height: chain.start_height,
+                    max_height: chain.max_height,
+                    chain_work: chain.chain_work,
+                })
+                .collect()
+        }
+
+        fn get_confirmations(block_hash: H256Le) -> Result<u32, DispatchError> {
+            BTCRelay::get_block_confirmations(block_hash)
+        }
+
+        fn verify_transaction_inclusion(proof: FullTransactionProof) -> Result<Vec<VerifiedTransactionOutput>, DispatchError> {
+            let transaction = BTCRelay::_verify_transaction_inclusion(proof, None)?;
+            Ok(transaction
+                .outputs
+                .into_iter()
+                .map(|output| VerifiedTransactionOutput {
+                    value: output.value,
+                    script: output.script.as_bytes().to_vec(),
+                })
+                .collect())
+        }
     }
 
     impl oracle_rpc_runtime_api::OracleApi<
@@ -1717,6 +1860,62 @@ impl_runtime_apis! {
             let result = VaultRegistry::get_required_collateral_for_vault(vault_id)?;
             Ok(BalanceWrapper{amount:result.amount()})
         }
+
+        fn get_auctioned_collateral(currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let currency_pair = primitives::VaultCurrencyPair { collateral: currency_id, wrapped: GetWrappedCurrencyId::get() };
+            let result = VaultRegistry::get_auctioned_collateral(&currency_pair)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
+
+        fn get_auction_bid_price(collateral: BalanceWrapper<Balance>, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let currency_pair = primitives::VaultCurrencyPair { collateral: currency_id, wrapped: GetWrappedCurrencyId::get() };
+            let collateral = Amount::new(collateral.amount, currency_id);
+            let result = VaultRegistry::get_auction_bid_price(&currency_pair, &collateral)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
+
+        fn get_vault_health(vault_id: VaultId) -> Result<VaultHealth<VaultId, Balance, UnsignedFixedPoint>, DispatchError> {
+            Ok(vault_health(VaultRegistry::get_vault_health(&vault_id)?))
+        }
+
+        fn get_vaults_health() -> Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>> {
+            VaultRegistry::get_vaults_health().into_iter().map(vault_health).collect()
+        }
+
+        fn get_vaults_health_by_account_id(account_id: AccountId) -> Result<Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>>, DispatchError> {
+            let result = VaultRegistry::get_vaults_health_by_account_id(account_id)?;
+            Ok(result.into_iter().map(vault_health).collect())
+        }
+
+        fn get_vaults_below_threshold(threshold: UnsignedFixedPoint) -> Vec<VaultHealth<VaultId, Balance, UnsignedFixedPoint>> {
+            VaultRegistry::get_vaults_below_threshold(threshold).into_iter().map(vault_health).collect()
+        }
+
+        fn get_issue_fee_quote(vault_id: VaultId, amount_btc: BalanceWrapper<Balance>) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let amount_btc = Amount::new(amount_btc.amount, vault_id.wrapped_currency());
+            let result = Fee::get_vault_issue_fee(&vault_id, &amount_btc)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
+
+        fn get_redeem_fee_quote(vault_id: VaultId, amount_btc: BalanceWrapper<Balance>) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let amount_btc = Amount::new(amount_btc.amount, vault_id.wrapped_currency());
+            let result = Fee::get_vault_redeem_fee(&vault_id, &amount_btc)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
+
+        fn get_vault_statistics(vault_id: VaultId) -> Result<VaultStatistics, DispatchError> {
+            let stats = VaultRegistry::get_vault_statistics(&vault_id)?;
+            Ok(VaultStatistics {
+                executed_issues: stats.executed_issues,
+                cancelled_issues: stats.cancelled_issues,
+                executed_redeems: stats.executed_redeems,
+                cancelled_redeems: stats.cancelled_redeems,
+                average_redeem_execution_time: stats.average_redeem_execution_time(),
+                executed_replaces: stats.executed_replaces,
+                cancelled_replaces: stats.cancelled_replaces,
+                punishments: stats.punishments,
+            })
+        }
     }
 
     impl escrow_rpc_runtime_api::EscrowApi<
@@ -1792,11 +1991,13 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl issue_rpc_runtime_api::IssueApi<
         Block,
         AccountId,
         H256,
-        IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>
+        IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, issue::IssueRequestStatus>
     > for Runtime {
         fn get_issue_requests(account_id: AccountId) -> Vec<H256> {
             Issue::get_issue_requests_for_account(account_id)
@@ -1805,15 +2006,31 @@ impl_runtime_apis! {
         fn get_vault_issue_requests(vault_id: AccountId) -> Vec<H256> {
             Issue::get_issue_requests_for_vault(vault_id)
         }
+
+        fn get_issue_requests_page(
+            account_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, issue::IssueRequestStatus>,
+        ) -> RequestPage<H256, IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Issue::get_issue_requests_page_for_account(account_id, query)
+        }
+
+        fn get_vault_issue_requests_page(
+            vault_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, issue::IssueRequestStatus>,
+        ) -> RequestPage<H256, IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Issue::get_issue_requests_page_for_vault(vault_id, query)
+        }
     }
 
+    #[api_version(2)]
     impl redeem_rpc_runtime_api::RedeemApi<
         Block,
         VaultId,
         Balance,
         AccountId,
         H256,
-        RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>
+        RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, redeem::RedeemRequestStatus>
     > for Runtime {
         fn get_redeem_requests(account_id: AccountId) -> Vec<H256> {
             Redeem::get_redeem_requests_for_account(account_id)
@@ -1823,6 +2040,20 @@ impl_runtime_apis! {
             Redeem::get_redeem_requests_for_vault(account_id)
         }
 
+        fn get_redeem_requests_page(
+            account_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, redeem::RedeemRequestStatus>,
+        ) -> RequestPage<H256, RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Redeem::get_redeem_requests_page_for_account(account_id, query)
+        }
+
+        fn get_vault_redeem_requests_page(
+            vault_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, redeem::RedeemRequestStatus>,
+        ) -> RequestPage<H256, RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Redeem::get_redeem_requests_page_for_vault(vault_id, query)
+        }
+
 
         fn get_premium_redeem_vaults() -> Result<Vec<(VaultId, BalanceWrapper<Balance>)>, DispatchError> {
             let result = Redeem::get_premium_redeem_vaults()?;
@@ -1830,11 +2061,13 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl replace_rpc_runtime_api::ReplaceApi<
         Block,
         AccountId,
         H256,
-        ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>
+        ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, replace::ReplaceRequestStatus>
     > for Runtime {
         fn get_old_vault_replace_requests(vault_id: AccountId) -> Vec<H256> {
             Replace::get_replace_requests_for_old_vault(vault_id)
@@ -1843,6 +2076,20 @@ impl_runtime_apis! {
         fn get_new_vault_replace_requests(vault_id: AccountId) -> Vec<H256> {
             Replace::get_replace_requests_for_new_vault(vault_id)
         }
+
+        fn get_old_vault_replace_requests_page(
+            vault_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, replace::ReplaceRequestStatus>,
+        ) -> RequestPage<H256, ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Replace::get_replace_requests_page_for_old_vault(vault_id, query)
+        }
+
+        fn get_new_vault_replace_requests_page(
+            vault_id: AccountId,
+            query: RequestQuery<AccountId, CurrencyId, replace::ReplaceRequestStatus>,
+        ) -> RequestPage<H256, ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>> {
+            Replace::get_replace_requests_page_for_new_vault(vault_id, query)
+        }
     }
 
     impl loans_rpc_runtime_api::LoansApi<
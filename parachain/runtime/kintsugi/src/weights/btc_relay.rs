@@ -42,8 +42,8 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 
 	/// Storage: BTCRelay BestBlock (r:1 w:1)
 	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainCounter (r:1 w:1)
 	/// Proof: BTCRelay ChainCounter (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:1 w:1)
@@ -75,8 +75,8 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:1 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -100,8 +100,8 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:1)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -130,8 +130,8 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay Chains (r:7 w:6)
 	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -161,8 +161,8 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsIndex (r:3 w:2)
 	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-	/// Storage: BTCRelay DisableDifficultyCheck (r:1 w:0)
-	/// Proof: BTCRelay DisableDifficultyCheck (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: BTCRelay ChainsHashes (r:13 w:24)
 	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	/// Storage: Security ActiveBlockCount (r:1 w:0)
@@ -189,4 +189,111 @@ impl<T: frame_system::Config> btc_relay::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(46_u64))
 			.saturating_add(Weight::from_parts(0, 1340).saturating_mul(f.into()))
 	}
+	/// Storage: BTCRelay Chains (r:7 w:6)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:101 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:106 w:100)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BitcoinNetwork (r:1 w:0)
+	/// Proof: BTCRelay BitcoinNetwork (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:100 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlock (r:1 w:0)
+	/// Proof: BTCRelay BestBlock (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `h` is `[1, 100]`.
+	/// The range of component `f` is `[1, 6]`.
+	fn store_block_headers	(h: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1786 + f * (99 ±0) + h * (139 ±0)`
+		//  Estimated: `6340 + f * (2499 ±0) + h * (5222 ±0)`
+		// Minimum execution time: 96_305_000 picoseconds.
+		Weight::from_parts(21_618_305, 6340)
+			// Standard Error: 11_052
+			.saturating_add(Weight::from_parts(58_126_440, 0).saturating_mul(h.into()))
+			// Standard Error: 207_413
+			.saturating_add(Weight::from_parts(6_011_871, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 5222).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StaleForkDepth (r:0 w:1)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:0 w:1)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_pruning_config	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_036_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:7 w:1)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:6 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:5 w:100)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:10 w:100)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// The range of component `f` is `[2, 6]`.
+	/// The range of component `h` is `[1, 100]`.
+	fn prune_stale_fork	(f: u32, h: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1164 + f * (229 ±0) + h * (108 ±0)`
+		//  Estimated: `6330 + f * (7721 ±0) + h * (2547 ±0)`
+		// Minimum execution time: 71_507_000 picoseconds.
+		Weight::from_parts(38_290_518, 6330)
+			// Standard Error: 174_212
+			.saturating_add(Weight::from_parts(16_842_307, 0).saturating_mul(f.into()))
+			// Standard Error: 6_891
+			.saturating_add(Weight::from_parts(2_937_646, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 7721).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(h.into()))
+	}
+	/// Storage: BTCRelay StaleForkDepth (r:1 w:0)
+	/// Proof: BTCRelay StaleForkDepth (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay MainChainRetention (r:1 w:0)
+	/// Proof: BTCRelay MainChainRetention (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay Chains (r:1 w:0)
+	/// Proof: BTCRelay Chains (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsIndex (r:2 w:1)
+	/// Proof: BTCRelay ChainsIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: BTCRelay ChainsHashes (r:1 w:1)
+	/// Proof: BTCRelay ChainsHashes (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BlockHeaders (r:0 w:1)
+	/// Proof: BTCRelay BlockHeaders (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	fn prune_main_chain_header	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `6004`
+		// Minimum execution time: 43_719_000 picoseconds.
+		Weight::from_parts(45_106_000, 6004)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
@@ -85,6 +85,61 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(18_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: VaultRegistry Vaults (r:101 w:5)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:100 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:100 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Security Nonce (r:1 w:1)
+	/// Proof: Security Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StartBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay StartBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay StableBitcoinConfirmations (r:1 w:0)
+	/// Proof: BTCRelay StableBitcoinConfirmations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BTCRelay BestBlockHeight (r:1 w:0)
+	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Fee IssueGriefingCollateral (r:1 w:0)
+	/// Proof: Fee IssueGriefingCollateral (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Issue IssueBtcDustValue (r:1 w:0)
+	/// Proof: Issue IssueBtcDustValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Fee IssueFee (r:1 w:0)
+	/// Proof: Fee IssueFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultBitcoinPublicKey (r:5 w:0)
+	/// Proof: VaultRegistry VaultBitcoinPublicKey (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssuePeriod (r:1 w:0)
+	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Issue IssueRequests (r:0 w:5)
+	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CurrencyPairVaults (r:100 w:0)
+	/// Proof: VaultRegistry CurrencyPairVaults (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// The range of component `v` is `[5, 100]`.
+	fn request_issue_auto	(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3918 + v * (343 ±0)`
+		//  Estimated: `26156 + v * (7865 ±0)`
+		// Minimum execution time: 2_418_306_000 picoseconds.
+		Weight::from_parts(2_134_773_204, 26156)
+			// Standard Error: 61_482
+			.saturating_add(Weight::from_parts(57_103_865, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(39_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(Weight::from_parts(0, 7865).saturating_mul(v.into()))
+	}
+
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
 	/// Storage: BTCRelay DisableInclusionCheck (r:1 w:0)
@@ -109,6 +164,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -116,9 +173,9 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_issue_exact	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2434`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 198_538_000 picoseconds.
-		Weight::from_parts(171_478_384, 3737)
+		Weight::from_parts(171_478_384, 6314)
 			// Standard Error: 67_279
 			.saturating_add(Weight::from_parts(3_496_696, 0).saturating_mul(h.into()))
 			// Standard Error: 60_626
@@ -127,8 +184,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(420_219, 0).saturating_mul(o.into()))
 			// Standard Error: 435
 			.saturating_add(Weight::from_parts(5_157, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(12_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -164,6 +221,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -171,9 +230,9 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_issue_overpayment	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3285`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 316_013_000 picoseconds.
-		Weight::from_parts(296_206_373, 3737)
+		Weight::from_parts(296_206_373, 6314)
 			// Standard Error: 170_956
 			.saturating_add(Weight::from_parts(3_648_998, 0).saturating_mul(h.into()))
 			// Standard Error: 154_049
@@ -182,8 +241,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(440_368, 0).saturating_mul(o.into()))
 			// Standard Error: 1_105
 			.saturating_add(Weight::from_parts(1_242, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(17_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -211,6 +270,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -218,17 +279,17 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_issue_underpayment	(h: u32, i: u32, o: u32, _b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2739`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 236_814_000 picoseconds.
-		Weight::from_parts(218_087_002, 3737)
+		Weight::from_parts(218_087_002, 6314)
 			// Standard Error: 143_849
 			.saturating_add(Weight::from_parts(3_739_468, 0).saturating_mul(h.into()))
 			// Standard Error: 129_622
 			.saturating_add(Weight::from_parts(1_289_008, 0).saturating_mul(i.into()))
 			// Standard Error: 129_622
 			.saturating_add(Weight::from_parts(452_541, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -254,6 +315,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -261,9 +324,9 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_expired_issue_exact	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3567`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 206_463_000 picoseconds.
-		Weight::from_parts(179_023_981, 3737)
+		Weight::from_parts(179_023_981, 6314)
 			// Standard Error: 118_695
 			.saturating_add(Weight::from_parts(3_632_454, 0).saturating_mul(h.into()))
 			// Standard Error: 106_956
@@ -272,8 +335,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(185_589, 0).saturating_mul(o.into()))
 			// Standard Error: 767
 			.saturating_add(Weight::from_parts(6_936, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(12_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -309,6 +372,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -316,9 +381,9 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_expired_issue_overpayment	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4039 + h * (17 ±0) + i * (2 ±0)`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 326_534_000 picoseconds.
-		Weight::from_parts(312_629_634, 3737)
+		Weight::from_parts(312_629_634, 6314)
 			// Standard Error: 118_522
 			.saturating_add(Weight::from_parts(3_380_960, 0).saturating_mul(h.into()))
 			// Standard Error: 106_800
@@ -327,8 +392,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(164_257, 0).saturating_mul(o.into()))
 			// Standard Error: 766
 			.saturating_add(Weight::from_parts(3_419, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(17_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -356,6 +421,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	/// The range of component `h` is `[2, 10]`.
 	/// The range of component `i` is `[1, 10]`.
 	/// The range of component `o` is `[1, 10]`.
@@ -363,9 +430,9 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	fn execute_expired_issue_underpayment	(h: u32, i: u32, o: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3633 + o * (11 ±0)`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 245_292_000 picoseconds.
-		Weight::from_parts(218_248_263, 3737)
+		Weight::from_parts(218_248_263, 6314)
 			// Standard Error: 75_656
 			.saturating_add(Weight::from_parts(3_638_323, 0).saturating_mul(h.into()))
 			// Standard Error: 68_174
@@ -374,8 +441,8 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(378_537, 0).saturating_mul(o.into()))
 			// Standard Error: 489
 			.saturating_add(Weight::from_parts(4_279, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Issue IssueRequests (r:1 w:1)
 	/// Proof: Issue IssueRequests (max_values: None, max_size: Some(272), added: 2747, mode: MaxEncodedLen)
@@ -387,14 +454,16 @@ impl<T: frame_system::Config> issue::WeightInfo for WeightInfo<T> {
 	/// Proof: BTCRelay BestBlockHeight (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: VaultRegistry Vaults (r:1 w:1)
 	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultStats (r:1 w:1)
+	/// Proof: VaultRegistry VaultStats (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
 	fn cancel_issue	() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1495`
-		//  Estimated: `3737`
+		//  Estimated: `6314`
 		// Minimum execution time: 91_653_000 picoseconds.
-		Weight::from_parts(93_397_000, 3737)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(93_397_000, 6314)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Issue IssuePeriod (r:0 w:1)
 	/// Proof: Issue IssuePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
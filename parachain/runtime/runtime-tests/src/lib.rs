@@ -4,5 +4,7 @@
 
 mod bitcoin_data;
 mod parachain;
+#[cfg(not(feature = "with-interlay-runtime"))]
+mod relaychain;
 mod setup;
 mod utils;
@@ -2,7 +2,7 @@ use crate::{
     bitcoin_data::{get_bitcoin_testdata, get_fork_testdata},
     setup::{assert_eq, *},
 };
-use bitcoin::{formatter::TryFormat, merkle::PartialTransactionProof};
+use bitcoin::{formatter::TryFormat, merkle::PartialTransactionProof, Network};
 use btc_relay::DIFFICULTY_ADJUSTMENT_INTERVAL;
 
 #[test]
@@ -36,9 +36,9 @@ fn integration_test_transaction_formatting_and_txid_calculation() {
 #[cfg_attr(feature = "skip-slow-tests", ignore)]
 fn integration_test_submit_block_headers_and_verify_transaction_inclusion() {
     ExtBuilder::build().execute_without_relay_init(|| {
-        // ensure that difficulty check is enabled
-        BTCRelayPallet::set_disable_difficulty_check(false);
-        assert!(!BTCRelayPallet::disable_difficulty_check());
+        // the test data is taken from the bitcoin mainnet
+        BTCRelayPallet::set_bitcoin_network(Network::Mainnet);
+        assert_eq!(BTCRelayPallet::bitcoin_network(), Network::Mainnet);
 
         // reduce number of blocks to reduce testing time, but higher than 2016 blocks for difficulty adjustment
         const BLOCKS_TO_TEST: usize = 2 * 2016 + 1;
@@ -142,6 +142,7 @@ fn integration_test_submit_fork_headers() {
         // https://github.com/bitcoin/bitcoin/blob/d6a59166a1879c1dd5b3a301847961f4b3f17742/test/functional/p2p_dos_header_tree.py#L39
         let test_data = get_fork_testdata();
 
+        BTCRelayPallet::set_bitcoin_network(Network::Testnet);
         SecurityPallet::set_active_block_number(1);
 
         let genesis_height = 0;
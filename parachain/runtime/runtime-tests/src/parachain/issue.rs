@@ -159,6 +159,8 @@ mod request_issue_tests {
                     amount: 0,
                     vault_id: dummy_vault_id_of(),
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 IssueError::WaitingForRelayerInitialization
@@ -173,6 +175,8 @@ mod request_issue_tests {
                     amount: 0,
                     vault_id: dummy_vault_id_of(),
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 IssueError::WaitingForRelayerInitialization
@@ -191,6 +195,8 @@ mod request_issue_tests {
                     amount: amount,
                     vault_id: vault_id,
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 VaultRegistryError::VaultNotFound
@@ -212,6 +218,8 @@ mod request_issue_tests {
                     amount: 1000,
                     vault_id: vault_id.clone(),
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 IssueError::VaultNotAcceptingNewIssues
@@ -229,6 +237,8 @@ mod request_issue_tests {
                     amount: amount.amount(),
                     vault_id: vault_id,
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 IssueError::AmountBelowDustAmount
@@ -263,6 +273,8 @@ mod request_issue_tests {
                     amount: amount.amount(),
                     vault_id: vault_id,
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 VaultRegistryError::ExceedingVaultLimit
@@ -295,6 +307,8 @@ mod request_issue_tests {
                 amount: amount.amount(),
                 vault_id: vault_id.clone(),
                 griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                destination: None,
+                max_fee: None,
             })
             .dispatch(origin_of(account_of(USER))));
         });
@@ -336,6 +350,8 @@ mod request_issue_tests {
                     amount: amount.amount(),
                     vault_id: vault_id.clone(),
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 VaultRegistryError::ExceedingVaultLimit
@@ -345,6 +361,8 @@ mod request_issue_tests {
                     amount: original_amount.amount(),
                     vault_id: vault_id,
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 VaultRegistryError::ExceedingVaultLimit
@@ -374,6 +392,8 @@ mod request_issue_tests {
                     amount: amount_btc.amount(),
                     vault_id: vault_id.clone(),
                     griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                    destination: None,
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 TokensError::BalanceTooLow
@@ -453,6 +473,8 @@ mod request_issue_tests {
                 amount: amount_btc.amount(),
                 vault_id: different_collateral_vault_id.clone(),
                 griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+                destination: None,
+                max_fee: None,
             })
             .dispatch(origin_of(account_of(ALICE))));
         });
@@ -479,6 +501,8 @@ fn integration_test_issue_wrapped_execute_succeeds() {
             amount: amount_btc.amount(),
             vault_id: vault_id,
             griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+            destination: None,
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(USER))));
 
@@ -548,6 +572,8 @@ fn integration_test_withdraw_after_request_issue() {
             amount: amount_btc.amount(),
             vault_id: vault_id.clone(),
             griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+            destination: None,
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(ALICE))));
 
@@ -556,6 +582,8 @@ fn integration_test_withdraw_after_request_issue() {
             amount: amount_btc.amount(),
             vault_id: vault_id.clone(),
             griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+            destination: None,
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(ALICE)))
         .is_err());
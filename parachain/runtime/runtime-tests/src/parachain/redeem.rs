@@ -357,7 +357,8 @@ mod spec_based_tests {
                 assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
                     amount_wrapped: amount.amount(),
                     btc_address: BtcAddress::random(),
-                    vault_id: vault_id.clone()
+                    vault_id: vault_id.clone(),
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))));
 
@@ -391,7 +392,8 @@ mod spec_based_tests {
                     RuntimeCall::Redeem(RedeemCall::request_redeem {
                         amount_wrapped: amount,
                         btc_address: BtcAddress::random(),
-                        vault_id: vault_id.clone()
+                        vault_id: vault_id.clone(),
+                        max_fee: None,
                     })
                     .dispatch(origin_of(account_of(USER))),
                     VaultRegistryError::InsufficientTokensCommitted
@@ -409,6 +411,7 @@ mod spec_based_tests {
                         amount_wrapped: 1500,
                         btc_address: BtcAddress::random(),
                         vault_id: vault_id.clone(),
+                        max_fee: None,
                     })
                     .dispatch(origin_of(account_of(ALICE))),
                     VaultRegistryError::VaultLiquidated,
@@ -428,6 +431,7 @@ mod spec_based_tests {
                     amount_wrapped: free_tokens_to_redeem.amount(),
                     btc_address: BtcAddress::random(),
                     vault_id: vault_id.clone(),
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(ALICE))));
 
@@ -441,6 +445,7 @@ mod spec_based_tests {
                         amount_wrapped: free_tokens_to_redeem.amount(),
                         btc_address: BtcAddress::random(),
                         vault_id: vault_id.clone(),
+                        max_fee: None,
                     })
                     .dispatch(origin_of(account_of(ALICE))),
                     RedeemError::AmountExceedsUserBalance,
@@ -529,6 +534,7 @@ mod spec_based_tests {
                         amount_wrapped: user_to_redeem.amount(),
                         btc_address: BtcAddress::random(),
                         vault_id: vault_id.clone(),
+                        max_fee: None,
                     })
                     .dispatch(origin_of(account_of(ALICE))),
                     VaultRegistryError::InsufficientTokensCommitted
@@ -556,6 +562,7 @@ mod spec_based_tests {
                         amount_wrapped: to_redeem.amount() - 1,
                         btc_address: BtcAddress::random(),
                         vault_id: vault_id.clone(),
+                        max_fee: None,
                     })
                     .dispatch(origin_of(account_of(ALICE))),
                     RedeemError::AmountBelowDustAmount
@@ -564,6 +571,7 @@ mod spec_based_tests {
                     amount_wrapped: to_redeem.amount(),
                     btc_address: BtcAddress::random(),
                     vault_id: vault_id.clone(),
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(ALICE))));
             });
@@ -595,6 +603,7 @@ mod spec_based_tests {
                     amount_wrapped: amount_btc.amount(),
                     btc_address: BtcAddress::random(),
                     vault_id: different_collateral_vault_id.clone(),
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(ALICE))));
             });
@@ -780,7 +789,8 @@ mod spec_based_tests {
             assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
                 amount_wrapped: 4_000,
                 btc_address: BtcAddress::random(),
-                vault_id: vault_id.clone()
+                vault_id: vault_id.clone(),
+                max_fee: None,
             })
             .dispatch(origin_of(account_of(USER))));
             // get the redeem id
@@ -1426,6 +1436,32 @@ fn integration_test_redeem_execute_succeeds() {
     });
 }
 
+#[test]
+fn integration_test_redeem_to_taproot_address_succeeds() {
+    test_with(|vault_id| {
+        let issued_tokens = vault_id.wrapped(10_000);
+        let user_btc_address = BtcAddress::P2TRv1(H256([2; 32]));
+
+        assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
+            amount_wrapped: issued_tokens.amount(),
+            btc_address: user_btc_address,
+            vault_id: vault_id.clone(),
+            max_fee: None,
+        })
+        .dispatch(origin_of(account_of(USER))));
+
+        let redeem_id = assert_redeem_request_event();
+        let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
+        assert_eq!(redeem.btc_address, user_btc_address);
+
+        ExecuteRedeemBuilder::new(redeem_id).assert_execute();
+        assert_eq!(
+            RedeemPallet::redeem_requests(redeem_id).unwrap().status,
+            redeem::RedeemRequestStatus::Completed
+        );
+    });
+}
+
 #[test]
 fn integration_test_execute_redeem_on_banned_vault_succeeds() {
     test_with(|vault_id| {
@@ -1467,7 +1503,8 @@ fn integration_test_premium_redeem_wrapped_execute() {
         assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
             amount_wrapped: issued_tokens.amount(),
             btc_address: user_btc_address,
-            vault_id: vault_id.clone()
+            vault_id: vault_id.clone(),
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(USER))));
 
@@ -1523,7 +1560,8 @@ fn integration_test_multiple_redeems_multiple_op_returns() {
         assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
             amount_wrapped: issued_tokens.amount(),
             btc_address: user_1_btc_address,
-            vault_id: vault_id.clone()
+            vault_id: vault_id.clone(),
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(ALICE))));
 
@@ -1534,7 +1572,8 @@ fn integration_test_multiple_redeems_multiple_op_returns() {
         assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
             amount_wrapped: issued_tokens.amount(),
             btc_address: user_2_btc_address,
-            vault_id: vault_id.clone()
+            vault_id: vault_id.clone(),
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(CAROL))));
 
@@ -1584,7 +1623,8 @@ fn integration_test_single_redeem_multiple_op_returns() {
         assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
             amount_wrapped: issued_tokens.amount(),
             btc_address: user_btc_address,
-            vault_id: vault_id.clone()
+            vault_id: vault_id.clone(),
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(ALICE))));
 
@@ -2381,7 +2421,8 @@ mod oracle_down {
                 RuntimeCall::Redeem(RedeemCall::request_redeem {
                     amount_wrapped: 10_000,
                     btc_address: USER_BTC_ADDRESS,
-                    vault_id: vault_id.clone()
+                    vault_id: vault_id.clone(),
+                    max_fee: None,
                 })
                 .dispatch(origin_of(account_of(USER))),
                 OracleError::MissingExchangeRate
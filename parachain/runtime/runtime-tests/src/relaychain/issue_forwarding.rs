@@ -0,0 +1,108 @@
+use crate::{
+    relaychain::kusama_test_net::*,
+    setup::{assert_eq, *},
+    utils::issue_utils::*,
+};
+use currency::Amount;
+use primitives::CustomMetadata;
+use xcm_emulator::TestExt;
+
+const FOREIGN_KBTC: u32 = 1;
+
+fn sibling_account(account: [u8; 32]) -> MultiLocation {
+    MultiLocation::new(
+        1,
+        X2(
+            Parachain(SIBLING_PARA_ID),
+            Junction::AccountId32 {
+                network: None,
+                id: account,
+            },
+        ),
+    )
+}
+
+fn sibling_sovereign_account() -> AccountId {
+    polkadot_parachain::primitives::Sibling::from(SIBLING_PARA_ID).into_account_truncating()
+}
+
+fn register_kbtc_on_sibling() {
+    let kbtc_location = Kintsugi::execute_with(|| non_canonical_currency_location(Token(KBTC)));
+
+    Sibling::execute_with(|| {
+        assert_ok!(AssetRegistry::register_asset(
+            root(),
+            AssetMetadata {
+                decimals: 8,
+                name: b"Kintsugi BTC".to_vec(),
+                symbol: b"KBTC".to_vec(),
+                existential_deposit: 0,
+                location: Some(kbtc_location.into()),
+                additional: CustomMetadata {
+                    fee_per_second: ksm_per_second() / 1_500_000,
+                    coingecko_id: vec![],
+                },
+            },
+            Some(FOREIGN_KBTC),
+        ));
+    });
+}
+
+fn request_and_execute_issue(
+    destination: MultiLocation,
+) -> IssueRequest<AccountId32, BlockNumber, Balance, CurrencyId> {
+    initialize_parachain();
+    initialize_relay_and_oracle();
+
+    let vault_id = default_vault_id_of(VAULT);
+    let (issue_id, issue) = RequestIssueBuilder::new(&vault_id, Amount::new(100_000, DEFAULT_WRAPPED_CURRENCY))
+        .with_destination(destination)
+        .request();
+    ExecuteIssueBuilder::new(issue_id).assert_execute();
+    issue
+}
+
+#[test]
+fn integration_test_issue_forwards_tokens_to_sibling() {
+    TestNet::reset();
+    register_kbtc_on_sibling();
+
+    let issue_amount = Kintsugi::execute_with(|| {
+        let issue = request_and_execute_issue(sibling_account(BOB));
+
+        assert!(SystemPallet::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Issue(IssueEvent::IssueForwarded { amount, .. }) if amount == issue.amount
+        )));
+        // kbtc is reserved on kintsugi, so the sibling's sovereign account holds the forwarded tokens
+        assert_eq!(TokensPallet::free_balance(Token(KBTC), &account_of(USER)), 0);
+        assert_eq!(
+            TokensPallet::free_balance(Token(KBTC), &sibling_sovereign_account()),
+            issue.amount
+        );
+        issue.amount
+    });
+
+    Sibling::execute_with(|| {
+        // execution on the sibling is paid from the forwarded tokens
+        let received = TokensPallet::free_balance(ForeignAsset(FOREIGN_KBTC), &account_of(BOB));
+        assert!(received > 0 && received < issue_amount);
+    });
+}
+
+#[test]
+fn integration_test_issue_keeps_tokens_if_forwarding_fails() {
+    TestNet::reset();
+
+    Kintsugi::execute_with(|| {
+        // there is no channel to this parachain, so the transfer can't be sent
+        let unreachable = MultiLocation::new(1, X2(Parachain(3000), Junction::AccountId32 { network: None, id: BOB }));
+        let issue = request_and_execute_issue(unreachable);
+
+        assert!(SystemPallet::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Issue(IssueEvent::IssueForwardingFailed { amount, .. }) if amount == issue.amount
+        )));
+        assert_eq!(TokensPallet::free_balance(Token(KBTC), &account_of(USER)), issue.amount);
+    });
+}
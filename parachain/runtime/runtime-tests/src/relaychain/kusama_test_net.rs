@@ -0,0 +1,111 @@
+use crate::setup::{ExtBuilder, ALICE};
+use frame_support::weights::Weight;
+use polkadot_primitives::{BlockNumber, MAX_CODE_SIZE, MAX_POV_SIZE};
+use polkadot_runtime_parachains::configuration::HostConfiguration;
+use primitives::TokenSymbol::KSM;
+use sp_runtime::AccountId32;
+use xcm_emulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub const KINTSUGI_PARA_ID: u32 = 2092;
+pub const SIBLING_PARA_ID: u32 = 2001;
+
+decl_test_relay_chain! {
+    pub struct KusamaRelay {
+        Runtime = kusama_runtime::Runtime,
+        XcmConfig = kusama_runtime::xcm_config::XcmConfig,
+        new_ext = kusama_ext(),
+    }
+}
+
+decl_test_parachain! {
+    pub struct Kintsugi {
+        Runtime = kintsugi_runtime_parachain::Runtime,
+        RuntimeOrigin = kintsugi_runtime_parachain::RuntimeOrigin,
+        XcmpMessageHandler = kintsugi_runtime_parachain::XcmpQueue,
+        DmpMessageHandler = kintsugi_runtime_parachain::DmpQueue,
+        new_ext = para_ext(KINTSUGI_PARA_ID),
+    }
+}
+
+// a second kintsugi instance, which only knows kbtc as a foreign asset
+decl_test_parachain! {
+    pub struct Sibling {
+        Runtime = kintsugi_runtime_parachain::Runtime,
+        RuntimeOrigin = kintsugi_runtime_parachain::RuntimeOrigin,
+        XcmpMessageHandler = kintsugi_runtime_parachain::XcmpQueue,
+        DmpMessageHandler = kintsugi_runtime_parachain::DmpQueue,
+        new_ext = para_ext(SIBLING_PARA_ID),
+    }
+}
+
+decl_test_network! {
+    pub struct TestNet {
+        relay_chain = KusamaRelay,
+        parachains = vec![
+            (2092, Kintsugi),
+            (2001, Sibling),
+        ],
+    }
+}
+
+fn default_parachains_host_configuration() -> HostConfiguration<BlockNumber> {
+    HostConfiguration {
+        minimum_validation_upgrade_delay: 5,
+        validation_upgrade_cooldown: 5u32,
+        validation_upgrade_delay: 5,
+        code_retention_period: 1200,
+        max_code_size: MAX_CODE_SIZE,
+        max_pov_size: MAX_POV_SIZE,
+        max_head_data_size: 32 * 1024,
+        max_upward_queue_count: 8,
+        max_upward_queue_size: 1024 * 1024,
+        max_downward_message_size: 1024,
+        ump_service_total_weight: Weight::from_parts(4 * 1_000_000_000, 0),
+        max_upward_message_size: 50 * 1024,
+        max_upward_message_num_per_candidate: 5,
+        hrmp_sender_deposit: 0,
+        hrmp_recipient_deposit: 0,
+        hrmp_channel_max_capacity: 8,
+        hrmp_channel_max_total_size: 8 * 1024,
+        hrmp_max_parachain_inbound_channels: 4,
+        hrmp_channel_max_message_size: 1024 * 1024,
+        hrmp_max_parachain_outbound_channels: 4,
+        hrmp_max_message_num_per_candidate: 5,
+        ..Default::default()
+    }
+}
+
+pub fn kusama_ext() -> sp_io::TestExternalities {
+    use kusama_runtime::{Runtime, System};
+
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![(AccountId32::from(ALICE), 2002 * KSM.one())],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    polkadot_runtime_parachains::configuration::GenesisConfig::<Runtime> {
+        config: default_parachains_host_configuration(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    pallet_xcm::GenesisConfig::<Runtime> {
+        safe_xcm_version: Some(2),
+        _config: Default::default(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+    ExtBuilder::build_parachain(para_id)
+}
@@ -0,0 +1,3 @@
+mod issue_forwarding;
+mod kusama_test_net;
+mod remote_requests;
@@ -0,0 +1,211 @@
+use crate::{
+    relaychain::kusama_test_net::*,
+    setup::{assert_eq, *},
+    utils::{issue_utils::*, redeem_utils::USER_BTC_ADDRESS},
+};
+use currency::Amount;
+use remote_requests::Event as RemoteRequestsEvent;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use xcm_emulator::TestExt;
+use xcm_executor::traits::ConvertLocation;
+
+/// Location of `ALICE` on the sibling, as seen from kintsugi.
+fn remote_origin() -> MultiLocation {
+    MultiLocation::new(
+        1,
+        X2(
+            Parachain(SIBLING_PARA_ID),
+            Junction::AccountId32 {
+                network: Some(NetworkId::Kusama),
+                id: ALICE,
+            },
+        ),
+    )
+}
+
+fn remote_account() -> AccountId {
+    RemoteLocationToAccountId::convert_location(&remote_origin()).unwrap()
+}
+
+fn sibling_sovereign_account() -> AccountId {
+    polkadot_parachain::primitives::Sibling::from(SIBLING_PARA_ID).into_account_truncating()
+}
+
+fn setup_kintsugi() -> VaultId {
+    initialize_parachain();
+    initialize_relay_and_oracle();
+
+    let vault_id = default_vault_id_of(VAULT);
+    try_register_vault(
+        Amount::new(DEFAULT_COLLATERAL, vault_id.collateral_currency()),
+        &vault_id,
+    );
+    // pays for execution on kintsugi
+    assert_ok!(<TokensPallet as MultiCurrency<AccountId>>::deposit(
+        Token(KSM),
+        &sibling_sovereign_account(),
+        KSM.one()
+    ));
+    vault_id
+}
+
+/// Lets `ALICE` on the sibling dispatch `call` on kintsugi, with the execution paid by the sovereign
+/// account of the sibling.
+fn transact_from_sibling(call: RuntimeCall) {
+    Sibling::execute_with(|| {
+        let fees: MultiAsset = (MultiLocation::parent(), KSM.one() / 10).into();
+        let message = Xcm(vec![
+            WithdrawAsset(fees.clone().into()),
+            BuyExecution {
+                fees,
+                weight_limit: Unlimited,
+            },
+            DescendOrigin(X1(Junction::AccountId32 {
+                network: Some(NetworkId::Kusama),
+                id: ALICE,
+            })),
+            Transact {
+                origin_kind: OriginKind::Xcm,
+                require_weight_at_most: Weight::from_parts(20_000_000_000, 200_000),
+                call: call.encode().into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: All.into(),
+                beneficiary: MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+            },
+        ]);
+        assert_ok!(send_xcm::<XcmRouter>(
+            MultiLocation::new(1, X1(Parachain(KINTSUGI_PARA_ID))),
+            message
+        ));
+    });
+}
+
+fn assert_indexed_by_remote_origin() {
+    let topic = BlakeTwo256::hash_of(&remote_origin());
+    assert!(!SystemPallet::event_topics(topic).is_empty());
+}
+
+#[test]
+fn integration_test_remote_issue_is_requested_by_derived_account() {
+    TestNet::reset();
+
+    let vault_id = Kintsugi::execute_with(|| {
+        let vault_id = setup_kintsugi();
+        assert_ok!(<TokensPallet as MultiCurrency<AccountId>>::deposit(
+            DEFAULT_GRIEFING_CURRENCY,
+            &remote_account(),
+            DEFAULT_COLLATERAL
+        ));
+        vault_id
+    });
+
+    let beneficiary = MultiLocation::new(
+        1,
+        X2(
+            Parachain(SIBLING_PARA_ID),
+            Junction::AccountId32 { network: None, id: BOB },
+        ),
+    );
+    transact_from_sibling(RuntimeCall::RemoteRequests(remote_requests::Call::request_issue {
+        amount: 100_000,
+        vault_id,
+        griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+        beneficiary: Box::new(beneficiary.into()),
+        max_fee: None,
+    }));
+
+    Kintsugi::execute_with(|| {
+        let issue_id = SystemPallet::events()
+            .iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::RemoteRequests(RemoteRequestsEvent::RemoteIssueRequested {
+                    origin,
+                    ref account_id,
+                    issue_id,
+                    beneficiary: requested_beneficiary,
+                }) => {
+                    assert_eq!(origin, remote_origin());
+                    assert_eq!(account_id, &remote_account());
+                    assert_eq!(requested_beneficiary, beneficiary);
+                    Some(issue_id)
+                }
+                _ => None,
+            })
+            .expect("remote issue was not requested");
+        assert_indexed_by_remote_origin();
+
+        let issue = IssuePallet::get_issue_request_from_id(&issue_id).unwrap();
+        assert_eq!(issue.requester, remote_account());
+    });
+}
+
+#[test]
+fn integration_test_remote_redeem_burns_tokens_of_derived_account() {
+    TestNet::reset();
+
+    let (vault_id, amount) = Kintsugi::execute_with(|| {
+        let vault_id = setup_kintsugi();
+        // issue tokens locally and hand them to the remote account, as a reserve transfer would
+        let (issue_id, issue) = request_issue(&vault_id, Amount::new(100_000, DEFAULT_WRAPPED_CURRENCY));
+        ExecuteIssueBuilder::new(issue_id).assert_execute();
+        assert_ok!(<TokensPallet as MultiCurrency<AccountId>>::transfer(
+            DEFAULT_WRAPPED_CURRENCY,
+            &account_of(USER),
+            &remote_account(),
+            issue.amount
+        ));
+        (vault_id, issue.amount)
+    });
+
+    transact_from_sibling(RuntimeCall::RemoteRequests(remote_requests::Call::request_redeem {
+        amount_wrapped: amount,
+        btc_address: USER_BTC_ADDRESS,
+        vault_id,
+        max_fee: None,
+    }));
+
+    Kintsugi::execute_with(|| {
+        let redeem_id = SystemPallet::events()
+            .iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::RemoteRequests(RemoteRequestsEvent::RemoteRedeemRequested {
+                    origin, redeem_id, ..
+                }) if origin == remote_origin() => Some(redeem_id),
+                _ => None,
+            })
+            .expect("remote redeem was not requested");
+        assert_indexed_by_remote_origin();
+
+        let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
+        assert_eq!(redeem.redeemer, remote_account());
+        assert_eq!(
+            TokensPallet::free_balance(DEFAULT_WRAPPED_CURRENCY, &remote_account()),
+            0
+        );
+    });
+}
+
+#[test]
+fn integration_test_other_transacts_are_rejected() {
+    TestNet::reset();
+
+    Kintsugi::execute_with(|| {
+        setup_kintsugi();
+    });
+
+    transact_from_sibling(RuntimeCall::Tokens(TokensCall::transfer {
+        dest: account_of(BOB),
+        currency_id: Token(KSM),
+        amount: 1,
+    }));
+
+    Kintsugi::execute_with(|| {
+        // the barrier rejects the message before anything is withdrawn
+        assert_eq!(
+            TokensPallet::free_balance(Token(KSM), &sibling_sovereign_account()),
+            KSM.one()
+        );
+    });
+}
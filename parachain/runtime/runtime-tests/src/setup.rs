@@ -1,5 +1,5 @@
 pub use crate::utils::*;
-use bitcoin::merkle::PartialTransactionProof;
+use bitcoin::{merkle::PartialTransactionProof, Network};
 pub use codec::Encode;
 pub use frame_support::{assert_noop, assert_ok, traits::Currency, BoundedVec};
 pub use frame_system::RawOrigin;
@@ -61,6 +61,26 @@ pub struct ExtBuilder {
 
 impl ExtBuilder {
     pub fn build() -> Self {
+        Self {
+            test_externalities: sp_io::TestExternalities::from(Self::genesis()),
+        }
+    }
+
+    /// Genesis of a parachain in the XCM test network
+    pub fn build_parachain(para_id: u32) -> sp_io::TestExternalities {
+        let mut storage = Self::genesis();
+
+        parachain_info::GenesisConfig::<Runtime> {
+            parachain_id: para_id.into(),
+            _config: Default::default(),
+        }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+        sp_io::TestExternalities::from(storage)
+    }
+
+    fn genesis() -> sp_runtime::Storage {
         let mut storage = frame_system::GenesisConfig::<Runtime>::default()
             .build_storage()
             .unwrap();
@@ -108,8 +128,10 @@ impl ExtBuilder {
         btc_relay::GenesisConfig::<Runtime> {
             bitcoin_confirmations: CONFIRMATIONS,
             parachain_confirmations: CONFIRMATIONS,
-            disable_difficulty_check: true,
+            bitcoin_network: Network::Regtest,
             disable_inclusion_check: false,
+            stale_fork_depth: None,
+            main_chain_retention: None,
         }
         .assimilate_storage(&mut storage)
         .unwrap();
@@ -200,31 +222,13 @@ impl ExtBuilder {
         .assimilate_storage(&mut storage)
         .unwrap();
 
-        Self {
-            test_externalities: sp_io::TestExternalities::from(storage),
-        }
+        storage
     }
 
     /// do setup common to all integration tests, then execute the callback
     pub fn execute_with<R>(self, execute: impl FnOnce() -> R) -> R {
         self.execute_without_relay_init(|| {
-            // initialize btc relay
-            let _ = TransactionGenerator::new().with_confirmations(7).mine();
-
-            assert_ok!(RuntimeCall::Oracle(OracleCall::insert_authorized_oracle {
-                account_id: account_of(ALICE),
-                name: BoundedVec::truncate_from(vec![])
-            })
-            .dispatch(root()));
-            assert_ok!(RuntimeCall::Oracle(OracleCall::feed_values {
-                values: vec![
-                    (OracleKey::ExchangeRate(DEFAULT_COLLATERAL_CURRENCY), FixedU128::from(1)),
-                    (OracleKey::ExchangeRate(DEFAULT_GRIEFING_CURRENCY), FixedU128::from(1)),
-                    (OracleKey::FeeEstimation, FixedU128::from(3)),
-                ]
-            })
-            .dispatch(origin_of(account_of(ALICE))));
-            OraclePallet::begin_block(0);
+            initialize_relay_and_oracle();
 
             let ret = execute();
             VaultRegistryPallet::total_user_vault_collateral_integrity_check();
@@ -236,14 +240,7 @@ impl ExtBuilder {
     /// used for btc-relay test
     pub fn execute_without_relay_init<R>(mut self, execute: impl FnOnce() -> R) -> R {
         self.test_externalities.execute_with(|| {
-            SystemPallet::set_block_number(1); // required to be able to dispatch functions
-            SecurityPallet::set_active_block_number(1);
-
-            assert_ok!(OraclePallet::_set_exchange_rate(
-                DEFAULT_COLLATERAL_CURRENCY,
-                FixedU128::one()
-            ));
-            set_default_thresholds();
+            initialize_parachain();
 
             let ret = execute();
             VaultRegistryPallet::total_user_vault_collateral_integrity_check();
@@ -251,3 +248,35 @@ impl ExtBuilder {
         })
     }
 }
+
+/// common state for all integration tests, used outside of `ExtBuilder` by the XCM test network
+pub fn initialize_parachain() {
+    SystemPallet::set_block_number(1); // required to be able to dispatch functions
+    SecurityPallet::set_active_block_number(1);
+
+    assert_ok!(OraclePallet::_set_exchange_rate(
+        DEFAULT_COLLATERAL_CURRENCY,
+        FixedU128::one()
+    ));
+    set_default_thresholds();
+}
+
+pub fn initialize_relay_and_oracle() {
+    // initialize btc relay
+    let _ = TransactionGenerator::new().with_confirmations(7).mine();
+
+    assert_ok!(RuntimeCall::Oracle(OracleCall::insert_authorized_oracle {
+        account_id: account_of(ALICE),
+        name: BoundedVec::truncate_from(vec![])
+    })
+    .dispatch(root()));
+    assert_ok!(RuntimeCall::Oracle(OracleCall::feed_values {
+        values: vec![
+            (OracleKey::ExchangeRate(DEFAULT_COLLATERAL_CURRENCY), FixedU128::from(1)),
+            (OracleKey::ExchangeRate(DEFAULT_GRIEFING_CURRENCY), FixedU128::from(1)),
+            (OracleKey::FeeEstimation, FixedU128::from(3)),
+        ]
+    })
+    .dispatch(origin_of(account_of(ALICE))));
+    OraclePallet::begin_block(0);
+}
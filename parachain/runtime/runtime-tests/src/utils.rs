@@ -1236,7 +1236,7 @@ impl TransactionGenerator {
             let init_block = BlockBuilder::new()
                 .with_version(4)
                 .with_coinbase(&self.coinbase_destination, 50, 3)
-                .with_timestamp(1588813835)
+                .with_timestamp(timestamp_at(height))
                 .mine(U256::from(2).pow(254.into()))
                 .unwrap();
 
@@ -1293,7 +1293,7 @@ impl TransactionGenerator {
             .with_previous_hash(prev_hash)
             .with_version(4)
             .with_coinbase(&self.coinbase_destination, 50, 3)
-            .with_timestamp(1588814835)
+            .with_timestamp(timestamp_at(height))
             .add_transaction(transaction.clone())
             .mine(U256::from(2).pow(254.into()))
             .unwrap();
@@ -1309,15 +1309,13 @@ impl TransactionGenerator {
 
         // Mine six new blocks to get over required confirmations
         let mut prev_block_hash = block.header.hash;
-        let mut timestamp = 1588814835;
         for _ in 0..extra_confirmations {
             height += 1;
-            timestamp += 1000;
             let conf_block = BlockBuilder::new()
                 .with_previous_hash(prev_block_hash)
                 .with_version(4)
                 .with_coinbase(&self.coinbase_destination, 50, 3)
-                .with_timestamp(timestamp)
+                .with_timestamp(timestamp_at(height))
                 .mine(U256::from(2).pow(254.into()))
                 .unwrap();
 
@@ -1357,6 +1355,11 @@ impl TransactionGenerator {
     }
 }
 
+/// Bitcoin timestamp of the block at the given height, increasing by the target spacing
+fn timestamp_at(height: u32) -> u32 {
+    1588813835 + height * btc_relay::TARGET_SPACING
+}
+
 pub fn generate_transaction_and_mine(
     signer: BtcPublicKey,
     inputs: Vec<(Transaction, u32, Option<BtcPublicKey>)>,
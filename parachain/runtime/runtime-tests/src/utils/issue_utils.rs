@@ -1,6 +1,7 @@
 use crate::setup::{assert_eq, *};
 use currency::Amount;
 use frame_support::transactional;
+use xcm::VersionedMultiLocation;
 
 pub const USER: [u8; 32] = ALICE;
 pub const VAULT: [u8; 32] = BOB;
@@ -20,6 +21,7 @@ pub struct RequestIssueBuilder {
     vault_id: VaultId,
     griefing_currency: CurrencyId,
     user: [u8; 32],
+    destination: Option<Box<VersionedMultiLocation>>,
 }
 
 impl RequestIssueBuilder {
@@ -29,6 +31,7 @@ impl RequestIssueBuilder {
             vault_id: vault_id.clone(),
             user: USER,
             griefing_currency: DEFAULT_GRIEFING_CURRENCY,
+            destination: None,
         }
     }
 
@@ -47,6 +50,11 @@ impl RequestIssueBuilder {
         self
     }
 
+    pub fn with_destination(&mut self, destination: MultiLocation) -> &mut Self {
+        self.destination = Some(Box::new(destination.into()));
+        self
+    }
+
     pub fn try_request(&self) -> DispatchResultWithPostInfo {
         try_register_vault(
             Amount::new(DEFAULT_COLLATERAL, self.vault_id.collateral_currency()),
@@ -57,6 +65,8 @@ impl RequestIssueBuilder {
             amount: self.amount_btc,
             vault_id: self.vault_id.clone(),
             griefing_currency: self.griefing_currency,
+            destination: self.destination.clone(),
+            max_fee: None,
         })
         .dispatch(origin_of(account_of(self.user)))
     }
@@ -130,7 +130,8 @@ pub fn setup_redeem(issued_tokens: Amount<Runtime>, user: [u8; 32], vault: &Vaul
     assert_ok!(RuntimeCall::Redeem(RedeemCall::request_redeem {
         amount_wrapped: issued_tokens.amount(),
         btc_address: USER_BTC_ADDRESS,
-        vault_id: vault.clone()
+        vault_id: vault.clone(),
+        max_fee: None,
     })
     .dispatch(origin_of(account_of(user))));
 
@@ -69,7 +69,7 @@ pub fn interlay_dev_config(enable_instant_seal: bool) -> InterlayDevChainSpec {
                 Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
                 id,
                 1,
-                false, // disable difficulty check
+                Network::Mainnet,
             ),
             enable_instant_seal,
             enable_create: true,
@@ -141,7 +141,7 @@ pub fn interlay_mainnet_config() -> InterlayChainSpec {
                 None, // no sudo key
                 id,
                 SECURE_BITCOIN_CONFIRMATIONS,
-                false, // enable difficulty check
+                Network::Mainnet,
             )
         },
         Vec::new(),
@@ -164,7 +164,7 @@ pub fn interlay_genesis(
     root_key: Option<AccountId>,
     id: ParaId,
     bitcoin_confirmations: u32,
-    disable_difficulty_check: bool,
+    bitcoin_network: Network,
 ) -> interlay_runtime::RuntimeGenesisConfig {
     let chain_id: u32 = id.into();
     endowed_accounts.extend(
@@ -221,8 +221,10 @@ pub fn interlay_genesis(
         btc_relay: interlay_runtime::BTCRelayConfig {
             bitcoin_confirmations,
             parachain_confirmations: bitcoin_confirmations.saturating_mul(interlay_runtime::BITCOIN_BLOCK_SPACING),
-            disable_difficulty_check,
+            bitcoin_network,
             disable_inclusion_check: false,
+            stale_fork_depth: Some(interlay_runtime::DEFAULT_STALE_FORK_DEPTH),
+            main_chain_retention: None,
         },
         issue: interlay_runtime::IssueConfig {
             issue_period: interlay_runtime::DAYS,
@@ -72,7 +72,7 @@ pub fn kintsugi_dev_config(enable_instant_seal: bool) -> KintsugiDevChainSpec {
                 Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
                 id,
                 1,
-                false, // disable difficulty check
+                Network::Mainnet,
             ),
             enable_instant_seal,
             enable_create: true,
@@ -149,7 +149,7 @@ pub fn kintsugi_mainnet_config() -> KintsugiChainSpec {
                 None, // no sudo key
                 id,
                 SECURE_BITCOIN_CONFIRMATIONS,
-                false, // enable difficulty check
+                Network::Mainnet,
             )
         },
         Vec::new(),
@@ -172,7 +172,7 @@ pub fn kintsugi_genesis(
     root_key: Option<AccountId>,
     id: ParaId,
     bitcoin_confirmations: u32,
-    disable_difficulty_check: bool,
+    bitcoin_network: Network,
 ) -> kintsugi_runtime::RuntimeGenesisConfig {
     let chain_id: u32 = id.into();
     endowed_accounts.extend(
@@ -229,8 +229,10 @@ pub fn kintsugi_genesis(
         btc_relay: kintsugi_runtime::BTCRelayConfig {
             bitcoin_confirmations,
             parachain_confirmations: bitcoin_confirmations.saturating_mul(kintsugi_runtime::BITCOIN_BLOCK_SPACING),
-            disable_difficulty_check,
+            bitcoin_network,
             disable_inclusion_check: false,
+            stale_fork_depth: Some(kintsugi_runtime::DEFAULT_STALE_FORK_DEPTH),
+            main_chain_retention: None,
         },
         issue: kintsugi_runtime::IssueConfig {
             issue_period: kintsugi_runtime::DAYS * 2,
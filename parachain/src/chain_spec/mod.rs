@@ -1,4 +1,7 @@
-use bitcoin::utils::{virtual_transaction_size, InputType, TransactionInputMetadata, TransactionOutputMetadata};
+use bitcoin::{
+    utils::{virtual_transaction_size, InputType, TransactionInputMetadata, TransactionOutputMetadata},
+    Network,
+};
 use cumulus_primitives_core::ParaId;
 use frame_support::BoundedVec;
 use hex_literal::hex;
@@ -72,7 +72,7 @@ pub fn staging_mainnet_config(benchmarking: bool) -> InterlayChainSpec {
                 )),
                 PARA_ID.into(),
                 DEFAULT_BITCOIN_CONFIRMATIONS,
-                true, // disable difficulty check
+                Network::Testnet,
             )
         },
         Vec::new(),
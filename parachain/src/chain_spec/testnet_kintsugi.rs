@@ -46,7 +46,7 @@ pub fn local_config(id: ParaId) -> KintsugiChainSpec {
                 None,
                 id,
                 DEFAULT_BITCOIN_CONFIRMATIONS,
-                true, // disable difficulty check
+                Network::Regtest,
             )
         },
         vec![],
@@ -101,7 +101,7 @@ pub fn development_config(id: ParaId, enable_instant_seal: bool) -> KintsugiDevC
                 Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
                 id,
                 DEFAULT_BITCOIN_CONFIRMATIONS,
-                true, // disable difficulty check
+                Network::Regtest,
             ),
             enable_instant_seal,
             enable_create: true,
@@ -175,7 +175,7 @@ pub fn staging_mainnet_config(benchmarking: bool) -> KintsugiChainSpec {
                 )),
                 PARA_ID.into(),
                 DEFAULT_BITCOIN_CONFIRMATIONS,
-                true, // disable difficulty check
+                Network::Testnet,
             )
         },
         Vec::new(),
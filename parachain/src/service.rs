@@ -92,7 +92,7 @@ pub trait RuntimeApiCollection:
     + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
     + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
     + cumulus_primitives_core::CollectCollationInfo<Block>
-    + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le>
+    + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le, BlockNumber, FullTransactionProof>
     + oracle_rpc_runtime_api::OracleApi<Block, Balance, CurrencyId>
     + vault_registry_rpc_runtime_api::VaultRegistryApi<
         Block,
@@ -107,6 +107,7 @@ pub trait RuntimeApiCollection:
         AccountId,
         H256,
         issue::IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        query::RequestQuery<AccountId, CurrencyId, issue::IssueRequestStatus>,
     > + redeem_rpc_runtime_api::RedeemApi<
         Block,
         VaultId<AccountId, CurrencyId>,
@@ -114,11 +115,13 @@ pub trait RuntimeApiCollection:
         AccountId,
         H256,
         redeem::RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        query::RequestQuery<AccountId, CurrencyId, redeem::RedeemRequestStatus>,
     > + replace_rpc_runtime_api::ReplaceApi<
         Block,
         AccountId,
         H256,
         replace::ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        query::RequestQuery<AccountId, CurrencyId, replace::ReplaceRequestStatus>,
     > + reward_rpc_runtime_api::RewardApi<
         Block,
         AccountId,
@@ -142,7 +145,7 @@ where
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + cumulus_primitives_core::CollectCollationInfo<Block>
-        + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le>
+        + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le, BlockNumber, FullTransactionProof>
         + oracle_rpc_runtime_api::OracleApi<Block, Balance, CurrencyId>
         + vault_registry_rpc_runtime_api::VaultRegistryApi<
             Block,
@@ -157,6 +160,7 @@ where
             AccountId,
             H256,
             issue::IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+            query::RequestQuery<AccountId, CurrencyId, issue::IssueRequestStatus>,
         > + redeem_rpc_runtime_api::RedeemApi<
             Block,
             VaultId<AccountId, CurrencyId>,
@@ -164,11 +168,13 @@ where
             AccountId,
             H256,
             redeem::RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+            query::RequestQuery<AccountId, CurrencyId, redeem::RedeemRequestStatus>,
         > + replace_rpc_runtime_api::ReplaceApi<
             Block,
             AccountId,
             H256,
             replace::ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+            query::RequestQuery<AccountId, CurrencyId, replace::ReplaceRequestStatus>,
         > + reward_rpc_runtime_api::RewardApi<
             Block,
             AccountId,
@@ -19,7 +19,7 @@ use scale_encode::EncodeAsType;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-pub use bitcoin::types::H256Le;
+pub use bitcoin::types::{FullTransactionProof, H256Le};
 
 pub const BITCOIN_TESTNET: &str = "bitcoin-testnet";
 pub const BITCOIN_MAINNET: &str = "bitcoin-mainnet";
@@ -343,6 +343,99 @@ pub mod replace {
     }
 }
 
+pub mod query {
+    use super::*;
+
+    /// The maximum number of requests returned in a single page.
+    pub const MAX_REQUEST_PAGE_SIZE: u32 = 100;
+
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Copy, Eq, PartialEq, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub enum SortOrder {
+        /// oldest requests first
+        Ascending,
+        /// newest requests first
+        Descending,
+    }
+
+    impl Default for SortOrder {
+        fn default() -> Self {
+            SortOrder::Ascending
+        }
+    }
+
+    /// Filters, ordering and pagination of a request history query.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub struct RequestQuery<AccountId, CurrencyId: Copy, Status> {
+        /// only include requests with this status
+        pub status: Option<Status>,
+        /// only include requests of this vault
+        pub vault: Option<VaultId<AccountId, CurrencyId>>,
+        /// only include requests of vaults with this collateral currency
+        pub currency: Option<CurrencyId>,
+        /// the order of the requests by the *active* block height when they were opened
+        pub order: SortOrder,
+        /// the number of matching requests to skip
+        pub offset: u32,
+        /// the maximum number of requests to return, capped at `MAX_REQUEST_PAGE_SIZE`
+        pub limit: u32,
+    }
+
+    impl<AccountId: PartialEq, CurrencyId: Copy + PartialEq, Status: PartialEq>
+        RequestQuery<AccountId, CurrencyId, Status>
+    {
+        pub fn matches_status(&self, status: &Status) -> bool {
+            self.status.as_ref().map_or(true, |expected| expected == status)
+        }
+
+        pub fn matches_vault(&self, vault: &VaultId<AccountId, CurrencyId>) -> bool {
+            self.vault.as_ref().map_or(true, |expected| expected == vault)
+                && self
+                    .currency
+                    .map_or(true, |currency| currency == vault.collateral_currency())
+        }
+
+        /// Sorts the matching requests by opening time and returns the requested page.
+        ///
+        /// # Arguments
+        ///
+        /// * `requests` - all requests that match the filters of this query
+        /// * `opentime` - the *active* block height when a request was opened
+        pub fn paginate<RequestId: Ord, Request, BlockNumber: Ord>(
+            &self,
+            mut requests: Vec<(RequestId, Request)>,
+            opentime: impl Fn(&Request) -> BlockNumber,
+        ) -> RequestPage<RequestId, Request> {
+            // ties are broken by id so that pages are stable
+            requests.sort_by(|(a_id, a), (b_id, b)| opentime(a).cmp(&opentime(b)).then_with(|| a_id.cmp(b_id)));
+            if self.order == SortOrder::Descending {
+                requests.reverse();
+            }
+
+            let total = requests.len() as u32;
+            let requests = requests
+                .into_iter()
+                .skip(self.offset as usize)
+                .take(self.limit.min(MAX_REQUEST_PAGE_SIZE) as usize)
+                .collect();
+            RequestPage { total, requests }
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub struct RequestPage<RequestId, Request> {
+        /// the number of requests that match the filters, across all pages
+        pub total: u32,
+        /// the requests on this page, with their ids
+        pub requests: Vec<(RequestId, Request)>,
+    }
+}
+
 pub mod oracle {
     use super::*;
 
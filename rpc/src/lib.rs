@@ -4,8 +4,12 @@
 //! capabilities that are specific to this project's runtime configuration.
 
 use primitives::{
-    issue::IssueRequest, redeem::RedeemRequest, replace::ReplaceRequest, AccountId, Balance, Block, BlockNumber,
-    CurrencyId, H256Le, Hash, Nonce, StablePoolId, VaultId,
+    issue::{IssueRequest, IssueRequestStatus},
+    query::RequestQuery,
+    redeem::{RedeemRequest, RedeemRequestStatus},
+    replace::{ReplaceRequest, ReplaceRequestStatus},
+    AccountId, Balance, Block, BlockNumber, CurrencyId, FullTransactionProof, H256Le, Hash, Nonce, StablePoolId,
+    VaultId,
 };
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 pub use sc_rpc_api::DenyUnsafe;
@@ -73,7 +77,7 @@ where
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: btc_relay_rpc::BtcRelayRuntimeApi<Block, H256Le>,
+    C::Api: btc_relay_rpc::BtcRelayRuntimeApi<Block, H256Le, BlockNumber, FullTransactionProof>,
     C::Api: oracle_rpc::OracleRuntimeApi<Block, Balance, CurrencyId>,
     C::Api: vault_registry_rpc::VaultRegistryRuntimeApi<
         Block,
@@ -83,8 +87,13 @@ where
         CurrencyId,
         AccountId,
     >,
-    C::Api:
-        issue_rpc::IssueRuntimeApi<Block, AccountId, H256, IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>>,
+    C::Api: issue_rpc::IssueRuntimeApi<
+        Block,
+        AccountId,
+        H256,
+        IssueRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, IssueRequestStatus>,
+    >,
     C::Api: redeem_rpc::RedeemRuntimeApi<
         Block,
         VaultId<AccountId, CurrencyId>,
@@ -92,12 +101,14 @@ where
         AccountId,
         H256,
         RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, RedeemRequestStatus>,
     >,
     C::Api: replace_rpc::ReplaceRuntimeApi<
         Block,
         AccountId,
         H256,
         ReplaceRequest<AccountId, BlockNumber, Balance, CurrencyId>,
+        RequestQuery<AccountId, CurrencyId, ReplaceRequestStatus>,
     >,
     C::Api: escrow_rpc::EscrowRuntimeApi<Block, AccountId, BlockNumber, Balance>,
     C::Api: reward_rpc::RewardRuntimeApi<
@@ -160,7 +171,7 @@ where
 
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
-    module.merge(BtcRelay::new(client.clone()).into_rpc())?;
+    module.merge(BtcRelay::<_, _, FullTransactionProof>::new(client.clone()).into_rpc())?;
 
     module.merge(Oracle::new(client.clone()).into_rpc())?;
 